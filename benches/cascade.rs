@@ -0,0 +1,39 @@
+use bevy_prng::WyRand;
+use bevy_rand::stress::{build_stress_world, StressConfig};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Reseeds the global source of increasingly large worlds, measuring the full cascade through
+/// every layer.
+fn cascade(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cascade_wyrand");
+
+    for (sources, targets_per_source, depth) in [(64, 16, 2), (16, 16, 3), (8, 8, 4)] {
+        let config = StressConfig {
+            sources,
+            targets_per_source,
+            depth,
+            seed: 0,
+        };
+        let total: usize = (0..depth).map(|layer| config.layer_len(layer)).sum();
+
+        group.throughput(Throughput::Elements(total as u64));
+        group.bench_with_input(
+            BenchmarkId::new("reseed", format!("{sources}x{targets_per_source}^{depth}")),
+            &config,
+            |b, &config| {
+                let mut stress = build_stress_world::<WyRand>(config);
+                let mut seed = 0;
+
+                b.iter(|| {
+                    seed += 1;
+                    stress.reseed(seed);
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, cascade);
+criterion_main!(benches);
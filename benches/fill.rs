@@ -0,0 +1,55 @@
+use bevy_prng::{ChaCha8Rng, EntropySource, WyRand};
+use bevy_rand::prelude::Entropy;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand_core::{RngCore, SeedableRng};
+
+const BUFFER_LEN: usize = 1 << 20;
+
+/// Fills the buffer a word at a time, as a naive per-call loop would.
+fn naive_fill<R: RngCore>(rng: &mut R, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+        let bytes = rng.next_u64().to_le_bytes();
+
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
+
+fn bench_fill<R: EntropySource + 'static>(c: &mut Criterion, name: &str)
+where
+    R::Seed: Send + Sync + Clone + AsMut<[u8]> + Default,
+{
+    let mut group = c.benchmark_group(format!("fill_{name}"));
+    let mut dest = vec![0u8; BUFFER_LEN];
+
+    group.throughput(Throughput::Bytes(BUFFER_LEN as u64));
+
+    group.bench_function("naive", |b| {
+        let mut rng = Entropy::<R>::from_seed(R::Seed::default());
+
+        b.iter(|| naive_fill(&mut rng, &mut dest));
+    });
+
+    group.bench_function("bulk", |b| {
+        let mut rng = Entropy::<R>::from_seed(R::Seed::default());
+
+        b.iter(|| rng.fill_bytes_bulk(&mut dest));
+    });
+
+    for chunk_len in [16 * 1024, 64 * 1024, 256 * 1024] {
+        group.bench_with_input(BenchmarkId::new("par", chunk_len), &chunk_len, |b, &len| {
+            let mut rng = Entropy::<R>::from_seed(R::Seed::default());
+
+            b.iter(|| rng.fill_bytes_par(&mut dest, len));
+        });
+    }
+
+    group.finish();
+}
+
+fn fill(c: &mut Criterion) {
+    bench_fill::<ChaCha8Rng>(c, "chacha8");
+    bench_fill::<WyRand>(c, "wyrand");
+}
+
+criterion_group!(benches, fill);
+criterion_main!(benches);
@@ -1,28 +1,102 @@
 use crate::{newtype::newtype_prng, EntropySource};
 
 use bevy_reflect::{Reflect, ReflectFromReflect};
-use rand_core::{RngCore, SeedableRng};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
 
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
+#[cfg(feature = "chacha8")]
 newtype_prng!(
     ChaCha8Rng,
     ::rand_chacha::ChaCha8Rng,
     "A newtyped [`rand_chacha::ChaCha8Rng`] RNG",
-    "rand_chacha"
+    "chacha8",
+    chacha8_tests
 );
 
+#[cfg(feature = "chacha12")]
 newtype_prng!(
     ChaCha12Rng,
     ::rand_chacha::ChaCha12Rng,
     "A newtyped [`rand_chacha::ChaCha12Rng`] RNG",
-    "rand_chacha"
+    "chacha12",
+    chacha12_tests
 );
 
+#[cfg(feature = "chacha20")]
 newtype_prng!(
     ChaCha20Rng,
     ::rand_chacha::ChaCha20Rng,
     "A newtyped [`rand_chacha::ChaCha20Rng`] RNG",
-    "rand_chacha"
+    "chacha20",
+    chacha20_tests
 );
+
+#[cfg(feature = "chacha8")]
+impl CryptoRng for ChaCha8Rng {}
+
+#[cfg(feature = "chacha12")]
+impl CryptoRng for ChaCha12Rng {}
+
+#[cfg(feature = "chacha20")]
+impl CryptoRng for ChaCha20Rng {}
+
+#[cfg(all(feature = "chacha8", feature = "compat_09"))]
+impl ::rand_core_09::CryptoRng for ChaCha8Rng {}
+
+#[cfg(all(feature = "chacha12", feature = "compat_09"))]
+impl ::rand_core_09::CryptoRng for ChaCha12Rng {}
+
+#[cfg(all(feature = "chacha20", feature = "compat_09"))]
+impl ::rand_core_09::CryptoRng for ChaCha20Rng {}
+
+/// Access to the key, stream and word position of ChaCha based PRNGs. Sources sharing a key but
+/// set to different streams produce independent output, so a single key can provide many
+/// independent sources without drawing any seeds.
+pub trait ChaChaStream: EntropySource {
+    /// Returns the key of the PRNG, which is the seed it was created from.
+    fn get_key(&self) -> Self::Seed;
+
+    /// Returns the stream number of the PRNG.
+    fn get_stream(&self) -> u64;
+
+    /// Sets the stream number of the PRNG, without changing its word position.
+    fn set_stream(&mut self, stream: u64);
+
+    /// Sets the word position of the PRNG within its stream.
+    fn set_word_pos(&mut self, word_offset: u128);
+}
+
+macro_rules! chacha_stream {
+    ($newtype:ty) => {
+        impl ChaChaStream for $newtype {
+            #[inline]
+            fn get_key(&self) -> Self::Seed {
+                self.0.get_seed()
+            }
+
+            #[inline]
+            fn get_stream(&self) -> u64 {
+                self.0.get_stream()
+            }
+
+            #[inline]
+            fn set_stream(&mut self, stream: u64) {
+                self.0.set_stream(stream);
+            }
+
+            #[inline]
+            fn set_word_pos(&mut self, word_offset: u128) {
+                self.0.set_word_pos(word_offset);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chacha8")]
+chacha_stream!(ChaCha8Rng);
+#[cfg(feature = "chacha12")]
+chacha_stream!(ChaCha12Rng);
+#[cfg(feature = "chacha20")]
+chacha_stream!(ChaCha20Rng);
@@ -4,17 +4,27 @@
 #![cfg_attr(docsrs, allow(unused_attributes))]
 #![no_std]
 
-#[cfg(feature = "rand_chacha")]
+#[cfg(feature = "thread_local_entropy")]
+extern crate alloc;
+#[cfg(any(test, feature = "thread_local_entropy"))]
+extern crate std;
+
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
 mod chacha;
 #[cfg(any(
     feature = "wyrand",
-    feature = "rand_chacha",
+    feature = "chacha8",
+    feature = "chacha12",
+    feature = "chacha20",
     feature = "rand_pcg",
     feature = "rand_xoshiro"
 ))]
 mod newtype;
 #[cfg(feature = "rand_pcg")]
 mod pcg;
+mod seed;
+#[cfg(feature = "thread_local_entropy")]
+mod thread_local_entropy;
 #[cfg(feature = "wyrand")]
 mod wyrand;
 #[cfg(feature = "rand_xoshiro")]
@@ -22,15 +32,32 @@ mod xoshiro;
 
 use core::fmt::Debug;
 
+/// The version of `rand_core` that [`EntropySource`] and the newtyped PRNGs are built against.
+/// Import `RngCore`, `SeedableRng` and the rest through this re-export, so that they always
+/// match the traits `bevy_prng` requires, even when another crate in the dependency tree pulls
+/// in a different version of `rand_core`.
+pub use rand_core;
+/// The `rand_core` 0.9 version whose traits are implemented by the newtyped PRNGs with the
+/// `compat_09` feature.
+#[cfg(feature = "compat_09")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compat_09")))]
+pub use rand_core_09;
+
 use bevy_reflect::{FromReflect, Reflectable, Typed};
 use rand_core::{RngCore, SeedableRng};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "rand_chacha")]
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
 pub use chacha::*;
 #[cfg(feature = "rand_pcg")]
 pub use pcg::*;
+pub use seed::Seed512;
+#[cfg(feature = "thread_local_entropy")]
+pub use thread_local_entropy::{
+    configure_thread_local_entropy, ChaChaRounds, ThreadLocalAlreadyConfigured, ThreadLocalConfig,
+    ThreadLocalEntropy,
+};
 #[cfg(feature = "wyrand")]
 pub use wyrand::WyRand;
 #[cfg(feature = "rand_xoshiro")]
@@ -38,7 +65,27 @@ pub use xoshiro::*;
 
 /// A marker trait to define the required trait bounds for a seedable PRNG to
 /// integrate into `Entropy` or `GlobalEntropy`. This is a sealed trait.
+///
+/// The bounds are on the traits of [`rand_core`] 0.6, re-exported as [`crate::rand_core`]. A PRNG
+/// implementing the traits of a different version of `rand_core` does not satisfy them:
+///
+/// ```compile_fail
+/// use bevy_prng::EntropySource;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct NotARng;
+///
+/// fn assert_source<R: EntropySource>() {}
+///
+/// assert_source::<NotARng>();
+/// ```
 #[cfg(feature = "serialize")]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a PRNG that can be used as an `EntropySource`",
+    label = "not an `EntropySource`",
+    note = "`EntropySource` requires `RngCore` and `SeedableRng` from `rand_core` 0.6, available as `bevy_prng::rand_core`",
+    note = "if `{Self}` implements `rand_core` 0.9 traits instead, check `cargo tree -i rand_core` for duplicate versions, or use one of the `bevy_prng` newtypes, which implement both with the `compat_09` feature"
+)]
 pub trait EntropySource:
     RngCore
     + SeedableRng<Seed: Typed>
@@ -63,6 +110,7 @@ pub trait EntropySeed:
     + Default
     + PartialEq
     + AsMut<[u8]>
+    + AsRef<[u8]>
     + Clone
     + Sync
     + Send
@@ -79,6 +127,7 @@ impl<
             + Default
             + PartialEq
             + AsMut<[u8]>
+            + AsRef<[u8]>
             + Clone
             + Sync
             + Send
@@ -93,6 +142,12 @@ impl<
 /// A marker trait to define the required trait bounds for a seedable PRNG to
 /// integrate into `Entropy` or `GlobalEntropy`. This is a sealed trait.
 #[cfg(not(feature = "serialize"))]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a PRNG that can be used as an `EntropySource`",
+    label = "not an `EntropySource`",
+    note = "`EntropySource` requires `RngCore` and `SeedableRng` from `rand_core` 0.6, available as `bevy_prng::rand_core`",
+    note = "if `{Self}` implements `rand_core` 0.9 traits instead, check `cargo tree -i rand_core` for duplicate versions, or use one of the `bevy_prng` newtypes, which implement both with the `compat_09` feature"
+)]
 pub trait EntropySource:
     RngCore
     + SeedableRng<Seed: Typed>
@@ -111,13 +166,31 @@ pub trait EntropySource:
 /// Marker trait for a suitable seed for [`EntropySource`]. This is an auto trait which will
 /// apply to all suitable types that meet the trait criteria.
 pub trait EntropySeed:
-    Debug + Default + PartialEq + AsMut<[u8]> + Clone + Sync + Send + Reflectable + FromReflect
+    Debug
+    + Default
+    + PartialEq
+    + AsMut<[u8]>
+    + AsRef<[u8]>
+    + Clone
+    + Sync
+    + Send
+    + Reflectable
+    + FromReflect
 {
 }
 
 #[cfg(not(feature = "serialize"))]
 impl<
-        T: Debug + Default + PartialEq + AsMut<[u8]> + Clone + Sync + Send + Reflectable + FromReflect,
+        T: Debug
+            + Default
+            + PartialEq
+            + AsMut<[u8]>
+            + AsRef<[u8]>
+            + Clone
+            + Sync
+            + Send
+            + Reflectable
+            + FromReflect,
     > EntropySeed for T
 {
 }
@@ -127,3 +200,59 @@ mod private {
 
     impl<T: super::EntropySource> SealedSeedable for T {}
 }
+
+#[cfg(all(
+    test,
+    any(
+        feature = "wyrand",
+        feature = "chacha8",
+        feature = "chacha12",
+        feature = "chacha20",
+        feature = "rand_pcg",
+        feature = "rand_xoshiro"
+    )
+))]
+mod tests {
+    use super::*;
+
+    /// Fails to compile if a PRNG newtype does not have value equality, as `Entropy` requires.
+    fn assert_eq_impls<T: Eq + EntropySource>() {}
+
+    #[test]
+    fn newtypes_implement_eq() {
+        #[cfg(feature = "wyrand")]
+        assert_eq_impls::<WyRand>();
+
+        #[cfg(feature = "chacha8")]
+        assert_eq_impls::<ChaCha8Rng>();
+        #[cfg(feature = "chacha12")]
+        assert_eq_impls::<ChaCha12Rng>();
+        #[cfg(feature = "chacha20")]
+        assert_eq_impls::<ChaCha20Rng>();
+
+        #[cfg(feature = "rand_pcg")]
+        {
+            assert_eq_impls::<Pcg32>();
+            assert_eq_impls::<Pcg64>();
+            assert_eq_impls::<Pcg64Mcg>();
+        }
+
+        #[cfg(feature = "rand_xoshiro")]
+        {
+            assert_eq_impls::<Xoshiro512StarStar>();
+            assert_eq_impls::<Xoshiro512PlusPlus>();
+            assert_eq_impls::<Xoshiro512Plus>();
+            assert_eq_impls::<Xoshiro256StarStar>();
+            assert_eq_impls::<Xoshiro256PlusPlus>();
+            assert_eq_impls::<Xoshiro256Plus>();
+            assert_eq_impls::<Xoroshiro128StarStar>();
+            assert_eq_impls::<Xoroshiro128PlusPlus>();
+            assert_eq_impls::<Xoroshiro128Plus>();
+            assert_eq_impls::<Xoshiro128StarStar>();
+            assert_eq_impls::<Xoshiro128PlusPlus>();
+            assert_eq_impls::<Xoshiro128Plus>();
+            assert_eq_impls::<Xoroshiro64StarStar>();
+            assert_eq_impls::<Xoroshiro64Star>();
+        }
+    }
+}
@@ -1,7 +1,33 @@
+/// Implements the `rand_core` 0.9 [`RngCore`](::rand_core_09::RngCore) trait for a newtype by
+/// forwarding to its `rand_core` 0.6 implementation, so the output is identical.
+macro_rules! compat_09 {
+    ($newtype:tt) => {
+        #[cfg(feature = "compat_09")]
+        impl ::rand_core_09::RngCore for $newtype {
+            #[inline(always)]
+            fn next_u32(&mut self) -> u32 {
+                RngCore::next_u32(self)
+            }
+
+            #[inline(always)]
+            fn next_u64(&mut self) -> u64 {
+                RngCore::next_u64(self)
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                RngCore::fill_bytes(self, dest)
+            }
+        }
+    };
+}
+
 macro_rules! newtype_prng {
-    ($newtype:tt, $rng:ty, $doc:tt, $feature:tt) => {
+    ($newtype:tt, $rng:ty, $doc:tt, $feature:tt, $tests:ident) => {
         #[doc = $doc]
-        #[derive(Debug, Clone, PartialEq, Reflect)]
+        // All wrapped PRNGs have integer state, so are `Eq`. None of them implement `Hash`, so
+        // it cannot be derived here.
+        #[derive(Debug, Clone, PartialEq, Eq, Reflect)]
         #[reflect(opaque)]
         #[cfg_attr(
             feature = "serialize",
@@ -73,14 +99,37 @@ macro_rules! newtype_prng {
         }
 
         impl EntropySource for $newtype {}
+
+        $crate::newtype::compat_09!($newtype);
+
+        #[cfg(test)]
+        mod $tests {
+            #[test]
+            fn golden_sequence() {
+                $crate::newtype::golden::check::<super::$newtype>(stringify!($newtype));
+            }
+
+            #[cfg(feature = "serialize")]
+            ::proptest::proptest! {
+                #[test]
+                fn serde_round_trips(
+                    seed in ::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 64),
+                    steps in 0usize..256
+                ) {
+                    $crate::newtype::round_trip::check::<super::$newtype>(&seed, steps);
+                }
+            }
+        }
     };
 }
 
 #[cfg(feature = "rand_xoshiro")]
 macro_rules! newtype_prng_remote {
-    ($newtype:tt, $rng:ty, $seed:ty, $doc:tt, $feature:tt) => {
+    ($newtype:tt, $rng:ty, $seed:ty, $doc:tt, $feature:tt, $tests:ident) => {
         #[doc = $doc]
-        #[derive(Debug, Clone, PartialEq, Reflect)]
+        // All wrapped PRNGs have integer state, so are `Eq`. None of them implement `Hash`, so
+        // it cannot be derived here.
+        #[derive(Debug, Clone, PartialEq, Eq, Reflect)]
         #[cfg_attr(
             feature = "serialize",
             derive(::serde::Serialize, ::serde::Deserialize)
@@ -134,7 +183,7 @@ macro_rules! newtype_prng_remote {
 
             #[inline]
             fn from_seed(seed: Self::Seed) -> Self {
-                Self::new(<$rng>::from_seed(seed.0))
+                Self::new(<$rng>::from_seed(seed.into()))
             }
 
             #[inline]
@@ -151,9 +200,223 @@ macro_rules! newtype_prng_remote {
         }
 
         impl EntropySource for $newtype {}
+
+        $crate::newtype::compat_09!($newtype);
+
+        #[cfg(test)]
+        mod $tests {
+            #[test]
+            fn golden_sequence() {
+                $crate::newtype::golden::check::<super::$newtype>(stringify!($newtype));
+            }
+
+            #[cfg(feature = "serialize")]
+            ::proptest::proptest! {
+                #[test]
+                fn serde_round_trips(
+                    seed in ::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 64),
+                    steps in 0usize..256
+                ) {
+                    $crate::newtype::round_trip::check::<super::$newtype>(&seed, steps);
+                }
+            }
+        }
     };
 }
 
+pub(crate) use compat_09;
 pub(crate) use newtype_prng;
 #[cfg(feature = "rand_xoshiro")]
 pub(crate) use newtype_prng_remote;
+
+/// Golden sequence regression tests, generated for every PRNG newtype, so that upstream PRNG
+/// crates changing their output across versions are caught rather than silently breaking
+/// replays. The golden values for each newtype are committed in `golden/<newtype>.txt`, and are
+/// regenerated intentionally by running the tests with `BEVY_PRNG_BLESS` set:
+///
+/// ```text
+/// BEVY_PRNG_BLESS=1 cargo test -p bevy_prng --all-features golden
+/// ```
+#[cfg(test)]
+pub(crate) mod golden {
+    use core::fmt::Write;
+    use std::{format, string::String, vec::Vec};
+
+    use crate::EntropySource;
+
+    /// Number of outputs pinned for each of `next_u32` and `next_u64`.
+    const OUTPUTS: usize = 8;
+
+    /// Number of bytes filled with `fill_bytes` for the digest.
+    const FILL_LEN: usize = 1024;
+
+    fn golden_rng<R: EntropySource>() -> R {
+        let mut seed = R::Seed::default();
+
+        seed.as_mut()
+            .iter_mut()
+            .zip(0u8..)
+            .for_each(|(byte, i)| *byte = i);
+
+        R::from_seed(seed)
+    }
+
+    /// A 64-bit FNV-1a digest, to pin `fill_bytes` output without committing all of it.
+    fn digest(bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+    }
+
+    fn render<R: EntropySource>() -> String {
+        let rng = golden_rng::<R>();
+        let mut out = String::new();
+
+        let mut source = rng.clone();
+        let outputs: Vec<_> = (0..OUTPUTS)
+            .map(|_| format!("{}", source.next_u32()))
+            .collect();
+        writeln!(out, "next_u32: {}", outputs.join(", ")).unwrap();
+
+        let mut source = rng.clone();
+        let outputs: Vec<_> = (0..OUTPUTS)
+            .map(|_| format!("{}", source.next_u64()))
+            .collect();
+        writeln!(out, "next_u64: {}", outputs.join(", ")).unwrap();
+
+        let mut source = rng.clone();
+        let mut bytes = [0; FILL_LEN];
+        source.fill_bytes(&mut bytes);
+        writeln!(out, "fill_bytes: {:016x}", digest(&bytes)).unwrap();
+
+        #[cfg(feature = "serialize")]
+        {
+            let serialized = ron::to_string(&rng).unwrap();
+            let deserialized: R = ron::from_str(&serialized).unwrap();
+
+            assert_eq!(deserialized, rng, "serde round trip changed the state");
+            writeln!(out, "serde: {serialized}").unwrap();
+        }
+
+        out
+    }
+
+    /// Checks the output of `R` against the golden values committed for `name`, or regenerates
+    /// them if `BEVY_PRNG_BLESS` is set.
+    pub(crate) fn check<R: EntropySource>(name: &str) {
+        let path = format!("{}/golden/{name}.txt", env!("CARGO_MANIFEST_DIR"));
+        let actual = render::<R>();
+
+        if std::env::var_os("BEVY_PRNG_BLESS").is_some() {
+            if !cfg!(feature = "serialize") {
+                panic!("golden values must be regenerated with all features enabled");
+            }
+
+            std::fs::write(&path, actual).unwrap();
+
+            return;
+        }
+
+        let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+            panic!(
+                "no golden values for {name} at {path}, generate them with \
+                 `BEVY_PRNG_BLESS=1 cargo test -p bevy_prng --all-features golden`"
+            )
+        });
+
+        // Serde output is only pinned when serialization is enabled.
+        let expected: Vec<_> = expected
+            .lines()
+            .filter(|line| cfg!(feature = "serialize") || !line.starts_with("serde:"))
+            .collect();
+
+        assert_eq!(
+            actual.lines().collect::<Vec<_>>(),
+            expected,
+            "{name} output no longer matches its golden values"
+        );
+    }
+}
+
+/// Serde round trip tests, generated for every PRNG newtype and run with random seeds and numbers
+/// of steps, checking that the state and further output survive round trips through RON, JSON and
+/// postcard, with both plain serde and the reflect (de)serializers.
+#[cfg(all(test, feature = "serialize"))]
+pub(crate) mod round_trip {
+    use bevy_reflect::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        TypeRegistry,
+    };
+    use serde::de::DeserializeSeed;
+
+    use crate::EntropySource;
+
+    fn assert_round_trip<R: EntropySource>(original: &R, mut restored: R, format: &str) {
+        assert_eq!(&restored, original, "{format} round trip changed the state");
+
+        let mut original = original.clone();
+
+        for _ in 0..8 {
+            assert_eq!(
+                restored.next_u64(),
+                original.next_u64(),
+                "{format} round trip changed the output"
+            );
+        }
+    }
+
+    /// Seeds `R` from `seed`, advances it by `steps` outputs and round trips it through every
+    /// supported format.
+    pub(crate) fn check<R: EntropySource>(seed: &[u8], steps: usize) {
+        let mut rng_seed = R::Seed::default();
+
+        rng_seed
+            .as_mut()
+            .iter_mut()
+            .zip(seed)
+            .for_each(|(byte, value)| *byte = *value);
+
+        let mut rng = R::from_seed(rng_seed);
+
+        // Advance by `u32` outputs, to also cover states partway through a buffered block.
+        for _ in 0..steps {
+            rng.next_u32();
+        }
+
+        let serialized = ron::to_string(&rng).unwrap();
+        assert_round_trip(&rng, ron::from_str(&serialized).unwrap(), "RON");
+
+        let serialized = serde_json::to_string(&rng).unwrap();
+        assert_round_trip(&rng, serde_json::from_str(&serialized).unwrap(), "JSON");
+
+        let serialized = postcard::to_allocvec(&rng).unwrap();
+        assert_round_trip(&rng, postcard::from_bytes(&serialized).unwrap(), "postcard");
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<R>();
+
+        let reflected = ReflectSerializer::new(&rng, &registry);
+
+        let serialized = ron::to_string(&reflected).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut ron::Deserializer::from_str(&serialized).unwrap())
+            .unwrap();
+        assert_round_trip(&rng, R::take_from_reflect(value).unwrap(), "reflect RON");
+
+        let serialized = serde_json::to_string(&reflected).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&serialized))
+            .unwrap();
+        assert_round_trip(&rng, R::take_from_reflect(value).unwrap(), "reflect JSON");
+
+        let serialized = postcard::to_allocvec(&reflected).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized))
+            .unwrap();
+        assert_round_trip(
+            &rng,
+            R::take_from_reflect(value).unwrap(),
+            "reflect postcard",
+        );
+    }
+}
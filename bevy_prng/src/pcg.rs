@@ -10,19 +10,22 @@ newtype_prng!(
     Pcg32,
     ::rand_pcg::Pcg32,
     "A newtyped [`rand_pcg::Pcg32`] RNG",
-    "rand_pcg"
+    "rand_pcg",
+    pcg32_tests
 );
 
 newtype_prng!(
     Pcg64,
     ::rand_pcg::Pcg64,
     "A newtyped [`rand_pcg::Pcg64`] RNG",
-    "rand_pcg"
+    "rand_pcg",
+    pcg64_tests
 );
 
 newtype_prng!(
     Pcg64Mcg,
     ::rand_pcg::Pcg64Mcg,
     "A newtyped [`rand_pcg::Pcg64Mcg`] RNG",
-    "rand_pcg"
+    "rand_pcg",
+    pcg64_mcg_tests
 );
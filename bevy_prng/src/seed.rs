@@ -0,0 +1,105 @@
+use core::fmt;
+
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// A 512 bit seed, for seeding the 512 bit variants of the Xoshiro PRNG, such as
+/// `Xoshiro512StarStar`. Serializes as a tuple of 64 bytes, in the same way as 32 byte
+/// array seeds do.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect)]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, Default, PartialEq, Hash, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, Default, PartialEq, Hash))]
+#[type_path = "bevy_prng"]
+pub struct Seed512(pub [u8; 64]);
+
+impl fmt::Debug for Seed512 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Seed512").field(&&self.0[..]).finish()
+    }
+}
+
+impl Default for Seed512 {
+    fn default() -> Self {
+        Self([0; 64])
+    }
+}
+
+impl From<[u8; 64]> for Seed512 {
+    #[inline]
+    fn from(value: [u8; 64]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Seed512> for [u8; 64] {
+    #[inline]
+    fn from(value: Seed512) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<[u8]> for Seed512 {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Seed512 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for Seed512 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(self.0.len())?;
+
+        for byte in &self.0 {
+            tuple.serialize_element(byte)?;
+        }
+
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for Seed512 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Seed512Visitor;
+
+        impl<'de> Visitor<'de> for Seed512Visitor {
+            type Value = Seed512;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a tuple of 64 bytes")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut seed = Seed512::default();
+
+                for (index, byte) in seed.0.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                }
+
+                Ok(seed)
+            }
+        }
+
+        deserializer.deserialize_tuple(64, Seed512Visitor)
+    }
+}
@@ -0,0 +1,456 @@
+use alloc::rc::Rc;
+use core::{cell::UnsafeCell, fmt, marker::PhantomData, ptr::NonNull};
+
+use std::{sync::OnceLock, thread_local};
+
+use rand_chacha::{ChaCha12Rng, ChaCha20Rng, ChaCha8Rng};
+use rand_core::{CryptoRng, OsRng, RngCore, SeedableRng};
+
+thread_local! {
+    // We require `Rc` to avoid premature freeing when `ThreadLocalEntropy` is used within thread-local destructors.
+    // The source is seeded on first access rather than here, so that fallible accesses can report OS entropy
+    // being unavailable instead of panicking.
+    static SOURCE: Rc<UnsafeCell<Option<LocalSource>>> = Rc::new(UnsafeCell::new(None));
+}
+
+/// The configuration for all thread local sources, fixed on first use.
+static CONFIG: OnceLock<ThreadLocalConfig> = OnceLock::new();
+
+#[inline]
+fn config() -> ThreadLocalConfig {
+    *CONFIG.get_or_init(ThreadLocalConfig::default)
+}
+
+/// The number of ChaCha rounds used by the [`ThreadLocalEntropy`] sources.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChaChaRounds {
+    /// ChaCha with 8 rounds, for the most throughput.
+    #[default]
+    Eight,
+    /// ChaCha with 12 rounds.
+    Twelve,
+    /// ChaCha with 20 rounds, for the highest security margin.
+    Twenty,
+}
+
+/// Configuration for the [`ThreadLocalEntropy`] sources, set with
+/// [`configure_thread_local_entropy`]. The default configuration uses ChaCha with 8 rounds and
+/// never rekeys.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadLocalConfig {
+    /// The number of rounds for each thread's ChaCha source.
+    pub rounds: ChaChaRounds,
+    /// If set, each thread's source is rekeyed from OS entropy once it has output this many
+    /// bytes, before its next output.
+    pub reseed_after_bytes: Option<u64>,
+}
+
+/// Error returned by [`configure_thread_local_entropy`] when the [`ThreadLocalEntropy`] sources
+/// have already been configured or used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadLocalAlreadyConfigured;
+
+impl fmt::Display for ThreadLocalAlreadyConfigured {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("thread local entropy was already configured or used")
+    }
+}
+
+impl std::error::Error for ThreadLocalAlreadyConfigured {}
+
+/// Configures the algorithm and rekeying of all [`ThreadLocalEntropy`] sources. Must be called
+/// once, before [`ThreadLocalEntropy`] is first used on any thread, such as at the start of
+/// `main`, as the configuration is fixed on first use. Returns an error if the sources were
+/// already configured or used, leaving the existing configuration in place.
+///
+/// ```
+/// use bevy_prng::{configure_thread_local_entropy, ChaChaRounds, ThreadLocalConfig};
+///
+/// configure_thread_local_entropy(ThreadLocalConfig {
+///     rounds: ChaChaRounds::Twelve,
+///     reseed_after_bytes: Some(1 << 20),
+/// })
+/// .expect("configured before first use");
+/// ```
+pub fn configure_thread_local_entropy(
+    config: ThreadLocalConfig,
+) -> Result<(), ThreadLocalAlreadyConfigured> {
+    CONFIG.set(config).map_err(|_| ThreadLocalAlreadyConfigured)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum LocalRng {
+    ChaCha8(ChaCha8Rng),
+    ChaCha12(ChaCha12Rng),
+    ChaCha20(ChaCha20Rng),
+}
+
+impl LocalRng {
+    fn from_seed(rounds: ChaChaRounds, seed: [u8; 32]) -> Self {
+        match rounds {
+            ChaChaRounds::Eight => Self::ChaCha8(ChaCha8Rng::from_seed(seed)),
+            ChaChaRounds::Twelve => Self::ChaCha12(ChaCha12Rng::from_seed(seed)),
+            ChaChaRounds::Twenty => Self::ChaCha20(ChaCha20Rng::from_seed(seed)),
+        }
+    }
+
+    #[inline]
+    fn as_rng(&mut self) -> &mut dyn RngCore {
+        match self {
+            Self::ChaCha8(rng) => rng,
+            Self::ChaCha12(rng) => rng,
+            Self::ChaCha20(rng) => rng,
+        }
+    }
+}
+
+/// A thread's source, counting its output to rekey it once the configured threshold is reached.
+#[derive(Debug, Clone, PartialEq)]
+struct LocalSource {
+    rng: LocalRng,
+    config: ThreadLocalConfig,
+    output: u64,
+}
+
+impl LocalSource {
+    fn from_seed(config: ThreadLocalConfig, seed: [u8; 32]) -> Self {
+        Self {
+            rng: LocalRng::from_seed(config.rounds, seed),
+            config,
+            output: 0,
+        }
+    }
+
+    fn from_entropy(config: ThreadLocalConfig) -> Self {
+        let mut seed = [0; 32];
+
+        OsRng.fill_bytes(&mut seed);
+
+        Self::from_seed(config, seed)
+    }
+
+    fn try_from_entropy(config: ThreadLocalConfig) -> Result<Self, rand_core::Error> {
+        let mut seed = [0; 32];
+
+        OsRng.try_fill_bytes(&mut seed)?;
+
+        Ok(Self::from_seed(config, seed))
+    }
+
+    /// Returns the RNG for the next `bytes` of output, rekeying it first if the threshold has
+    /// been reached.
+    #[inline]
+    fn draw(&mut self, bytes: usize) -> &mut dyn RngCore {
+        if let Some(threshold) = self.config.reseed_after_bytes {
+            if self.output >= threshold {
+                *self = Self::from_entropy(self.config);
+            }
+
+            self.output = self.output.saturating_add(bytes as u64);
+        }
+
+        self.rng.as_rng()
+    }
+}
+
+impl RngCore for LocalSource {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.draw(4).next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.draw(8).next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.draw(dest.len()).fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.draw(dest.len()).try_fill_bytes(dest)
+    }
+}
+
+/// [`ThreadLocalEntropy`] uses thread local [`ChaCha8Rng`] instances to provide faster alternative for
+/// sourcing entropy to OS/Hardware sources. The use of `ChaCha8` with 8 rounds as opposed to 12 or 20 rounds
+/// is due to tuning for additional speed/throughput, though the rounds and periodic rekeying can be
+/// configured with [`configure_thread_local_entropy`]. While this does minimise the quality of the entropy,
+/// the output should still be sufficiently secure as per the recommendations set in the
+/// [Too Much Crypto](https://eprint.iacr.org/2019/1492.pdf) paper. [`ThreadLocalEntropy`] is not thread-safe and
+/// cannot be sent or synchronised between threads, it should be initialised within each thread context it is
+/// needed in.
+///
+/// Each thread's source is seeded from OS entropy when first accessed, so the output is never deterministic and
+/// should only be used for seeding other PRNGs or for values that don't need to be reproducible. All instances on
+/// the same thread share the same source, so they advance each other.
+///
+/// ```
+/// use bevy_prng::{ThreadLocalEntropy, WyRand};
+/// use rand_core::{RngCore, SeedableRng};
+///
+/// // Seeding a PRNG outside of the ECS, such as within an asset loader thread.
+/// let mut rng = WyRand::from_seed(ThreadLocalEntropy::new().fill_seed::<WyRand>());
+///
+/// let mut key = [0u8; 16];
+/// ThreadLocalEntropy::new().gen_seed_bytes(&mut key);
+///
+/// println!("{} {key:?}", rng.next_u32());
+/// ```
+pub struct ThreadLocalEntropy(PhantomData<*mut ()>);
+
+impl ThreadLocalEntropy {
+    /// Create a new [`ThreadLocalEntropy`] instance.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Generates a new seed for `R` from the thread local source.
+    #[inline]
+    pub fn fill_seed<R: SeedableRng>(&mut self) -> R::Seed {
+        let mut seed = R::Seed::default();
+
+        self.fill_bytes(seed.as_mut());
+
+        seed
+    }
+
+    /// Fills `dest` with seed bytes from the thread local source, for seeding PRNGs or keys with
+    /// seeds of arbitrary length.
+    #[inline]
+    pub fn gen_seed_bytes(&mut self, dest: &mut [u8]) {
+        self.fill_bytes(dest);
+    }
+
+    /// Initiates an access to the thread local source, passing it to the closure, seeding it
+    /// from OS entropy first if this is the first access on this thread.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the thread destructor is running or has been previously run, or
+    /// if the source has to be seeded and OS entropy is unavailable.
+    #[inline]
+    fn access_local_source<F, O>(&mut self, f: F) -> O
+    where
+        F: FnOnce(&mut LocalSource) -> O,
+    {
+        self.access_local_slot(|slot| {
+            f(slot.get_or_insert_with(|| LocalSource::from_entropy(config())))
+        })
+    }
+
+    /// Initiates an access to the thread local source slot, passing it to the closure. The slot is
+    /// empty until the source is first seeded on this thread.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the thread destructor is running or has been previously run.
+    #[inline]
+    fn access_local_slot<F, O>(&mut self, f: F) -> O
+    where
+        F: FnOnce(&mut Option<LocalSource>) -> O,
+    {
+        SOURCE.with(|source| {
+            // SAFETY: Constructing `NonNull` from a `&T` is safe as it will never be a
+            // null pointer, and the contents of the reference will always be initialised.
+            let mut ptr = unsafe { NonNull::new_unchecked(source.get()) };
+
+            // SAFETY: The `&mut` reference constructed from `NonNull` will never outlive the closure
+            // for the thread local access.
+            unsafe { f(ptr.as_mut()) }
+        })
+    }
+}
+
+impl Default for ThreadLocalEntropy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for ThreadLocalEntropy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ThreadLocalEntropy").finish()
+    }
+}
+
+impl RngCore for ThreadLocalEntropy {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self.access_local_source(RngCore::next_u32)
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        self.access_local_source(RngCore::next_u64)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.access_local_source(|rng| rng.fill_bytes(dest));
+    }
+
+    /// Fills `dest` from the thread local source, returning an error instead of panicking if the
+    /// source has yet to be seeded on this thread and OS entropy is unavailable.
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.access_local_slot(|slot| {
+            let source = match slot {
+                Some(source) => source,
+                None => slot.insert(LocalSource::try_from_entropy(config())?),
+            };
+
+            source.try_fill_bytes(dest)
+        })
+    }
+}
+
+impl CryptoRng for ThreadLocalEntropy {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn smoke_test() {
+        let mut rng1 = ThreadLocalEntropy::new();
+        let mut rng2 = ThreadLocalEntropy::new();
+
+        // Neither instance should interfere with each other
+        rng1.next_u32();
+        rng2.next_u64();
+
+        let mut bytes1 = vec![0u8; 128];
+        let mut bytes2 = vec![0u8; 128];
+
+        rng1.fill_bytes(&mut bytes1);
+        rng2.fill_bytes(&mut bytes2);
+
+        // ThreadLocalEntropy instances won't output the same entropy as the
+        // underlying thread local source gets mutated each access.
+        assert_ne!(&bytes1, &bytes2);
+    }
+
+    #[test]
+    fn unique_source_per_thread() {
+        let mut bytes1: Vec<u8> = vec![0u8; 128];
+        let mut bytes2: Vec<u8> = vec![0u8; 128];
+
+        let b1 = bytes1.as_mut();
+        let b2 = bytes2.as_mut();
+
+        let (a, b) = std::thread::scope(|s| {
+            let a = s.spawn(move || {
+                // Obtain a thread local entropy source from this thread context.
+                // It should be initialised with a random state.
+                let mut rng = ThreadLocalEntropy::new();
+
+                // Use the source to produce some stored entropy.
+                rng.fill_bytes(b1);
+
+                rng.access_local_source(|rng| rng.clone())
+            });
+            let b = s.spawn(move || {
+                // Obtain a thread local entropy source from this thread context.
+                // It should be initialised with a random state.
+                let mut rng = ThreadLocalEntropy::new();
+
+                // Use the source to produce some stored entropy.
+                rng.fill_bytes(b2);
+
+                rng.access_local_source(|rng| rng.clone())
+            });
+
+            (a.join(), b.join())
+        });
+
+        let a = a.unwrap();
+        let b = b.unwrap();
+
+        // The references to the thread local RNG sources will not be
+        // the same, as they each were initialised with different random
+        // states to each other from OS sources, even though each went
+        // through the exact same deterministic steps to fill some bytes.
+        // If the tasks ran on the same thread, then the RNG sources should
+        // be in different resulting states as the same source was advanced
+        // further.
+        assert_ne!(&a, &b);
+
+        // Double check the entropy output in each buffer is not the same either.
+        assert_ne!(&bytes1, &bytes2);
+    }
+
+    #[test]
+    fn seeds_are_unique() {
+        let mut rng = ThreadLocalEntropy::new();
+
+        assert_ne!(rng.fill_seed::<ChaCha8Rng>(), rng.fill_seed::<ChaCha8Rng>());
+    }
+
+    #[test]
+    fn sources_rekey_after_threshold() {
+        let config = ThreadLocalConfig {
+            rounds: ChaChaRounds::Twelve,
+            reseed_after_bytes: Some(1024),
+        };
+
+        let mut rekeyed = LocalSource::from_seed(config, [7; 32]);
+        let mut unrekeyed = LocalSource::from_seed(
+            ThreadLocalConfig {
+                reseed_after_bytes: None,
+                ..config
+            },
+            [7; 32],
+        );
+
+        let draw = |source: &mut LocalSource| {
+            let mut bytes = vec![0u8; 2048];
+
+            bytes
+                .chunks_mut(64)
+                .for_each(|chunk| source.fill_bytes(chunk));
+
+            bytes
+        };
+
+        let (rekeyed, unrekeyed) = (draw(&mut rekeyed), draw(&mut unrekeyed));
+
+        assert_eq!(rekeyed[..1024], unrekeyed[..1024]);
+        assert_ne!(rekeyed[1024..], unrekeyed[1024..]);
+        assert_eq!(
+            unrekeyed,
+            draw(&mut LocalSource::from_seed(
+                ThreadLocalConfig {
+                    reseed_after_bytes: None,
+                    ..config
+                },
+                [7; 32]
+            ))
+        );
+    }
+
+    #[test]
+    fn configuring_after_use_fails() {
+        ThreadLocalEntropy::new().next_u32();
+
+        assert_eq!(
+            configure_thread_local_entropy(ThreadLocalConfig::default()),
+            Err(ThreadLocalAlreadyConfigured)
+        );
+    }
+
+    #[test]
+    fn non_leaking_debug() {
+        assert_eq!(
+            "ThreadLocalEntropy",
+            format!("{:?}", ThreadLocalEntropy::new())
+        );
+    }
+}
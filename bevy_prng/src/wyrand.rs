@@ -10,5 +10,6 @@ newtype_prng!(
     WyRand,
     ::wyrand::WyRand,
     "A newtyped [`wyrand::WyRand`] RNG",
-    "wyrand"
+    "wyrand",
+    wyrand_tests
 );
@@ -1,24 +1,18 @@
 use crate::{
     newtype::{newtype_prng, newtype_prng_remote},
-    EntropySource,
+    EntropySource, Seed512,
 };
 
-use bevy_reflect::{reflect_remote, std_traits::ReflectDefault, Reflect, ReflectFromReflect};
+use bevy_reflect::{Reflect, ReflectFromReflect};
 use rand_core::{RngCore, SeedableRng};
 
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
-/// Remote reflected version of [`rand_xoshiro::Seed512`], needed to support
-/// proper reflection for the 512 bit variants of the Xoshiro PRNG.
-#[reflect_remote(::rand_xoshiro::Seed512)]
-#[derive(Debug, Default, Clone)]
-#[reflect(Debug, Default)]
-pub struct Seed512(pub [u8; 64]);
-
-impl AsMut<[u8]> for Seed512 {
-    fn as_mut(&mut self) -> &mut [u8] {
-        self.0.as_mut()
+impl From<Seed512> for ::rand_xoshiro::Seed512 {
+    #[inline]
+    fn from(value: Seed512) -> Self {
+        Self(value.0)
     }
 }
 
@@ -27,7 +21,8 @@ newtype_prng_remote!(
     ::rand_xoshiro::Xoshiro512StarStar,
     Seed512,
     "A newtyped [`rand_xoshiro::Xoshiro512StarStar`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro512_starstar_tests
 );
 
 newtype_prng_remote!(
@@ -35,7 +30,8 @@ newtype_prng_remote!(
     ::rand_xoshiro::Xoshiro512PlusPlus,
     Seed512,
     "A newtyped [`rand_xoshiro::Xoshiro512PlusPlus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro512_plusplus_tests
 );
 
 newtype_prng_remote!(
@@ -43,82 +39,94 @@ newtype_prng_remote!(
     ::rand_xoshiro::Xoshiro512Plus,
     Seed512,
     "A newtyped [`rand_xoshiro::Xoshiro512Plus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro512_plus_tests
 );
 
 newtype_prng!(
     Xoshiro256StarStar,
     ::rand_xoshiro::Xoshiro256StarStar,
     "A newtyped [`rand_xoshiro::Xoshiro256StarStar`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro256_starstar_tests
 );
 
 newtype_prng!(
     Xoshiro256PlusPlus,
     ::rand_xoshiro::Xoshiro256PlusPlus,
     "A newtyped [`rand_xoshiro::Xoshiro256PlusPlus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro256_plusplus_tests
 );
 
 newtype_prng!(
     Xoshiro256Plus,
     ::rand_xoshiro::Xoshiro256Plus,
     "A newtyped [`rand_xoshiro::Xoshiro256Plus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro256_plus_tests
 );
 
 newtype_prng!(
     Xoroshiro128StarStar,
     ::rand_xoshiro::Xoroshiro128StarStar,
     "A newtyped [`rand_xoshiro::Xoshiro128StarStar`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoroshiro128_starstar_tests
 );
 
 newtype_prng!(
     Xoroshiro128PlusPlus,
     ::rand_xoshiro::Xoroshiro128PlusPlus,
     "A newtyped [`rand_xoshiro::Xoshiro256PlusPlus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoroshiro128_plusplus_tests
 );
 
 newtype_prng!(
     Xoroshiro128Plus,
     ::rand_xoshiro::Xoroshiro128Plus,
     "A newtyped [`rand_xoshiro::Xoshiro128Plus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoroshiro128_plus_tests
 );
 
 newtype_prng!(
     Xoshiro128StarStar,
     ::rand_xoshiro::Xoshiro128StarStar,
     "A newtyped [`rand_xoshiro::Xoshiro128StarStar`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro128_starstar_tests
 );
 
 newtype_prng!(
     Xoshiro128PlusPlus,
     ::rand_xoshiro::Xoshiro128PlusPlus,
     "A newtyped [`rand_xoshiro::Xoshiro256PlusPlus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro128_plusplus_tests
 );
 
 newtype_prng!(
     Xoshiro128Plus,
     ::rand_xoshiro::Xoshiro128Plus,
     "A newtyped [`rand_xoshiro::Xoshiro128Plus`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoshiro128_plus_tests
 );
 
 newtype_prng!(
     Xoroshiro64StarStar,
     ::rand_xoshiro::Xoroshiro64StarStar,
     "A newtyped [`rand_xoshiro::Xoroshiro64StarStar`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoroshiro64_starstar_tests
 );
 
 newtype_prng!(
     Xoroshiro64Star,
     ::rand_xoshiro::Xoroshiro64Star,
     "A newtyped [`rand_xoshiro::Xoroshiro64Star`] RNG",
-    "rand_xoshiro"
+    "rand_xoshiro",
+    xoroshiro64_star_tests
 );
@@ -3,7 +3,7 @@
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_prng::ChaCha8Rng;
-use bevy_rand::prelude::{Entropy, EntropyPlugin, ForkableRng, GlobalEntropy};
+use bevy_rand::prelude::{AppRngExt, Entropy, ForkableRng, ForkableSeed, GlobalEntropy, SeedToken};
 use rand::prelude::{IteratorRandom, Rng};
 
 #[derive(Component, PartialEq, Eq)]
@@ -38,9 +38,18 @@ struct Health {
     amount: f32,
 }
 
+/// Event for an entity attempting to buff itself. It carries a seed forked from the entity's own
+/// source, so the observer doesn't need to query for it, without embedding a whole `ChaCha8Rng`
+/// in the event.
+#[derive(Event)]
+struct TryBuff {
+    rng: SeedToken<ChaCha8Rng>,
+}
+
 fn main() {
     App::new()
-        .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+        .init_rng_with_seed::<ChaCha8Rng>(1)
+        .add_observer(apply_buff)
         .add_systems(Startup, (setup_player, setup_enemies).chain())
         .add_systems(
             Update,
@@ -173,11 +182,27 @@ fn attack_turn(
 }
 
 fn buff_entities(
-    mut q_entities: Query<(&Name, &Buff, &mut Health, &mut Entropy<ChaCha8Rng>), With<Kind>>,
+    mut commands: Commands,
+    mut q_entities: Query<(Entity, &mut Entropy<ChaCha8Rng>), (With<Kind>, With<Buff>)>,
 ) {
-    // Query iteration order is not stable, but entities having their own RNG source side-steps this
-    // completely, so the result is always deterministic.
-    for (name, buff, mut hp, mut rng) in q_entities.iter_mut() {
+    // Query iteration order is not stable, but each event carries a seed forked from the entity's
+    // own source, so the result is always deterministic.
+    for (entity, mut rng) in q_entities.iter_mut() {
+        commands.trigger_targets(
+            TryBuff {
+                rng: rng.fork_token(),
+            },
+            entity,
+        );
+    }
+}
+
+fn apply_buff(trigger: Trigger<TryBuff>, mut q_entities: Query<(&Name, &Buff, &mut Health)>) {
+    let entity = trigger.target();
+    // The RNG is only reconstructed from the seed here, where it is used
+    let mut rng = trigger.event().rng.into_rng();
+
+    if let Ok((name, buff, mut hp)) = q_entities.get_mut(entity) {
         if rng.gen_bool(buff.chance) {
             hp.amount += buff.effect;
 
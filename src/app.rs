@@ -0,0 +1,202 @@
+use bevy_app::{App, SubApp};
+#[cfg(feature = "experimental")]
+use bevy_ecs::prelude::Component;
+use bevy_prng::{EntropySeed, EntropySource};
+
+#[cfg(feature = "experimental")]
+use crate::plugin::LinkedEntropySources;
+use crate::{plugin::EntropyPlugin, seed::RngSeed, traits::SeedSource};
+
+/// Extension trait for setting up RNG sources on an [`App`] or [`SubApp`], as shorthand for adding
+/// the corresponding plugins. Each method only adds its plugin if it hasn't been added already,
+/// so they are safe to call from several plugins that each need the same sources.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::{ChaCha8Rng, WyRand};
+/// use bevy_rand::prelude::AppRngExt;
+///
+/// App::new()
+///     .init_rng_with_seed::<WyRand>(42)
+///     .init_rng::<ChaCha8Rng>();
+/// ```
+pub trait AppRngExt {
+    /// Adds an [`EntropyPlugin`] for `R`, with the [`crate::global::Global`] source seeded from
+    /// entropy. Does nothing if one was already added.
+    fn init_rng<R: EntropySource + 'static>(&mut self) -> &mut Self
+    where
+        R::Seed: EntropySeed;
+
+    /// Adds an [`EntropyPlugin`] for `R`, with the [`crate::global::Global`] source seeded from
+    /// `seed`, expanded to a full seed as with [`RngSeed::from_u64`]. If one was already added,
+    /// it is kept as is, and with the `tracing` feature a warning is logged that `seed` was not
+    /// applied.
+    ///
+    /// ```
+    /// use bevy_app::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{AppRngExt, RngSeed};
+    ///
+    /// let mut app = App::new();
+    ///
+    /// app.init_rng_with_seed::<WyRand>(42);
+    ///
+    /// let world = app.world_mut();
+    /// let seed = world.query::<&RngSeed<WyRand>>().iter(world).next().unwrap();
+    ///
+    /// assert_eq!(seed, &RngSeed::<WyRand>::from_u64(42));
+    /// ```
+    fn init_rng_with_seed<R: EntropySource + 'static>(&mut self, seed: u64) -> &mut Self
+    where
+        R::Seed: EntropySeed;
+
+    /// Adds [`LinkedEntropySources`] for linking `Source` entities to `Target` entities with `R`
+    /// sources. Does nothing if it was already added.
+    ///
+    /// ```
+    /// use bevy_app::prelude::*;
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::AppRngExt;
+    ///
+    /// #[derive(Component)]
+    /// struct Squad;
+    /// #[derive(Component)]
+    /// struct Soldier;
+    ///
+    /// App::new()
+    ///     .init_rng_with_seed::<WyRand>(42)
+    ///     .init_rng_relations::<Squad, Soldier, WyRand>();
+    /// ```
+    #[cfg(feature = "experimental")]
+    fn init_rng_relations<Source: Component, Target: Component, R: EntropySource + 'static>(
+        &mut self,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone;
+}
+
+impl AppRngExt for SubApp {
+    fn init_rng<R: EntropySource + 'static>(&mut self) -> &mut Self
+    where
+        R::Seed: EntropySeed,
+    {
+        if !self.is_plugin_added::<EntropyPlugin<R>>() {
+            self.add_plugins(EntropyPlugin::<R>::new());
+        }
+
+        self
+    }
+
+    fn init_rng_with_seed<R: EntropySource + 'static>(&mut self, seed: u64) -> &mut Self
+    where
+        R::Seed: EntropySeed,
+    {
+        if self.is_plugin_added::<EntropyPlugin<R>>() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                "EntropyPlugin<{}> was already added, so the seed {seed} was not applied",
+                core::any::type_name::<R>()
+            );
+
+            return self;
+        }
+
+        self.add_plugins(EntropyPlugin::<R>::with_seed(
+            RngSeed::<R>::from_u64(seed).clone_seed(),
+        ))
+    }
+
+    #[cfg(feature = "experimental")]
+    fn init_rng_relations<Source: Component, Target: Component, R: EntropySource + 'static>(
+        &mut self,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        if !self.is_plugin_added::<LinkedEntropySources<Source, Target, R>>() {
+            self.add_plugins(LinkedEntropySources::<Source, Target, R>::default());
+        }
+
+        self
+    }
+}
+
+impl AppRngExt for App {
+    fn init_rng<R: EntropySource + 'static>(&mut self) -> &mut Self
+    where
+        R::Seed: EntropySeed,
+    {
+        self.main_mut().init_rng::<R>();
+        self
+    }
+
+    fn init_rng_with_seed<R: EntropySource + 'static>(&mut self, seed: u64) -> &mut Self
+    where
+        R::Seed: EntropySeed,
+    {
+        self.main_mut().init_rng_with_seed::<R>(seed);
+        self
+    }
+
+    #[cfg(feature = "experimental")]
+    fn init_rng_relations<Source: Component, Target: Component, R: EntropySource + 'static>(
+        &mut self,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.main_mut().init_rng_relations::<Source, Target, R>();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::AppLabel;
+    use bevy_ecs::prelude::{AppTypeRegistry, With};
+    use bevy_prng::WyRand;
+
+    use crate::global::Global;
+
+    use super::*;
+
+    #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct RngSubApp;
+
+    fn global_seeds(app: &mut SubApp) -> alloc::vec::Vec<RngSeed<WyRand>> {
+        let world = app.world_mut();
+
+        world
+            .query_filtered::<&RngSeed<WyRand>, With<Global>>()
+            .iter(world)
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn repeated_inits_keep_the_first_plugin() {
+        let mut app = App::new();
+
+        app.init_rng_with_seed::<WyRand>(42)
+            .init_rng::<WyRand>()
+            .init_rng_with_seed::<WyRand>(7);
+
+        assert_eq!(
+            global_seeds(app.main_mut()),
+            [RngSeed::<WyRand>::from_u64(42)]
+        );
+
+        // Sub-apps get their own, independent sources
+        let mut sub_app = SubApp::new();
+
+        sub_app.init_resource::<AppTypeRegistry>();
+        app.insert_sub_app(RngSubApp, sub_app);
+
+        let sub_app = app.sub_app_mut(RngSubApp);
+
+        sub_app.init_rng_with_seed::<WyRand>(7).init_rng::<WyRand>();
+
+        assert_eq!(global_seeds(sub_app), [RngSeed::<WyRand>::from_u64(7)]);
+    }
+}
@@ -0,0 +1,369 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{fmt, marker::PhantomData};
+
+use bevy_app::{App, Plugin, PreUpdate, Startup};
+use bevy_asset::{
+    io::Reader, Asset, AssetApp, AssetEvent, AssetLoadFailedEvent, AssetLoader, AssetServer,
+    Assets, Handle, LoadContext,
+};
+use bevy_ecs::prelude::{Commands, Entity, EventReader, Query, Res, Resource, With};
+use bevy_prng::EntropySource;
+use bevy_reflect::TypePath;
+use serde::Deserialize;
+
+use crate::{global::Global, seed::RngSeed, traits::SeedSource, util::derive_seed};
+
+/// A seed value within a [`SeedConfig`], either as a number or as a hex string.
+///
+/// Numbers are expanded into a full seed with a `SplitMix64` stream, so any PRNG algorithm can be
+/// seeded from a single `u64`. Hex strings, with or without a `0x` prefix, are the raw seed bytes
+/// and must be exactly as long as the seed of the PRNG algorithm they are applied to.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum SeedValue {
+    /// A number to expand into a full seed.
+    Number(u64),
+    /// The raw seed bytes as a hex string.
+    Hex(String),
+}
+
+impl SeedValue {
+    /// Converts this value into a seed for `R`.
+    pub fn to_seed<R: EntropySource>(&self) -> Result<R::Seed, SeedValueError>
+    where
+        R::Seed: Clone,
+    {
+        match self {
+            Self::Number(value) => Ok(derive_seed::<R>(&R::Seed::default(), *value)),
+            Self::Hex(hex) => {
+                let hex = hex.strip_prefix("0x").unwrap_or(hex);
+                let mut seed = R::Seed::default();
+                let bytes = seed.as_mut();
+
+                if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                    return Err(SeedValueError::InvalidHex);
+                }
+
+                if hex.len() != bytes.len() * 2 {
+                    return Err(SeedValueError::WrongLength {
+                        expected: bytes.len(),
+                        found: hex.len() / 2,
+                    });
+                }
+
+                for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+                    let nibble = |digit: u8| (digit as char).to_digit(16).unwrap_or(0) as u8;
+
+                    *byte = (nibble(pair[0]) << 4) | nibble(pair[1]);
+                }
+
+                Ok(seed)
+            }
+        }
+    }
+}
+
+/// Error for when a [`SeedValue`] cannot be converted into a seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeedValueError {
+    /// The hex string contains non-hex characters.
+    InvalidHex,
+    /// The hex string does not match the length of the seed in bytes.
+    WrongLength {
+        /// The expected number of bytes.
+        expected: usize,
+        /// The number of bytes found.
+        found: usize,
+    },
+}
+
+impl fmt::Display for SeedValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHex => f.write_str("seed contains invalid hex characters"),
+            Self::WrongLength { expected, found } => {
+                write!(f, "seed is {found} bytes long, expected {expected} bytes")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SeedValueError {}
+
+/// A seed configuration asset, loaded from `.seeds.ron` files by [`SeedConfigLoader`]. Contains an
+/// optional seed for the [`Global`] sources, and a map of labelled seeds for any other sources.
+///
+/// ```ron
+/// (
+///     global: Some("0x2a00000000000000"),
+///     labels: {
+///         "loot": 7,
+///     },
+/// )
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Asset, TypePath, Deserialize)]
+pub struct SeedConfig {
+    /// The seed to apply to [`Global`] sources.
+    #[serde(default)]
+    pub global: Option<SeedValue>,
+    /// Seeds for other sources, keyed by label.
+    #[serde(default)]
+    pub labels: BTreeMap<String, SeedValue>,
+}
+
+impl SeedConfig {
+    /// Returns the seed for `label` as a seed for `R`, if it is configured.
+    pub fn label_seed<R: EntropySource>(
+        &self,
+        label: &str,
+    ) -> Option<Result<R::Seed, SeedValueError>>
+    where
+        R::Seed: Clone,
+    {
+        self.labels.get(label).map(SeedValue::to_seed::<R>)
+    }
+}
+
+/// Error for when a [`SeedConfig`] fails to load.
+#[derive(Debug)]
+pub enum SeedConfigLoaderError {
+    /// The asset could not be read.
+    Io(std::io::Error),
+    /// The asset is not a valid RON [`SeedConfig`].
+    Ron(ron::error::SpannedError),
+}
+
+impl fmt::Display for SeedConfigLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read seed config: {error}"),
+            Self::Ron(error) => write!(f, "could not parse seed config: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for SeedConfigLoaderError {}
+
+impl From<std::io::Error> for SeedConfigLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for SeedConfigLoaderError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+/// [`AssetLoader`] for [`SeedConfig`] assets in RON format.
+#[derive(Debug, Default)]
+pub struct SeedConfigLoader;
+
+impl AssetLoader for SeedConfigLoader {
+    type Asset = SeedConfig;
+    type Settings = ();
+    type Error = SeedConfigLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+
+        reader.read_to_end(&mut bytes).await?;
+
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["seeds.ron"]
+    }
+}
+
+/// Resource holding the [`SeedConfig`] handle tracked by [`SeedConfigPlugin`] for `R`. Replacing
+/// the handle will apply the new [`SeedConfig`] once it is loaded.
+#[derive(Debug, Resource)]
+pub struct SeedConfigHandle<R: EntropySource> {
+    /// The handle of the tracked [`SeedConfig`].
+    pub handle: Handle<SeedConfig>,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> SeedConfigHandle<R> {
+    /// Creates a new resource tracking `handle`.
+    #[inline]
+    pub fn new(handle: Handle<SeedConfig>) -> Self {
+        Self {
+            handle,
+            _rng: PhantomData,
+        }
+    }
+}
+
+/// Plugin for seeding the [`Global`] `R` source from a [`SeedConfig`] asset. The global seed is
+/// applied once the asset loads, and re-applied whenever the asset is modified, such as when it is
+/// hot reloaded. Requires `bevy_asset`'s `AssetPlugin`.
+///
+/// As the seed is applied by inserting a new [`RngSeed`] into the global source, any targets
+/// linked to the global source with [`crate::plugin::LinkedEntropySources`] will be reseeded in
+/// turn. If the asset fails to load or its global seed is invalid, a warning is logged and the
+/// current seed is left as is.
+///
+/// ```
+/// use bevy_app::{prelude::*, TaskPoolPlugin};
+/// use bevy_asset::AssetPlugin;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyPlugin, SeedConfigPlugin};
+///
+/// App::new()
+///     .add_plugins((
+///         TaskPoolPlugin::default(),
+///         AssetPlugin::default(),
+///         EntropyPlugin::<WyRand>::default(),
+///         SeedConfigPlugin::<WyRand>::new("world.seeds.ron"),
+///     ));
+/// ```
+pub struct SeedConfigPlugin<R: EntropySource> {
+    path: String,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> SeedConfigPlugin<R> {
+    /// Creates a new plugin instance, loading the [`SeedConfig`] asset at `path`.
+    #[inline]
+    #[must_use]
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            _rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for SeedConfigPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Assets<SeedConfig>>() {
+            app.init_asset::<SeedConfig>()
+                .register_asset_loader(SeedConfigLoader);
+        }
+
+        let path = self.path.clone();
+
+        app.add_systems(
+            Startup,
+            move |mut commands: Commands, server: Res<AssetServer>| {
+                commands.insert_resource(SeedConfigHandle::<R>::new(server.load(path.clone())));
+            },
+        )
+        .add_systems(PreUpdate, apply_seed_config::<R>);
+    }
+}
+
+fn apply_seed_config<R: EntropySource + 'static>(
+    mut events: EventReader<AssetEvent<SeedConfig>>,
+    mut failures: EventReader<AssetLoadFailedEvent<SeedConfig>>,
+    tracked: Option<Res<SeedConfigHandle<R>>>,
+    configs: Res<Assets<SeedConfig>>,
+    q_global: Query<Entity, (With<Global>, With<RngSeed<R>>)>,
+    mut commands: Commands,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    let Some(tracked) = tracked else {
+        return;
+    };
+
+    let id = tracked.handle.id();
+
+    for failure in failures.read().filter(|failure| failure.id == id) {
+        tracing::warn!(
+            "Failed to load seed config {}, keeping the current seed: {}",
+            failure.path,
+            failure.error
+        );
+    }
+
+    let changed = events.read().any(|event| match event {
+        AssetEvent::LoadedWithDependencies { id: changed }
+        | AssetEvent::Modified { id: changed } => *changed == id,
+        _ => false,
+    });
+
+    if !changed {
+        return;
+    }
+
+    let Some(value) = configs.get(id).and_then(|config| config.global.as_ref()) else {
+        return;
+    };
+
+    match value.to_seed::<R>() {
+        Ok(seed) => {
+            for entity in q_global.iter() {
+                commands
+                    .entity(entity)
+                    .insert(RngSeed::<R>::from_seed(seed.clone()));
+            }
+        }
+        Err(error) => {
+            tracing::warn!("Invalid global seed in seed config, keeping the current seed: {error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::{ChaCha8Rng, WyRand};
+
+    use super::*;
+
+    #[test]
+    fn seed_values() {
+        assert_eq!(
+            SeedValue::Hex("0x2a00000000000000".into()).to_seed::<WyRand>(),
+            Ok(42u64.to_le_bytes())
+        );
+        assert_eq!(
+            SeedValue::Number(42).to_seed::<WyRand>(),
+            Ok(derive_seed::<WyRand>(&[0; 8], 42))
+        );
+        assert_eq!(
+            SeedValue::Hex("2a".into()).to_seed::<ChaCha8Rng>(),
+            Err(SeedValueError::WrongLength {
+                expected: 32,
+                found: 1
+            })
+        );
+        assert_eq!(
+            SeedValue::Hex("zz00000000000000".into()).to_seed::<WyRand>(),
+            Err(SeedValueError::InvalidHex)
+        );
+    }
+
+    #[test]
+    fn parse_config() {
+        let config: SeedConfig = ron::from_str(
+            r#"(
+                global: Some("0x2a00000000000000"),
+                labels: { "loot": 7 },
+            )"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.global,
+            Some(SeedValue::Hex("0x2a00000000000000".into()))
+        );
+        assert_eq!(
+            config.label_seed::<WyRand>("loot"),
+            Some(Ok(derive_seed::<WyRand>(&[0; 8], 7)))
+        );
+        assert_eq!(config.label_seed::<WyRand>("missing"), None);
+    }
+}
@@ -0,0 +1,335 @@
+use core::{marker::PhantomData, time::Duration};
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::{Commands, Entity, Query, Res, ResMut, Resource, With};
+use bevy_prng::EntropySource;
+use bevy_time::Time;
+
+use crate::{global::Global, seed::RngSeed, traits::SeedSource, util::derive_seed};
+
+/// A source for the current day, returning a day index such as the number of days since the
+/// Unix epoch. Only changes to the returned value matter, so any calendar can be used.
+pub type DateSource = fn() -> u64;
+
+enum Mode<R: EntropySource> {
+    Interval {
+        interval: Duration,
+        base: Option<R::Seed>,
+    },
+    Daily {
+        base: R::Seed,
+        date: DateSource,
+    },
+}
+
+impl<R: EntropySource> Clone for Mode<R>
+where
+    R::Seed: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Interval { interval, base } => Self::Interval {
+                interval: *interval,
+                base: base.clone(),
+            },
+            Self::Daily { base, date } => Self::Daily {
+                base: base.clone(),
+                date: *date,
+            },
+        }
+    }
+}
+
+/// Plugin for automatically reseeding the [`Global`] `R` source, either at a fixed interval of
+/// [`Time`] or whenever the day changes. Requires a [`Time`] resource, such as the one provided by
+/// `bevy_time`'s `TimePlugin`.
+///
+/// Seeds are generated according to the configured mode:
+///
+/// - [`AutoReseedPlugin::every`] reseeds from the OS entropy source every interval.
+/// - [`AutoReseedPlugin::every_derived`] reseeds every interval with a seed derived from a base
+///   seed and the number of reseeds performed so far, so the sequence of seeds is deterministic.
+/// - [`AutoReseedPlugin::daily`] reseeds when first run and whenever the day returned by the
+///   [`DateSource`] changes, with a seed derived from a base seed and the day index. Everyone with
+///   the same base seed gets the same seed on the same day, which is ideal for daily challenges.
+///
+/// Derived seeds XOR the base seed bytes with a `SplitMix64` stream keyed by the reseed count or
+/// day index, so they are stable across platforms and versions.
+///
+/// The time of the next reseed is exposed with the [`NextReseed`] resource. As the reseed is
+/// performed by inserting a new [`RngSeed`] into the global source, any targets linked to the
+/// global source with [`crate::plugin::LinkedEntropySources`] will be reseeded in turn.
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{AutoReseedPlugin, EntropyPlugin};
+///
+/// App::new()
+///     .add_plugins((
+///         EntropyPlugin::<WyRand>::default(),
+///         AutoReseedPlugin::<WyRand>::every(Duration::from_secs(600)),
+///     ));
+/// ```
+pub struct AutoReseedPlugin<R: EntropySource> {
+    mode: Mode<R>,
+}
+
+impl<R: EntropySource> AutoReseedPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// Reseed the global source from the OS entropy source every `interval`.
+    #[inline]
+    #[must_use]
+    pub fn every(interval: Duration) -> Self {
+        Self {
+            mode: Mode::Interval {
+                interval,
+                base: None,
+            },
+        }
+    }
+
+    /// Reseed the global source every `interval`, with seeds derived from `base`.
+    #[inline]
+    #[must_use]
+    pub fn every_derived(interval: Duration, base: R::Seed) -> Self {
+        Self {
+            mode: Mode::Interval {
+                interval,
+                base: Some(base),
+            },
+        }
+    }
+
+    /// Reseed the global source whenever the day returned by `date` changes, with seeds derived
+    /// from `base` and the day index.
+    #[inline]
+    #[must_use]
+    pub fn daily(base: R::Seed, date: DateSource) -> Self {
+        Self {
+            mode: Mode::Daily { base, date },
+        }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for AutoReseedPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        let next = match &self.mode {
+            Mode::Interval { interval, .. } => Some(*interval),
+            Mode::Daily { .. } => None,
+        };
+
+        app.insert_resource(NextReseed::<R> {
+            mode: self.mode.clone(),
+            next,
+            day: None,
+            count: 0,
+            rng: PhantomData,
+        })
+        .add_systems(PreUpdate, auto_reseed::<R>);
+    }
+}
+
+/// Resource tracking when the [`Global`] `R` source will next be reseeded by
+/// [`AutoReseedPlugin`], for displaying countdowns and the like.
+#[derive(Resource)]
+pub struct NextReseed<R: EntropySource> {
+    mode: Mode<R>,
+    next: Option<Duration>,
+    day: Option<u64>,
+    count: u64,
+    rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> NextReseed<R> {
+    /// Returns the elapsed [`Time`] at which the next interval reseed happens. Returns `None`
+    /// when reseeding daily, as the next reseed depends on the [`DateSource`].
+    #[inline]
+    pub fn at(&self) -> Option<Duration> {
+        self.next
+    }
+
+    /// Returns the time remaining until the next interval reseed, relative to `elapsed`
+    /// [`Time`]. Returns `None` when reseeding daily.
+    #[inline]
+    pub fn remaining(&self, elapsed: Duration) -> Option<Duration> {
+        self.next.map(|next| next.saturating_sub(elapsed))
+    }
+
+    /// Returns the day index of the last daily reseed, if any.
+    #[inline]
+    pub fn day(&self) -> Option<u64> {
+        self.day
+    }
+
+    /// Returns the number of reseeds performed so far.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+fn auto_reseed<R: EntropySource + 'static>(
+    time: Res<Time>,
+    mut next: ResMut<NextReseed<R>>,
+    q_global: Query<Entity, (With<Global>, With<RngSeed<R>>)>,
+    mut commands: Commands,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    let next = next.as_mut();
+
+    let seed = match &next.mode {
+        Mode::Interval { interval, base } => {
+            let elapsed = time.elapsed();
+
+            match next.next {
+                Some(at) if at <= elapsed => {
+                    // Skip any intervals missed due to a long frame, only reseeding once.
+                    let missed = (elapsed - at).as_nanos() / interval.as_nanos().max(1);
+                    let skip = u32::try_from(missed + 1).unwrap_or(u32::MAX);
+
+                    next.next = at.checked_add(interval.saturating_mul(skip));
+
+                    base.as_ref()
+                        .map_or_else(RngSeed::<R>::from_entropy, |base| {
+                            RngSeed::<R>::from_seed(derive_seed::<R>(base, next.count))
+                        })
+                }
+                _ => return,
+            }
+        }
+        Mode::Daily { base, date } => {
+            let today = date();
+
+            if next.day == Some(today) {
+                return;
+            }
+
+            next.day = Some(today);
+
+            RngSeed::<R>::from_seed(derive_seed::<R>(base, today))
+        }
+    };
+
+    next.count += 1;
+
+    for entity in q_global.iter() {
+        commands.entity(entity).insert(seed.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    use bevy_app::App;
+    use bevy_prng::WyRand;
+
+    use crate::plugin::EntropyPlugin;
+
+    use super::*;
+
+    fn global_seed(app: &mut App) -> u64 {
+        let seed = app
+            .world_mut()
+            .query_filtered::<&RngSeed<WyRand>, With<Global>>()
+            .iter(app.world())
+            .next()
+            .unwrap()
+            .clone_seed();
+
+        u64::from_ne_bytes(seed)
+    }
+
+    fn advance(app: &mut App, secs: u64) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(secs));
+        app.update();
+    }
+
+    #[test]
+    fn interval_reseeds() {
+        let mut app = App::new();
+
+        app.init_resource::<Time>().add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            AutoReseedPlugin::<WyRand>::every_derived(Duration::from_secs(10), [1; 8]),
+        ));
+
+        let initial = global_seed(&mut app);
+
+        advance(&mut app, 9);
+
+        assert_eq!(global_seed(&mut app), initial);
+        assert_eq!(
+            app.world()
+                .resource::<NextReseed<WyRand>>()
+                .remaining(Duration::from_secs(9)),
+            Some(Duration::from_secs(1))
+        );
+
+        advance(&mut app, 1);
+
+        let first = global_seed(&mut app);
+
+        assert_ne!(first, initial);
+        assert_eq!(first, u64::from_ne_bytes(derive_seed::<WyRand>(&[1; 8], 0)));
+
+        // A long frame skipping multiple intervals only reseeds once
+        advance(&mut app, 35);
+
+        let next = app.world().resource::<NextReseed<WyRand>>();
+
+        assert_eq!(next.count(), 2);
+        assert_eq!(next.at(), Some(Duration::from_secs(50)));
+        assert_eq!(
+            global_seed(&mut app),
+            u64::from_ne_bytes(derive_seed::<WyRand>(&[1; 8], 1))
+        );
+    }
+
+    static DAY: AtomicU64 = AtomicU64::new(20_000);
+
+    #[test]
+    fn daily_reseeds() {
+        let mut app = App::new();
+
+        app.init_resource::<Time>().add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            AutoReseedPlugin::<WyRand>::daily([7; 8], || DAY.load(Ordering::Relaxed)),
+        ));
+
+        app.update();
+
+        let today = global_seed(&mut app);
+
+        // Seeds are derived from the base seed and the day index
+        assert_eq!(
+            today,
+            u64::from_ne_bytes(derive_seed::<WyRand>(&[7; 8], 20_000))
+        );
+        assert_eq!(
+            app.world().resource::<NextReseed<WyRand>>().day(),
+            Some(20_000)
+        );
+
+        advance(&mut app, 3600);
+
+        assert_eq!(global_seed(&mut app), today);
+
+        DAY.store(20_001, Ordering::Relaxed);
+        advance(&mut app, 1);
+
+        assert_ne!(global_seed(&mut app), today);
+        assert_eq!(app.world().resource::<NextReseed<WyRand>>().count(), 2);
+    }
+}
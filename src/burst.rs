@@ -0,0 +1,221 @@
+use core::marker::PhantomData;
+
+use bevy_prng::EntropySource;
+
+use crate::util::{mix_u64, splitmix64};
+
+/// Stateless random values for a burst of particles, or any other large batch of short-lived
+/// things, derived from a single fork with [`ForkableSeed::fork_burst`].
+///
+/// Every value is a pure function of the burst's seed, the particle's `index` and a `lane`
+/// picking which value of that particle to return, such as one lane for lifetime and another
+/// for velocity. Forking a burst advances the parent source exactly once, however many
+/// particles are spawned, and as there is no state to advance, values can be requested in any
+/// order and from any number of threads while always being the same.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{ForkableSeed, GlobalEntropy};
+///
+/// const LIFETIME: u32 = 0;
+/// const SPIN: u32 = 1;
+///
+/// fn spawn_sparks(mut commands: Commands, mut global: GlobalEntropy<WyRand>) {
+///     let burst = global.fork_burst();
+///
+///     for index in 0..500 {
+///         let lifetime = 0.5 + burst.f32_for(index, LIFETIME);
+///         let spin = burst.u32_for(index, SPIN) % 360;
+///
+///         // Spawn a spark with `lifetime` and `spin`
+///     }
+/// }
+/// ```
+///
+/// [`ForkableSeed::fork_burst`]: crate::traits::ForkableSeed::fork_burst
+#[derive(Debug)]
+pub struct BurstRng<R: EntropySource> {
+    key: u64,
+    rng: PhantomData<fn() -> R>,
+}
+
+impl<R: EntropySource> Clone for BurstRng<R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: EntropySource> Copy for BurstRng<R> {}
+
+impl<R: EntropySource> PartialEq for BurstRng<R> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<R: EntropySource> Eq for BurstRng<R> {}
+
+impl<R: EntropySource> BurstRng<R>
+where
+    R::Seed: Clone,
+{
+    /// Creates a burst from `seed`. Every byte of the seed affects every value of the burst.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: &R::Seed) -> Self {
+        let mut seed = seed.clone();
+
+        Self {
+            key: mix_u64(seed.as_mut(), 0),
+            rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource> BurstRng<R> {
+    #[inline]
+    fn mix(&self, index: u64, lane: u32, component: u32) -> u64 {
+        let mut state = self.key ^ index;
+        let mut state = splitmix64(&mut state) ^ (u64::from(component) << 32 | u64::from(lane));
+
+        splitmix64(&mut state)
+    }
+
+    /// Returns the random `u64` for lane `lane` of particle `index`.
+    #[inline]
+    pub fn value_for(&self, index: u64, lane: u32) -> u64 {
+        self.mix(index, lane, 0)
+    }
+
+    /// Returns the random `u32` for lane `lane` of particle `index`, taken from the top bits of
+    /// [`BurstRng::value_for`].
+    #[inline]
+    pub fn u32_for(&self, index: u64, lane: u32) -> u32 {
+        (self.value_for(index, lane) >> 32) as u32
+    }
+
+    /// Returns the random `f32` in `[0, 1)` for lane `lane` of particle `index`, built from
+    /// [`BurstRng::u32_for`] in the same bit-identical way as
+    /// [`crate::util::random_f32_canonical`].
+    #[inline]
+    pub fn f32_for(&self, index: u64, lane: u32) -> f32 {
+        f32::from_bits(0x3F80_0000 | (self.u32_for(index, lane) >> 9)) - 1.0
+    }
+
+    /// Returns the random [`bevy_math::Vec2`] for lane `lane` of particle `index`, with each
+    /// component in `[0, 1)`. The `x` component is [`BurstRng::f32_for`] for the same lane, and
+    /// the `y` component is derived separately, so it does not overlap with any other lane.
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    pub fn vec2_for(&self, index: u64, lane: u32) -> bevy_math::Vec2 {
+        bevy_math::Vec2::new(self.f32_for(index, lane), self.component(index, lane, 1))
+    }
+
+    /// Returns the random [`bevy_math::Vec3`] for lane `lane` of particle `index`, with each
+    /// component in `[0, 1)`. The `x` and `y` components match [`BurstRng::vec2_for`] for the
+    /// same lane, and the `z` component is derived separately.
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    pub fn vec3_for(&self, index: u64, lane: u32) -> bevy_math::Vec3 {
+        self.vec2_for(index, lane)
+            .extend(self.component(index, lane, 2))
+    }
+
+    #[cfg(feature = "bevy_math")]
+    #[inline]
+    fn component(&self, index: u64, lane: u32, component: u32) -> f32 {
+        let bits = (self.mix(index, lane, component) >> 32) as u32;
+
+        f32::from_bits(0x3F80_0000 | (bits >> 9)) - 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_prng::WyRand;
+    use bevy_tasks::TaskPoolBuilder;
+    use rand_core::SeedableRng;
+
+    use crate::{component::Entropy, traits::ForkableSeed};
+
+    #[test]
+    fn bursts_advance_the_parent_once() {
+        let mut parent = Entropy::<WyRand>::from_seed([3; 8]);
+        let mut expected = parent.clone();
+
+        let burst = parent.fork_burst();
+
+        (0..10_000).for_each(|index| {
+            burst.value_for(index, 0);
+        });
+
+        expected.fork_seed();
+
+        assert_eq!(parent, expected);
+    }
+
+    #[test]
+    fn values_do_not_depend_on_call_order() {
+        let burst = Entropy::<WyRand>::from_seed([3; 8]).fork_burst();
+
+        let forwards: Vec<_> = (0..256).map(|index| burst.value_for(index, 1)).collect();
+        let mut backwards: Vec<_> = (0..256)
+            .rev()
+            .map(|index| burst.value_for(index, 1))
+            .collect();
+
+        backwards.reverse();
+
+        assert_eq!(forwards, backwards);
+        assert_ne!(burst.value_for(0, 0), burst.value_for(0, 1));
+        assert_ne!(burst.value_for(0, 0), burst.value_for(1, 0));
+        assert!((0..256).all(|index| (0.0..1.0).contains(&burst.f32_for(index, 2))));
+    }
+
+    #[test]
+    fn values_do_not_depend_on_thread_count() {
+        let burst = Entropy::<WyRand>::from_seed([3; 8]).fork_burst();
+
+        let values = |threads: usize| -> Vec<u32> {
+            let pool = TaskPoolBuilder::new().num_threads(threads).build();
+            let chunk_size = 1024 / threads as u64;
+
+            pool.scope(|scope| {
+                for chunk in 0..threads as u64 {
+                    scope.spawn(async move {
+                        (chunk * chunk_size..(chunk + 1) * chunk_size)
+                            .map(|index| burst.u32_for(index, 0))
+                            .collect::<Vec<_>>()
+                    });
+                }
+            })
+            .concat()
+        };
+
+        let single = values(1);
+
+        assert_eq!(values(2), single);
+        assert_eq!(values(4), single);
+    }
+
+    #[cfg(feature = "bevy_math")]
+    #[test]
+    fn vector_lanes_extend_scalar_lanes() {
+        let burst = Entropy::<WyRand>::from_seed([3; 8]).fork_burst();
+
+        let vec2 = burst.vec2_for(7, 3);
+        let vec3 = burst.vec3_for(7, 3);
+
+        assert_eq!(vec2.x, burst.f32_for(7, 3));
+        assert_eq!(vec3.truncate(), vec2);
+        assert_ne!(vec2.x, vec2.y);
+        assert_ne!(vec3.y, vec3.z);
+    }
+}
@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::prelude::{Entity, ResMut, Resource};
+use bevy_prng::EntropySource;
+
+use crate::{seed::RngSeed, traits::SeedSource};
+
+/// A single seed propagated from a linked source to one of its targets, as recorded in the
+/// [`CascadeTrace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CascadeStep {
+    /// The type name of the RNG algorithm of the propagated seed.
+    pub rng: &'static str,
+    /// The source entity the seed was forked or derived from.
+    pub source: Entity,
+    /// The target entity the seed was propagated to.
+    pub target: Entity,
+    /// The bytes of the propagated seed.
+    pub seed: Vec<u8>,
+}
+
+/// A trace of every seed propagated from linked sources to their targets in the current frame,
+/// in the exact order they were propagated, for comparing between peers or runs when
+/// investigating desyncs. Added and cleared at the start of each frame by [`CascadeTracePlugin`].
+///
+/// Within a single source, seeds are always propagated to its targets in
+/// [`RngLinkIndex`](crate::observers::RngLinkIndex) order. When several sources are reseeded in the
+/// same frame, their cascades run in the order the sources were reseeded, which follows the order
+/// of the commands reseeding them. As each target's seed only depends on its own source, that
+/// order does not change the resulting seeds, but it does change the order of this trace. Compare
+/// [`CascadeTrace::canonical`] instead of [`CascadeTrace::steps`] to ignore the order sources were
+/// reseeded in.
+///
+/// Seeds assigned by stream assignment are not traced, as no seeds are propagated.
+#[derive(Debug, Default, Resource)]
+pub struct CascadeTrace {
+    steps: Vec<CascadeStep>,
+}
+
+impl CascadeTrace {
+    /// Returns the seeds propagated in the current frame, in the exact order they were propagated.
+    #[inline]
+    pub fn steps(&self) -> &[CascadeStep] {
+        &self.steps
+    }
+
+    /// Returns the seeds propagated in the current frame, ordered by their source entity, and by
+    /// propagation order within each source. Unlike [`CascadeTrace::steps`], this is the same
+    /// regardless of the order the sources were reseeded in.
+    pub fn canonical(&self) -> Vec<&CascadeStep> {
+        let mut steps: Vec<_> = self.steps.iter().collect();
+
+        steps.sort_by_key(|step| step.source);
+
+        steps
+    }
+
+    /// Clears the trace.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.steps.clear();
+    }
+}
+
+/// Records the seeds propagated from `source` into the `trace`, if tracing is set up.
+pub(crate) fn record<Rng: EntropySource>(
+    trace: Option<ResMut<CascadeTrace>>,
+    source: Entity,
+    batch: &[(Entity, RngSeed<Rng>)],
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let Some(mut trace) = trace else {
+        return;
+    };
+
+    trace
+        .steps
+        .extend(batch.iter().map(|(target, seed)| CascadeStep {
+            rng: core::any::type_name::<Rng>(),
+            source,
+            target: *target,
+            seed: seed.clone_seed().as_mut().to_vec(),
+        }));
+}
+
+/// Plugin for recording a [`CascadeTrace`] of all seeds propagated to linked targets in each
+/// frame. The trace is cleared at the start of each frame, in [`First`].
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{cascade_trace::CascadeTracePlugin, prelude::EntropyPlugin};
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     CascadeTracePlugin,
+/// ));
+/// ```
+#[derive(Debug, Default)]
+pub struct CascadeTracePlugin;
+
+impl Plugin for CascadeTracePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CascadeTrace>()
+            .add_systems(First, clear_trace);
+    }
+}
+
+fn clear_trace(mut trace: ResMut<CascadeTrace>) {
+    trace.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::{Component, Or, With};
+    use bevy_prng::WyRand;
+
+    use crate::{commands::RngCommandsExt, plugin::EntropyPlugin};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Wizard;
+
+    #[derive(Component)]
+    struct Familiar;
+
+    #[derive(Component)]
+    struct Knight;
+
+    #[derive(Component)]
+    struct Squire;
+
+    type Cascade = (
+        Vec<(Entity, RngSeed<WyRand>)>,
+        Vec<CascadeStep>,
+        Vec<CascadeStep>,
+    );
+
+    fn cascade(reverse: bool) -> Cascade {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            CascadeTracePlugin,
+        ));
+
+        let world = app.world_mut();
+
+        let wizard = world
+            .spawn((Wizard, RngSeed::<WyRand>::from_seed([2; 8])))
+            .id();
+        let knight = world
+            .spawn((Knight, RngSeed::<WyRand>::from_seed([3; 8])))
+            .id();
+
+        for _ in 0..2 {
+            world.spawn(Familiar);
+            world.spawn(Squire);
+        }
+
+        let mut commands = world.commands();
+
+        commands.link_entropy_sources::<Wizard, Familiar, WyRand>();
+        commands.link_entropy_sources::<Knight, Squire, WyRand>();
+        world.flush();
+
+        app.update();
+
+        let world = app.world_mut();
+        let mut reseeds = [
+            (wizard, RngSeed::<WyRand>::from_seed([4; 8])),
+            (knight, RngSeed::<WyRand>::from_seed([5; 8])),
+        ];
+
+        if reverse {
+            reseeds.reverse();
+        }
+
+        for (source, seed) in reseeds {
+            world.commands().entity(source).insert(seed);
+        }
+
+        world.flush();
+
+        let mut seeds: Vec<_> = world
+            .query_filtered::<(Entity, &RngSeed<WyRand>), Or<(With<Familiar>, With<Squire>)>>()
+            .iter(world)
+            .map(|(entity, seed)| (entity, seed.clone()))
+            .collect();
+
+        seeds.sort_by_key(|(entity, _)| *entity);
+
+        let trace = world.resource::<CascadeTrace>();
+
+        (
+            seeds,
+            trace.steps().to_vec(),
+            trace.canonical().into_iter().cloned().collect(),
+        )
+    }
+
+    #[test]
+    fn reseed_order_does_not_change_canonical_traces() {
+        let (seeds, steps, canonical) = cascade(false);
+        let (reversed_seeds, reversed_steps, reversed_canonical) = cascade(true);
+
+        assert_eq!(seeds, reversed_seeds);
+        assert_eq!(canonical, reversed_canonical);
+        assert_eq!(steps.len(), 4);
+        assert_ne!(steps, reversed_steps);
+    }
+}
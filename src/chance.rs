@@ -0,0 +1,352 @@
+use core::{marker::PhantomData, time::Duration};
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::{Component, Query, ReflectComponent, Res};
+use bevy_prng::EntropySource;
+use bevy_reflect::Reflect;
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use bevy_time::Time;
+use rand_core::RngCore;
+
+use crate::component::Entropy;
+
+/// Returns a uniformly distributed value in `[0, 1)` from the top 53 bits of a `u64`.
+#[inline]
+fn unit_f64(rng: &mut impl RngCore) -> f64 {
+    (rng.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// A component for events that happen at random at an average rate per second, such as an idle
+/// animation or an ambient sound, independently of the frame rate.
+///
+/// Rolling `rate * delta` as a probability each frame is subtly wrong, as it triggers more often
+/// at lower frame rates and exceeds a probability of 1 with long frames. Instead,
+/// [`ChancePerSecond::should_trigger`] uses the chance of at least one event of a Poisson process
+/// happening within `delta`, `1 - exp(-rate * delta)`, so the chance over a second is the same
+/// however it is split into frames.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{ChancePerSecond, Entropy};
+/// use bevy_time::Time;
+///
+/// fn yawn(time: Res<Time>, mut q_idlers: Query<(&ChancePerSecond, &mut Entropy<WyRand>)>) {
+///     for (chance, mut rng) in q_idlers.iter_mut() {
+///         if chance.should_trigger_with_entity_rng(&mut rng, time.delta()) {
+///             println!("*yawns*");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct ChancePerSecond {
+    rate: f64,
+}
+
+impl ChancePerSecond {
+    /// Creates a new chance of events happening `rate` times per second on average. Negative or
+    /// NaN rates are treated as zero, so never trigger.
+    #[inline]
+    pub fn new(rate: f64) -> Self {
+        Self {
+            rate: if rate > 0.0 { rate } else { 0.0 },
+        }
+    }
+
+    /// Returns the average number of events per second.
+    #[inline]
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Returns the probability of at least one event happening within `delta`.
+    #[inline]
+    pub fn probability(&self, delta: Duration) -> f64 {
+        -(-self.rate * delta.as_secs_f64()).exp_m1()
+    }
+
+    /// Rolls whether an event happens within `delta` with the provided RNG source, drawing a
+    /// single `u64` from it.
+    #[inline]
+    pub fn should_trigger(&self, rng: &mut impl RngCore, delta: Duration) -> bool {
+        unit_f64(rng) < self.probability(delta)
+    }
+
+    /// Rolls whether an event happens within `delta` with an [`Entropy`] source, typically the
+    /// one belonging to the same entity as this chance. This is the same as
+    /// [`ChancePerSecond::should_trigger`], but allows passing `&mut Mut<Entropy<R>>` from a query
+    /// without explicit derefs.
+    #[inline]
+    pub fn should_trigger_with_entity_rng<R: EntropySource + 'static>(
+        &self,
+        rng: &mut Entropy<R>,
+        delta: Duration,
+    ) -> bool {
+        self.should_trigger(rng, delta)
+    }
+}
+
+/// The distribution that a [`RandomTimer`] picks its durations from.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq))]
+pub enum TimerDistribution {
+    /// Durations uniformly distributed between `min` (inclusive) and `max` (exclusive).
+    Uniform {
+        /// The shortest duration.
+        min: Duration,
+        /// The longest duration.
+        max: Duration,
+    },
+    /// Exponentially distributed durations with the given mean, as between the events of a
+    /// [`ChancePerSecond`] with a rate of `1 / mean`.
+    Exponential {
+        /// The mean duration.
+        mean: Duration,
+    },
+}
+
+impl TimerDistribution {
+    /// Samples a duration with the provided RNG source, drawing a single `u64` from it.
+    pub fn sample(&self, rng: &mut impl RngCore) -> Duration {
+        let unit = unit_f64(rng);
+
+        match *self {
+            Self::Uniform { min, max } => min + max.saturating_sub(min).mul_f64(unit),
+            Self::Exponential { mean } => mean.mul_f64(-(-unit).ln_1p()),
+        }
+    }
+}
+
+/// A timer component that finishes after a random duration, picking its next duration from a
+/// [`TimerDistribution`] with the entity's own [`Entropy`] each time it finishes. Ticked with
+/// [`Time`] by [`RandomTimerPlugin`], before `Update`.
+///
+/// The first duration is picked on the first tick, so the same seed always gives the same
+/// sequence of durations. Any time left over when the timer finishes counts towards the next
+/// duration, so the timer doesn't drift with the frame rate.
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{RandomTimer, TimerDistribution};
+///
+/// #[derive(Component)]
+/// struct Spawner;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Spawner,
+///         RandomTimer::new(TimerDistribution::Uniform {
+///             min: Duration::from_secs(2),
+///             max: Duration::from_secs(5),
+///         }),
+///     ));
+/// }
+///
+/// fn spawn_enemies(q_spawners: Query<&RandomTimer, With<Spawner>>) {
+///     for timer in q_spawners.iter() {
+///         for _ in 0..timer.times_finished_this_tick() {
+///             println!("An enemy appears!");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct RandomTimer {
+    distribution: TimerDistribution,
+    remaining: Option<Duration>,
+    finished: u32,
+}
+
+impl RandomTimer {
+    /// Creates a new timer picking its durations from `distribution`.
+    #[inline]
+    pub fn new(distribution: TimerDistribution) -> Self {
+        Self {
+            distribution,
+            remaining: None,
+            finished: 0,
+        }
+    }
+
+    /// Returns the distribution the timer picks its durations from.
+    #[inline]
+    pub fn distribution(&self) -> &TimerDistribution {
+        &self.distribution
+    }
+
+    /// Returns the time remaining until the timer next finishes, or `None` if it hasn't picked
+    /// its first duration yet.
+    #[inline]
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining
+    }
+
+    /// Returns the number of times the timer finished during the last tick. This can be more
+    /// than once if the last tick was longer than the picked durations.
+    #[inline]
+    pub fn times_finished_this_tick(&self) -> u32 {
+        self.finished
+    }
+
+    /// Returns `true` if the timer finished at least once during the last tick.
+    #[inline]
+    pub fn just_finished(&self) -> bool {
+        self.finished > 0
+    }
+
+    /// Advances the timer by `delta`, picking new durations with the provided RNG source
+    /// whenever it finishes. Returns the number of times the timer finished.
+    pub fn tick(&mut self, delta: Duration, rng: &mut impl RngCore) -> u32 {
+        let mut remaining = match self.remaining {
+            Some(remaining) => remaining,
+            None => self.distribution.sample(rng),
+        };
+        let mut delta = delta;
+
+        self.finished = 0;
+
+        while delta >= remaining {
+            delta -= remaining;
+            self.finished = self.finished.saturating_add(1);
+
+            remaining = self.distribution.sample(rng);
+
+            // Zero length durations would finish forever, so only finish once more this tick.
+            if remaining.is_zero() {
+                delta = Duration::ZERO;
+                remaining = Duration::from_nanos(1);
+            }
+        }
+
+        self.remaining = Some(remaining - delta);
+
+        self.finished
+    }
+}
+
+/// Plugin for ticking all [`RandomTimer`]s on entities with an `R` [`Entropy`] source with the
+/// [`Time`] delta, in `PreUpdate`. Requires a [`Time`] resource, such as the one provided by
+/// `bevy_time`'s `TimePlugin`.
+pub struct RandomTimerPlugin<R: EntropySource>(PhantomData<R>);
+
+impl<R: EntropySource> Default for RandomTimerPlugin<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for RandomTimerPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ChancePerSecond>()
+            .register_type::<RandomTimer>()
+            .add_systems(PreUpdate, tick_random_timers::<R>);
+    }
+}
+
+fn tick_random_timers<R: EntropySource + 'static>(
+    time: Res<Time>,
+    mut q_timers: Query<(&mut RandomTimer, &mut Entropy<R>)>,
+) {
+    for (mut timer, mut rng) in q_timers.iter_mut() {
+        timer.tick(time.delta(), rng.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_app::Update;
+    use bevy_ecs::prelude::{Local, ResMut, Resource};
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn chance_is_frame_rate_independent() {
+        let chance = ChancePerSecond::new(2.0);
+
+        let second = chance.probability(Duration::from_secs(1));
+        let split = 1.0 - (1.0 - chance.probability(Duration::from_millis(100))).powi(10);
+
+        assert!((second - split).abs() < 1e-12);
+        assert!(chance.probability(Duration::from_secs(1000)) <= 1.0);
+        assert_eq!(ChancePerSecond::new(-1.0).probability(Duration::MAX), 0.0);
+    }
+
+    #[test]
+    fn chance_triggers_on_exact_frames() {
+        let chance = ChancePerSecond::new(1.0);
+        let mut rng = WyRand::from_seed([4; 8]);
+
+        let frames: Vec<_> = (0..60)
+            .filter(|_| chance.should_trigger(&mut rng, Duration::from_millis(100)))
+            .collect();
+
+        assert_eq!(frames, [4, 10, 26, 43, 44]);
+    }
+
+    #[derive(Resource, Default)]
+    struct Finished(Vec<u32>);
+
+    #[test]
+    fn timers_finish_on_exact_frames() {
+        let mut app = App::new();
+
+        app.init_resource::<Time>()
+            .init_resource::<Finished>()
+            .add_plugins(RandomTimerPlugin::<WyRand>::default())
+            .add_systems(
+                Update,
+                |q_timers: Query<&RandomTimer>,
+                 mut finished: ResMut<Finished>,
+                 mut frame: Local<u32>| {
+                    if q_timers.iter().any(RandomTimer::just_finished) {
+                        finished.0.push(*frame);
+                    }
+
+                    *frame += 1;
+                },
+            );
+
+        app.world_mut().spawn((
+            RandomTimer::new(TimerDistribution::Uniform {
+                min: Duration::from_millis(250),
+                max: Duration::from_millis(750),
+            }),
+            Entropy::<WyRand>::from_seed([4; 8]),
+        ));
+
+        for _ in 0..30 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<Finished>().0, [4, 9, 13, 17, 20, 25]);
+    }
+}
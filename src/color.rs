@@ -0,0 +1,155 @@
+use core::ops::RangeInclusive;
+
+use bevy_color::{Hsla, Oklcha, Srgba};
+use rand_core::RngCore;
+
+/// Ranges of Oklch components to sample from with [`RngColorExt::random_oklch_in`].
+///
+/// Ranges are clamped to the valid range of each component, `[0.0, 1.0]` for lightness and
+/// chroma and `[0.0, 360.0]` for hue, and reversed ranges are treated as if their bounds were
+/// swapped. The default covers the full valid range of every component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OklchRanges {
+    /// Range of the lightness channel.
+    pub lightness: RangeInclusive<f32>,
+    /// Range of the chroma channel.
+    pub chroma: RangeInclusive<f32>,
+    /// Range of the hue channel, in degrees.
+    pub hue: RangeInclusive<f32>,
+}
+
+impl Default for OklchRanges {
+    fn default() -> Self {
+        Self {
+            lightness: 0.0..=1.0,
+            chroma: 0.0..=1.0,
+            hue: 0.0..=360.0,
+        }
+    }
+}
+
+/// Extension trait for generating random [`bevy_color`] colors from any RNG source, such as
+/// [`crate::component::Entropy`] or [`crate::global::GlobalEntropy`]. All generated colors are
+/// fully opaque, and can be converted into [`bevy_color::Color`] with `.into()`.
+///
+/// ```
+/// use bevy_color::Color;
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{GlobalEntropy, RngColorExt};
+///
+/// fn pick_team_color(mut rng: GlobalEntropy<WyRand>) {
+///     let color: Color = rng.random_hue(0.8, 0.5).into();
+///
+///     println!("Team color: {color:?}");
+/// }
+/// ```
+pub trait RngColorExt: RngCore {
+    /// Generate a color with a uniformly random hue and the given `saturation` and
+    /// `lightness`, which are clamped to `[0.0, 1.0]`.
+    fn random_hue(&mut self, saturation: f32, lightness: f32) -> Hsla {
+        let hue = unit_f32(self) * 360.0;
+
+        Hsla::hsl(hue, saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0))
+    }
+
+    /// Generate a color with uniformly random red, green and blue channels.
+    fn random_srgb(&mut self) -> Srgba {
+        let red = unit_f32(self);
+        let green = unit_f32(self);
+        let blue = unit_f32(self);
+
+        Srgba::rgb(red, green, blue)
+    }
+
+    /// Generate a color with each Oklch component sampled uniformly from `ranges`.
+    fn random_oklch_in(&mut self, ranges: &OklchRanges) -> Oklcha {
+        let lightness = in_range(self, &ranges.lightness, 1.0);
+        let chroma = in_range(self, &ranges.chroma, 1.0);
+        let hue = in_range(self, &ranges.hue, 360.0);
+
+        Oklcha::lch(lightness, chroma, hue)
+    }
+}
+
+impl<R: RngCore + ?Sized> RngColorExt for R {}
+
+/// Generates a uniform `f32` in `[0.0, 1.0)`, using the upper 24 bits of a `u32`
+/// so that every output is exactly representable.
+#[inline]
+fn unit_f32<R: RngCore + ?Sized>(rng: &mut R) -> f32 {
+    const SCALE: f32 = 1.0 / (1u32 << 24) as f32;
+
+    (rng.next_u32() >> 8) as f32 * SCALE
+}
+
+fn in_range<R: RngCore + ?Sized>(rng: &mut R, range: &RangeInclusive<f32>, max: f32) -> f32 {
+    let start = range.start().clamp(0.0, max);
+    let end = range.end().clamp(0.0, max);
+    let (low, high) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+
+    low + (high - low) * unit_f32(rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn assert_close(a: [f32; 3], b: [f32; 3]) {
+        assert!(
+            a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-5),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn pinned_colors() {
+        let mut rng = WyRand::from_seed([5; 8]);
+
+        let hsla = rng.random_hue(0.5, 2.0);
+        let srgba = rng.random_srgb();
+
+        assert_close(
+            [hsla.hue, hsla.saturation, hsla.lightness],
+            [100.562836, 0.5, 1.0],
+        );
+        assert_close(
+            [srgba.red, srgba.green, srgba.blue],
+            [0.37988204, 0.5940442, 0.39827675],
+        );
+        assert_eq!(srgba.alpha, 1.0);
+    }
+
+    #[test]
+    fn oklch_ranges_are_clamped() {
+        let mut rng = WyRand::from_seed([6; 8]);
+
+        let ranges = OklchRanges {
+            lightness: 0.6..=4.0,
+            chroma: 0.2..=0.1,
+            hue: -90.0..=30.0,
+        };
+
+        for _ in 0..100 {
+            let color = rng.random_oklch_in(&ranges);
+
+            assert!((0.6..=1.0).contains(&color.lightness));
+            assert!((0.1..=0.2).contains(&color.chroma));
+            assert!((0.0..=30.0).contains(&color.hue));
+        }
+
+        let color = WyRand::from_seed([6; 8]).random_oklch_in(&OklchRanges::default());
+
+        assert_close(
+            [color.lightness, color.chroma, color.hue],
+            [0.23868638, 0.45648897, 172.9826],
+        );
+    }
+}
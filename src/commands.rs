@@ -0,0 +1,1731 @@
+#[cfg(feature = "experimental")]
+use alloc::format;
+use alloc::{borrow::Cow, string::String, vec::Vec};
+#[cfg(feature = "experimental")]
+use bevy_ecs::{
+    entity_disabling::Disabled,
+    prelude::{Has, Without},
+};
+#[cfg(feature = "experimental")]
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+use bevy_ecs::{
+    prelude::{
+        Bundle, Commands, Component, Entity, EntityCommands, Event, OnInsert, Query, Trigger, With,
+        World,
+    },
+    system::IntoObserverSystem,
+};
+use bevy_prng::{EntropySeed, EntropySource};
+use rand_core::SeedableRng;
+
+#[cfg(feature = "experimental")]
+use crate::observers::{
+    fork_by_link_index, next_link_index, register_linked_observers, LinkRngSourceToTarget,
+    LinkedReseeded, RngChildren, RngLinkIndex, RngLinkKey, RngParent, SeedFromGlobal,
+    SeedFromParent,
+};
+use crate::{
+    component::{Entropy, SimulationRng},
+    derived::DerivedFromRng,
+    global::Global,
+    label::RngLabel,
+    params::NonGlobal,
+    pool::EntropyPool,
+    seed::{DetachedSeed, ForkCounters, ReflectSeedSource, ReservedSeeds, RngSeed, SeedDispenser},
+    short_seed::ShortSeed,
+    temporary::TemporarySeedExt,
+    traits::{ForkableAsSeed, ForkableInnerRng, ForkableInnerSeed, ForkableSeed, SeedSource},
+    util::mix_seed,
+};
+
+/// An event payload bundled with an RNG forked at trigger time, so that observers can make use
+/// of self-contained randomness without needing to query for an [`Entropy`] source.
+///
+/// Triggered with [`RngEntityCommands::trigger_with_rng`] or
+/// [`RngCommandsExt::trigger_with_global_rng`]. The forked RNG is exactly the same as what
+/// manually calling [`ForkableInnerRng::fork_inner`] on the source and then triggering would
+/// provide, so determinism is preserved.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{RngCommandsExt, WithRng};
+/// use rand_core::RngCore;
+///
+/// #[derive(Debug)]
+/// struct Hit;
+///
+/// fn attack(mut commands: Commands, q_targets: Query<Entity, With<Name>>) {
+///     for target in q_targets.iter() {
+///         commands.rng_entity::<WyRand>(target).trigger_with_rng(Hit);
+///     }
+/// }
+///
+/// fn on_hit(mut trigger: Trigger<WithRng<Hit, WyRand>>) {
+///     let damage = trigger.event_mut().rng.next_u32() % 10;
+///
+///     println!("{} took {damage} damage", trigger.target());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Event)]
+pub struct WithRng<E: Send + Sync + 'static, R: EntropySource> {
+    /// The event payload.
+    pub event: E,
+    /// The RNG forked from the source at trigger time.
+    pub rng: R,
+}
+
+impl<E: Send + Sync + 'static, R: EntropySource> WithRng<E, R> {
+    /// Bundle an event payload with an RNG.
+    #[inline]
+    pub fn new(event: E, rng: R) -> Self {
+        Self { event, rng }
+    }
+}
+
+/// Observer event triggered on an entity observed with [`RngEntityCommands::observe_reseeds`]
+/// whenever its [`RngSeed<R>`] is inserted, carrying the new seed.
+#[derive(Clone, Event)]
+pub struct Reseeded<R: EntropySource>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// The entity's new seed.
+    pub seed: RngSeed<R>,
+}
+
+/// Marker for an entity that already forwards its seed insertions as [`Reseeded`] events.
+#[derive(Component)]
+struct ForwardReseeds<R: EntropySource>(PhantomData<R>);
+
+/// Entity observer forwarding seed insertions as [`Reseeded`] events.
+fn forward_reseeds<R: EntropySource>(
+    trigger: Trigger<OnInsert, RngSeed<R>>,
+    q_seeds: Query<&RngSeed<R>>,
+    mut commands: Commands,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    let target = trigger.target();
+
+    if let Ok(seed) = q_seeds.get(target) {
+        commands.trigger_targets(Reseeded { seed: seed.clone() }, target);
+    }
+}
+
+/// How [`RngCommandsExt::reseed_world`] reseeds sources that aren't linked to a parent source.
+/// Linked sources are always reseeded by the cascade from their parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReseedWorldPolicy {
+    /// Leaves unlinked sources untouched.
+    SkipUnlinked,
+    /// Reseeds each unlinked source with a seed mixed from the master seed and a per-entity key:
+    /// its [`RngLinkKey`](crate::observers::RngLinkKey) if it has one, otherwise its [`Entity`]
+    /// bits. Entity bits are only stable if entities are spawned in the same order.
+    DeriveByEntityKey,
+    /// Reseeds each unlinked source with a seed forked from the reseeded [`Global`] source, in
+    /// [`Entity`] order.
+    ForkFromGlobal,
+}
+
+/// Which targets [`RngEntityCommands::link_existing_targets`] forks seeds for.
+#[cfg(feature = "experimental")]
+#[derive(Clone, Copy)]
+enum TargetSeeding {
+    All,
+    Unseeded,
+    None,
+}
+
+/// Extension trait for [`Commands`], providing RNG specific commands.
+pub trait RngCommandsExt {
+    /// Returns the [`RngEntityCommands`] for `entity`, for issuing commands relating to
+    /// its `R` RNG source.
+    fn rng_entity<R: EntropySource>(&mut self, entity: Entity) -> RngEntityCommands<'_, R>;
+
+    /// Triggers `event` bundled with an RNG forked from the [`Global`] `R` source, as a
+    /// [`WithRng`] event. If there is no global source, no event is triggered.
+    fn trigger_with_global_rng<R: EntropySource, E: Send + Sync + 'static>(&mut self, event: E);
+
+    /// Spawns `bundle` with the next seed taken from `pool`, returning the [`EntityCommands`]
+    /// for the new entity. If the pool is exhausted, nothing is spawned and `None` is returned.
+    fn spawn_from_pool<R: EntropySource, B: Bundle>(
+        &mut self,
+        pool: &mut EntropyPool<R>,
+        bundle: B,
+    ) -> Option<EntityCommands<'_>>
+    where
+        R::Seed: Send + Sync + Clone;
+
+    /// Reseeds the [`Global`] `R` source with `seed`, and then every other `R` source in the
+    /// world according to `policy`, all within a single command. Sources linked to a parent are
+    /// reseeded by the cascade from their parent, so reseeding the world twice with the same
+    /// seed leaves every source with the same seed both times. The
+    /// [`UiRng`](crate::ui::UiRng) source is left untouched. If there is no global source,
+    /// nothing is reseeded.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{ReseedWorldPolicy, RngCommandsExt};
+    ///
+    /// fn new_game(mut commands: Commands) {
+    ///     commands.reseed_world::<WyRand>(
+    ///         12345u64.to_le_bytes(),
+    ///         ReseedWorldPolicy::DeriveByEntityKey,
+    ///     );
+    /// }
+    /// ```
+    fn reseed_world<R: EntropySource>(&mut self, seed: R::Seed, policy: ReseedWorldPolicy)
+    where
+        R::Seed: Send + Sync + Clone;
+
+    /// Runs `f` with the [`Global`] `R` source temporarily seeded with `seed`, restoring the
+    /// source afterwards, as with [`TemporarySeedExt::with_temporary_seed`].
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{GlobalEntropy, RngCommandsExt};
+    /// use rand_core::RngCore;
+    ///
+    /// fn take_photo(mut commands: Commands) {
+    ///     commands.with_temporary_seed::<WyRand>(7u64.to_le_bytes(), |world| {
+    ///         world
+    ///             .run_system_cached(|mut global: GlobalEntropy<WyRand>| global.next_u32())
+    ///             .unwrap();
+    ///     });
+    /// }
+    /// ```
+    fn with_temporary_seed<R: EntropySource>(
+        &mut self,
+        seed: R::Seed,
+        f: impl FnOnce(&mut World) + Send + 'static,
+    ) where
+        R::Seed: Send + Sync + Clone;
+
+    /// Links the `Source` entity's `R` source to all `Target` entities, as with triggering
+    /// [`LinkRngSourceToTarget`]. The observers for linking are registered the first time this
+    /// is used for a given `Source`, `Target` and `R`, so adding
+    /// [`crate::plugin::LinkedEntropySources`] is not required.
+    #[cfg(feature = "experimental")]
+    fn link_entropy_sources<Source: Component, Target: Component, R: EntropySource>(&mut self)
+    where
+        R::Seed: Send + Sync + Clone;
+
+    /// Reseeds `entity` with a seed forked from the [`Global`] `R` source, by triggering
+    /// [`SeedFromGlobal`] for it. If `entity` is a linked source, its linked targets are reseeded
+    /// in turn.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngCommandsExt;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// fn reseed_enemies(mut commands: Commands, q_enemies: Query<Entity, With<Enemy>>) {
+    ///     for enemy in q_enemies.iter() {
+    ///         commands.reseed_from_global::<WyRand>(enemy);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "experimental")]
+    fn reseed_from_global<R: EntropySource>(&mut self, entity: Entity);
+
+    /// Reseeds `entity` with a seed forked from its linked parent `R` source, by triggering
+    /// [`SeedFromParent`] for it. If `entity` is not linked to a parent source, it is not
+    /// reseeded.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngCommandsExt;
+    ///
+    /// fn reseed_from_parent(mut commands: Commands, target: Single<Entity, With<Name>>) {
+    ///     commands.reseed_from_parent::<WyRand>(*target);
+    /// }
+    /// ```
+    #[cfg(feature = "experimental")]
+    fn reseed_from_parent<R: EntropySource>(&mut self, entity: Entity);
+
+    /// Reseeds the `Target` entities already linked to the `Source` entity `source` with seeds
+    /// forked from its current `R` source, in [`RngLinkIndex`] order, as its linking observers
+    /// do when it is reseeded. Targets that are themselves sources continue the cascade with their
+    /// own observers. Neither `source`'s seed nor the [`Global`] source are changed, and no
+    /// entities are linked, so `source` must already be linked with its targets, such as with
+    /// [`RngCommandsExt::link_entropy_sources`]. Disabled targets are reseeded as well.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngCommandsExt;
+    ///
+    /// #[derive(Component)]
+    /// struct Spawner;
+    /// #[derive(Component)]
+    /// struct Minion;
+    ///
+    /// fn reseed_minions(mut commands: Commands, spawner: Single<Entity, With<Spawner>>) {
+    ///     commands.reseed_linked::<Spawner, Minion, WyRand>(*spawner);
+    /// }
+    /// ```
+    #[cfg(feature = "experimental")]
+    fn reseed_linked<Source: Component, Target: Component, R: EntropySource>(
+        &mut self,
+        source: Entity,
+    ) where
+        R::Seed: Send + Sync + Clone;
+}
+
+impl RngCommandsExt for Commands<'_, '_> {
+    fn rng_entity<R: EntropySource>(&mut self, entity: Entity) -> RngEntityCommands<'_, R> {
+        RngEntityCommands {
+            commands: self.entity(entity),
+            _rng: PhantomData,
+        }
+    }
+
+    fn trigger_with_global_rng<R: EntropySource, E: Send + Sync + 'static>(&mut self, event: E) {
+        self.queue(move |world: &mut World| {
+            let rng = world
+                .query_filtered::<&mut Entropy<R>, With<Global>>()
+                .iter_mut(world)
+                .next()
+                .map(|mut source| source.fork_inner());
+
+            if let Some(rng) = rng {
+                world.trigger(WithRng::new(event, rng));
+            }
+        });
+    }
+
+    fn spawn_from_pool<R: EntropySource, B: Bundle>(
+        &mut self,
+        pool: &mut EntropyPool<R>,
+        bundle: B,
+    ) -> Option<EntityCommands<'_>>
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let seed = pool.take_seed()?;
+
+        Some(self.spawn((bundle, seed)))
+    }
+
+    fn reseed_world<R: EntropySource>(&mut self, seed: R::Seed, policy: ReseedWorldPolicy)
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.queue(move |world: &mut World| {
+            let Some(global) = world
+                .query_filtered::<Entity, (With<RngSeed<R>>, With<Global>)>()
+                .iter(world)
+                .next()
+            else {
+                return;
+            };
+
+            #[cfg(not(feature = "experimental"))]
+            let mut q_unlinked = world.query_filtered::<Entity, NonGlobal<R>>();
+            #[cfg(feature = "experimental")]
+            let mut q_unlinked =
+                world.query_filtered::<Entity, (NonGlobal<R>, Without<RngParent<R>>)>();
+
+            let mut unlinked: Vec<Entity> = q_unlinked.iter(world).collect();
+
+            unlinked.sort_unstable();
+
+            world
+                .entity_mut(global)
+                .insert(RngSeed::<R>::from_seed(seed.clone()));
+            // Apply the new global seed, and the cascade from it, before reseeding the rest.
+            world.flush();
+
+            let seeds: Vec<(Entity, RngSeed<R>)> = match policy {
+                ReseedWorldPolicy::SkipUnlinked => return,
+                ReseedWorldPolicy::DeriveByEntityKey => unlinked
+                    .into_iter()
+                    .map(|entity| {
+                        #[cfg(feature = "experimental")]
+                        let key = world
+                            .get::<RngLinkKey>(entity)
+                            .map_or_else(|| entity.to_bits(), RngLinkKey::key);
+                        #[cfg(not(feature = "experimental"))]
+                        let key = entity.to_bits();
+
+                        (entity, RngSeed::from_seed(mix_seed::<R>(&seed, key)))
+                    })
+                    .collect(),
+                ReseedWorldPolicy::ForkFromGlobal => {
+                    let Some(mut global) = world.get_mut::<Entropy<R>>(global) else {
+                        return;
+                    };
+
+                    unlinked
+                        .into_iter()
+                        .map(|entity| (entity, global.fork_seed()))
+                        .collect()
+                }
+            };
+
+            for (entity, seed) in seeds {
+                world.entity_mut(entity).insert(seed);
+            }
+        });
+    }
+
+    fn with_temporary_seed<R: EntropySource>(
+        &mut self,
+        seed: R::Seed,
+        f: impl FnOnce(&mut World) + Send + 'static,
+    ) where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.queue(move |world: &mut World| world.with_temporary_seed::<R, _>(seed, f));
+    }
+
+    #[cfg(feature = "experimental")]
+    fn link_entropy_sources<Source: Component, Target: Component, R: EntropySource>(&mut self)
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.queue(|world: &mut World| {
+            register_linked_observers::<Source, Target, R>(world);
+            // Ensure the newly registered observers are in place for the trigger.
+            world.flush();
+            world.trigger(LinkRngSourceToTarget::<Source, Target, R>::default());
+        });
+    }
+
+    #[cfg(feature = "experimental")]
+    fn reseed_from_global<R: EntropySource>(&mut self, entity: Entity) {
+        self.trigger_targets(SeedFromGlobal::<R>::default(), entity);
+    }
+
+    #[cfg(feature = "experimental")]
+    fn reseed_from_parent<R: EntropySource>(&mut self, entity: Entity) {
+        self.trigger_targets(SeedFromParent::<R>::default(), entity);
+    }
+
+    #[cfg(feature = "experimental")]
+    fn reseed_linked<Source: Component, Target: Component, R: EntropySource>(
+        &mut self,
+        source: Entity,
+    ) where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.queue(move |world: &mut World| {
+            let targets: Vec<_> = world
+                .query_filtered::<(Entity, &RngParent<R>, Option<&RngLinkIndex>, Has<Disabled>), (
+                    With<Target>,
+                    Without<Source>,
+                )>()
+                .iter(world)
+                .filter(|(_, parent, ..)| parent.entity() == source)
+                .map(|(target, _, index, _)| (target, index.copied()))
+                .collect();
+
+            let Ok(mut rng) = world
+                .query_filtered::<&mut Entropy<R>, (With<Source>, With<RngChildren<R>>)>()
+                .get_mut(world, source)
+            else {
+                return;
+            };
+
+            let batch = fork_by_link_index(
+                targets
+                    .iter()
+                    .map(|(target, index)| (*target, index.as_ref())),
+                || rng.fork_seed(),
+            );
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                ?source,
+                targets = batch.len(),
+                "propagating seeds to linked targets"
+            );
+
+            world.insert_batch(batch);
+            world.flush();
+            world.trigger_targets(LinkedReseeded::<R>::default(), source);
+        });
+    }
+}
+
+/// Commands for an entity relating to its `R` RNG source. Obtained with
+/// [`RngCommandsExt::rng_entity`].
+pub struct RngEntityCommands<'a, R: EntropySource> {
+    commands: EntityCommands<'a>,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> RngEntityCommands<'_, R> {
+    /// Returns the [`Entity`] these commands apply to.
+    #[inline]
+    pub fn id(&self) -> Entity {
+        self.commands.id()
+    }
+
+    /// Returns the underlying [`EntityCommands`].
+    #[inline]
+    pub fn entity_commands(&mut self) -> EntityCommands<'_> {
+        self.commands.reborrow()
+    }
+
+    /// Labels this entity with a stable [`RngLabel`], such as `"boss_rng"`, preferred over the
+    /// entity id in messages about its source. Labels are expected to be unique, with duplicates
+    /// warned about but not rejected.
+    pub fn labelled(&mut self, label: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.commands.insert(RngLabel::new(label));
+
+        self
+    }
+
+    /// Triggers `event` targeting this entity, bundled with an RNG forked from this entity's
+    /// [`Entropy<R>`] at the time the command is applied, as a [`WithRng`] event. If the entity
+    /// has no [`Entropy<R>`] source, no event is triggered.
+    pub fn trigger_with_rng<E: Send + Sync + 'static>(&mut self, event: E) -> &mut Self {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            let rng = world
+                .get_mut::<Entropy<R>>(entity)
+                .map(|mut source| source.fork_inner());
+
+            if let Some(rng) = rng {
+                world.trigger_targets(WithRng::new(event, rng), entity);
+            }
+        });
+
+        self
+    }
+
+    /// Reserves `count` seeds forked from this entity's [`Entropy<R>`] at the time the command
+    /// is applied, inserting them as a [`ReservedSeeds<R>`] resource. Use with the
+    /// [`GlobalSource`](crate::global::GlobalSource) entity to reserve from the global source.
+    /// If the entity has no [`Entropy<R>`] source, nothing is reserved.
+    pub fn reserve_seeds(&mut self, count: usize) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            let reserved = world
+                .get_mut::<Entropy<R>>(entity)
+                .map(|mut source| ReservedSeeds::<R>::reserve(&mut source, count));
+
+            if let Some(reserved) = reserved {
+                world.insert_resource(reserved);
+            }
+        });
+
+        self
+    }
+
+    /// Rewinds this entity's [`Entropy<R>`] to the start of its sequence, as given by its current
+    /// [`RngSeed<R>`], when the command is applied. The seed is unchanged and the [`Entropy<R>`]
+    /// is restored in place, so linked targets are not reseeded. Use with the
+    /// [`GlobalSource`](crate::global::GlobalSource) entity to rewind the global source, such as
+    /// for replaying a run. If the entity has no [`RngSeed<R>`], nothing is rewound.
+    pub fn rewind(&mut self) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            let Some(seed) = world.get::<RngSeed<R>>(entity).map(RngSeed::clone_seed) else {
+                return;
+            };
+
+            if let Some(mut rng) = world.get_mut::<Entropy<R>>(entity) {
+                *rng = Entropy::from_seed(seed);
+            }
+        });
+
+        self
+    }
+
+    /// Rewinds this entity's [`Entropy<R>`] as with [`RngEntityCommands::rewind`], but by
+    /// reinserting it, so that reseeding propagates to any linked targets as it would when
+    /// reseeding the entity with a new seed.
+    pub fn rewind_and_cascade(&mut self) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            let Some(seed) = world.get::<RngSeed<R>>(entity).map(RngSeed::clone_seed) else {
+                return;
+            };
+
+            world
+                .entity_mut(entity)
+                .insert(Entropy::<R>::from_seed(seed));
+        });
+
+        self
+    }
+
+    /// Replaces the state of this entity's [`Entropy<R>`] with that of `simulation` when the
+    /// command is applied, for the rare case where a simulated branch is chosen and should
+    /// actually consume the source's entropy. The source then continues exactly where the
+    /// simulation left off. The seed is unchanged, so linked targets are not reseeded. If the
+    /// entity has no [`Entropy<R>`], nothing is replaced.
+    pub fn commit_simulation(&mut self, simulation: SimulationRng<R>) -> &mut Self {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            if let Some(mut rng) = world.get_mut::<Entropy<R>>(entity) {
+                *rng = Entropy::new(simulation.into_inner());
+            }
+        });
+
+        self
+    }
+
+    /// Removes this entity's [`RngSeed<R>`] while keeping its [`Entropy<R>`], which otherwise is
+    /// removed along with the seed. Useful for discarding the seeds of many short lived entities
+    /// once their sources are initialised, as the entity keeps drawing from its current state.
+    ///
+    /// Without a seed, the entity's initial seed can no longer be inspected, nor can it be used
+    /// as a source for derivations from its seed, such as stable keyed targets. Reseeding by
+    /// inserting a new [`RngSeed<R>`], as done when reseeding from a global or parent source,
+    /// reattaches a seed and reinitialises the [`Entropy<R>`] from it as usual.
+    pub fn detach_seed(&mut self) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.commands
+            .insert(DetachedSeed::<R>::default())
+            .remove::<RngSeed<R>>();
+
+        self
+    }
+
+    /// Attaches `observer` to this entity, to run with a [`Reseeded`] event whenever the entity's
+    /// [`RngSeed<R>`] is inserted, whether by reseeding it directly or by propagation from a
+    /// linked source. Only this entity's reseeds are observed.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::{commands::Reseeded, prelude::RngCommandsExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Boss;
+    ///
+    /// fn observe_boss(mut commands: Commands, boss: Single<Entity, Added<Boss>>) {
+    ///     commands
+    ///         .rng_entity::<WyRand>(*boss)
+    ///         .observe_reseeds(|trigger: Trigger<Reseeded<WyRand>>| {
+    ///             println!("{} reseeded with {}", trigger.target(), trigger.event().seed);
+    ///         });
+    /// }
+    /// ```
+    pub fn observe_reseeds<M>(
+        &mut self,
+        observer: impl IntoObserverSystem<Reseeded<R>, (), M>,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let entity = self.commands.id();
+
+        // Seed insertions are only forwarded once per entity, however many observers there are.
+        self.commands.commands().queue(move |world: &mut World| {
+            if !world.entities().contains(entity)
+                || world.get::<ForwardReseeds<R>>(entity).is_some()
+            {
+                return;
+            }
+
+            world
+                .entity_mut(entity)
+                .insert(ForwardReseeds::<R>(PhantomData))
+                .observe(forward_reseeds::<R>);
+        });
+
+        self.commands.observe(observer);
+
+        self
+    }
+
+    /// Attaches `observer` to this linked source entity, to run with a [`LinkedReseeded`] event
+    /// once reseeding the source has propagated new seeds to all of its linked targets.
+    #[cfg(feature = "experimental")]
+    pub fn observe_linked_reseeds<M>(
+        &mut self,
+        observer: impl IntoObserverSystem<LinkedReseeded<R>, (), M>,
+    ) -> &mut Self {
+        self.commands.observe(observer);
+
+        self
+    }
+
+    /// Inserts an [`RngSeed<R>`] forked from this entity's [`Entropy<R>`] onto `target`, when the
+    /// command is applied. Use with the [`GlobalSource`](crate::global::GlobalSource) entity to
+    /// seed entities from the global source without also borrowing its [`Entropy<R>`]. The fork
+    /// is the same as forking with [`crate::traits::ForkableSeed::fork_seed`] at the same point.
+    /// If this entity has no [`Entropy<R>`] or `target` no longer exists, nothing is forked.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{GlobalSource, RngCommandsExt, RngSeed, SeedSource};
+    ///
+    /// fn spawn_and_reseed(mut commands: Commands, global: GlobalSource<WyRand>) {
+    ///     let spawned = commands.spawn_empty().id();
+    ///
+    ///     commands
+    ///         .rng_entity::<WyRand>(*global)
+    ///         .fork_seed_into(spawned)
+    ///         .entity_commands()
+    ///         .insert(RngSeed::<WyRand>::from_seed([1; 8]));
+    /// }
+    /// ```
+    pub fn fork_seed_into(&mut self, target: Entity) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.fork_into(target, |source| source.fork_seed())
+    }
+
+    /// Inserts an [`RngSeed<T>`] forked from this entity's [`Entropy<R>`] onto `target`, when the
+    /// command is applied. As with [`RngEntityCommands::fork_seed_into`], but for seeding a
+    /// different PRNG algorithm.
+    pub fn fork_as_seed_into<T: EntropySource>(&mut self, target: Entity) -> &mut Self
+    where
+        T::Seed: Send + Sync + Clone,
+    {
+        self.fork_into(target, |source| source.fork_as_seed::<T>())
+    }
+
+    /// Inserts the bundle built by `bundle` from an `R` seed forked from this entity's
+    /// [`Entropy<R>`] onto `target`, when the command is applied. As with
+    /// [`RngEntityCommands::fork_seed_into`], but for components that take the raw seed.
+    pub fn fork_inner_seed_into<B: Bundle>(
+        &mut self,
+        target: Entity,
+        bundle: impl FnOnce(R::Seed) -> B + Send + 'static,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.fork_into(target, |source| bundle(source.fork_inner_seed()))
+    }
+
+    /// Inserts an [`RngSeed<R>`] onto `target` derived from this entity's [`RngSeed<R>`], `label`
+    /// and the label's counter in [`ForkCounters`], when the command is applied. Unlike
+    /// [`RngEntityCommands::fork_seed_into`], this entity's [`Entropy<R>`] is never advanced, so
+    /// forking for every entity in a wave of any size leaves the sequence drawn by other systems
+    /// unchanged. [`ForkCounters`] is added if it is missing. If this entity has no
+    /// [`RngSeed<R>`], nothing is inserted.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{GlobalSource, RngCommandsExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// fn spawn_wave(mut commands: Commands, global: GlobalSource<WyRand>) {
+    ///     for _ in 0..5 {
+    ///         let enemy = commands.spawn(Enemy).id();
+    ///
+    ///         commands
+    ///             .rng_entity::<WyRand>(*global)
+    ///             .fork_isolated("wave", enemy);
+    ///     }
+    /// }
+    /// ```
+    pub fn fork_isolated(&mut self, label: impl Into<String>, target: Entity) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let entity = self.commands.id();
+        let label = label.into();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            if !world.entities().contains(target) {
+                return;
+            }
+
+            let Some(seed) = world.get::<RngSeed<R>>(entity).cloned() else {
+                return;
+            };
+
+            let forked = world
+                .get_resource_or_init::<ForkCounters>()
+                .fork_isolated(&seed, &label);
+
+            world.entity_mut(target).insert(forked);
+        });
+
+        self
+    }
+
+    /// Queues forking a bundle from this entity's [`Entropy<R>`] and inserting it onto `target`.
+    fn fork_into<B: Bundle>(
+        &mut self,
+        target: Entity,
+        fork: impl FnOnce(&mut Entropy<R>) -> B + Send + 'static,
+    ) -> &mut Self {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            if !world.entities().contains(target) {
+                return;
+            }
+
+            let forked = world
+                .get_mut::<Entropy<R>>(entity)
+                .map(|mut source| fork(&mut source));
+
+            if let Some(forked) = forked {
+                world.entity_mut(target).insert(forked);
+            }
+        });
+
+        self
+    }
+
+    /// Spawns a linked target entity for each item, keyed by the item's stable key, such as a name
+    /// or index. Each target's seed is derived from this entity's [`RngSeed<R>`] and its key when
+    /// the command is applied, rather than drawn in order, so adding, removing or reordering
+    /// items only affects the seeds of the items touched. The key is recorded with a
+    /// [`RngLinkKey`], for rederiving seeds when this entity is reseeded with
+    /// [`crate::plugin::LinkedEntropySources::with_stable_keys`]. If this entity has no
+    /// [`RngSeed<R>`], the targets are spawned without seeds. If this entity has an [`RngLabel`],
+    /// targets without one of their own are labelled after it by their index among the items,
+    /// such as `"boss_rng/0"`.
+    #[cfg(feature = "experimental")]
+    pub fn with_target_rngs_stable<K: Hash, B: Bundle>(
+        &mut self,
+        items: impl IntoIterator<Item = (K, B)>,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let source = self.commands.id();
+        let mut commands = self.commands.commands();
+
+        let targets: Vec<(Entity, RngLinkKey)> = items
+            .into_iter()
+            .map(|(key, bundle)| {
+                let key = RngLinkKey::new(&key);
+
+                let target = commands
+                    .spawn((bundle, key, RngParent::<R>::new(source)))
+                    .id();
+
+                (target, key)
+            })
+            .collect();
+
+        self.commands.insert(RngChildren::<R>::default());
+
+        self.commands.commands().queue(move |world: &mut World| {
+            if let Some(label) = world.get::<RngLabel>(source).cloned() {
+                for (index, &(target, _)) in targets.iter().enumerate() {
+                    if world.entities().contains(target) && world.get::<RngLabel>(target).is_none()
+                    {
+                        world
+                            .entity_mut(target)
+                            .insert(RngLabel::new(format!("{label}/{index}")));
+                    }
+                }
+            }
+
+            let Some(seed) = world.get::<RngSeed<R>>(source).cloned() else {
+                return;
+            };
+
+            for (target, key) in targets {
+                if world.entities().contains(target) {
+                    world.entity_mut(target).insert(key.derive_seed(&seed));
+                }
+            }
+        });
+
+        self
+    }
+
+    /// Links the existing `targets` to this entity's `R` source and reseeds every one of them
+    /// with a seed forked from this entity's [`Entropy<R>`] in the given order, when the command
+    /// is applied. Targets that are already seeded are reseeded all the same, so running this
+    /// twice leaves every target with a new seed, advancing this entity's source each time. Use
+    /// [`RngEntityCommands::with_target_rngs_if_unseeded`] for setup that may run more than once,
+    /// such as after a hot reload, and this to explicitly rerandomise the targets.
+    ///
+    /// Targets not yet linked to this entity are given the next free [`RngLinkIndex`] among its
+    /// targets, while targets already linked to it keep theirs. If this entity has no
+    /// [`Entropy<R>`], the targets are linked without being seeded.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngCommandsExt;
+    ///
+    /// #[derive(Component)]
+    /// struct Board;
+    /// #[derive(Component)]
+    /// struct Tile;
+    ///
+    /// fn shuffle_board(
+    ///     mut commands: Commands,
+    ///     board: Single<Entity, With<Board>>,
+    ///     q_tiles: Query<Entity, With<Tile>>,
+    /// ) {
+    ///     commands
+    ///         .rng_entity::<WyRand>(*board)
+    ///         .with_target_rngs(q_tiles.iter());
+    /// }
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn with_target_rngs(&mut self, targets: impl IntoIterator<Item = Entity>) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.link_existing_targets(targets, TargetSeeding::All)
+    }
+
+    /// Links the existing `targets` to this entity's `R` source, as with
+    /// [`RngEntityCommands::with_target_rngs`], but only forks seeds for the targets without an
+    /// [`RngSeed<R>`], leaving already seeded targets untouched. Running this again with the same
+    /// targets does nothing, and doesn't advance this entity's source, so it is safe for setup
+    /// systems that may run more than once.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngCommandsExt;
+    ///
+    /// #[derive(Component)]
+    /// struct Board;
+    /// #[derive(Component)]
+    /// struct Tile;
+    ///
+    /// fn setup_board(
+    ///     mut commands: Commands,
+    ///     board: Single<Entity, With<Board>>,
+    ///     q_tiles: Query<Entity, With<Tile>>,
+    /// ) {
+    ///     commands
+    ///         .rng_entity::<WyRand>(*board)
+    ///         .with_target_rngs_if_unseeded(q_tiles.iter());
+    /// }
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn with_target_rngs_if_unseeded(
+        &mut self,
+        targets: impl IntoIterator<Item = Entity>,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.link_existing_targets(targets, TargetSeeding::Unseeded)
+    }
+
+    /// Links the existing `targets` to this entity's `R` source, as with
+    /// [`RngEntityCommands::with_target_rngs`], without seeding or reseeding any of them, such as
+    /// for restoring the links of targets loaded with their seeds. The targets are reseeded from
+    /// this entity the next time its seeds propagate.
+    #[cfg(feature = "experimental")]
+    pub fn relink_target_rngs(&mut self, targets: impl IntoIterator<Item = Entity>) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.link_existing_targets(targets, TargetSeeding::None)
+    }
+
+    /// Queues linking `targets` to this entity, then forking seeds for the targets selected by
+    /// `seeding`.
+    #[cfg(feature = "experimental")]
+    fn link_existing_targets(
+        &mut self,
+        targets: impl IntoIterator<Item = Entity>,
+        seeding: TargetSeeding,
+    ) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let source = self.commands.id();
+        let targets: Vec<Entity> = targets.into_iter().collect();
+
+        self.commands.insert(RngChildren::<R>::default());
+
+        self.commands.commands().queue(move |world: &mut World| {
+            let targets: Vec<Entity> = targets
+                .into_iter()
+                .filter(|&target| target != source && world.entities().contains(target))
+                .collect();
+
+            let mut next = next_link_index(
+                world
+                    .query::<(&RngParent<R>, Option<&RngLinkIndex>)>()
+                    .iter(world)
+                    .filter(|(parent, _)| parent.entity() == source)
+                    .map(|(_, index)| index),
+            );
+
+            for &target in &targets {
+                let mut target = world.entity_mut(target);
+
+                if target
+                    .get::<RngParent<R>>()
+                    .is_some_and(|parent| parent.entity() == source)
+                {
+                    continue;
+                }
+
+                target.insert((RngParent::<R>::new(source), RngLinkIndex::new(next)));
+                next += 1;
+            }
+
+            for target in targets {
+                let reseed = match seeding {
+                    TargetSeeding::All => true,
+                    TargetSeeding::Unseeded => world.get::<RngSeed<R>>(target).is_none(),
+                    TargetSeeding::None => false,
+                };
+
+                if !reseed {
+                    continue;
+                }
+
+                let Some(seed) = world
+                    .get_mut::<Entropy<R>>(source)
+                    .map(|mut rng| rng.fork_seed())
+                else {
+                    return;
+                };
+
+                world.entity_mut(target).insert(seed);
+            }
+        });
+
+        self
+    }
+
+    /// Spawns `bundle` as a child linked to this entity's `R` source, returning the commands for
+    /// the child to keep building it. The child is seeded with a seed forked from this entity's
+    /// [`Entropy<R>`] when the command is applied, so it draws in order with any surrounding
+    /// forks. If this entity has no [`Entropy<R>`], the child is spawned without a seed.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngCommandsExt;
+    ///
+    /// #[derive(Component)]
+    /// struct Weapon;
+    /// #[derive(Component)]
+    /// struct Projectile;
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// fn fire(mut commands: Commands, weapon: Single<Entity, With<Weapon>>) {
+    ///     commands
+    ///         .rng_entity::<WyRand>(*weapon)
+    ///         .fork_child(Projectile)
+    ///         .entity_commands()
+    ///         .insert(Velocity(10.0));
+    /// }
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn fork_child<B: Bundle>(&mut self, bundle: B) -> RngEntityCommands<'_, R>
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let source = self.commands.id();
+
+        self.commands.insert(RngChildren::<R>::default());
+
+        let child = self
+            .commands
+            .commands()
+            .spawn((bundle, RngParent::<R>::new(source)))
+            .id();
+
+        self.fork_seed_into(child);
+
+        self.commands.commands_mut().rng_entity(child)
+    }
+
+    /// Spawns `bundle` as a child seeded with a `T` seed forked from this entity's
+    /// [`Entropy<R>`] when the command is applied, returning the commands for the child, as with
+    /// [`RngEntityCommands::fork_child`]. As links are between sources of the same algorithm, the
+    /// child is not linked to this entity.
+    pub fn fork_child_as<T: EntropySource, B: Bundle>(
+        &mut self,
+        bundle: B,
+    ) -> RngEntityCommands<'_, T>
+    where
+        T::Seed: Send + Sync + Clone,
+    {
+        let child = self.commands.commands().spawn(bundle).id();
+
+        self.fork_as_seed_into::<T>(child);
+
+        self.commands.commands_mut().rng_entity(child)
+    }
+
+    /// Stamps this entity with a [`DerivedFromRng<R>`] snapshot of its current [`RngSeed<R>`]
+    /// when the command is applied, marking its procedurally generated data as derived from that
+    /// seed. Used with [`crate::derived::DerivedRngPlugin`], after regenerating the data. If the
+    /// entity has no [`RngSeed<R>`], nothing is stamped.
+    pub fn mark_derived(&mut self) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            let derived = world.get::<RngSeed<R>>(entity).map(DerivedFromRng::new);
+
+            if let Some(derived) = derived {
+                world.entity_mut(entity).insert(derived);
+            }
+        });
+
+        self
+    }
+
+    /// Reseeds this entity with the next seed from the [`SeedDispenser<R>`] on `dispenser`,
+    /// advancing the dispenser when the command is applied. If `dispenser` has no
+    /// [`SeedDispenser<R>`], nothing is reseeded.
+    pub fn reseed_from_dispenser(&mut self, dispenser: Entity) -> &mut Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let entity = self.commands.id();
+
+        self.commands.commands().queue(move |world: &mut World| {
+            let seed = world
+                .get_mut::<SeedDispenser<R>>(dispenser)
+                .map(|mut dispenser| dispenser.next_seed());
+
+            if let Some(seed) = seed.filter(|_| world.entities().contains(entity)) {
+                world
+                    .entity_mut(entity)
+                    .insert(RngSeed::<R>::from_seed(seed));
+            }
+        });
+
+        self
+    }
+
+    /// Reseeds this entity with a seed forked from the `source` entity's `R` source, by
+    /// triggering [`SeedFromGlobal::from_entity`] for it, such as to pick which of several
+    /// global-like sources to fork from. If `source` has no `R` source, an
+    /// [`RngError`](crate::observers::RngError) is triggered on this entity instead.
+    #[cfg(feature = "experimental")]
+    pub fn reseed_from_global_entity(&mut self, source: Entity) -> &mut Self {
+        let entity = self.commands.id();
+
+        self.commands
+            .commands()
+            .trigger_targets(SeedFromGlobal::<R>::from_entity(source), entity);
+
+        self
+    }
+
+    /// Reseeds this entity with the seed expanded from `code`, as with [`ShortSeed::expand`].
+    pub fn reseed_short(&mut self, code: &ShortSeed) -> &mut Self
+    where
+        R::Seed: EntropySeed,
+    {
+        self.commands
+            .insert(RngSeed::<R>::from_seed(code.expand::<R>()));
+
+        self
+    }
+}
+
+/// Extension trait for [`EntityCommands`], for reseeding or removing every RNG source an entity
+/// has without naming their algorithms, such as from debug tooling or editor integrations.
+///
+/// The algorithms are looked up by their [`ReflectSeedSource`] type data, so only those
+/// registered by [`crate::plugin::RngTypesPlugin`] are affected. Each command goes through the
+/// registered algorithms in type path order, so the seeds drawn from [`Global`] sources are the
+/// same every time for the same global seeds.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_rand::prelude::RngEntityCommandsAnyExt;
+///
+/// #[derive(Component)]
+/// struct Inspected;
+///
+/// fn refresh_inspected(mut commands: Commands, q_inspected: Query<Entity, With<Inspected>>) {
+///     for entity in q_inspected.iter() {
+///         commands.entity(entity).reseed_any_from_global();
+///     }
+/// }
+/// ```
+pub trait RngEntityCommandsAnyExt {
+    /// Reseeds every seeded RNG source of this entity with a seed forked from the [`Global`]
+    /// source of its algorithm. Sources whose algorithm has no [`Global`] source are left as
+    /// they are.
+    fn reseed_any_from_global(&mut self) -> &mut Self;
+
+    /// Reseeds every seeded RNG source of this entity with a seed sourced from entropy.
+    fn reseed_any_from_os(&mut self) -> &mut Self;
+
+    /// Removes every RNG source of this entity, along with their seeds.
+    fn remove_any_rng(&mut self) -> &mut Self;
+}
+
+impl RngEntityCommandsAnyExt for EntityCommands<'_> {
+    fn reseed_any_from_global(&mut self) -> &mut Self {
+        let entity = self.id();
+
+        self.commands().queue(move |world: &mut World| {
+            for source in seeded_sources(world, entity) {
+                if let Some(seed) = source.fork_from_global(world) {
+                    source.reseed(world, entity, &seed);
+                }
+            }
+        });
+
+        self
+    }
+
+    fn reseed_any_from_os(&mut self) -> &mut Self {
+        let entity = self.id();
+
+        self.commands().queue(move |world: &mut World| {
+            for source in seeded_sources(world, entity) {
+                source.reseed(world, entity, &source.seed_from_entropy());
+            }
+        });
+
+        self
+    }
+
+    fn remove_any_rng(&mut self) -> &mut Self {
+        let entity = self.id();
+
+        self.commands().queue(move |world: &mut World| {
+            for source in ReflectSeedSource::registered(world) {
+                source.remove(world, entity);
+            }
+        });
+
+        self
+    }
+}
+
+/// Returns the registered algorithms `entity` has a seeded source of, in type path order.
+fn seeded_sources(world: &World, entity: Entity) -> Vec<ReflectSeedSource> {
+    ReflectSeedSource::registered(world)
+        .into_iter()
+        .filter(|source| source.seed(world, entity).is_some())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::{ResMut, Resource, Trigger};
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Roll(u32);
+
+    #[derive(Resource, Default)]
+    struct Received(Option<(Entity, u32, ChaCha8Rng)>);
+
+    #[test]
+    fn trigger_forks_from_entity_source() {
+        let mut world = World::new();
+
+        world.init_resource::<Received>();
+        world.add_observer(
+            |mut trigger: Trigger<WithRng<Roll, ChaCha8Rng>>, mut received: ResMut<Received>| {
+                let target = trigger.target();
+                let event = trigger.event_mut();
+
+                received.0 = Some((target, event.event.0, event.rng.clone()));
+            },
+        );
+
+        let source = world.spawn(Entropy::<ChaCha8Rng>::from_seed([2; 32])).id();
+
+        world
+            .commands()
+            .rng_entity::<ChaCha8Rng>(source)
+            .trigger_with_rng(Roll(5));
+        world.flush();
+
+        // Manually forking from an identical source must produce the same RNG
+        let mut expected_source = Entropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut expected = expected_source.fork_inner();
+
+        let (target, roll, mut rng) = world.resource_mut::<Received>().0.take().unwrap();
+
+        assert_eq!(target, source);
+        assert_eq!(roll, 5);
+        assert_eq!(rng, expected);
+        assert_eq!(rng.next_u32(), expected.next_u32());
+        assert_eq!(
+            world.get::<Entropy<ChaCha8Rng>>(source).unwrap(),
+            &expected_source
+        );
+    }
+
+    #[test]
+    fn no_trigger_without_source() {
+        let mut world = World::new();
+
+        world.init_resource::<Received>();
+        world.add_observer(
+            |_trigger: Trigger<WithRng<Roll, ChaCha8Rng>>, mut received: ResMut<Received>| {
+                received.0 = Some((Entity::PLACEHOLDER, 0, ChaCha8Rng::from_seed([0; 32])));
+            },
+        );
+
+        let entity = world.spawn_empty().id();
+
+        world
+            .commands()
+            .rng_entity::<ChaCha8Rng>(entity)
+            .trigger_with_rng(Roll(1));
+        world
+            .commands()
+            .trigger_with_global_rng::<ChaCha8Rng, _>(Roll(2));
+        world.flush();
+
+        assert!(world.resource::<Received>().0.is_none());
+    }
+
+    #[test]
+    fn reseed_from_dispenser_advances_dispenser() {
+        let mut world = World::new();
+
+        let dispenser = world.spawn(SeedDispenser::<ChaCha8Rng>::new([4; 32])).id();
+        let entities = [world.spawn_empty().id(), world.spawn_empty().id()];
+
+        for entity in entities {
+            world
+                .commands()
+                .rng_entity::<ChaCha8Rng>(entity)
+                .reseed_from_dispenser(dispenser);
+        }
+
+        world.flush();
+
+        let dispenser = world.get::<SeedDispenser<ChaCha8Rng>>(dispenser).unwrap();
+
+        assert_eq!(dispenser.counter(), 2);
+
+        for (index, entity) in entities.into_iter().enumerate() {
+            assert_eq!(
+                world.get::<RngSeed<ChaCha8Rng>>(entity).unwrap().get_seed(),
+                &dispenser.seed_at(index as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn fork_into_matches_global_entropy_forks() {
+        use bevy_ecs::system::RunSystemOnce;
+        use bevy_prng::WyRand;
+
+        use crate::{
+            global::{GlobalEntropy, GlobalSource},
+            traits::{ForkableAsSeed, ForkableInnerSeed, ForkableSeed},
+        };
+
+        let mut world = World::new();
+
+        world.spawn((Global, RngSeed::<ChaCha8Rng>::from_seed([2; 32])));
+
+        let targets: [Entity; 4] = core::array::from_fn(|_| world.spawn_empty().id());
+
+        // Interleave forks queued through the global entity with direct forks from its source
+        world
+            .run_system_once(
+                move |mut commands: Commands, global: GlobalSource<ChaCha8Rng>| {
+                    commands
+                        .rng_entity::<ChaCha8Rng>(*global)
+                        .fork_seed_into(targets[0]);
+                },
+            )
+            .unwrap();
+        world
+            .run_system_once(
+                move |mut commands: Commands, mut global: GlobalEntropy<ChaCha8Rng>| {
+                    commands.entity(targets[1]).insert(global.fork_seed());
+                },
+            )
+            .unwrap();
+        world
+            .run_system_once(
+                move |mut commands: Commands, global: GlobalSource<ChaCha8Rng>| {
+                    commands
+                        .rng_entity::<ChaCha8Rng>(*global)
+                        .fork_as_seed_into::<WyRand>(targets[2])
+                        .fork_inner_seed_into(targets[3], Entropy::<ChaCha8Rng>::from_seed);
+                },
+            )
+            .unwrap();
+
+        let mut expected = Entropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        for target in &targets[..2] {
+            assert_eq!(
+                world.get::<RngSeed<ChaCha8Rng>>(*target).unwrap(),
+                &expected.fork_seed()
+            );
+        }
+
+        assert_eq!(
+            world.get::<RngSeed<WyRand>>(targets[2]).unwrap(),
+            &expected.fork_as_seed::<WyRand>()
+        );
+        assert_eq!(
+            world.get::<Entropy<ChaCha8Rng>>(targets[3]).unwrap(),
+            &Entropy::from_seed(expected.fork_inner_seed())
+        );
+    }
+
+    #[test]
+    fn isolated_forks_leave_global_draws_unchanged() {
+        use bevy_ecs::system::RunSystemOnce;
+
+        use crate::global::{GlobalEntropy, GlobalSource};
+
+        fn run(waves: &[usize]) -> (Vec<u64>, Vec<RngSeed<ChaCha8Rng>>) {
+            let mut world = World::new();
+
+            world.spawn((Global, RngSeed::<ChaCha8Rng>::from_seed([4; 32])));
+
+            let mut draws = Vec::new();
+            let mut forked = Vec::new();
+
+            for &wave in waves {
+                let targets: Vec<Entity> = (0..wave).map(|_| world.spawn_empty().id()).collect();
+                let queued = targets.clone();
+
+                world
+                    .run_system_once(
+                        move |mut commands: Commands, global: GlobalSource<ChaCha8Rng>| {
+                            for target in &queued {
+                                commands
+                                    .rng_entity::<ChaCha8Rng>(*global)
+                                    .fork_isolated("wave", *target);
+                            }
+                        },
+                    )
+                    .unwrap();
+
+                forked.extend(
+                    targets
+                        .iter()
+                        .map(|target| world.get::<RngSeed<ChaCha8Rng>>(*target).unwrap().clone()),
+                );
+
+                draws.push(
+                    world
+                        .run_system_once(|mut global: GlobalEntropy<ChaCha8Rng>| global.next_u64())
+                        .unwrap(),
+                );
+            }
+
+            assert_eq!(
+                world
+                    .get_resource::<ForkCounters>()
+                    .map_or(0, |counters| counters.counter("wave")),
+                forked.len() as u64
+            );
+
+            (draws, forked)
+        }
+
+        let (draws, forked) = run(&[3, 7, 1, 12]);
+        let (resized_draws, resized_forked) = run(&[1, 2, 0, 5]);
+        let (unforked_draws, _) = run(&[0; 4]);
+
+        assert_eq!(draws, unforked_draws);
+        assert_eq!(resized_draws, unforked_draws);
+
+        // Isolated forks are unique, and deterministic regardless of how they are batched
+        for (index, seed) in forked.iter().enumerate() {
+            assert!(!forked[index + 1..].contains(seed));
+        }
+
+        assert_eq!(resized_forked[..], forked[..resized_forked.len()]);
+        assert_eq!(run(&[23]).1, forked);
+    }
+
+    #[test]
+    fn detached_seed_keeps_entropy() {
+        let mut world = World::new();
+
+        let entity = world.spawn(RngSeed::<ChaCha8Rng>::from_seed([3; 32])).id();
+
+        let mut expected = Entropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        world
+            .get_mut::<Entropy<ChaCha8Rng>>(entity)
+            .unwrap()
+            .next_u32();
+        expected.next_u32();
+
+        world
+            .commands()
+            .rng_entity::<ChaCha8Rng>(entity)
+            .detach_seed();
+        world.flush();
+
+        assert!(world.get::<RngSeed<ChaCha8Rng>>(entity).is_none());
+        assert!(world.get::<DetachedSeed<ChaCha8Rng>>(entity).is_none());
+
+        // Drawing continues from the live state
+        let mut rng = world.get_mut::<Entropy<ChaCha8Rng>>(entity).unwrap();
+
+        assert_eq!(rng.next_u64(), expected.next_u64());
+
+        // Reattaching a seed reinitialises the entropy, and removing it again removes both
+        world
+            .entity_mut(entity)
+            .insert(RngSeed::<ChaCha8Rng>::from_seed([4; 32]));
+
+        assert_eq!(
+            world.get::<Entropy<ChaCha8Rng>>(entity),
+            Some(&Entropy::from_seed([4; 32]))
+        );
+
+        world.entity_mut(entity).remove::<RngSeed<ChaCha8Rng>>();
+
+        assert!(world.get::<Entropy<ChaCha8Rng>>(entity).is_none());
+    }
+
+    #[test]
+    fn simulations_only_consume_entropy_when_committed() {
+        let mut world = World::new();
+
+        let entity = world.spawn(RngSeed::<ChaCha8Rng>::from_seed([5; 32])).id();
+
+        let mut expected = ChaCha8Rng::from_seed([5; 32]);
+
+        // Looking ahead draws what the live source would, without perturbing it
+        let rng = world.get::<Entropy<ChaCha8Rng>>(entity).unwrap();
+        let lookahead = rng.with_simulation(|rng| [rng.next_u32(), rng.next_u32()]);
+        let mut branch = rng.simulation_fork();
+
+        assert_eq!(lookahead, [branch.next_u32(), branch.next_u32()]);
+        assert_eq!(rng, &Entropy::from_seed([5; 32]));
+        assert_eq!(lookahead, [expected.next_u32(), expected.next_u32()]);
+
+        // Committing continues the live source from where the branch left off
+        branch.next_u64();
+        expected.next_u64();
+
+        world
+            .commands()
+            .rng_entity::<ChaCha8Rng>(entity)
+            .commit_simulation(branch);
+        world.flush();
+
+        assert_eq!(
+            world.get::<Entropy<ChaCha8Rng>>(entity),
+            Some(&Entropy::new(expected))
+        );
+        assert_eq!(
+            world.get::<RngSeed<ChaCha8Rng>>(entity),
+            Some(&RngSeed::from_seed([5; 32]))
+        );
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn target_rngs_are_only_reseeded_when_intended() {
+        #[derive(Component)]
+        struct Tile;
+
+        fn tile_seeds(world: &mut World) -> Vec<Option<RngSeed<ChaCha8Rng>>> {
+            let mut tiles: Vec<_> = world
+                .query_filtered::<(Entity, Option<&RngSeed<ChaCha8Rng>>), With<Tile>>()
+                .iter(world)
+                .map(|(tile, seed)| (tile, seed.cloned()))
+                .collect();
+
+            tiles.sort_unstable_by_key(|(tile, _)| *tile);
+            tiles.into_iter().map(|(_, seed)| seed).collect()
+        }
+
+        fn setup(world: &mut World, board: Entity) {
+            let tiles: Vec<_> = world
+                .query_filtered::<Entity, With<Tile>>()
+                .iter(world)
+                .collect();
+
+            world
+                .commands()
+                .rng_entity::<ChaCha8Rng>(board)
+                .with_target_rngs_if_unseeded(tiles);
+            world.flush();
+        }
+
+        let mut world = World::new();
+
+        let board = world.spawn(RngSeed::<ChaCha8Rng>::from_seed([6; 32])).id();
+        let tiles: Vec<_> = (0..3).map(|_| world.spawn(Tile).id()).collect();
+
+        // Running the setup again, such as after a hot reload, changes nothing
+        setup(&mut world, board);
+
+        let seeded = tile_seeds(&mut world);
+        let board_rng = world.get::<Entropy<ChaCha8Rng>>(board).unwrap().clone();
+
+        assert!(seeded.iter().all(Option::is_some));
+
+        setup(&mut world, board);
+
+        assert_eq!(tile_seeds(&mut world), seeded);
+        assert_eq!(world.get::<Entropy<ChaCha8Rng>>(board), Some(&board_rng));
+
+        // Only a newly added tile is seeded, with the next link index
+        let added = world.spawn(Tile).id();
+
+        setup(&mut world, board);
+
+        let with_added = tile_seeds(&mut world);
+
+        assert_eq!(with_added[..3], seeded[..]);
+        assert!(with_added[3].is_some());
+        assert_eq!(
+            world.get::<RngLinkIndex>(added),
+            Some(&RngLinkIndex::new(3))
+        );
+
+        // Relinking fixes the links without touching any seeds
+        world.entity_mut(tiles[1]).remove::<RngParent<ChaCha8Rng>>();
+        world
+            .commands()
+            .rng_entity::<ChaCha8Rng>(board)
+            .relink_target_rngs(tiles.iter().copied());
+        world.flush();
+
+        assert_eq!(tile_seeds(&mut world), with_added);
+        assert_eq!(
+            world
+                .get::<RngParent<ChaCha8Rng>>(tiles[1])
+                .map(RngParent::entity),
+            Some(board)
+        );
+        assert_eq!(
+            world.get::<RngLinkIndex>(tiles[0]),
+            Some(&RngLinkIndex::new(0))
+        );
+
+        // Explicitly rerandomising reseeds every tile
+        world
+            .commands()
+            .rng_entity::<ChaCha8Rng>(board)
+            .with_target_rngs(tiles.iter().copied().chain([added]));
+        world.flush();
+
+        assert!(tile_seeds(&mut world)
+            .iter()
+            .zip(&with_added)
+            .all(|(new, old)| new != old));
+    }
+
+    #[test]
+    fn any_rng_commands_cover_every_algorithm() {
+        use bevy_app::App;
+        use bevy_prng::WyRand;
+
+        use crate::plugin::EntropyPlugin;
+
+        fn reseeded_from_global() -> (RngSeed<WyRand>, RngSeed<ChaCha8Rng>) {
+            let mut app = App::new();
+
+            app.add_plugins((
+                EntropyPlugin::<WyRand>::with_seed([1; 8]),
+                EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+            ));
+
+            let world = app.world_mut();
+            let entity = world
+                .spawn((
+                    RngSeed::<WyRand>::from_seed([3; 8]),
+                    RngSeed::<ChaCha8Rng>::from_seed([4; 32]),
+                ))
+                .id();
+
+            world.commands().entity(entity).reseed_any_from_global();
+            world.flush();
+
+            (
+                world.get::<RngSeed<WyRand>>(entity).unwrap().clone(),
+                world.get::<RngSeed<ChaCha8Rng>>(entity).unwrap().clone(),
+            )
+        }
+
+        let (wyrand, chacha) = reseeded_from_global();
+
+        // Both sources are seeded as if forked directly from their global sources
+        assert_eq!(wyrand, Entropy::<WyRand>::from_seed([1; 8]).fork_seed());
+        assert_eq!(
+            chacha,
+            Entropy::<ChaCha8Rng>::from_seed([2; 32]).fork_seed()
+        );
+        assert_eq!(reseeded_from_global(), (wyrand, chacha));
+
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::default(),
+            EntropyPlugin::<ChaCha8Rng>::default(),
+        ));
+
+        let world = app.world_mut();
+        let entity = world
+            .spawn((
+                RngSeed::<WyRand>::from_seed([3; 8]),
+                RngSeed::<ChaCha8Rng>::from_seed([4; 32]),
+            ))
+            .id();
+        let unseeded = world.spawn(RngSeed::<WyRand>::from_seed([5; 8])).id();
+
+        world.commands().entity(entity).reseed_any_from_os();
+        world.flush();
+
+        assert_ne!(
+            world.get::<RngSeed<WyRand>>(entity),
+            Some(&RngSeed::from_seed([3; 8]))
+        );
+        assert_ne!(
+            world.get::<RngSeed<ChaCha8Rng>>(entity),
+            Some(&RngSeed::from_seed([4; 32]))
+        );
+        // Algorithms an entity has no source of are not added
+        world.commands().entity(unseeded).reseed_any_from_os();
+        world.flush();
+
+        assert!(world.get::<RngSeed<ChaCha8Rng>>(unseeded).is_none());
+
+        world.commands().entity(entity).remove_any_rng();
+        world.flush();
+
+        assert!(world.get::<RngSeed<WyRand>>(entity).is_none());
+        assert!(world.get::<Entropy<WyRand>>(entity).is_none());
+        assert!(world.get::<RngSeed<ChaCha8Rng>>(entity).is_none());
+        assert!(world.get::<Entropy<ChaCha8Rng>>(entity).is_none());
+    }
+}
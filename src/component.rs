@@ -1,19 +1,31 @@
+use alloc::{boxed::Box, vec, vec::Vec};
 use core::fmt::Debug;
 
 use crate::{
-    seed::RngSeed,
+    seed::{RngSeed, SeedToken},
     traits::{
         EcsEntropy, ForkableAsRng, ForkableAsSeed, ForkableInnerRng, ForkableInnerSeed,
         ForkableRng, ForkableSeed,
     },
 };
-use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    prelude::{Component, Mut, ReflectComponent},
+    query::QueryFilter,
+    system::Single,
+};
 use bevy_prng::EntropySource;
 use bevy_reflect::{Reflect, ReflectFromReflect};
-use rand_core::{RngCore, SeedableRng};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
 
 #[cfg(feature = "thread_local_entropy")]
-use crate::thread_local_entropy::ThreadLocalEntropy;
+use bevy_prng::ThreadLocalEntropy;
+
+#[cfg(feature = "rand")]
+use rand::{
+    distributions::{uniform::SampleRange, uniform::SampleUniform, Distribution, Standard},
+    Fill, Rng,
+};
 
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -118,6 +130,169 @@ impl<R: EntropySource + 'static> Entropy<R> {
         Self(rng)
     }
 
+    /// Returns a reference to the internal `RngCore` instance.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the internal `RngCore` instance, for passing to APIs
+    /// expecting the concrete PRNG type. Unlike forking, this draws from the component's own
+    /// state.
+    ///
+    /// ```
+    /// use bevy_prng::ChaCha8Rng;
+    /// use bevy_rand::prelude::Entropy;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// fn roll(rng: &mut ChaCha8Rng) -> u32 {
+    ///     rng.next_u32() % 6 + 1
+    /// }
+    ///
+    /// let mut entropy = Entropy::<ChaCha8Rng>::from_seed([1; 32]);
+    /// let mut expected = ChaCha8Rng::from_seed([1; 32]);
+    ///
+    /// assert_eq!(roll(entropy.inner_mut()), roll(&mut expected));
+    /// assert_eq!(entropy.into_inner(), expected);
+    /// ```
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut R {
+        &mut self.0
+    }
+
+    /// Consumes the component, returning the internal `RngCore` instance with its state intact.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+
+    /// Returns the next `n` values [`RngCore::next_u32`] would return, without advancing the
+    /// source, such as for showing upcoming rolls in a debug overlay. Only takes `&self`, so
+    /// peeking through a [`bevy_ecs::change_detection::Mut`] does not mark the component as
+    /// changed.
+    ///
+    /// Peeked values are only a preview: acting on them without drawing them from the source
+    /// breaks the expectation that what was seen is what is drawn next, as the next draws will
+    /// still return them. Draw from the source instead whenever the values are used.
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::Entropy;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = Entropy::<WyRand>::from_seed([1; 8]);
+    /// let upcoming = rng.peek_u32s(2);
+    ///
+    /// assert_eq!(upcoming, [rng.next_u32(), rng.next_u32()]);
+    /// ```
+    pub fn peek_u32s(&self, n: usize) -> Vec<u32> {
+        let mut rng = self.0.clone();
+
+        (0..n).map(|_| rng.next_u32()).collect()
+    }
+
+    /// Returns the next `n` values [`RngCore::next_u64`] would return, without advancing the
+    /// source. See [`Entropy::peek_u32s`] for the caveats of peeking.
+    pub fn peek_u64s(&self, n: usize) -> Vec<u64> {
+        let mut rng = self.0.clone();
+
+        (0..n).map(|_| rng.next_u64()).collect()
+    }
+
+    /// Returns the next `len` bytes [`RngCore::fill_bytes`] would fill, without advancing the
+    /// source. See [`Entropy::peek_u32s`] for the caveats of peeking.
+    pub fn peek_bytes(&self, len: usize) -> Vec<u8> {
+        let mut bytes = vec![0; len];
+
+        self.0.clone().fill_bytes(&mut bytes);
+
+        bytes
+    }
+
+    /// Fills `dest` with random bytes in a single call to the internal PRNG, for filling large
+    /// buffers such as heightmaps. For block based PRNGs such as the ChaCha family, the whole
+    /// buffer is filled by the PRNG's own block generation. The output is the same as
+    /// [`RngCore::fill_bytes`]. For buffers large enough to benefit from multiple threads, see
+    /// [`Entropy::fill_bytes_par`].
+    #[inline]
+    pub fn fill_bytes_bulk(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    /// Deliberately skips the next `n` values [`RngCore::next_u64`] would return, such as to step
+    /// past rolls that were peeked and shown but not used.
+    #[inline]
+    pub fn advance_by(&mut self, n: usize) {
+        for _ in 0..n {
+            self.0.next_u64();
+        }
+    }
+
+    /// Returns a [`SimulationRng`] continuing from this source's current state, for simulating
+    /// ahead with the same outcomes the source would produce, such as for AI lookahead. It draws
+    /// exactly what a clone would draw, but as a distinct type it can't be mistaken for the live
+    /// source. The source itself is not advanced. To make a simulated branch actually consume
+    /// the source's entropy, use
+    /// [`RngEntityCommands::commit_simulation`](crate::commands::RngEntityCommands::commit_simulation).
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::Entropy;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = Entropy::<WyRand>::from_seed([1; 8]);
+    /// let mut simulation = rng.simulation_fork();
+    ///
+    /// assert_eq!(simulation.next_u32(), rng.next_u32());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn simulation_fork(&self) -> SimulationRng<R> {
+        SimulationRng(self.0.clone())
+    }
+
+    /// Runs `simulate` with a copy of the internal PRNG continuing from the source's current
+    /// state, discarding the copy afterwards and returning the result. The source is not
+    /// advanced. See [`Entropy::simulation_fork`].
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::Entropy;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = Entropy::<WyRand>::from_seed([1; 8]);
+    /// let best_roll = rng.with_simulation(|rng| (0..3).map(|_| rng.next_u32() % 6).max());
+    ///
+    /// assert!(best_roll.is_some());
+    /// ```
+    #[inline]
+    pub fn with_simulation<Ret>(&self, simulate: impl FnOnce(&mut R) -> Ret) -> Ret {
+        simulate(&mut self.0.clone())
+    }
+
+    /// Constructs a foreign `T` RNG seeded from this source, as with `T::from_rng`, for handing
+    /// to library code that expects its own RNG type. Works through [`Mut`] and
+    /// [`crate::global::GlobalEntropy`] without needing to import [`SeedableRng`] or [`RngCore`]
+    /// or to dereference manually. Draws the seed from this source, advancing it.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::{ChaCha8Rng, WyRand};
+    /// use bevy_rand::prelude::GlobalEntropy;
+    ///
+    /// fn generate_dungeon(rng: ChaCha8Rng) {
+    ///     // ...
+    /// }
+    ///
+    /// fn setup(mut global: GlobalEntropy<WyRand>) {
+    ///     generate_dungeon(global.seed_other());
+    /// }
+    /// ```
+    #[inline]
+    pub fn seed_other<T: SeedableRng>(&mut self) -> T {
+        T::from_rng(&mut self.0).unwrap()
+    }
+
     /// Reseeds the internal `RngCore` instance with a new seed.
     #[inline]
     #[deprecated = "Make use of `RngSeed` component instead for reseeding."]
@@ -126,6 +301,228 @@ impl<R: EntropySource + 'static> Entropy<R> {
     }
 }
 
+/// Convenience sampling methods, so that systems do not need to import `rand::Rng` (and match its
+/// version) in order to generate values. These are thin wrappers that delegate to `rand::Rng`,
+/// so the trait methods remain available and yield the same output for the same state.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::GlobalEntropy;
+///
+/// fn roll_die(mut rng: GlobalEntropy<WyRand>) {
+///     let roll: u32 = rng.random_range(1..=6);
+///
+///     println!("Rolled a {roll}");
+/// }
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl<R: EntropySource + 'static> Entropy<R> {
+    /// Return a random value supporting the [`Standard`] distribution.
+    /// Equivalent to `rand::Rng::gen`.
+    #[inline]
+    pub fn random<T>(&mut self) -> T
+    where
+        Standard: Distribution<T>,
+    {
+        self.gen()
+    }
+
+    /// Generate a random value in the given range. Equivalent to `rand::Rng::gen_range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    #[inline]
+    pub fn random_range<T, Range>(&mut self, range: Range) -> T
+    where
+        T: SampleUniform,
+        Range: SampleRange<T>,
+    {
+        self.gen_range(range)
+    }
+
+    /// Return a bool with a probability `p` of being true. Equivalent to `rand::Rng::gen_bool`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p < 0` or `p > 1`.
+    #[inline]
+    pub fn random_bool(&mut self, p: f64) -> bool {
+        self.gen_bool(p)
+    }
+
+    /// Fill any type implementing [`Fill`], such as slices of integers, with random data.
+    /// Equivalent to `rand::Rng::fill`.
+    #[inline]
+    pub fn fill<T: Fill + ?Sized>(&mut self, dest: &mut T) {
+        Rng::fill(self, dest);
+    }
+}
+
+/// Extension trait for drawing from an [`Entropy`] component without marking it as changed, so
+/// that systems filtering on `Changed<Entropy<R>>`, such as replication layers, don't run after
+/// every draw. Implemented for [`Mut`], so it can be used on query items as well as on
+/// [`crate::global::GlobalEntropy`].
+///
+/// Draws still advance the source's state: only change detection is suppressed. This is safe as
+/// long as nothing relies on observing the state through change detection, such as replicating
+/// or saving the [`Entropy`] whenever it changes, as those copies will fall behind the actual
+/// state. When only the seed needs to be kept in sync, watch the immutable [`RngSeed`]
+/// component instead, which only changes when the source is reseeded.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyChangeExt, GlobalEntropy};
+/// use rand_core::RngCore;
+///
+/// fn ambient_flicker(mut rng: GlobalEntropy<WyRand>) {
+///     let flicker = rng.bypass_changed().next_u32() % 16;
+///
+///     println!("Flicker: {flicker}");
+/// }
+/// ```
+pub trait EntropyChangeExt<R: EntropySource + 'static> {
+    /// Returns the [`Entropy`] for drawing from without marking it as changed.
+    fn bypass_changed(&mut self) -> &mut Entropy<R>;
+
+    /// Draws from the [`Entropy`] with `draw` without marking it as changed, returning the
+    /// result.
+    #[inline]
+    fn draw_untracked<T>(&mut self, draw: impl FnOnce(&mut Entropy<R>) -> T) -> T {
+        draw(self.bypass_changed())
+    }
+}
+
+impl<R: EntropySource + 'static> EntropyChangeExt<R> for Mut<'_, Entropy<R>> {
+    #[inline]
+    fn bypass_changed(&mut self) -> &mut Entropy<R> {
+        self.bypass_change_detection()
+    }
+}
+
+/// A copy of an [`Entropy`] source's PRNG for simulating ahead without consuming the source's
+/// entropy, created with [`Entropy::simulation_fork`]. Deliberately not a component or
+/// convertible into an [`Entropy`], so it can't be accidentally written back to the live source.
+/// Committing a simulated branch is only possible with
+/// [`RngEntityCommands::commit_simulation`](crate::commands::RngEntityCommands::commit_simulation).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationRng<R: EntropySource>(R);
+
+impl<R: EntropySource> SimulationRng<R> {
+    /// Returns a mutable reference to the internal PRNG, for passing to APIs expecting the
+    /// concrete PRNG type.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut R {
+        &mut self.0
+    }
+
+    pub(crate) fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R: EntropySource> RngCore for SimulationRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R: EntropySource + CryptoRng> CryptoRng for SimulationRng<R> {}
+
+/// Extension trait for passing [`Entropy`] sources accessed through a [`Mut`] or a
+/// [`Single`](bevy_ecs::system::Single) system param, such as
+/// [`crate::global::GlobalEntropy`], to generic code bounded on [`RngCore`], such as
+/// `SeedableRng::from_rng` or `rand::seq` methods. Those wrappers can't implement [`RngCore`]
+/// themselves, as they are defined outside of this crate, so the bound is otherwise only met
+/// after manually dereferencing to the [`Entropy`].
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{AsEntropyMut, Entropy, GlobalEntropy};
+/// use rand_core::RngCore;
+///
+/// fn roll<G: RngCore>(rng: &mut G) -> u32 {
+///     rng.next_u32() % 6 + 1
+/// }
+///
+/// fn roll_all(mut global: GlobalEntropy<WyRand>, mut q_rngs: Query<&mut Entropy<WyRand>>) {
+///     println!("Global rolled {}", roll(global.as_entropy_mut()));
+///
+///     q_rngs
+///         .iter_mut()
+///         .for_each(|mut rng| println!("Rolled {}", roll(rng.as_entropy_mut())));
+/// }
+/// ```
+pub trait AsEntropyMut<R: EntropySource + 'static> {
+    /// Returns the [`Entropy`] for drawing from, marking it as changed when accessed through a
+    /// [`Mut`].
+    fn as_entropy_mut(&mut self) -> &mut Entropy<R>;
+}
+
+impl<R: EntropySource + 'static> AsEntropyMut<R> for Entropy<R> {
+    #[inline]
+    fn as_entropy_mut(&mut self) -> &mut Entropy<R> {
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> AsEntropyMut<R> for Mut<'_, Entropy<R>> {
+    #[inline]
+    fn as_entropy_mut(&mut self) -> &mut Entropy<R> {
+        self
+    }
+}
+
+impl<R: EntropySource + 'static, F: QueryFilter> AsEntropyMut<R>
+    for Single<'_, &mut Entropy<R>, F>
+{
+    #[inline]
+    fn as_entropy_mut(&mut self) -> &mut Entropy<R> {
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> From<R> for Entropy<R> {
+    #[inline]
+    fn from(value: R) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<R: EntropySource + 'static> From<SeedToken<R>> for Entropy<R> {
+    #[inline]
+    fn from(token: SeedToken<R>) -> Self {
+        Self::new(token.into_rng())
+    }
+}
+
+impl<R: EntropySource + 'static> AsMut<R> for Entropy<R> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut R {
+        &mut self.0
+    }
+}
+
 impl<R: EntropySource + 'static> Default for Entropy<R> {
     #[inline]
     fn default() -> Self {
@@ -155,6 +552,32 @@ impl<R: EntropySource + 'static> RngCore for Entropy<R> {
     }
 }
 
+impl<R: EntropySource + CryptoRng + 'static> CryptoRng for Entropy<R> {}
+
+/// Forwards to the `rand_core` 0.6 implementation, so the output is identical.
+#[cfg(feature = "compat_09")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compat_09")))]
+impl<R: EntropySource + 'static> rand_core_09::RngCore for Entropy<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+}
+
+#[cfg(feature = "compat_09")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compat_09")))]
+impl<R: EntropySource + CryptoRng + 'static> rand_core_09::CryptoRng for Entropy<R> {}
+
 impl<R: EntropySource + 'static> SeedableRng for Entropy<R> {
     type Seed = R::Seed;
 
@@ -186,6 +609,86 @@ impl<R: EntropySource + 'static> SeedableRng for Entropy<R> {
 
 impl<R: EntropySource + 'static> EcsEntropy for Entropy<R> {}
 
+/// A type-erased RNG component, for plugins that need randomness without knowing which PRNG
+/// algorithm the app uses. Created from an [`Entropy`] with [`BoxedEntropy::from_entropy`], or
+/// forked from a source with [`ForkableInnerRng::fork_boxed`].
+///
+/// As the PRNG algorithm is erased, [`BoxedEntropy`] cannot be reflected, serialized or reseeded
+/// generically, and has no associated [`RngSeed`]. It only provides [`RngCore`], along with the
+/// type path of the erased algorithm for diagnostics. To reseed it, replace it with a new instance.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_rand::prelude::BoxedEntropy;
+/// use rand_core::RngCore;
+///
+/// fn plugin_system(mut q_rngs: Query<&mut BoxedEntropy>) {
+///     for mut rng in q_rngs.iter_mut() {
+///         println!("Random value from {}: {}", rng.algorithm(), rng.next_u32());
+///     }
+/// }
+/// ```
+#[derive(Component)]
+pub struct BoxedEntropy {
+    rng: Box<dyn RngCore + Send + Sync>,
+    algorithm: &'static str,
+}
+
+impl BoxedEntropy {
+    /// Create a new component by boxing a PRNG instance.
+    #[inline]
+    #[must_use]
+    pub fn new<R: EntropySource + 'static>(rng: R) -> Self {
+        Self {
+            rng: Box::new(rng),
+            algorithm: R::type_path(),
+        }
+    }
+
+    /// Create a new component by boxing the PRNG instance of an [`Entropy`].
+    #[inline]
+    #[must_use]
+    pub fn from_entropy<R: EntropySource + 'static>(entropy: Entropy<R>) -> Self {
+        Self::new(entropy.0)
+    }
+
+    /// Returns the type path of the erased PRNG algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static str {
+        self.algorithm
+    }
+}
+
+impl Debug for BoxedEntropy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BoxedEntropy")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RngCore for BoxedEntropy {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
 impl<R> ForkableRng for Entropy<R>
 where
     R: EntropySource + 'static,
@@ -246,6 +749,44 @@ mod tests {
 
     use super::*;
 
+    #[cfg(feature = "compat_09")]
+    #[test]
+    fn compat_09_matches_native_output() {
+        use bevy_prng::WyRand;
+
+        fn draw<R: rand_core_09::RngCore>(rng: &mut R) -> (u32, u64, [u8; 5]) {
+            let mut bytes = [0; 5];
+
+            rng.fill_bytes(&mut bytes);
+
+            (rng.next_u32(), rng.next_u64(), bytes)
+        }
+
+        let mut native = Entropy::<WyRand>::from_seed([7; 8]);
+        let mut bytes = [0; 5];
+
+        native.fill_bytes(&mut bytes);
+
+        let expected = (native.next_u32(), native.next_u64(), bytes);
+
+        assert_eq!(draw(&mut Entropy::<WyRand>::from_seed([7; 8])), expected);
+        assert_eq!(draw(&mut WyRand::from_seed([7; 8])), expected);
+    }
+
+    #[test]
+    fn into_inner_preserves_state() {
+        let mut rng = Entropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        rng.next_u64();
+
+        let mut expected = rng.clone();
+        let mut inner = rng.into_inner();
+
+        assert_eq!(expected.inner(), &inner);
+        assert_eq!(inner.next_u64(), expected.next_u64());
+        assert_eq!(Entropy::from(inner), expected);
+    }
+
     #[test]
     fn forking() {
         let mut rng1 = Entropy::<ChaCha8Rng>::default();
@@ -292,6 +833,32 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn inherent_sampling() {
+        use rand::Rng;
+
+        let mut rng = Entropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        assert_eq!(rng.random::<u32>(), 3692720504);
+        assert_eq!(rng.random_range(0u32..=20), 18);
+        assert!(!rng.random_bool(0.5));
+
+        let mut bytes = [0u8; 4];
+        rng.fill(&mut bytes);
+
+        assert_eq!(&bytes, &[236, 171, 59, 248]);
+
+        // The inherent methods must give the same output as the `rand::Rng` methods.
+        let mut a = Entropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut b = Entropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        assert_eq!(a.random::<u64>(), b.gen::<u64>());
+        assert_eq!(a.random_range(-5i32..5), b.gen_range(-5i32..5));
+        assert_eq!(a.random_bool(0.3), b.gen_bool(0.3));
+        assert_eq!(a, b);
+    }
+
     #[cfg(feature = "serialize")]
     #[test]
     fn rng_untyped_serialization() {
@@ -389,4 +956,149 @@ mod tests {
             "The deserialized Entropy should have the same output as original"
         );
     }
+
+    #[test]
+    fn peeking_leaves_the_source_untouched() {
+        use bevy_ecs::{change_detection::DetectChanges, world::World};
+
+        let mut world = World::new();
+        let entity = world.spawn(Entropy::<ChaCha8Rng>::from_seed([1; 32])).id();
+
+        world.clear_trackers();
+
+        let mut expected = ChaCha8Rng::from_seed([1; 32]);
+        let mut source = world.get_mut::<Entropy<ChaCha8Rng>>(entity).unwrap();
+
+        let u32s = source.peek_u32s(3);
+        let u64s = source.peek_u64s(2);
+        let bytes = source.peek_bytes(5);
+
+        assert!(!source.is_changed());
+        assert_eq!(
+            u32s,
+            [
+                expected.next_u32(),
+                expected.next_u32(),
+                expected.next_u32()
+            ]
+        );
+
+        let mut expected = ChaCha8Rng::from_seed([1; 32]);
+
+        assert_eq!(u64s, [expected.next_u64(), expected.next_u64()]);
+        assert_eq!(source.peek_u32s(3), u32s);
+
+        let mut expected_bytes = [0; 5];
+
+        ChaCha8Rng::from_seed([1; 32]).fill_bytes(&mut expected_bytes);
+
+        assert_eq!(bytes, expected_bytes);
+
+        // Drawing returns the peeked values
+        assert_eq!(source.next_u64(), u64s[0]);
+
+        // Advancing skips values
+        source.advance_by(2);
+
+        let mut expected = ChaCha8Rng::from_seed([1; 32]);
+
+        expected.next_u64();
+        expected.next_u64();
+        expected.next_u64();
+
+        assert_eq!(source.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn wrapped_sources_meet_generic_rng_bounds() {
+        use bevy_ecs::{
+            prelude::{Query, Without, World},
+            system::RunSystemOnce,
+        };
+        use bevy_prng::WyRand;
+
+        use crate::global::{Global, GlobalEntropy};
+
+        fn roll<G: RngCore>(rng: &mut G) -> u32 {
+            rng.next_u32()
+        }
+
+        fn foreign<G: RngCore>(rng: G) -> WyRand {
+            WyRand::from_rng(rng).unwrap()
+        }
+
+        let mut world = World::new();
+        let entity = world.spawn(Entropy::<ChaCha8Rng>::from_seed([1; 32])).id();
+
+        world.spawn((Global, Entropy::<ChaCha8Rng>::from_seed([2; 32])));
+
+        let mut expected = ChaCha8Rng::from_seed([1; 32]);
+
+        // Closures capturing a `Mut` can hand it to generic code
+        let mut source = world.get_mut::<Entropy<ChaCha8Rng>>(entity).unwrap();
+        let mut draw = || roll(source.as_entropy_mut());
+
+        assert_eq!(draw(), expected.next_u32());
+        assert_eq!(foreign(source.as_entropy_mut()), foreign(&mut expected));
+        assert_eq!(source.seed_other::<WyRand>(), foreign(&mut expected));
+
+        let rolls = world
+            .run_system_once(
+                |mut global: GlobalEntropy<ChaCha8Rng>,
+                 mut q_rngs: Query<&mut Entropy<ChaCha8Rng>, Without<Global>>| {
+                    let global = roll(global.as_entropy_mut());
+                    let local = q_rngs
+                        .iter_mut()
+                        .map(|mut rng| roll(rng.as_entropy_mut()))
+                        .next();
+
+                    (global, local)
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            rolls,
+            (
+                ChaCha8Rng::from_seed([2; 32]).next_u32(),
+                Some(expected.next_u32())
+            )
+        );
+    }
+
+    #[test]
+    fn untracked_draws_do_not_mark_changed() {
+        use bevy_ecs::{prelude::Changed, world::World};
+
+        let mut world = World::new();
+        let entity = world.spawn(Entropy::<ChaCha8Rng>::from_seed([1; 32])).id();
+
+        let changed = |world: &mut World| {
+            let count = world
+                .query_filtered::<(), Changed<Entropy<ChaCha8Rng>>>()
+                .iter(world)
+                .count();
+
+            world.clear_trackers();
+
+            count
+        };
+
+        changed(&mut world);
+
+        let mut expected = ChaCha8Rng::from_seed([1; 32]);
+        let mut source = world.get_mut::<Entropy<ChaCha8Rng>>(entity).unwrap();
+
+        assert_eq!(source.bypass_changed().next_u32(), expected.next_u32());
+        assert_eq!(
+            source.draw_untracked(|rng| rng.next_u64()),
+            expected.next_u64()
+        );
+        assert_eq!(changed(&mut world), 0);
+
+        let mut source = world.get_mut::<Entropy<ChaCha8Rng>>(entity).unwrap();
+
+        assert_eq!(source.next_u32(), expected.next_u32());
+        assert_eq!(changed(&mut world), 1);
+    }
 }
@@ -0,0 +1,466 @@
+//! The grammar understood by [`exec_rng_command`] is:
+//!
+//! ```text
+//! rng.show <target> [rng]
+//! rng.reseed <target> <rng> <seed>
+//! rng.reroll <target> [rng]
+//! ```
+//!
+//! - `<target>` is `global` for the [`Global`] source, an entity as displayed by bevy such as
+//!   `4v1`, or an entity index such as `4`, or else an [`RngLabel`](crate::label::RngLabel).
+//! - `<rng>` is the short or full type path of an RNG algorithm with registered sources, such as
+//!   `ChaCha8Rng` or `bevy_prng::ChaCha8Rng`. It can be left out of `rng.show` and `rng.reroll`
+//!   if it is not ambiguous.
+//! - `<seed>` is either the seed's bytes as hex, as displayed by [`RngSeed`], such as
+//!   `0123456789abcdef` for an 8 byte seed, or a `0x` prefixed `u64` value such as `0xDEADBEEF`,
+//!   expanded to a full seed as with [`RngSeed::from_u64`].
+//!
+//! `rng.show` lists the seeds of the target's sources. `rng.reseed` reseeds the target with the
+//! given seed. `rng.reroll` reseeds the target with a seed forked from the [`Global`] source, or
+//! from entropy when rerolling the [`Global`] source itself.
+//!
+//! [`Global`]: crate::global::Global
+//! [`RngSeed`]: crate::seed::RngSeed
+//! [`RngSeed::from_u64`]: crate::seed::RngSeed::from_u64
+
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use bevy_ecs::prelude::{Entity, World};
+
+use crate::{label::RngLabelIndex, seed::ReflectSeedSource, util::to_hex};
+
+/// Errors from parsing or executing a command with [`exec_rng_command`], with messages suitable
+/// for showing in a console.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RngConsoleError {
+    /// The command was not one of `rng.show`, `rng.reseed` or `rng.reroll`.
+    UnknownCommand(String),
+    /// A required argument was missing.
+    MissingArgument(&'static str),
+    /// More arguments were given than the command takes.
+    UnexpectedArgument(String),
+    /// No registered RNG algorithm has the given type path.
+    UnknownRng(String),
+    /// The RNG algorithm was left out, but the target has sources of several algorithms.
+    AmbiguousRng(Vec<String>),
+    /// The target was not `global`, an existing entity, nor a label of one.
+    MissingEntity(String),
+    /// The target has no sources of the given RNG algorithm, or of any algorithm if none was
+    /// given.
+    NoSource(String),
+    /// The seed was not valid hex of the right length, nor a `0x` prefixed `u64`.
+    BadSeed {
+        /// The seed as given.
+        seed: String,
+        /// The expected length of the seed in bytes.
+        len: usize,
+    },
+}
+
+impl fmt::Display for RngConsoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand(command) => write!(
+                f,
+                "unknown command `{command}`, expected `rng.show`, `rng.reseed` or `rng.reroll`"
+            ),
+            Self::MissingArgument(argument) => write!(f, "missing argument <{argument}>"),
+            Self::UnexpectedArgument(argument) => write!(f, "unexpected argument `{argument}`"),
+            Self::UnknownRng(rng) => write!(
+                f,
+                "unknown RNG `{rng}`, expected the type path of an RNG with registered sources"
+            ),
+            Self::AmbiguousRng(rngs) => write!(
+                f,
+                "the target has sources of several RNGs, specify one of: {}",
+                rngs.join(", ")
+            ),
+            Self::MissingEntity(target) => {
+                write!(f, "`{target}` is not an existing entity or RNG label")
+            }
+            Self::NoSource(message) => f.write_str(message),
+            Self::BadSeed { seed, len } => write!(
+                f,
+                "bad seed `{seed}`, expected {} hex digits or a 0x prefixed u64",
+                len * 2
+            ),
+        }
+    }
+}
+
+impl core::error::Error for RngConsoleError {}
+
+/// Parses and executes an RNG console `command` against `world`, returning a human readable
+/// response. See the [module docs](self) for the grammar. RNG algorithms are looked up in the
+/// world's [`AppTypeRegistry`] by their [`ReflectSeedSource`] type data, so no generics are
+/// needed at the call site.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use bevy_rand::{console::exec_rng_command, prelude::EntropyPlugin};
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(EntropyPlugin::<ChaCha8Rng>::default());
+///
+/// let response = exec_rng_command(app.world_mut(), "rng.reseed global ChaCha8Rng 0xDEADBEEF");
+///
+/// assert!(response.is_ok());
+/// ```
+pub fn exec_rng_command(world: &mut World, command: &str) -> Result<String, RngConsoleError> {
+    let mut args = command.split_whitespace();
+
+    let name = args.next().unwrap_or_default();
+    let target = args.next();
+    let rng = args.next();
+    let seed = args.next();
+
+    if let Some(unexpected) = args.next() {
+        return Err(RngConsoleError::UnexpectedArgument(unexpected.to_owned()));
+    }
+
+    let sources = ReflectSeedSource::registered(world);
+
+    match name {
+        "rng.show" => {
+            reject(seed)?;
+
+            let target = target.ok_or(RngConsoleError::MissingArgument("target"))?;
+            let lines = resolve(world, &sources, target, rng)?
+                .into_iter()
+                .filter_map(|(entity, source)| {
+                    source.seed(world, entity).map(|seed| {
+                        format!(
+                            "{entity} {}: seed {}",
+                            source.rng_short_type_path(),
+                            to_hex(&seed)
+                        )
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            Ok(lines.join("\n"))
+        }
+        "rng.reseed" => {
+            let target = target.ok_or(RngConsoleError::MissingArgument("target"))?;
+            let rng = rng.ok_or(RngConsoleError::MissingArgument("rng"))?;
+            let seed = seed.ok_or(RngConsoleError::MissingArgument("seed"))?;
+
+            let (entity, source) = resolve_one(world, &sources, target, Some(rng))?;
+            let bytes = parse_seed(source, seed)?;
+
+            source.reseed(world, entity, &bytes);
+
+            Ok(format!(
+                "Reseeded {entity} {} with seed {}",
+                source.rng_short_type_path(),
+                to_hex(&bytes)
+            ))
+        }
+        "rng.reroll" => {
+            reject(seed)?;
+
+            let target = target.ok_or(RngConsoleError::MissingArgument("target"))?;
+            let (entity, source) = resolve_one(world, &sources, target, rng)?;
+
+            let bytes = if source.global(world) == Some(entity) {
+                source.seed_from_entropy()
+            } else {
+                source.fork_from_global(world).ok_or_else(|| {
+                    RngConsoleError::NoSource(format!(
+                        "there is no global {} source to reroll from",
+                        source.rng_short_type_path()
+                    ))
+                })?
+            };
+
+            source.reseed(world, entity, &bytes);
+
+            Ok(format!(
+                "Rerolled {entity} {} to seed {}",
+                source.rng_short_type_path(),
+                to_hex(&bytes)
+            ))
+        }
+        _ => Err(RngConsoleError::UnknownCommand(name.to_owned())),
+    }
+}
+
+fn reject(argument: Option<&str>) -> Result<(), RngConsoleError> {
+    match argument {
+        Some(argument) => Err(RngConsoleError::UnexpectedArgument(argument.to_owned())),
+        None => Ok(()),
+    }
+}
+
+/// Resolves `target` and `rng` into the sources they refer to.
+fn resolve<'a>(
+    world: &mut World,
+    sources: &'a [ReflectSeedSource],
+    target: &str,
+    rng: Option<&str>,
+) -> Result<Vec<(Entity, &'a ReflectSeedSource)>, RngConsoleError> {
+    let sources: Vec<_> = match rng {
+        Some(rng) => {
+            let source = sources
+                .iter()
+                .find(|source| source.rng_short_type_path() == rng || source.rng_type_path() == rng)
+                .ok_or_else(|| RngConsoleError::UnknownRng(rng.to_owned()))?;
+
+            alloc::vec![source]
+        }
+        None => sources.iter().collect(),
+    };
+
+    let resolved: Vec<_> = if target == "global" {
+        sources
+            .into_iter()
+            .filter_map(|source| source.global(world).map(|global| (global, source)))
+            .collect()
+    } else {
+        let entity = parse_entity(world, target)?;
+
+        sources
+            .into_iter()
+            .filter(|source| source.seed(world, entity).is_some())
+            .map(|source| (entity, source))
+            .collect()
+    };
+
+    if resolved.is_empty() {
+        return Err(RngConsoleError::NoSource(match rng {
+            Some(rng) => format!("`{target}` has no {rng} source"),
+            None => format!("`{target}` has no RNG sources"),
+        }));
+    }
+
+    Ok(resolved)
+}
+
+/// Resolves `target` and `rng` into a single source.
+fn resolve_one<'a>(
+    world: &mut World,
+    sources: &'a [ReflectSeedSource],
+    target: &str,
+    rng: Option<&str>,
+) -> Result<(Entity, &'a ReflectSeedSource), RngConsoleError> {
+    let mut resolved = resolve(world, sources, target, rng)?;
+
+    if resolved.len() > 1 {
+        return Err(RngConsoleError::AmbiguousRng(
+            resolved
+                .iter()
+                .map(|(_, source)| source.rng_short_type_path().to_string())
+                .collect(),
+        ));
+    }
+
+    Ok(resolved.remove(0))
+}
+
+/// Parses `target` as an entity, either as displayed such as `4v1`, as an index such as `4`, or
+/// as an [`RngLabel`](crate::label::RngLabel).
+fn parse_entity(world: &World, target: &str) -> Result<Entity, RngConsoleError> {
+    let parsed = match target.split_once('v') {
+        Some((index, generation)) => index
+            .parse::<u32>()
+            .ok()
+            .zip(generation.parse::<u32>().ok())
+            .and_then(|(index, generation)| {
+                Entity::try_from_bits(u64::from(generation) << 32 | u64::from(index)).ok()
+            }),
+        None => target.parse::<u32>().ok().map(Entity::from_raw),
+    };
+
+    let entity = parsed.or_else(|| {
+        world
+            .get_resource::<RngLabelIndex>()
+            .and_then(|labels| labels.get(target))
+    });
+
+    entity
+        .filter(|&entity| world.entities().contains(entity))
+        .ok_or_else(|| RngConsoleError::MissingEntity(target.to_owned()))
+}
+
+/// Parses `seed` as hex bytes of a full seed, or as a `0x` prefixed `u64`.
+fn parse_seed(source: &ReflectSeedSource, seed: &str) -> Result<Vec<u8>, RngConsoleError> {
+    let len = source.seed_len();
+    let bad_seed = || RngConsoleError::BadSeed {
+        seed: seed.to_owned(),
+        len,
+    };
+
+    if let Some(value) = seed.strip_prefix("0x") {
+        return u64::from_str_radix(value, 16)
+            .map(|value| source.seed_from_u64(value))
+            .map_err(|_| bad_seed());
+    }
+
+    if seed.len() != len * 2 || !seed.is_ascii() {
+        return Err(bad_seed());
+    }
+
+    (0..len)
+        .map(|i| u8::from_str_radix(&seed[i * 2..i * 2 + 2], 16).map_err(|_| bad_seed()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::prelude::With;
+    use bevy_prng::{ChaCha8Rng, WyRand};
+    use rand_core::SeedableRng;
+
+    use crate::{
+        commands::RngCommandsExt,
+        component::Entropy,
+        global::Global,
+        plugin::EntropyPlugin,
+        seed::RngSeed,
+        traits::{ForkableInnerSeed, SeedSource},
+    };
+
+    use super::*;
+
+    fn prepared() -> (App, Entity) {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]),
+            EntropyPlugin::<WyRand>::with_seed([2; 8]),
+        ));
+
+        let world = app.world_mut();
+        let npc = world
+            .spawn(RngSeed::<WyRand>::from_seed([
+                0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+            ]))
+            .id();
+
+        world.commands().rng_entity::<WyRand>(npc).labelled("npc");
+        world.flush();
+
+        (app, npc)
+    }
+
+    #[test]
+    fn show_lists_seeds() {
+        let (mut app, npc) = prepared();
+        let world = app.world_mut();
+
+        assert_eq!(
+            exec_rng_command(world, &format!("rng.show {npc}")),
+            Ok(format!("{npc} WyRand: seed 0123456789abcdef"))
+        );
+        assert_eq!(
+            exec_rng_command(world, "rng.show npc WyRand"),
+            exec_rng_command(world, &format!("rng.show {}", npc.index())),
+        );
+
+        let globals = exec_rng_command(world, "rng.show global").unwrap();
+
+        assert_eq!(globals.lines().count(), 2);
+        assert!(globals.contains(&format!("ChaCha8Rng: seed {}", "01".repeat(32))));
+        assert!(globals.contains("WyRand: seed 0202020202020202"));
+    }
+
+    #[test]
+    fn reseed_applies_hex_and_u64_seeds() {
+        let (mut app, npc) = prepared();
+        let world = app.world_mut();
+
+        assert_eq!(
+            exec_rng_command(world, &format!("rng.reseed {npc} WyRand 0000000000000007")),
+            Ok(format!("Reseeded {npc} WyRand with seed 0000000000000007"))
+        );
+        assert_eq!(
+            world.get::<RngSeed<WyRand>>(npc),
+            Some(&RngSeed::from_seed([0, 0, 0, 0, 0, 0, 0, 7]))
+        );
+        assert_eq!(
+            world.get::<Entropy<WyRand>>(npc),
+            Some(&Entropy::from_seed([0, 0, 0, 0, 0, 0, 0, 7]))
+        );
+
+        let response =
+            exec_rng_command(world, "rng.reseed global bevy_prng::ChaCha8Rng 0xDEADBEEF").unwrap();
+        let expected = RngSeed::<ChaCha8Rng>::from_u64(0xDEAD_BEEF);
+
+        assert!(response.ends_with(&format!("ChaCha8Rng with seed {expected}")));
+
+        let global = world
+            .query_filtered::<&RngSeed<ChaCha8Rng>, With<Global>>()
+            .iter(world)
+            .next();
+
+        assert_eq!(global, Some(&expected));
+    }
+
+    #[test]
+    fn reroll_forks_from_the_global_source() {
+        let (mut app, npc) = prepared();
+        let world = app.world_mut();
+
+        let mut global = Entropy::<WyRand>::from_seed([2; 8]);
+        let expected = RngSeed::<WyRand>::from_seed(global.fork_inner_seed());
+
+        assert_eq!(
+            exec_rng_command(world, "rng.reroll npc"),
+            Ok(format!("Rerolled {npc} WyRand to seed {expected}"))
+        );
+        assert_eq!(world.get::<RngSeed<WyRand>>(npc), Some(&expected));
+    }
+
+    #[test]
+    fn errors_explain_what_went_wrong() {
+        let (mut app, npc) = prepared();
+        let world = app.world_mut();
+
+        let error = |world: &mut World, command: &str| {
+            exec_rng_command(world, command).unwrap_err().to_string()
+        };
+
+        assert_eq!(
+            error(world, "rng.shuffle global"),
+            "unknown command `rng.shuffle`, expected `rng.show`, `rng.reseed` or `rng.reroll`"
+        );
+        assert_eq!(
+            error(world, "rng.reseed global Pcg32 0x1"),
+            "unknown RNG `Pcg32`, expected the type path of an RNG with registered sources"
+        );
+        assert_eq!(
+            error(world, "rng.reseed global WyRand 0xZZ"),
+            "bad seed `0xZZ`, expected 16 hex digits or a 0x prefixed u64"
+        );
+        assert_eq!(
+            error(world, "rng.reseed global WyRand abcd"),
+            "bad seed `abcd`, expected 16 hex digits or a 0x prefixed u64"
+        );
+        assert_eq!(
+            error(world, "rng.show 999v1"),
+            "`999v1` is not an existing entity or RNG label"
+        );
+        assert_eq!(
+            error(world, "rng.show boss"),
+            "`boss` is not an existing entity or RNG label"
+        );
+        assert_eq!(
+            error(world, &format!("rng.reroll {npc} ChaCha8Rng")),
+            format!("`{npc}` has no ChaCha8Rng source")
+        );
+        assert_eq!(
+            error(world, "rng.reroll global"),
+            "the target has sources of several RNGs, specify one of: ChaCha8Rng, WyRand"
+        );
+        assert_eq!(error(world, "rng.reseed npc"), "missing argument <rng>");
+    }
+}
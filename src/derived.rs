@@ -0,0 +1,260 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::{
+    Commands, Component, EntityCommands, Event, OnInsert, Query, Res, Resource, Trigger,
+};
+use bevy_prng::{EntropySeed, EntropySource};
+use bevy_reflect::Reflect;
+
+use crate::{seed::RngSeed, traits::SeedSource};
+
+/// A snapshot of the [`RngSeed<R>`] that an entity's procedurally generated data, such as a nav
+/// mesh or texture, was generated from. Stamped with
+/// [`RngEntityCommands::mark_derived`](crate::commands::RngEntityCommands::mark_derived) once
+/// the data has been regenerated. When the entity is reseeded with a different seed,
+/// [`DerivedRngPlugin`] triggers [`RngDerivedInvalidated`] for it.
+#[derive(Debug, Clone, PartialEq, Eq, Component, Reflect)]
+pub struct DerivedFromRng<R: EntropySource>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    seed: R::Seed,
+    #[reflect(ignore)]
+    rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> DerivedFromRng<R>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    /// Creates a snapshot of `seed`.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: &RngSeed<R>) -> Self {
+        Self {
+            seed: seed.clone_seed(),
+            rng: PhantomData,
+        }
+    }
+
+    /// Returns the seed the data was derived from.
+    #[inline]
+    pub fn seed(&self) -> &R::Seed {
+        &self.seed
+    }
+
+    /// Returns whether the data is stale for `seed`, having been derived from a different seed.
+    #[inline]
+    pub fn is_stale(&self, seed: &RngSeed<R>) -> bool
+    where
+        R::Seed: PartialEq,
+    {
+        &self.seed != seed.get_seed()
+    }
+}
+
+/// Observer event triggered on an entity with a [`DerivedFromRng`] snapshot whenever it is
+/// reseeded with a seed different to the snapshot, signalling that its derived data is stale and
+/// should be regenerated.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{RngCommandsExt, RngDerivedInvalidated};
+///
+/// #[derive(Component)]
+/// struct NavMesh;
+///
+/// fn regenerate_nav_mesh(trigger: Trigger<RngDerivedInvalidated>, mut commands: Commands) {
+///     // Regenerate the nav mesh from the entity's new seed, then stamp the new snapshot
+///     commands
+///         .entity(trigger.target())
+///         .insert(NavMesh);
+///     commands
+///         .rng_entity::<WyRand>(trigger.target())
+///         .mark_derived();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct RngDerivedInvalidated;
+
+/// Plugin for invalidating procedurally generated data derived from `R` sources, by triggering
+/// [`RngDerivedInvalidated`] on any entity whose [`RngSeed<R>`] no longer matches its
+/// [`DerivedFromRng<R>`] snapshot after being reseeded. Optionally removes components holding the
+/// stale data as well, with [`DerivedRngPlugin::removing`].
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{DerivedRngPlugin, EntropyPlugin};
+///
+/// #[derive(Component)]
+/// struct NavMesh;
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     DerivedRngPlugin::<WyRand>::default().removing::<NavMesh>(),
+/// ));
+/// ```
+pub struct DerivedRngPlugin<R: EntropySource> {
+    removals: Vec<fn(&mut EntityCommands)>,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> Default for DerivedRngPlugin<R> {
+    fn default() -> Self {
+        Self {
+            removals: Vec::new(),
+            _rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource> DerivedRngPlugin<R> {
+    /// Configures the plugin to remove the `C` component from invalidated entities, such as a
+    /// component holding the stale data.
+    #[inline]
+    #[must_use]
+    pub fn removing<C: Component>(mut self) -> Self {
+        self.removals.push(|entity| {
+            entity.remove::<C>();
+        });
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for DerivedRngPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        app.register_type::<DerivedFromRng<R>>()
+            .insert_resource(DerivedRemovals::<R> {
+                removals: self.removals.clone(),
+                _rng: PhantomData,
+            })
+            .add_observer(invalidate_derived::<R>);
+    }
+}
+
+#[derive(Resource)]
+struct DerivedRemovals<R> {
+    removals: Vec<fn(&mut EntityCommands)>,
+    _rng: PhantomData<R>,
+}
+
+fn invalidate_derived<R: EntropySource + 'static>(
+    trigger: Trigger<OnInsert, RngSeed<R>>,
+    q_derived: Query<(&RngSeed<R>, &DerivedFromRng<R>)>,
+    removals: Res<DerivedRemovals<R>>,
+    mut commands: Commands,
+) where
+    R::Seed: EntropySeed,
+{
+    let target = trigger.target();
+
+    let Ok((seed, derived)) = q_derived.get(target) else {
+        return;
+    };
+
+    if !derived.is_stale(seed) {
+        return;
+    }
+
+    let mut entity = commands.entity(target);
+
+    for remove in &removals.removals {
+        remove(&mut entity);
+    }
+
+    commands.trigger_targets(RngDerivedInvalidated, target);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::{Entity, ResMut, World};
+    use bevy_prng::WyRand;
+
+    use crate::{commands::RngCommandsExt, plugin::EntropyPlugin};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct NavMesh;
+
+    #[derive(Resource, Default)]
+    struct Invalidations(Vec<Entity>);
+
+    fn mark_derived(world: &mut World, entity: Entity) {
+        world.commands().rng_entity::<WyRand>(entity).mark_derived();
+        world.flush();
+    }
+
+    #[test]
+    fn reseeding_invalidates_derived_data_once() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            DerivedRngPlugin::<WyRand>::default().removing::<NavMesh>(),
+        ))
+        .init_resource::<Invalidations>()
+        .add_observer(
+            |trigger: Trigger<RngDerivedInvalidated>, mut invalidations: ResMut<Invalidations>| {
+                invalidations.0.push(trigger.target());
+            },
+        );
+
+        let world = app.world_mut();
+
+        let entity = world
+            .spawn((NavMesh, RngSeed::<WyRand>::from_seed([2; 8])))
+            .id();
+
+        mark_derived(world, entity);
+
+        // Reinserting the seed the data was derived from leaves it valid
+        world
+            .entity_mut(entity)
+            .insert(RngSeed::<WyRand>::from_seed([2; 8]));
+        world.flush();
+
+        assert!(world.resource::<Invalidations>().0.is_empty());
+        assert!(world.get::<NavMesh>(entity).is_some());
+
+        world
+            .entity_mut(entity)
+            .insert(RngSeed::<WyRand>::from_seed([3; 8]));
+        world.flush();
+
+        assert_eq!(world.resource::<Invalidations>().0, [entity]);
+        assert!(world.get::<NavMesh>(entity).is_none());
+
+        app.update();
+
+        let world = app.world_mut();
+
+        assert_eq!(world.resource::<Invalidations>().0, [entity]);
+
+        // Regenerating and re-marking the data stops further invalidations
+        world.entity_mut(entity).insert(NavMesh);
+        mark_derived(world, entity);
+
+        assert_eq!(
+            world.get::<DerivedFromRng<WyRand>>(entity).unwrap().seed(),
+            &[3; 8]
+        );
+
+        world
+            .entity_mut(entity)
+            .insert(RngSeed::<WyRand>::from_seed([3; 8]));
+        world.flush();
+        app.update();
+
+        assert_eq!(app.world().resource::<Invalidations>().0, [entity]);
+        assert!(app.world().get::<NavMesh>(entity).is_some());
+    }
+}
@@ -0,0 +1,418 @@
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_reflect::Reflect;
+use rand_core::RngCore;
+use rand_distr::{Distribution, Exp, Normal, Poisson};
+
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+pub use rand_distr::{ExpError, NormalError, PoissonError};
+
+/// Parameters for a [normal distribution](Normal), sampled with a provided RNG source such as
+/// an entity's own [`crate::component::Entropy`].
+///
+/// The parameters are validated on construction and when changed with setters. The underlying
+/// distribution is cached, and rebuilt on the next sample whenever the parameters no longer match
+/// the ones it was built from, which includes changes made through reflection. The cache is not
+/// reflected nor serialized.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{Entropy, NormalParams};
+///
+/// #[derive(Component)]
+/// struct Spawner {
+///     interval: NormalParams,
+///     countdown: f64,
+/// }
+///
+/// fn tick_spawners(mut q_spawners: Query<(&mut Spawner, &mut Entropy<WyRand>)>) {
+///     for (mut spawner, mut rng) in q_spawners.iter_mut() {
+///         spawner.countdown -= 1.0 / 60.0;
+///
+///         if spawner.countdown <= 0.0 {
+///             // Draw the next spawn delay, never waiting less than a tenth of a second.
+///             let delay = spawner.interval.sample(rng.as_mut()).max(0.1);
+///
+///             spawner.countdown = delay;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(try_from = "NormalShape"))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct NormalParams {
+    mean: f64,
+    std_dev: f64,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    distribution: Option<((f64, f64), Normal<f64>)>,
+}
+
+impl NormalParams {
+    /// Create new normal distribution parameters. `std_dev` must be finite.
+    pub fn new(mean: f64, std_dev: f64) -> Result<Self, NormalError> {
+        let distribution = Normal::new(mean, std_dev)?;
+
+        Ok(Self {
+            mean,
+            std_dev,
+            distribution: Some(((mean, std_dev), distribution)),
+        })
+    }
+
+    /// Returns the mean of the distribution.
+    #[inline]
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the standard deviation of the distribution.
+    #[inline]
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+
+    /// Sets the mean of the distribution.
+    pub fn set_mean(&mut self, mean: f64) -> Result<(), NormalError> {
+        *self = Self::new(mean, self.std_dev)?;
+
+        Ok(())
+    }
+
+    /// Sets the standard deviation of the distribution. The change is rejected if
+    /// `std_dev` is not finite.
+    pub fn set_std_dev(&mut self, std_dev: f64) -> Result<(), NormalError> {
+        *self = Self::new(self.mean, std_dev)?;
+
+        Ok(())
+    }
+
+    /// Samples a value with the provided RNG source, rebuilding the cached distribution first
+    /// if the parameters have changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters were made invalid by modifying them through reflection.
+    pub fn sample<R: RngCore + ?Sized>(&mut self, rng: &mut R) -> f64 {
+        let params = (self.mean, self.std_dev);
+
+        match self.distribution {
+            Some((cached, distribution)) if cached == params => distribution.sample(rng),
+            _ => {
+                let distribution =
+                    Normal::new(self.mean, self.std_dev).expect("NormalParams are invalid");
+
+                self.distribution = Some((params, distribution));
+
+                distribution.sample(rng)
+            }
+        }
+    }
+}
+
+impl PartialEq for NormalParams {
+    fn eq(&self, other: &Self) -> bool {
+        // The cached distribution is derived from the parameters, so is not compared.
+        self.mean == other.mean && self.std_dev == other.std_dev
+    }
+}
+
+/// Parameters for a [Poisson distribution](Poisson), sampled with a provided RNG source such as
+/// an entity's own [`crate::component::Entropy`].
+///
+/// Caching and validation behave the same as [`NormalParams`].
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(try_from = "LambdaShape"))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct PoissonParams {
+    lambda: f64,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    distribution: Option<(f64, Poisson<f64>)>,
+}
+
+impl PoissonParams {
+    /// Create new Poisson distribution parameters. `lambda` must be greater than zero.
+    pub fn new(lambda: f64) -> Result<Self, PoissonError> {
+        let distribution = Poisson::new(lambda)?;
+
+        Ok(Self {
+            lambda,
+            distribution: Some((lambda, distribution)),
+        })
+    }
+
+    /// Returns the rate of the distribution.
+    #[inline]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Sets the rate of the distribution. The change is rejected if `lambda` is not greater
+    /// than zero.
+    pub fn set_lambda(&mut self, lambda: f64) -> Result<(), PoissonError> {
+        *self = Self::new(lambda)?;
+
+        Ok(())
+    }
+
+    /// Samples a value with the provided RNG source, rebuilding the cached distribution first
+    /// if the parameters have changed. Sampled values are always whole numbers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters were made invalid by modifying them through reflection.
+    pub fn sample<R: RngCore + ?Sized>(&mut self, rng: &mut R) -> f64 {
+        match self.distribution {
+            Some((cached, distribution)) if cached == self.lambda => distribution.sample(rng),
+            _ => {
+                let distribution = Poisson::new(self.lambda).expect("PoissonParams are invalid");
+
+                self.distribution = Some((self.lambda, distribution));
+
+                distribution.sample(rng)
+            }
+        }
+    }
+}
+
+impl PartialEq for PoissonParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.lambda == other.lambda
+    }
+}
+
+/// Parameters for an [exponential distribution](Exp), sampled with a provided RNG source such as
+/// an entity's own [`crate::component::Entropy`].
+///
+/// Caching and validation behave the same as [`NormalParams`].
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(try_from = "LambdaShape"))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct ExpParams {
+    lambda: f64,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    distribution: Option<(f64, Exp<f64>)>,
+}
+
+impl ExpParams {
+    /// Create new exponential distribution parameters. `lambda` must not be negative.
+    pub fn new(lambda: f64) -> Result<Self, ExpError> {
+        let distribution = Exp::new(lambda)?;
+
+        Ok(Self {
+            lambda,
+            distribution: Some((lambda, distribution)),
+        })
+    }
+
+    /// Returns the rate of the distribution.
+    #[inline]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Sets the rate of the distribution. The change is rejected if `lambda` is negative.
+    pub fn set_lambda(&mut self, lambda: f64) -> Result<(), ExpError> {
+        *self = Self::new(lambda)?;
+
+        Ok(())
+    }
+
+    /// Samples a value with the provided RNG source, rebuilding the cached distribution first
+    /// if the parameters have changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters were made invalid by modifying them through reflection.
+    pub fn sample<R: RngCore + ?Sized>(&mut self, rng: &mut R) -> f64 {
+        match self.distribution {
+            Some((cached, distribution)) if cached == self.lambda => distribution.sample(rng),
+            _ => {
+                let distribution = Exp::new(self.lambda).expect("ExpParams are invalid");
+
+                self.distribution = Some((self.lambda, distribution));
+
+                distribution.sample(rng)
+            }
+        }
+    }
+}
+
+impl PartialEq for ExpParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.lambda == other.lambda
+    }
+}
+
+/// The serialized shape of [`NormalParams`], validated upon deserialization.
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+struct NormalShape {
+    mean: f64,
+    std_dev: f64,
+}
+
+#[cfg(feature = "serialize")]
+impl TryFrom<NormalShape> for NormalParams {
+    type Error = NormalError;
+
+    fn try_from(value: NormalShape) -> Result<Self, Self::Error> {
+        Self::new(value.mean, value.std_dev)
+    }
+}
+
+/// The serialized shape of [`PoissonParams`] and [`ExpParams`], validated upon deserialization.
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+struct LambdaShape {
+    lambda: f64,
+}
+
+#[cfg(feature = "serialize")]
+impl TryFrom<LambdaShape> for PoissonParams {
+    type Error = PoissonError;
+
+    fn try_from(value: LambdaShape) -> Result<Self, Self::Error> {
+        Self::new(value.lambda)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl TryFrom<LambdaShape> for ExpParams {
+    type Error = ExpError;
+
+    fn try_from(value: LambdaShape) -> Result<Self, Self::Error> {
+        Self::new(value.lambda)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+    use bevy_reflect::Struct;
+    use rand_core::SeedableRng;
+
+    use crate::component::Entropy;
+
+    use super::*;
+
+    #[test]
+    fn validates_params() {
+        assert_eq!(
+            NormalParams::new(1.0, f64::INFINITY).unwrap_err(),
+            NormalError::BadVariance
+        );
+        assert_eq!(
+            PoissonParams::new(0.0).unwrap_err(),
+            PoissonError::ShapeTooSmall
+        );
+        assert_eq!(ExpParams::new(-1.0).unwrap_err(), ExpError::LambdaTooSmall);
+
+        let mut normal = NormalParams::new(5.0, 1.0).unwrap();
+
+        assert_eq!(
+            normal.set_std_dev(f64::NAN).unwrap_err(),
+            NormalError::BadVariance
+        );
+        // Rejected changes must leave the params untouched
+        assert_eq!(normal.std_dev(), 1.0);
+    }
+
+    #[test]
+    fn deterministic_sampling() {
+        let mut rng = Entropy::<WyRand>::from_seed([42; 8]);
+
+        let mut normal = NormalParams::new(10.0, 2.0).unwrap();
+        let mut poisson = PoissonParams::new(3.0).unwrap();
+        let mut exp = ExpParams::new(0.5).unwrap();
+
+        assert_eq!(normal.sample(&mut rng), 8.712389462661177);
+        assert_eq!(poisson.sample(&mut rng), 3.0);
+        assert_eq!(exp.sample(&mut rng), 0.7431325260978211);
+    }
+
+    #[test]
+    fn reflected_changes_rebuild_distribution() {
+        let mut params = NormalParams::new(0.0, 1.0).unwrap();
+        let mut rng = WyRand::from_seed([7; 8]);
+
+        params.sample(&mut rng);
+
+        *params
+            .field_mut("mean")
+            .unwrap()
+            .try_downcast_mut::<f64>()
+            .unwrap() = 100.0;
+
+        assert!(params.sample(&mut rng) > 90.0);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn reflect_round_trip() {
+        use bevy_reflect::{
+            serde::{ReflectDeserializer, ReflectSerializer},
+            FromReflect, TypeRegistry,
+        };
+        use ron::to_string;
+        use serde::de::DeserializeSeed;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<NormalParams>();
+        registry.register::<ExpParams>();
+
+        let val = NormalParams::new(2.5, 0.5).unwrap();
+
+        let serialized = to_string(&ReflectSerializer::new(&val, &registry)).unwrap();
+
+        assert_eq!(
+            &serialized,
+            "{\"bevy_rand::distributions::NormalParams\":(mean:2.5,std_dev:0.5)}"
+        );
+
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut dynamic = NormalParams::take_from_reflect(value).unwrap();
+
+        assert_eq!(val, dynamic);
+
+        let mut val = val;
+        let mut rng1 = WyRand::from_seed([1; 8]);
+        let mut rng2 = WyRand::from_seed([1; 8]);
+
+        assert_eq!(val.sample(&mut rng1), dynamic.sample(&mut rng2));
+
+        // Invalid params are rejected
+        let mut deserializer =
+            ron::Deserializer::from_str("{\"bevy_rand::distributions::ExpParams\":(lambda:-2.0)}")
+                .unwrap();
+
+        assert!(ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use alloc::collections::BTreeMap;
+use core::{
+    panic::Location,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use std::sync::{Mutex, PoisonError};
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::prelude::{Res, ResMut, Resource};
+
+/// Whether forks are being recorded, so that forking has no extra cost until tracking is set up.
+static TRACKING: AtomicBool = AtomicBool::new(false);
+
+/// Forks recorded since they were last collected into the [`ForkLog`], counted by call site.
+static FORKS: Mutex<BTreeMap<&'static Location<'static>, u32>> = Mutex::new(BTreeMap::new());
+
+/// Records a fork from `location`, if fork tracking is enabled.
+pub(crate) fn record_fork(location: &'static Location<'static>) {
+    if !TRACKING.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut forks = FORKS.lock().unwrap_or_else(PoisonError::into_inner);
+
+    *forks.entry(location).or_default() += 1;
+}
+
+/// Fork counts for a single call site, as recorded in the [`ForkLog`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ForkSite {
+    total: u64,
+    frames: u64,
+    last_frame: u64,
+    last_frame_count: u32,
+}
+
+impl ForkSite {
+    /// Returns the total number of forks from this call site.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of frames in which this call site forked.
+    #[inline]
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// Returns the last frame in which this call site forked.
+    #[inline]
+    pub fn last_frame(&self) -> u64 {
+        self.last_frame
+    }
+
+    /// Returns the number of forks from this call site in the last frame it forked.
+    #[inline]
+    pub fn last_frame_count(&self) -> u32 {
+        self.last_frame_count
+    }
+}
+
+/// A log of all forks from [`crate::component::Entropy`] sources, by the call site of the
+/// `fork_*` method, for tracking down code that unexpectedly advances sources. Added and updated
+/// at the end of each frame by [`ForkTrackingPlugin`].
+///
+/// Forks are recorded process wide, so with several apps in the same process, forks are logged by
+/// whichever app collects them first.
+#[derive(Debug, Default, Resource)]
+pub struct ForkLog {
+    frame: u64,
+    sites: BTreeMap<&'static Location<'static>, ForkSite>,
+}
+
+impl ForkLog {
+    /// Returns the current frame, counting from zero for the first frame after tracking began.
+    #[inline]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Returns the fork counts for the call site at `line` of `file`, if it has forked.
+    pub fn site(&self, file: &str, line: u32) -> Option<&ForkSite> {
+        self.sites
+            .iter()
+            .find(|(location, _)| location.file() == file && location.line() == line)
+            .map(|(_, site)| site)
+    }
+
+    /// Returns an iterator over all call sites that have forked, with their fork counts.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static Location<'static>, &ForkSite)> {
+        self.sites.iter().map(|(location, site)| (*location, site))
+    }
+}
+
+/// Plugin for recording forks from all [`crate::component::Entropy`] sources into a
+/// [`ForkLog`], optionally warning when a call site forks more than a given number of times in a
+/// single frame. Forking is not tracked until this plugin is added.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{fork_tracking::ForkTrackingPlugin, prelude::EntropyPlugin};
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     ForkTrackingPlugin::default().with_warn_threshold(1),
+/// ));
+/// ```
+#[derive(Debug, Default)]
+pub struct ForkTrackingPlugin {
+    warn_threshold: Option<u32>,
+}
+
+impl ForkTrackingPlugin {
+    /// Configures the plugin to warn whenever a call site forks more than `threshold` times in a
+    /// single frame.
+    #[inline]
+    #[must_use]
+    pub fn with_warn_threshold(mut self, threshold: u32) -> Self {
+        self.warn_threshold = Some(threshold);
+        self
+    }
+}
+
+impl Plugin for ForkTrackingPlugin {
+    fn build(&self, app: &mut App) {
+        TRACKING.store(true, Ordering::Relaxed);
+
+        app.init_resource::<ForkLog>()
+            .insert_resource(ForkWarnThreshold(self.warn_threshold))
+            .add_systems(Last, collect_forks);
+    }
+}
+
+#[derive(Resource)]
+struct ForkWarnThreshold(Option<u32>);
+
+fn collect_forks(mut log: ResMut<ForkLog>, threshold: Res<ForkWarnThreshold>) {
+    let forks = core::mem::take(&mut *FORKS.lock().unwrap_or_else(PoisonError::into_inner));
+    let frame = log.frame;
+
+    for (location, count) in forks {
+        let site = log.sites.entry(location).or_default();
+
+        site.total += u64::from(count);
+        site.frames += 1;
+        site.last_frame = frame;
+        site.last_frame_count = count;
+
+        if threshold.0.is_some_and(|threshold| count > threshold) {
+            tracing::warn!("{location} forked {count} times in frame {frame}");
+        }
+    }
+
+    log.frame += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::Update;
+    use bevy_ecs::prelude::Resource;
+    use bevy_prng::WyRand;
+
+    use crate::{global::GlobalEntropy, plugin::EntropyPlugin, traits::ForkableSeed};
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Lines {
+        once: u32,
+        twice: u32,
+    }
+
+    #[test]
+    fn forks_are_attributed_to_their_call_sites() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            ForkTrackingPlugin::default().with_warn_threshold(1),
+        ))
+        .init_resource::<Lines>()
+        .add_systems(
+            Update,
+            |mut global: GlobalEntropy<WyRand>, mut lines: ResMut<Lines>| {
+                (_, lines.once) = (global.fork_seed(), line!());
+
+                for _ in 0..2 {
+                    (_, lines.twice) = (global.fork_seed(), line!());
+                }
+            },
+        );
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let lines = app.world().resource::<Lines>();
+        let log = app.world().resource::<ForkLog>();
+
+        let once = log.site(file!(), lines.once).unwrap();
+        let twice = log.site(file!(), lines.twice).unwrap();
+
+        assert_eq!(log.frame(), 3);
+        assert_eq!((once.total(), once.frames(), once.last_frame()), (3, 3, 2));
+        assert_eq!(once.last_frame_count(), 1);
+        assert_eq!(
+            (twice.total(), twice.frames(), twice.last_frame()),
+            (6, 3, 2)
+        );
+        assert_eq!(twice.last_frame_count(), 2);
+    }
+}
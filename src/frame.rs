@@ -0,0 +1,151 @@
+use bevy_diagnostic::FrameCount;
+use bevy_ecs::system::{Local, Res, SystemParam};
+use bevy_prng::{EntropySeed, EntropySource};
+use rand_core::{RngCore, SeedableRng};
+
+use crate::{component::Entropy, global::GlobalSeed, traits::SeedSource, util::mix_seed};
+
+/// A [`SystemParam`] providing an RNG derived purely from the seed of the [`crate::global::Global`]
+/// `R` source and the current [`FrameCount`], for effects that should look random while being
+/// reproducible per frame, such as dither offsets or screen shake.
+///
+/// The global source is never advanced, so systems using [`FrameRng`] can run in parallel, and the
+/// values drawn in a given frame don't depend on how many other systems drew from the global
+/// source, or on which systems are added or removed. Draws within the same system share one
+/// instance for the whole frame, so drawing more than once continues the sequence instead of
+/// repeating it. Each system gets its own instance, so different systems draw the same sequence
+/// in the same frame.
+///
+/// Requires the [`FrameCount`] resource, such as the one provided by `bevy_diagnostic`'s
+/// `FrameCountPlugin`. A reseed of the global source takes effect from the next frame.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::frame::FrameRng;
+/// use rand_core::RngCore;
+///
+/// #[derive(Resource)]
+/// struct DitherOffset(u32);
+///
+/// fn dither(mut rng: FrameRng<WyRand>, mut offset: ResMut<DitherOffset>) {
+///     offset.0 = rng.next_u32() % 16;
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct FrameRng<'w, 's, R: EntropySource + 'static>
+where
+    R::Seed: EntropySeed,
+{
+    frame: Res<'w, FrameCount>,
+    seed: GlobalSeed<'w, R>,
+    rng: Local<'s, Option<(u32, Entropy<R>)>>,
+}
+
+impl<R: EntropySource + 'static> FrameRng<'_, '_, R>
+where
+    R::Seed: EntropySeed,
+{
+    /// Returns the frame the RNG is derived for.
+    #[inline]
+    pub fn frame(&self) -> u32 {
+        self.frame.0
+    }
+
+    fn rng(&mut self) -> &mut Entropy<R> {
+        let frame = self.frame.0;
+
+        let rng = match self.rng.take() {
+            Some((derived, rng)) if derived == frame => rng,
+            _ => Entropy::from_seed(mix_seed::<R>(self.seed.get_seed(), u64::from(frame))),
+        };
+
+        &mut self.rng.insert((frame, rng)).1
+    }
+}
+
+impl<R: EntropySource + 'static> RngCore for FrameRng<'_, '_, R>
+where
+    R::Seed: EntropySeed,
+{
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng().next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng().next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng().fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng().try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_app::{App, Update};
+    use bevy_diagnostic::FrameCountPlugin;
+    use bevy_ecs::prelude::{ResMut, Resource};
+    use bevy_prng::WyRand;
+
+    use crate::{global::GlobalEntropy, plugin::EntropyPlugin};
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Drawn(Vec<(u32, u64, u64)>);
+
+    fn record(mut rng: FrameRng<WyRand>, mut drawn: ResMut<Drawn>) {
+        let frame = rng.frame();
+        let (first, second) = (rng.next_u64(), rng.next_u64());
+
+        drawn.0.push((frame, first, second));
+    }
+
+    fn run(global_draws: usize) -> Vec<(u32, u64, u64)> {
+        let mut app = App::new();
+
+        app.add_plugins((EntropyPlugin::<WyRand>::with_seed([7; 8]), FrameCountPlugin))
+            .init_resource::<Drawn>()
+            .add_systems(
+                Update,
+                (
+                    move |mut global: GlobalEntropy<WyRand>| {
+                        (0..global_draws).for_each(|_| {
+                            global.next_u64();
+                        });
+                    },
+                    record,
+                ),
+            );
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        app.world_mut().remove_resource::<Drawn>().unwrap().0
+    }
+
+    #[test]
+    fn frame_rng_is_stable_per_frame() {
+        let drawn = run(0);
+
+        assert_eq!(drawn, run(5));
+        assert_eq!(
+            drawn.iter().map(|(frame, ..)| *frame).collect::<Vec<_>>(),
+            [0, 1, 2]
+        );
+        assert!(drawn.iter().all(|(_, first, second)| first != second));
+        assert_ne!(drawn[0].1, drawn[1].1);
+    }
+}
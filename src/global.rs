@@ -1,6 +1,22 @@
-use bevy_ecs::{component::Component, entity::Entity, query::With, system::Single};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    prelude::{Commands, OnInsert, Query, ReflectResource, Resource, Trigger, World},
+    query::With,
+    system::Single,
+};
+use bevy_prng::EntropySource;
+use bevy_reflect::Reflect;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
 
-use crate::{prelude::Entropy, seed::RngSeed};
+use crate::{
+    prelude::Entropy,
+    seed::RngSeed,
+    traits::{
+        EcsEntropy, ForkableAsRng, ForkableAsSeed, ForkableInnerRng, ForkableInnerSeed,
+        ForkableRng, ForkableSeed,
+    },
+};
 
 /// A marker component to signify a global source. Warning: there should only be **one** entity per
 /// PRNG type that qualifies as the `Global` source.
@@ -18,3 +34,187 @@ pub type GlobalSeed<'w, T> = Single<'w, &'static RngSeed<T>, With<Global>>;
 /// A helper query to yield the [`Global`] source for a given [`EntropySource`]. This returns the
 /// [`Entity`] id to modify the source with via commands.
 pub type GlobalSource<'w, T> = Single<'w, Entity, (With<RngSeed<T>>, With<Global>)>;
+
+/// A resource mirror of the [`Global`] source, for code that expects a `ResMut` rather than a
+/// query, such as systems ported from resource based globals. Inserted by
+/// [`crate::plugin::EntropyPlugin::with_resource_mirror`], forked once from the [`Global`] source
+/// at startup, and kept in sync with it according to a [`ResourceMirrorPolicy`]. It can be drawn
+/// from and forked like any other [`Entropy`] source.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{ForkableRng, GlobalEntropyResource};
+/// use rand_core::RngCore;
+///
+/// fn roll_loot(mut rng: ResMut<GlobalEntropyResource<WyRand>>, mut commands: Commands) {
+///     println!("Rolled: {}", rng.next_u32() % 100);
+///
+///     commands.spawn(rng.fork_rng());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Resource, Reflect)]
+#[reflect(Debug, PartialEq, Resource)]
+pub struct GlobalEntropyResource<R: EntropySource + 'static>(Entropy<R>);
+
+impl<R: EntropySource + 'static> GlobalEntropyResource<R> {
+    /// Creates a new resource from an [`Entropy`] source.
+    #[inline]
+    #[must_use]
+    pub fn new(rng: Entropy<R>) -> Self {
+        Self(rng)
+    }
+
+    /// Returns a reference to the wrapped [`Entropy`] source.
+    #[inline]
+    pub fn entropy(&self) -> &Entropy<R> {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the wrapped [`Entropy`] source.
+    #[inline]
+    pub fn entropy_mut(&mut self) -> &mut Entropy<R> {
+        &mut self.0
+    }
+}
+
+impl<R: EntropySource + 'static> RngCore for GlobalEntropyResource<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R: EntropySource + CryptoRng + 'static> CryptoRng for GlobalEntropyResource<R> {}
+
+impl<R: EntropySource + 'static> SeedableRng for GlobalEntropyResource<R> {
+    type Seed = R::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self(Entropy::from_seed(seed))
+    }
+
+    #[inline]
+    fn from_rng<S: RngCore>(rng: S) -> Result<Self, rand_core::Error> {
+        Entropy::from_rng(rng).map(Self)
+    }
+}
+
+impl<R: EntropySource + 'static> EcsEntropy for GlobalEntropyResource<R> {}
+
+impl<R> ForkableRng for GlobalEntropyResource<R>
+where
+    R: EntropySource + 'static,
+{
+    type Output = Entropy<R>;
+}
+
+impl<R> ForkableAsRng for GlobalEntropyResource<R>
+where
+    R: EntropySource + 'static,
+{
+    type Output<T>
+        = Entropy<T>
+    where
+        T: EntropySource;
+}
+
+impl<R> ForkableInnerRng for GlobalEntropyResource<R>
+where
+    R: EntropySource + 'static,
+{
+    type Output = R;
+}
+
+impl<R> ForkableSeed<R> for GlobalEntropyResource<R>
+where
+    R: EntropySource + 'static,
+    R::Seed: Send + Sync + Clone,
+{
+    type Output = RngSeed<R>;
+}
+
+impl<R> ForkableAsSeed<R> for GlobalEntropyResource<R>
+where
+    R: EntropySource + 'static,
+{
+    type Output<T>
+        = RngSeed<T>
+    where
+        T: EntropySource,
+        T::Seed: Send + Sync + Clone;
+}
+
+impl<R> ForkableInnerSeed<R> for GlobalEntropyResource<R>
+where
+    R: EntropySource + 'static,
+    R::Seed: Send + Sync + Clone + AsMut<[u8]> + Default,
+{
+    type Output = R::Seed;
+}
+
+/// How a [`GlobalEntropyResource`] is kept in sync with the [`Global`] source it mirrors.
+///
+/// Either way, the resource is forked from the [`Global`] source once at startup, so under a
+/// fixed seed its output is deterministic, and the [`Global`] source is advanced by that fork
+/// compared to an app without the mirror.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResourceMirrorPolicy {
+    /// The resource diverges from the [`Global`] source after the initial fork, and reseeding the
+    /// [`Global`] source does not affect it. Its output only depends on the [`Global`] seed at
+    /// startup and on how much has been drawn from the resource itself.
+    #[default]
+    Independent,
+    /// The resource is replaced with a fresh fork of the [`Global`] source whenever its
+    /// [`RngSeed`] changes, so after a reseed the resource produces the same output as at startup
+    /// with that seed, regardless of what was drawn before. Each reseed advances the newly seeded
+    /// [`Global`] source by one fork.
+    MirrorSeed,
+}
+
+/// Observer replacing the [`GlobalEntropyResource`] with a fork of the reseeded [`Global`]
+/// source. The fork is queued, so that it is taken after the [`Global`] source's [`Entropy`] has
+/// been reset from its new seed.
+pub(crate) fn mirror_global_seed<R: EntropySource + 'static>(
+    trigger: Trigger<OnInsert, RngSeed<R>>,
+    q_global: Query<(), With<Global>>,
+    mut commands: Commands,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    if !q_global.contains(trigger.target()) {
+        return;
+    }
+
+    commands.queue(fork_resource_mirror::<R>);
+}
+
+/// Inserts a [`GlobalEntropyResource`] forked from the [`Global`] `R` source, if there is one.
+pub(crate) fn fork_resource_mirror<R: EntropySource + 'static>(world: &mut World) {
+    let Some(rng) = world
+        .query_filtered::<&mut Entropy<R>, With<Global>>()
+        .iter_mut(world)
+        .next()
+        .map(|mut global| global.fork_rng())
+    else {
+        return;
+    };
+
+    world.insert_resource(GlobalEntropyResource(rng));
+}
@@ -0,0 +1,221 @@
+use core::marker::PhantomData;
+
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::prelude::{Component, Query, ReflectComponent};
+use bevy_math::Vec3;
+use bevy_prng::EntropySource;
+use bevy_reflect::Reflect;
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use rand_core::RngCore;
+
+use crate::{component::Entropy, util::random_f32_canonical};
+
+/// A component for positional jitter, such as for shaking VFX, that changes once per frame and
+/// stays the same for the rest of the frame, so every system reading it sees the same value.
+///
+/// With the [`FrameJitterPlugin`] for `R`, the value is updated in [`First`] from the same
+/// entity's `R` [`Entropy`] source, so systems only need to read it with a plain `&FrameJitter`
+/// query, without accessing the source themselves.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_math::Vec3;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{FrameJitter, RngSeed, SeedSource};
+///
+/// #[derive(Component)]
+/// struct Spark;
+///
+/// fn spawn_spark(mut commands: Commands) {
+///     commands.spawn((
+///         Spark,
+///         FrameJitter::new(Vec3::new(0.5, 0.5, 0.0)),
+///         RngSeed::<WyRand>::from_u64(3),
+///     ));
+/// }
+///
+/// fn draw_sparks(q_sparks: Query<&FrameJitter, With<Spark>>) {
+///     for jitter in q_sparks.iter() {
+///         println!("Drawing a spark offset by {}", jitter.value());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct FrameJitter {
+    amplitude: Vec3,
+    value: Vec3,
+}
+
+impl FrameJitter {
+    /// Creates a new jitter of up to `amplitude` along each axis, in either direction. Negative
+    /// amplitudes are treated as positive, and non-finite ones as zero. The value is zero until
+    /// the first update.
+    #[inline]
+    pub fn new(amplitude: Vec3) -> Self {
+        let amplitude = amplitude.abs();
+
+        Self {
+            amplitude: Vec3::select(amplitude.is_finite_mask(), amplitude, Vec3::ZERO),
+            value: Vec3::ZERO,
+        }
+    }
+
+    /// Creates a new jitter of up to `amplitude` along every axis, as with [`FrameJitter::new`].
+    #[inline]
+    pub fn uniform(amplitude: f32) -> Self {
+        Self::new(Vec3::splat(amplitude))
+    }
+
+    /// Returns the amplitude of the jitter along each axis.
+    #[inline]
+    pub fn amplitude(&self) -> Vec3 {
+        self.amplitude
+    }
+
+    /// Returns the jitter for the current frame, within `[-amplitude, amplitude)` along each
+    /// axis.
+    #[inline]
+    pub fn value(&self) -> Vec3 {
+        self.value
+    }
+
+    /// Picks a new value with the provided RNG source, drawing exactly three `u32`s from it, one
+    /// for each axis, even if some amplitudes are zero. Each axis is scaled from
+    /// [`random_f32_canonical`] with exact steps and a single multiply, so the values are
+    /// bit-identical on every platform.
+    pub fn update(&mut self, rng: &mut impl RngCore) {
+        let mut axis = || 2.0 * random_f32_canonical(rng) - 1.0;
+        let unit = Vec3::new(axis(), axis(), axis());
+
+        self.value = unit * self.amplitude;
+    }
+}
+
+/// Plugin for updating every [`FrameJitter`] on entities with an `R` [`Entropy`] source, once per
+/// frame in [`First`], before any systems in the main schedules read them. Nothing else draws
+/// from the sources for the jitter, so their sequences only depend on the seeds and the number
+/// of frames.
+pub struct FrameJitterPlugin<R: EntropySource>(PhantomData<R>);
+
+impl<R: EntropySource> Default for FrameJitterPlugin<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for FrameJitterPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FrameJitter>()
+            .add_systems(First, update_frame_jitter::<R>);
+    }
+}
+
+fn update_frame_jitter<R: EntropySource + 'static>(
+    mut q_jitters: Query<(&mut FrameJitter, &mut Entropy<R>)>,
+) {
+    for (mut jitter, mut rng) in q_jitters.iter_mut() {
+        jitter.update(rng.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_app::{PostUpdate, Update};
+    use bevy_ecs::prelude::{ResMut, Resource, Single};
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use crate::{plugin::EntropyPlugin, seed::RngSeed, traits::SeedSource};
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Seen(Vec<(Vec3, Vec3)>);
+
+    fn frames(frames: usize) -> Vec<(Vec3, Vec3)> {
+        let mut app = App::new();
+
+        app.init_resource::<Seen>().add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            FrameJitterPlugin::<WyRand>::default(),
+        ));
+
+        app.world_mut()
+            .spawn((FrameJitter::uniform(2.0), RngSeed::<WyRand>::from_u64(5)));
+
+        // One reader early and one late in the frame
+        app.add_systems(
+            Update,
+            |jitter: Single<&FrameJitter>, mut seen: ResMut<Seen>| {
+                seen.0.push((jitter.value(), Vec3::NAN));
+            },
+        )
+        .add_systems(
+            PostUpdate,
+            |jitter: Single<&FrameJitter>, mut seen: ResMut<Seen>| {
+                seen.0.last_mut().unwrap().1 = jitter.value();
+            },
+        );
+
+        for _ in 0..frames {
+            app.update();
+        }
+
+        app.world_mut().remove_resource::<Seen>().unwrap().0
+    }
+
+    #[test]
+    fn readers_in_a_frame_agree() {
+        let seen = frames(8);
+
+        assert!(seen.iter().all(|(early, late)| early == late));
+        assert!(seen.windows(2).all(|pair| pair[0].0 != pair[1].0));
+        assert!(seen
+            .iter()
+            .all(|(value, _)| value.abs().max_element() <= 2.0));
+    }
+
+    #[test]
+    fn sequences_are_deterministic() {
+        let seen = frames(4);
+
+        assert_eq!(seen, frames(4));
+
+        let mut rng = Entropy::<WyRand>::from_seed(RngSeed::<WyRand>::from_u64(5).clone_seed());
+        let mut expected = FrameJitter::uniform(2.0);
+
+        for (value, _) in seen {
+            expected.update(&mut rng);
+
+            assert_eq!(value, expected.value());
+        }
+    }
+
+    #[test]
+    fn zero_amplitudes_still_draw() {
+        let mut rng = WyRand::from_seed([2; 8]);
+        let mut flat = FrameJitter::new(Vec3::new(1.0, 0.0, f32::NAN));
+
+        flat.update(&mut rng);
+
+        assert_eq!(flat.amplitude(), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!((flat.value().y, flat.value().z), (0.0, 0.0));
+
+        let mut expected = WyRand::from_seed([2; 8]);
+
+        for _ in 0..3 {
+            expected.next_u32();
+        }
+
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+}
@@ -0,0 +1,261 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+use core::fmt;
+
+use bevy_ecs::{
+    component::{Immutable, StorageType},
+    prelude::{Component, Entity, ReflectComponent, Resource, World},
+};
+use bevy_reflect::Reflect;
+
+/// A stable, human readable label for an RNG source entity, such as `"boss_rng"`. Unlike
+/// [`Entity`] ids, labels are the same on every run, so they can be used to correlate sources
+/// across logs and runs, and are preferred over the entity in messages about the source.
+///
+/// Labels are expected to be unique, but this is not enforced: inserting a label already used by
+/// another entity logs a warning with the `tracing` feature, and the duplicates can be found with
+/// [`RngLabelIndex::duplicates`]. Labels are usually inserted with
+/// [`RngEntityCommands::labelled`](crate::commands::RngEntityCommands::labelled), and targets
+/// spawned for a labelled source are labelled after it, as `"boss_rng/0"`, `"boss_rng/1"` and so
+/// on.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Debug, PartialEq, Component)]
+pub struct RngLabel(Cow<'static, str>);
+
+impl RngLabel {
+    /// Creates a new label.
+    #[inline]
+    #[must_use]
+    pub fn new(label: impl Into<Cow<'static, str>>) -> Self {
+        Self(label.into())
+    }
+
+    /// Returns the label as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns a [`fmt::Display`] naming a source by its label if it has one, or by its `entity`
+    /// otherwise, for use in messages about the source.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_rand::prelude::RngLabel;
+    ///
+    /// let entity = Entity::from_raw(7);
+    /// let label = RngLabel::new("boss_rng");
+    ///
+    /// assert_eq!(RngLabel::describe(Some(&label), entity).to_string(), "boss_rng");
+    /// assert_eq!(RngLabel::describe(None, entity).to_string(), entity.to_string());
+    /// ```
+    #[inline]
+    pub fn describe(label: Option<&RngLabel>, entity: Entity) -> impl fmt::Display + '_ {
+        DescribeSource { label, entity }
+    }
+}
+
+impl fmt::Display for RngLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Component for RngLabel {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Immutable;
+
+    fn register_component_hooks(hooks: &mut bevy_ecs::component::ComponentHooks) {
+        hooks
+            .on_insert(|mut world, entity, _| {
+                let label = world.get::<RngLabel>(entity).unwrap().clone();
+
+                world.commands().queue(move |world: &mut World| {
+                    world
+                        .get_resource_or_init::<RngLabelIndex>()
+                        .insert(label, entity);
+                });
+            })
+            .on_replace(|mut world, entity, _| {
+                let label = world.get::<RngLabel>(entity).unwrap().clone();
+
+                world.commands().queue(move |world: &mut World| {
+                    if let Some(mut index) = world.get_resource_mut::<RngLabelIndex>() {
+                        index.remove(&label, entity);
+                    }
+                });
+            });
+    }
+}
+
+struct DescribeSource<'a> {
+    label: Option<&'a RngLabel>,
+    entity: Entity,
+}
+
+impl fmt::Display for DescribeSource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.label {
+            Some(label) => label.fmt(f),
+            None => self.entity.fmt(f),
+        }
+    }
+}
+
+/// An index of all labelled entities by their [`RngLabel`], for looking up sources by label.
+/// Added and kept up to date by the [`RngLabel`] hooks, as of the last command flush.
+#[derive(Debug, Default, Resource)]
+pub struct RngLabelIndex {
+    labels: BTreeMap<RngLabel, Vec<Entity>>,
+}
+
+impl RngLabelIndex {
+    /// Returns the entity labelled `label`, or the first one to be labelled with it if the label
+    /// is not unique.
+    pub fn get(&self, label: &str) -> Option<Entity> {
+        self.labels
+            .get(&RngLabel::new(Cow::Owned(label.into())))
+            .and_then(|entities| entities.first().copied())
+    }
+
+    /// Returns an iterator over all labels used by more than one entity, with the entities using
+    /// them.
+    pub fn duplicates(&self) -> impl Iterator<Item = (&RngLabel, &[Entity])> {
+        self.labels
+            .iter()
+            .filter(|(_, entities)| entities.len() > 1)
+            .map(|(label, entities)| (label, entities.as_slice()))
+    }
+
+    fn insert(&mut self, label: RngLabel, entity: Entity) {
+        #[cfg(feature = "tracing")]
+        if let Some(&first) = self
+            .labels
+            .get(&label)
+            .and_then(|entities| entities.first())
+        {
+            tracing::warn!(
+                "RNG label {label} is already used by {first}, and is now also used by {entity}"
+            );
+        }
+
+        self.labels.entry(label).or_default().push(entity);
+    }
+
+    fn remove(&mut self, label: &RngLabel, entity: Entity) {
+        if let Some(entities) = self.labels.get_mut(label) {
+            entities.retain(|&labelled| labelled != entity);
+
+            if entities.is_empty() {
+                self.labels.remove(label);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use bevy_prng::WyRand;
+
+    use crate::{commands::RngCommandsExt, seed::RngSeed, traits::SeedSource};
+
+    use super::*;
+
+    #[test]
+    fn labels_are_indexed_and_describe_sources() {
+        let mut world = World::new();
+
+        let boss = world.spawn(RngSeed::<WyRand>::from_seed([1; 8])).id();
+        let minion = world.spawn(RngSeed::<WyRand>::from_seed([2; 8])).id();
+        let unlabelled = world.spawn(RngSeed::<WyRand>::from_seed([3; 8])).id();
+
+        world
+            .commands()
+            .rng_entity::<WyRand>(boss)
+            .labelled("boss_rng");
+        world
+            .commands()
+            .rng_entity::<WyRand>(minion)
+            .labelled("boss_rng");
+        world.flush();
+
+        let describe =
+            |world: &World, entity| RngLabel::describe(world.get(entity), entity).to_string();
+
+        assert_eq!(describe(&world, boss), "boss_rng");
+        assert_eq!(describe(&world, unlabelled), unlabelled.to_string());
+
+        // Duplicates are allowed, but reported
+        let index = world.resource::<RngLabelIndex>();
+
+        assert_eq!(index.get("boss_rng"), Some(boss));
+        assert_eq!(
+            index.duplicates().collect::<Vec<_>>(),
+            [(&RngLabel::new("boss_rng"), [boss, minion].as_slice())]
+        );
+
+        // Relabelling or despawning removes the old entries
+        world
+            .commands()
+            .rng_entity::<WyRand>(minion)
+            .labelled("minion_rng");
+        world.despawn(boss);
+        world.flush();
+
+        let index = world.resource::<RngLabelIndex>();
+
+        assert_eq!(index.get("boss_rng"), None);
+        assert_eq!(index.get("minion_rng"), Some(minion));
+        assert_eq!(index.duplicates().count(), 0);
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn stable_targets_are_labelled_after_their_source() {
+        use bevy_app::App;
+
+        use crate::plugin::EntropyPlugin;
+
+        #[derive(Component)]
+        struct Target;
+
+        #[derive(Component)]
+        struct Source;
+
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]));
+
+        let world = app.world_mut();
+
+        let source = world
+            .spawn((Source, RngSeed::<WyRand>::from_seed([2; 8])))
+            .id();
+
+        world
+            .commands()
+            .rng_entity::<WyRand>(source)
+            .labelled("boss_rng")
+            .with_target_rngs_stable([("left", Target), ("right", Target)]);
+        world.flush();
+
+        let mut labels: Vec<_> = world
+            .query::<(&RngLabel, Option<&Target>)>()
+            .iter(world)
+            .map(|(label, target)| (label.to_string(), target.is_some()))
+            .collect();
+
+        labels.sort();
+
+        assert_eq!(
+            labels,
+            [
+                ("boss_rng".to_string(), false),
+                ("boss_rng/0".to_string(), true),
+                ("boss_rng/1".to_string(), true),
+            ]
+        );
+    }
+}
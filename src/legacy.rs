@@ -0,0 +1,341 @@
+use alloc::{format, string::String};
+use core::{fmt, marker::PhantomData};
+
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::{
+    component::{Immutable, StorageType},
+    prelude::{Component, Entity, ReflectComponent, Resource, With, World},
+};
+use bevy_prng::EntropySource;
+use bevy_reflect::{
+    Reflect, ReflectDeserialize, ReflectFromReflect, ReflectSerialize, TypeRegistry,
+};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::{
+    component::Entropy,
+    global::Global,
+    seed::{RecordedSeed, RngSeed},
+    traits::{ForkableInnerSeed, SeedSource},
+};
+
+/// The serialized form of the legacy `GlobalEntropy<R>` resource, from before global sources
+/// were entities. Only supports deserialization, for migrating old save files with
+/// [`migrate_legacy_global`].
+///
+/// Accepts both the typed reflection form, `((state))`, and the untyped reflection form keyed by
+/// the old type path, `{"bevy_rand::resource::GlobalEntropy<R>":((state))}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegacyGlobalEntropy<R: EntropySource>(R);
+
+impl<R: EntropySource> LegacyGlobalEntropy<R> {
+    /// Returns the type path the legacy resource was serialized under.
+    pub fn type_path() -> String {
+        format!("bevy_rand::resource::GlobalEntropy<{}>", R::type_path())
+    }
+
+    /// Returns the deserialized PRNG state.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<'de, R: EntropySource> Deserialize<'de> for LegacyGlobalEntropy<R> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LegacyVisitor<R>(PhantomData<R>);
+
+        impl<'de, R: EntropySource> Visitor<'de> for LegacyVisitor<R> {
+            type Value = LegacyGlobalEntropy<R>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a legacy GlobalEntropy resource")
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                R::deserialize(deserializer).map(LegacyGlobalEntropy)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                seq.next_element::<R>()?
+                    .map(LegacyGlobalEntropy)
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let expected = LegacyGlobalEntropy::<R>::type_path();
+
+                let key = map
+                    .next_key::<String>()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+                if key != expected {
+                    return Err(de::Error::custom(format!(
+                        "expected type path `{expected}`, found `{key}`"
+                    )));
+                }
+
+                map.next_value::<LegacyGlobalEntropy<R>>()
+            }
+        }
+
+        deserializer.deserialize_any(LegacyVisitor(PhantomData))
+    }
+}
+
+/// The serialized form of the legacy `GlobalRngSeed<R>` resource, in its typed reflection form,
+/// `(seed:(..))`. Only supports deserialization.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(bound(deserialize = "R::Seed: Deserialize<'de>"))]
+pub struct LegacyGlobalSeed<R: EntropySource> {
+    seed: R::Seed,
+    #[serde(skip)]
+    rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> LegacyGlobalSeed<R> {
+    /// Returns the deserialized seed.
+    #[inline]
+    pub fn into_inner(self) -> R::Seed {
+        self.seed
+    }
+}
+
+/// The `EntropyComponent<R>` component, from before it was renamed to [`Entropy`], registered
+/// under its old type path, `bevy_rand::component::EntropyComponent<R>`, by
+/// [`register_legacy_rng_paths`]. Scenes saved with the old name deserialize into this
+/// component, which replaces itself with an [`Entropy`] continuing from the same state as soon
+/// as it is inserted into a world.
+///
+/// If the scene also has an [`RngSeed`] for the entity, the source continues from the saved
+/// state only if the legacy component is inserted after the seed, as otherwise the source is
+/// reinitialised from the seed once it is inserted.
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[serde(bound(deserialize = "R: for<'a> Deserialize<'a>"))]
+#[reflect(Debug, PartialEq, Component, FromReflect, Serialize, Deserialize)]
+#[type_path = "bevy_rand::component"]
+#[type_name = "EntropyComponent"]
+pub struct LegacyEntropyComponent<R: EntropySource + 'static>(R);
+
+impl<R: EntropySource + 'static> LegacyEntropyComponent<R> {
+    /// Returns the deserialized PRNG state.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R: EntropySource + 'static> Component for LegacyEntropyComponent<R> {
+    const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+    type Mutability = Immutable;
+
+    fn register_component_hooks(hooks: &mut bevy_ecs::component::ComponentHooks) {
+        hooks.on_insert(|mut world, entity, _| {
+            let rng = world
+                .get::<LegacyEntropyComponent<R>>(entity)
+                .unwrap()
+                .clone()
+                .into_inner();
+
+            world
+                .commands()
+                .entity(entity)
+                .remove::<LegacyEntropyComponent<R>>()
+                .insert(Entropy::new(rng));
+        });
+    }
+}
+
+/// Registers the types standing in for `R`'s RNG types under the type paths they had in earlier
+/// versions, so that scenes and other reflected data saved with the old paths keep
+/// deserializing into the current types. Registered automatically by
+/// [`crate::plugin::RngTypesPlugin`] with [`crate::plugin::ReflectionScope::Full`], or call it
+/// on a [`TypeRegistry`] directly when deserializing outside of an app.
+///
+/// The registered paths are:
+///
+/// - `bevy_rand::component::EntropyComponent<R>`, deserializing into
+///   [`LegacyEntropyComponent`] and replaced by an [`Entropy`] on being inserted.
+///
+/// Any type paths changed by future renames will be added here as well.
+pub fn register_legacy_rng_paths<R: EntropySource + 'static>(registry: &mut TypeRegistry) {
+    registry.register::<LegacyEntropyComponent<R>>();
+}
+
+/// A snapshot of the legacy global resources, to be migrated to the [`Global`] `R` source.
+/// Insert it as a resource for [`LegacyGlobalMigrationPlugin`] to migrate, or pass it directly
+/// to [`migrate_legacy_global`].
+#[derive(Debug, Resource)]
+pub struct LegacySnapshot<R: EntropySource> {
+    rng: R,
+    seed: Option<R::Seed>,
+}
+
+impl<R: EntropySource> LegacySnapshot<R> {
+    /// Creates a snapshot from the legacy `GlobalEntropy` state, and the legacy `GlobalRngSeed`
+    /// if the save file contained one.
+    #[inline]
+    #[must_use]
+    pub fn new(rng: LegacyGlobalEntropy<R>, seed: Option<LegacyGlobalSeed<R>>) -> Self {
+        Self {
+            rng: rng.into_inner(),
+            seed: seed.map(LegacyGlobalSeed::into_inner),
+        }
+    }
+}
+
+/// Migrates a [`LegacySnapshot`] to the [`Global`] `R` source, spawning the global source if it
+/// does not exist yet. The global [`Entropy`] continues exactly from the legacy state, while its
+/// [`RngSeed`] is the legacy seed, or forked from the legacy state if there was none. Any
+/// [`LegacySnapshot`] resource is removed.
+pub fn migrate_legacy_global<R: EntropySource + 'static>(
+    world: &mut World,
+    snapshot: LegacySnapshot<R>,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    world.remove_resource::<LegacySnapshot<R>>();
+
+    let LegacySnapshot { rng, seed } = snapshot;
+
+    let rng = Entropy::new(rng);
+    let seed = seed.unwrap_or_else(|| rng.clone().fork_inner_seed());
+
+    // The seed is recorded, so that the legacy state is not reinitialised from it.
+    let migrated = (
+        RngSeed::<R>::from_seed(seed),
+        RecordedSeed::<R>::default(),
+        rng,
+    );
+
+    let global = world
+        .query_filtered::<Entity, (With<Global>, With<RngSeed<R>>)>()
+        .iter(world)
+        .next();
+
+    match global {
+        Some(global) => {
+            world.entity_mut(global).insert(migrated);
+        }
+        None => {
+            world.spawn((migrated, Global));
+        }
+    }
+
+    world.flush();
+}
+
+/// Plugin for migrating a [`LegacySnapshot`] resource to the [`Global`] `R` source, once it is
+/// inserted. Useful for loading old save files, where the snapshot is inserted by the loading
+/// code and migrated at the start of the next frame.
+pub struct LegacyGlobalMigrationPlugin<R: EntropySource> {
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> Default for LegacyGlobalMigrationPlugin<R> {
+    fn default() -> Self {
+        Self { _rng: PhantomData }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for LegacyGlobalMigrationPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(First, migrate_snapshot::<R>);
+    }
+}
+
+fn migrate_snapshot<R: EntropySource + 'static>(world: &mut World)
+where
+    R::Seed: Send + Sync + Clone,
+{
+    if let Some(snapshot) = world.remove_resource::<LegacySnapshot<R>>() {
+        migrate_legacy_global(world, snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::{RngCore, SeedableRng};
+
+    use crate::plugin::EntropyPlugin;
+
+    use super::*;
+
+    const LEGACY_ENTROPY: &str = "{\"bevy_rand::resource::GlobalEntropy<bevy_prng::ChaCha8Rng>\":(((seed:(7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7),stream:0,word_pos:1)))}";
+
+    const LEGACY_SEED: &str =
+        "(seed:(7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7))";
+
+    fn global(app: &mut App) -> (Entropy<ChaCha8Rng>, [u8; 32]) {
+        app.world_mut()
+            .query_filtered::<(&Entropy<ChaCha8Rng>, &RngSeed<ChaCha8Rng>), With<Global>>()
+            .iter(app.world())
+            .next()
+            .map(|(rng, seed)| (rng.clone(), seed.clone_seed()))
+            .unwrap()
+    }
+
+    #[test]
+    fn legacy_global_continues_sequence() {
+        let rng: LegacyGlobalEntropy<ChaCha8Rng> = ron::from_str(LEGACY_ENTROPY).unwrap();
+        let seed: LegacyGlobalSeed<ChaCha8Rng> = ron::from_str(LEGACY_SEED).unwrap();
+
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]),
+            LegacyGlobalMigrationPlugin::<ChaCha8Rng>::default(),
+        ))
+        .insert_resource(LegacySnapshot::new(rng, Some(seed)));
+
+        app.update();
+
+        assert!(!app
+            .world()
+            .contains_resource::<LegacySnapshot<ChaCha8Rng>>());
+
+        let (mut migrated, seed) = global(&mut app);
+
+        // The legacy resource had drawn one word from a source seeded with [7; 32]
+        let mut expected = ChaCha8Rng::from_seed([7; 32]);
+        expected.next_u32();
+
+        assert_eq!(seed, [7; 32]);
+        assert_eq!(migrated.next_u64(), expected.next_u64());
+        assert_eq!(migrated.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn legacy_global_without_seed_is_spawned() {
+        let rng: LegacyGlobalEntropy<ChaCha8Rng> =
+            ron::from_str("(((seed:(3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3),stream:0,word_pos:0)))").unwrap();
+
+        let mut app = App::new();
+
+        migrate_legacy_global(app.world_mut(), LegacySnapshot::new(rng, None));
+
+        let (mut migrated, seed) = global(&mut app);
+
+        let mut expected = Entropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        assert_eq!(seed, expected.clone().fork_inner_seed());
+        assert_eq!(migrated.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn mismatched_type_path_is_rejected() {
+        let wrong = LEGACY_ENTROPY.replace("ChaCha8Rng>", "ChaCha12Rng>");
+
+        assert!(ron::from_str::<LegacyGlobalEntropy<ChaCha8Rng>>(&wrong).is_err());
+    }
+}
@@ -11,22 +11,125 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+/// Extension methods for setting up RNG sources on apps.
+pub mod app;
+#[cfg(feature = "bevy_asset")]
+/// Seed configuration assets, for seeding sources from data files with hot reloading.
+pub mod asset;
+#[cfg(feature = "bevy_time")]
+/// Plugin for automatically reseeding [`crate::global::Global`] sources over time.
+pub mod auto_reseed;
+/// Stateless per-particle random values derived from a single fork.
+pub mod burst;
+#[cfg(feature = "cascade_trace")]
+/// Tracing of the order seeds are propagated to linked targets, for investigating desyncs.
+pub mod cascade_trace;
+#[cfg(all(feature = "bevy_time", feature = "std"))]
+/// Frame rate independent random chances and timers, driven by [`crate::component::Entropy`] sources.
+pub mod chance;
+#[cfg(feature = "bevy_color")]
+/// Random [`bevy_color`] color generation from [`crate::component::Entropy`] sources.
+pub mod color;
+/// Commands for issuing RNG specific commands and triggering events with forked RNGs.
+pub mod commands;
 /// Components for integrating [`RngCore`] PRNGs into bevy. Must be newtyped to support [`Reflect`].
 pub mod component;
+#[cfg(feature = "console")]
+/// Developer console commands for inspecting and reseeding sources through reflection.
+pub mod console;
+/// Invalidation of procedurally generated data derived from RNG sources when they are reseeded.
+pub mod derived;
+#[cfg(feature = "rand_distr")]
+/// Data-driven distribution components, sampled with [`crate::component::Entropy`] sources.
+pub mod distributions;
+#[cfg(feature = "fork_tracking")]
+/// Instrumentation for tracking down where [`crate::component::Entropy`] sources are forked from.
+pub mod fork_tracking;
+#[cfg(feature = "bevy_diagnostic")]
+/// Per-frame RNGs derived from the [`crate::global::Global`] seed and the frame count.
+pub mod frame;
 /// Global [`crate::component::Entropy`] sources, with query helpers.
 pub mod global;
+#[cfg(feature = "bevy_math")]
+/// Per-entity jitter that changes once per frame, drawn from [`crate::component::Entropy`] sources.
+pub mod jitter;
+/// Stable labels for identifying RNG sources in logs and across runs.
+pub mod label;
+#[cfg(feature = "serialize")]
+/// Migration of legacy resource based global sources to [`crate::global::Global`] entities.
+pub mod legacy;
+/// Opt-in lints for systems that mix [`crate::global::Global`] and per-entity RNG streams.
+pub mod lint;
+#[cfg(feature = "bevy_math")]
+/// Sampling of [`bevy_math`] directions, rotations and shapes from [`crate::component::Entropy`] sources.
+pub mod math;
+/// Seeds for external libraries such as noise generators, derived from [`crate::global::Global`] seeds.
+pub mod noise;
 #[cfg(feature = "experimental")]
 /// Utility observers for handling seeding between parent/child entropy sources
 pub mod observers;
+/// Parallel query iteration with deterministically derived RNGs.
+pub mod parallel;
+/// Query filters and system params for accessing RNG sources.
+pub mod params;
+/// Integration with third-party save crates, for persisting RNG sources without their types.
+pub mod persistence;
 /// Plugin for integrating [`RngCore`] PRNGs into bevy. Must be newtyped to support [`Reflect`].
 pub mod plugin;
+/// Pools of pre-generated seeds, for spawning many RNG sources at once.
+pub mod pool;
 /// Prelude for providing all necessary types for easy use.
 pub mod prelude;
-/// Seed Resource for seeding [`crate::resource::GlobalEntropy`].
+/// Resource for creating RNG sources matching the app's preferred algorithms.
+pub mod provider;
+/// Detection and repair of entities whose seeds and sources have fallen out of sync.
+pub mod repair;
+/// Capturing, applying and verifying the seeds of a world's sources for replays.
+pub mod replay;
+#[cfg(feature = "experimental")]
+/// Event-driven reseeding systems, as an alternative to the reseeding observers.
+pub mod requests;
+#[cfg(feature = "bevy_math")]
+/// Deterministic scattering of spaced points within [`bevy_math`] shapes, for placing spawned entities.
+pub mod scatter;
+#[cfg(feature = "bevy_scene")]
+/// Policies for seeding the RNG sources of newly spawned scene instances.
+pub mod scene;
+/// Seed components for seeding [`crate::component::Entropy`] sources.
 pub mod seed;
-#[cfg(feature = "thread_local_entropy")]
-mod thread_local_entropy;
-/// Traits for enabling utility methods for [`crate::component::Entropy`] and [`crate::resource::GlobalEntropy`].
+/// Utilities for stable, order-independent random selection from query results.
+pub mod select;
+/// Short, shareable codes standing in for seeds, with checksums against typos.
+pub mod short_seed;
+#[cfg(feature = "rand")]
+/// Shuffle bags for drawing items without replacement with [`crate::component::Entropy`] sources.
+pub mod shuffle;
+/// Keyed forks for entities spawned at startup, independent of the startup schedule order.
+pub mod startup;
+#[cfg(feature = "bevy_state")]
+/// Plugin for reseeding [`crate::global::Global`] sources when entering states.
+pub mod state;
+#[cfg(feature = "stress")]
+/// Reproducible worlds of linked sources for profiling and testing seed propagation.
+pub mod stress;
+/// Plugin for synchronising [`crate::global::Global`] seeds between peers over the network.
+pub mod sync;
+/// Running code with a [`crate::global::Global`] source temporarily seeded with a specific seed.
+pub mod temporary;
+#[cfg(feature = "test_harness")]
+/// Headless app builder for deterministic RNG tests.
+pub mod test_harness;
+#[cfg(feature = "uuid")]
+/// UUID and random token generation from [`crate::component::Entropy`] sources.
+pub mod token;
+/// Traits for enabling utility methods for [`crate::component::Entropy`] and [`crate::global::GlobalEntropy`].
 pub mod traits;
 #[cfg(doc)]
 pub mod tutorial;
+/// A dedicated global source for UI and layout randomness, kept apart from gameplay sources.
+pub mod ui;
+/// Bit-exact random helpers for lockstep and replays, and stable keys for ordering entities.
+pub mod util;
+#[cfg(feature = "rand")]
+/// Weighted tables for sampling items with an entity's [`crate::component::Entropy`].
+pub mod weighted;
@@ -0,0 +1,301 @@
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use core::{any::type_name, marker::PhantomData};
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::{
+    archetype::ArchetypeComponentId,
+    prelude::{Resource, World},
+    schedule::Schedules,
+};
+use bevy_prng::EntropySource;
+
+use crate::{component::Entropy, global::Global};
+
+/// A system flagged by [`RngLintPlugin`] for drawing from both the [`Global`] source and other
+/// [`Entropy`] sources of the same algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RngLintWarning {
+    system: Cow<'static, str>,
+    schedule: String,
+    rng: &'static str,
+}
+
+impl RngLintWarning {
+    /// Returns the name of the flagged system.
+    #[inline]
+    pub fn system(&self) -> &str {
+        &self.system
+    }
+
+    /// Returns the debug name of the schedule the flagged system is in.
+    #[inline]
+    pub fn schedule(&self) -> &str {
+        &self.schedule
+    }
+
+    /// Returns the type name of the RNG algorithm whose sources the system mixes.
+    #[inline]
+    pub fn rng(&self) -> &'static str {
+        self.rng
+    }
+}
+
+/// A report of all systems flagged by [`RngLintPlugin`], each recorded once. Added by the plugin
+/// and updated at the end of each frame.
+#[derive(Debug, Default, Resource)]
+pub struct RngLintReport {
+    warnings: Vec<RngLintWarning>,
+}
+
+impl RngLintReport {
+    /// Returns an iterator over all flagged systems.
+    pub fn iter(&self) -> impl Iterator<Item = &RngLintWarning> {
+        self.warnings.iter()
+    }
+
+    /// Returns whether the system named `system` has been flagged, matching as with
+    /// [`RngLintPlugin::allow`].
+    pub fn contains(&self, system: &str) -> bool {
+        self.warnings
+            .iter()
+            .any(|warning| name_matches(&warning.system, system))
+    }
+
+    /// Returns whether no systems have been flagged.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+/// Opt-in plugin for flagging systems that draw from both the [`Global`] `R` source and other
+/// `R` sources, such as a system taking [`crate::global::GlobalEntropy`] while iterating a
+/// query of [`Entropy`] components. Mixing the streams makes the outcome depend on the query's
+/// iteration order, a common source of lost determinism.
+///
+/// The check is a heuristic based on which archetypes' [`Entropy<R>`] components each system has
+/// mutable access to, so a system is only flagged once it has run with both the global source and
+/// other sources in the world. Systems are checked at the end of each frame, and flagged systems
+/// are recorded in the [`RngLintReport`], with a warning logged when the `tracing` feature is
+/// enabled. Exclusive systems are never flagged. Intended for debug builds.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{lint::RngLintPlugin, prelude::EntropyPlugin};
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     RngLintPlugin::<WyRand>::default().allow("reseed_everything"),
+/// ));
+/// ```
+pub struct RngLintPlugin<R: EntropySource> {
+    allowed: Vec<Cow<'static, str>>,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> Default for RngLintPlugin<R> {
+    fn default() -> Self {
+        Self {
+            allowed: Vec::new(),
+            _rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource> RngLintPlugin<R> {
+    /// Silences the lint for the system named `system`. Matches either the full name of the
+    /// system, such as `my_game::enemies::spawn_wave`, or its trailing path segments, such as
+    /// `spawn_wave` or `enemies::spawn_wave`.
+    #[inline]
+    #[must_use]
+    pub fn allow(mut self, system: impl Into<Cow<'static, str>>) -> Self {
+        self.allowed.push(system.into());
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for RngLintPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RngLintReport>()
+            .insert_resource(RngLintAllowlist::<R> {
+                allowed: self.allowed.clone(),
+                _rng: PhantomData,
+            })
+            .add_systems(Last, lint_rng_access::<R>);
+    }
+}
+
+#[derive(Resource)]
+struct RngLintAllowlist<R> {
+    allowed: Vec<Cow<'static, str>>,
+    _rng: PhantomData<R>,
+}
+
+/// Returns whether the system `name` is `pattern`, or ends with `pattern` as whole path segments.
+fn name_matches(name: &str, pattern: &str) -> bool {
+    name.strip_suffix(pattern)
+        .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with("::"))
+}
+
+fn lint_rng_access<R: EntropySource + 'static>(world: &mut World) {
+    let (Some(entropy), Some(global)) = (
+        world.component_id::<Entropy<R>>(),
+        world.component_id::<Global>(),
+    ) else {
+        return;
+    };
+
+    let mut global_sources: Vec<ArchetypeComponentId> = Vec::new();
+    let mut other_sources: Vec<ArchetypeComponentId> = Vec::new();
+
+    for archetype in world.archetypes().iter() {
+        if let Some(id) = archetype.get_archetype_component_id(entropy) {
+            if archetype.contains(global) {
+                global_sources.push(id);
+            } else {
+                other_sources.push(id);
+            }
+        }
+    }
+
+    if global_sources.is_empty() || other_sources.is_empty() {
+        return;
+    }
+
+    let allowlist = world.resource::<RngLintAllowlist<R>>();
+    let mut flagged = Vec::new();
+
+    for (label, schedule) in world.resource::<Schedules>().iter() {
+        let Ok(systems) = schedule.systems() else {
+            continue;
+        };
+
+        for (_, system) in systems {
+            if system.is_exclusive() {
+                continue;
+            }
+
+            let access = system.archetype_component_access();
+            let writes_any =
+                |ids: &[ArchetypeComponentId]| ids.iter().any(|id| access.has_component_write(*id));
+
+            let name = system.name();
+
+            if writes_any(&global_sources)
+                && writes_any(&other_sources)
+                && !allowlist
+                    .allowed
+                    .iter()
+                    .any(|allowed| name_matches(&name, allowed))
+            {
+                flagged.push(RngLintWarning {
+                    system: name,
+                    schedule: format!("{label:?}"),
+                    rng: type_name::<R>(),
+                });
+            }
+        }
+    }
+
+    let mut report = world.resource_mut::<RngLintReport>();
+
+    for warning in flagged {
+        if report.warnings.contains(&warning) {
+            continue;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            "{} in {} draws from both the Global {} source and other sources, so its output depends on query iteration order",
+            warning.system,
+            warning.schedule,
+            warning.rng
+        );
+
+        report.warnings.push(warning);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::Update;
+    use bevy_ecs::prelude::{Query, Without};
+    use bevy_prng::WyRand;
+    use rand_core::RngCore;
+
+    use crate::{
+        global::GlobalEntropy,
+        plugin::EntropyPlugin,
+        seed::RngSeed,
+        traits::{ForkableRng, SeedSource},
+    };
+
+    use super::*;
+
+    fn mixes_streams(
+        mut global: GlobalEntropy<WyRand>,
+        mut q_sources: Query<&mut Entropy<WyRand>, Without<Global>>,
+    ) {
+        for mut source in q_sources.iter_mut() {
+            source.next_u32();
+            global.next_u32();
+        }
+    }
+
+    fn reseeds_everything(
+        mut global: GlobalEntropy<WyRand>,
+        mut q_sources: Query<&mut Entropy<WyRand>, Without<Global>>,
+    ) {
+        for mut source in q_sources.iter_mut() {
+            *source = global.fork_rng();
+        }
+    }
+
+    fn draws_from_sources(mut q_sources: Query<&mut Entropy<WyRand>, Without<Global>>) {
+        for mut source in q_sources.iter_mut() {
+            source.next_u32();
+        }
+    }
+
+    fn draws_from_global(mut global: GlobalEntropy<WyRand>) {
+        global.next_u32();
+    }
+
+    #[test]
+    fn systems_mixing_global_and_entity_sources_are_flagged() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            RngLintPlugin::<WyRand>::default().allow("reseeds_everything"),
+        ))
+        .add_systems(
+            Update,
+            (
+                mixes_streams,
+                reseeds_everything,
+                draws_from_sources,
+                draws_from_global,
+            ),
+        );
+
+        app.world_mut()
+            .spawn_batch([2, 3].map(|seed| RngSeed::<WyRand>::from_seed([seed; 8])));
+
+        app.update();
+        app.update();
+
+        let report = app.world().resource::<RngLintReport>();
+
+        assert_eq!(report.iter().count(), 1);
+        assert!(report.contains("mixes_streams"));
+        assert!(report.contains("lint::tests::mixes_streams"));
+        assert!(!report.contains("streams"));
+
+        let warning = report.iter().next().unwrap();
+
+        assert_eq!(warning.schedule(), "Update");
+        assert_eq!(warning.rng(), type_name::<WyRand>());
+    }
+}
@@ -0,0 +1,106 @@
+use bevy_math::{sampling::FromRng, Dir2, Dir3, Quat, Rot2, ShapeSample};
+use rand_core::RngCore;
+
+/// Extension trait for sampling [`bevy_math`] types from any RNG source, such as
+/// [`crate::component::Entropy`] or [`crate::global::GlobalEntropy`].
+///
+/// All methods delegate to `bevy_math`'s own sampling, so the output is identical to calling
+/// [`ShapeSample::sample_interior`] or [`FromRng::from_rng`] with the same RNG state.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_math::primitives::Circle;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{GlobalEntropy, RngMathExt};
+///
+/// fn spawn_position(mut rng: GlobalEntropy<WyRand>) {
+///     let position = rng.random_in(&Circle::new(10.0));
+///     let facing = rng.random_dir2();
+///
+///     println!("Spawning at {position} facing {facing:?}");
+/// }
+/// ```
+pub trait RngMathExt: RngCore {
+    /// Generate a random [`Dir2`], uniformly distributed on the unit circle.
+    #[inline]
+    fn random_dir2(&mut self) -> Dir2 {
+        Dir2::from_rng(self)
+    }
+
+    /// Generate a random [`Dir3`], uniformly distributed on the unit sphere.
+    #[inline]
+    fn random_dir3(&mut self) -> Dir3 {
+        Dir3::from_rng(self)
+    }
+
+    /// Generate a random [`Rot2`], uniformly distributed over all angles.
+    #[inline]
+    fn random_rot2(&mut self) -> Rot2 {
+        Rot2::from_rng(self)
+    }
+
+    /// Generate a random [`Quat`], uniformly distributed over all 3D rotations.
+    #[inline]
+    fn random_quat(&mut self) -> Quat {
+        Quat::from_rng(self)
+    }
+
+    /// Sample a point uniformly from the interior of `shape`, centered on the origin.
+    #[inline]
+    fn random_in<S: ShapeSample>(&mut self, shape: &S) -> S::Output {
+        shape.sample_interior(self)
+    }
+
+    /// Sample a point uniformly from the boundary of `shape`, centered on the origin.
+    #[inline]
+    fn random_on_boundary<S: ShapeSample>(&mut self, shape: &S) -> S::Output {
+        shape.sample_boundary(self)
+    }
+}
+
+impl<R: RngCore + ?Sized> RngMathExt for R {}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{
+        primitives::{Circle, Rectangle, Sphere},
+        Vec2,
+    };
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn pinned_outputs() {
+        let mut rng = WyRand::from_seed([3; 8]);
+
+        // Compared with a tolerance, as trigonometric functions may differ in the last few bits
+        // between platforms.
+        assert!(rng
+            .random_dir2()
+            .abs_diff_eq(Vec2::new(-0.48539588, -0.87429446), 1e-6));
+        assert!((rng.random_rot2().as_radians() - 2.2733574).abs() < 1e-6);
+        assert!(rng
+            .random_in(&Rectangle::new(2.0, 2.0))
+            .abs_diff_eq(Vec2::new(0.65669084, 0.41253936), 1e-6));
+    }
+
+    #[test]
+    fn matches_bevy_math_sampling() {
+        let mut rng1 = WyRand::from_seed([9; 8]);
+        let mut rng2 = WyRand::from_seed([9; 8]);
+
+        let circle = Circle::new(4.0);
+        let sphere = Sphere::new(2.0);
+
+        assert_eq!(rng1.random_in(&circle), circle.sample_interior(&mut rng2));
+        assert_eq!(
+            rng1.random_on_boundary(&sphere),
+            sphere.sample_boundary(&mut rng2)
+        );
+        assert_eq!(rng1.random_dir3(), Dir3::from_rng(&mut rng2));
+        assert_eq!(rng1.random_quat(), Quat::from_rng(&mut rng2));
+        assert_eq!(rng1, rng2);
+    }
+}
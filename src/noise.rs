@@ -0,0 +1,190 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::{OnInsert, Query, ResMut, Resource, Trigger, With};
+use bevy_prng::{EntropySeed, EntropySource};
+
+use crate::{global::Global, seed::RngSeed};
+
+/// Resource of `u64` seeds for external libraries, such as noise generators, by label, derived
+/// from the seed of the [`Global`] `R` source with [`RngSeed::derive_noise_seed_u64`]. Added by
+/// [`NoiseSeedsPlugin`], which keeps the seeds in sync whenever the global source is reseeded.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::noise::NoiseSeeds;
+///
+/// fn generate_terrain(seeds: Res<NoiseSeeds<WyRand>>) {
+///     if seeds.is_changed() {
+///         let seed = seeds.get_u32("terrain").unwrap();
+///
+///         // Regenerate terrain with a noise generator seeded by `seed`
+///     }
+/// }
+/// ```
+#[derive(Debug, Resource)]
+pub struct NoiseSeeds<R: EntropySource> {
+    seeds: BTreeMap<String, u64>,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> NoiseSeeds<R> {
+    /// Returns the `u64` seed for `label`, if it was registered.
+    #[inline]
+    pub fn get(&self, label: &str) -> Option<u64> {
+        self.seeds.get(label).copied()
+    }
+
+    /// Returns the `u32` seed for `label`, if it was registered, as with
+    /// [`RngSeed::derive_noise_seed_u32`].
+    #[inline]
+    pub fn get_u32(&self, label: &str) -> Option<u32> {
+        self.get(label).map(|seed| (seed >> 32) as u32)
+    }
+
+    /// Returns an iterator over all labels and their `u64` seeds.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.seeds
+            .iter()
+            .map(|(label, seed)| (label.as_str(), *seed))
+    }
+
+    fn refresh(&mut self, seed: &RngSeed<R>)
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        for (label, value) in self.seeds.iter_mut() {
+            *value = seed.derive_noise_seed_u64(label);
+        }
+    }
+}
+
+/// Plugin for adding a [`NoiseSeeds`] resource with seeds for the given labels, derived from
+/// the [`Global`] `R` source and refreshed whenever it is reseeded. Must be added after the
+/// [`crate::plugin::EntropyPlugin`] for `R`.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{noise::NoiseSeedsPlugin, prelude::EntropyPlugin};
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     NoiseSeedsPlugin::<WyRand>::new(["terrain", "caves"]),
+/// ));
+/// ```
+pub struct NoiseSeedsPlugin<R: EntropySource> {
+    labels: Vec<String>,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> NoiseSeedsPlugin<R> {
+    /// Creates a new plugin instance deriving seeds for each of `labels`.
+    pub fn new<L: Into<String>>(labels: impl IntoIterator<Item = L>) -> Self {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+            _rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for NoiseSeedsPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        let mut seeds = NoiseSeeds::<R> {
+            seeds: self.labels.iter().map(|label| (label.clone(), 0)).collect(),
+            _rng: PhantomData,
+        };
+
+        let world = app.world_mut();
+
+        if let Some(seed) = world
+            .query_filtered::<&RngSeed<R>, With<Global>>()
+            .iter(world)
+            .next()
+        {
+            seeds.refresh(seed);
+        }
+
+        app.insert_resource(seeds)
+            .add_observer(refresh_noise_seeds::<R>);
+    }
+}
+
+fn refresh_noise_seeds<R: EntropySource + 'static>(
+    trigger: Trigger<OnInsert, RngSeed<R>>,
+    q_global: Query<&RngSeed<R>, With<Global>>,
+    mut seeds: ResMut<NoiseSeeds<R>>,
+) where
+    R::Seed: EntropySeed,
+{
+    if let Ok(seed) = q_global.get(trigger.target()) {
+        seeds.refresh(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+
+    use crate::{plugin::EntropyPlugin, traits::SeedSource};
+
+    use super::*;
+
+    #[test]
+    fn derived_noise_seeds_are_pinned() {
+        let seed = RngSeed::<WyRand>::from_u64(42);
+
+        assert_eq!(seed.derive_noise_seed_u64("terrain"), 7963966868076350101);
+        assert_eq!(seed.derive_noise_seed_u64("caves"), 6461770982348795246);
+        assert_eq!(seed.derive_noise_seed_u32("terrain"), 1854255531);
+    }
+
+    #[test]
+    fn noise_seeds_follow_global_reseeds() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed(42u64.to_le_bytes()),
+            NoiseSeedsPlugin::<WyRand>::new(["terrain", "caves"]),
+        ));
+
+        let initial = RngSeed::<WyRand>::from_u64(42);
+        let seeds = app.world().resource::<NoiseSeeds<WyRand>>();
+
+        assert_eq!(
+            seeds.get("terrain"),
+            Some(initial.derive_noise_seed_u64("terrain"))
+        );
+        assert_eq!(
+            seeds.get_u32("caves"),
+            Some(initial.derive_noise_seed_u32("caves"))
+        );
+        assert_eq!(seeds.get("rivers"), None);
+
+        let world = app.world_mut();
+        let global = world
+            .query_filtered::<bevy_ecs::entity::Entity, With<Global>>()
+            .iter(world)
+            .next()
+            .unwrap();
+
+        let reseeded = RngSeed::<WyRand>::from_seed(7u64.to_le_bytes());
+
+        world.entity_mut(global).insert(reseeded.clone());
+
+        let seeds = app.world().resource::<NoiseSeeds<WyRand>>();
+
+        assert_eq!(
+            seeds.iter().collect::<Vec<_>>(),
+            [
+                ("caves", reseeded.derive_noise_seed_u64("caves")),
+                ("terrain", reseeded.derive_noise_seed_u64("terrain")),
+            ]
+        );
+    }
+}
@@ -1,18 +1,41 @@
-use alloc::vec::Vec;
-use core::marker::PhantomData;
+use alloc::{borrow::Cow, collections::BTreeMap, vec, vec::Vec};
+use core::{
+    any::TypeId,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
 
 use bevy_ecs::{
-    prelude::{Commands, Component, Entity, Event, OnInsert, Trigger, With},
-    query::Without,
+    entity_disabling::Disabled,
+    prelude::{
+        Commands, Component, Entity, Event, OnInsert, OnRemove, Query, ReflectComponent, Res,
+        Resource, Trigger, With, World,
+    },
+    query::{Has, Or, Without},
     system::{Populated, Single},
 };
 
+#[cfg(feature = "cascade_trace")]
+use bevy_ecs::prelude::ResMut;
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+use bevy_prng::ChaChaStream;
 use bevy_prng::EntropySource;
+use bevy_reflect::Reflect;
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
+#[cfg(feature = "cascade_trace")]
+use crate::cascade_trace::CascadeTrace;
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+use crate::seed::RecordedSeed;
 use crate::{
-    prelude::{Entropy, ForkableSeed, GlobalEntropy},
+    global::Global,
+    label::RngLabelIndex,
+    prelude::{Entropy, ForkableAsSeed, ForkableSeed},
     seed::RngSeed,
     traits::SeedSource,
+    util::{hash_label, mix_seed, StableHasher},
 };
 
 /// Component to denote a source has linked children entities
@@ -41,17 +64,298 @@ impl<Source: EntropySource> RngParent<Source> {
     }
 }
 
+/// Component recording the ChaCha stream assigned to a linked target entity, when seeds are
+/// propagated with [`crate::plugin::LinkedEntropySources::with_stream_assignment`]. The target's
+/// [`RngSeed`] holds the key shared with its source, and whenever the target's source is
+/// reinitialised from its seed, the stream is set again, so the target's source can be restored
+/// from the seed and this stream.
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+#[derive(Debug, Component)]
+pub struct RngStream<Rng: EntropySource>(u64, PhantomData<Rng>);
+
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+impl<Rng: EntropySource> RngStream<Rng> {
+    /// Initialises the component with the assigned stream
+    pub fn new(stream: u64) -> Self {
+        Self(stream, PhantomData)
+    }
+
+    /// Get the assigned stream
+    pub fn stream(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Component recording the stable key of a linked target entity, used to derive the target's seed
+/// from its source's seed when propagating with
+/// [`crate::plugin::LinkedEntropySources::with_stable_keys`]. As seeds are derived from keys
+/// rather than drawn in order, adding, removing or reordering targets only affects the seeds of
+/// the targets touched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub struct RngLinkKey(u64);
+
+impl RngLinkKey {
+    /// Creates a link key by hashing `key` with a stable hash, such as a name or an index.
+    pub fn new<K: Hash + ?Sized>(key: &K) -> Self {
+        let mut hasher = StableHasher::default();
+
+        key.hash(&mut hasher);
+
+        Self(hasher.finish())
+    }
+
+    /// Creates a link key from an already hashed key
+    pub fn from_hash(hash: u64) -> Self {
+        Self(hash)
+    }
+
+    /// Get the hashed key
+    pub fn key(&self) -> u64 {
+        self.0
+    }
+
+    /// Derives the seed for this key from a source's seed.
+    pub(crate) fn derive_seed<Rng: EntropySource>(&self, source: &RngSeed<Rng>) -> RngSeed<Rng>
+    where
+        Rng::Seed: Send + Sync + Clone,
+    {
+        RngSeed::from_seed(mix_seed::<Rng>(source.get_seed(), self.0))
+    }
+}
+
+/// Component recording the index of a linked target entity among its source's targets, assigned
+/// densely when targets are linked. When propagating, seeds are forked from the source in index
+/// order, so each target's seed depends only on its source's seed and its index, rather than on
+/// the order the targets happen to be iterated in. As the index is serialized with the entity,
+/// targets restored from a save with new entity ids are reseeded exactly as in the original
+/// session. Targets without an index are seeded in iteration order, after all indexed targets.
+///
+/// As seeds for skipped indices are still forked, indices above [`RngLinkIndex::MAX`] are ignored,
+/// seeding their targets as if they had no index, so that a stray index such as `u32::MAX` can't
+/// stall propagation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Hash, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Hash, Component))]
+pub struct RngLinkIndex(u32);
+
+impl RngLinkIndex {
+    /// The largest index used for seeding, limiting the forks skipped for unused indices.
+    pub const MAX: u32 = u16::MAX as u32;
+
+    /// Creates a link index
+    pub fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    /// Get the index
+    pub fn index(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the index if it is no greater than [`RngLinkIndex::MAX`], as otherwise it is
+    /// ignored for seeding.
+    #[inline]
+    pub(crate) fn seeding_index(index: Option<&Self>) -> Option<u32> {
+        index.map(Self::index).filter(|&index| index <= Self::MAX)
+    }
+}
+
+/// Returns the next free link index after the `indices` of a source's targets.
+pub(crate) fn next_link_index<'a>(indices: impl Iterator<Item = Option<&'a RngLinkIndex>>) -> u32 {
+    indices
+        .filter_map(RngLinkIndex::seeding_index)
+        .map(|index| index + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Forks a seed for each target with `fork`, in link index order, skipping forks for unused
+/// indices so that each indexed target's seed only depends on its index. Targets without an index,
+/// or with one above [`RngLinkIndex::MAX`], are seeded afterwards in iteration order.
+pub(crate) fn fork_by_link_index<'a, Rng: EntropySource>(
+    targets: impl Iterator<Item = (Entity, Option<&'a RngLinkIndex>)>,
+    mut fork: impl FnMut() -> RngSeed<Rng>,
+) -> Vec<(Entity, RngSeed<Rng>)> {
+    let (mut indexed, unindexed): (Vec<_>, Vec<_>) = targets
+        .map(|(target, index)| (target, RngLinkIndex::seeding_index(index)))
+        .partition(|(_, index)| index.is_some());
+
+    indexed.sort_by_key(|(_, index)| *index);
+
+    let mut next = 0;
+    let mut batch = Vec::with_capacity(indexed.len() + unindexed.len());
+
+    for (target, index) in indexed {
+        let index = index.unwrap_or(0);
+
+        for _ in next..index {
+            fork();
+        }
+
+        batch.push((target, fork()));
+        next = index.max(next) + 1;
+    }
+
+    batch.extend(unindexed.into_iter().map(|(target, _)| (target, fork())));
+
+    batch
+}
+
+/// Component requesting extra entropy for a linked target entity, in addition to its seed, such
+/// as bytes for generating a terrain chunk's material. Whenever the target is seeded, an
+/// [`RngExtraEntropy`] with the requested number of bytes is derived from its new seed and the
+/// request's label, so the target's own [`Entropy`] is not drawn from for setup and its sequence
+/// stays the same as without the request. Targets with different labels receive unrelated bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct RngLinkRequest {
+    extra_bytes: usize,
+    label: Cow<'static, str>,
+}
+
+impl RngLinkRequest {
+    /// Creates a request for `extra_bytes` bytes of extra entropy
+    pub fn extra_bytes(extra_bytes: usize) -> Self {
+        Self {
+            extra_bytes,
+            label: Cow::Borrowed("extra"),
+        }
+    }
+
+    /// Sets the label the extra entropy is derived with, such as `"material"`
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Get the number of requested bytes
+    pub fn len(&self) -> usize {
+        self.extra_bytes
+    }
+
+    /// Whether no bytes are requested
+    pub fn is_empty(&self) -> bool {
+        self.extra_bytes == 0
+    }
+
+    /// Get the label the extra entropy is derived with
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Derives the requested bytes from a target's seed.
+    fn derive<Rng: EntropySource>(&self, seed: &RngSeed<Rng>) -> RngExtraEntropy
+    where
+        Rng::Seed: Send + Sync + Clone,
+    {
+        let mut bytes = vec![0; self.extra_bytes];
+
+        Rng::from_seed(mix_seed::<Rng>(seed.get_seed(), hash_label(&self.label)))
+            .fill_bytes(&mut bytes);
+
+        RngExtraEntropy(bytes)
+    }
+}
+
+/// Component holding the extra entropy requested by a target's [`RngLinkRequest`], rederived
+/// every time the target is seeded.
+#[derive(Debug, Clone, PartialEq, Eq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Component))]
+pub struct RngExtraEntropy(Vec<u8>);
+
+impl RngExtraEntropy {
+    /// Get the extra entropy bytes
+    pub fn bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Marker component for entities that should pull a new seed when re-enabled after being
+/// [`Disabled`], such as pooled entities. The seed is forked from the entity's linked parent
+/// source if it has one, otherwise from the [`crate::global::Global`] source. Without this
+/// marker, an entity's [`RngSeed`], [`Entropy`] and links are left untouched by disabling and
+/// re-enabling it.
+#[derive(Debug, Component)]
+pub struct ReseedOnEnable<Rng: EntropySource>(PhantomData<Rng>);
+
+impl<Rng: EntropySource> Default for ReseedOnEnable<Rng> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
 /// Observer event for triggering an entity to pull a new seed value from a
-/// GlobalEntropy source.
+/// GlobalEntropy source, or from an explicitly chosen source entity with
+/// [`SeedFromGlobal::from_entity`], such as when an app has more than one global-like source of
+/// the same algorithm. If the chosen source has no [`Entropy`] of the algorithm, an [`RngError`]
+/// is triggered on the target instead.
 #[derive(Debug, Event)]
-pub struct SeedFromGlobal<Rng: EntropySource>(PhantomData<Rng>);
+pub struct SeedFromGlobal<Rng: EntropySource> {
+    source: Option<Entity>,
+    _rng: PhantomData<Rng>,
+}
+
+impl<Rng: EntropySource> SeedFromGlobal<Rng> {
+    /// Creates an event pulling the new seed from the `source` entity's source rather than from
+    /// the [`Global`] source.
+    #[inline]
+    pub fn from_entity(source: Entity) -> Self {
+        Self {
+            source: Some(source),
+            _rng: PhantomData,
+        }
+    }
+
+    /// Returns the explicitly chosen source entity, if any.
+    #[inline]
+    pub fn source(&self) -> Option<Entity> {
+        self.source
+    }
+}
 
 impl<Rng: EntropySource> Default for SeedFromGlobal<Rng> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            source: None,
+            _rng: PhantomData,
+        }
     }
 }
 
+/// Observer event triggered on an entity when reseeding it by an observer event failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub enum RngError {
+    /// The chosen source entity had no [`Entropy`] source of the event's algorithm.
+    MissingSource(Entity),
+}
+
+impl fmt::Display for RngError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSource(source) => {
+                write!(f, "{source} has no RNG source of the requested algorithm")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RngError {}
+
 /// Observer event for triggering an entity to pull a new seed value from a
 /// linked parent entity.
 #[derive(Debug, Event)]
@@ -63,6 +367,17 @@ impl<Rng: EntropySource> Default for SeedFromParent<Rng> {
     }
 }
 
+/// Observer event triggered on a linked source entity once reseeding it has propagated new
+/// seeds to all of its linked targets.
+#[derive(Debug, Event)]
+pub struct LinkedReseeded<Rng: EntropySource>(PhantomData<Rng>);
+
+impl<Rng: EntropySource> Default for LinkedReseeded<Rng> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
 /// Observer event for triggering an entity to use a new seed value from the
 /// the event.
 #[derive(Debug, Event)]
@@ -102,6 +417,270 @@ where
     }
 }
 
+/// Registry of the linked source observers registered with the world, by the [`Entity`] ids of
+/// the observers, so that each observer is only registered once, whether by
+/// [`crate::plugin::LinkedEntropySources`] or lazily with
+/// [`crate::commands::RngCommandsExt::link_entropy_sources`]. The registered observers can be
+/// removed with [`remove_rng_observers`], such as when swapping linking plugins in an editor.
+///
+/// The observers attached to linked entities by
+/// [`crate::plugin::LinkedEntropySources::scoped`] are not tracked, as they are despawned along
+/// with the entities they observe.
+#[derive(Debug, Default, Resource)]
+pub struct RngObservers(BTreeMap<TypeId, Vec<Entity>>);
+
+impl RngObservers {
+    #[inline]
+    fn key<Source: Component, Target: Component, Rng: EntropySource>() -> TypeId {
+        TypeId::of::<LinkRngSourceToTarget<Source, Target, Rng>>()
+    }
+
+    /// Returns whether the observers for linking `Source` to `Target` entities with `Rng`
+    /// sources have been registered.
+    pub fn is_registered<Source: Component, Target: Component, Rng: EntropySource>(&self) -> bool {
+        self.0.contains_key(&Self::key::<Source, Target, Rng>())
+    }
+
+    /// Returns the observers registered for linking `Source` to `Target` entities with `Rng`
+    /// sources, excluding the observers shared by all links with `Rng` sources.
+    pub fn observers<Source: Component, Target: Component, Rng: EntropySource>(&self) -> &[Entity] {
+        self.0
+            .get(&Self::key::<Source, Target, Rng>())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns an iterator over all registered observers.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.values().flatten().copied()
+    }
+}
+
+/// Returns whether the observers for linking `Source` to `Target` entities with `Rng` sources
+/// are registered with `world`.
+pub fn has_rng_observers<Source: Component, Target: Component, Rng: EntropySource>(
+    world: &World,
+) -> bool {
+    world
+        .get_resource::<RngObservers>()
+        .is_some_and(RngObservers::is_registered::<Source, Target, Rng>)
+}
+
+/// Despawns the observers for linking `Source` to `Target` entities with `Rng` sources, so that
+/// seeds are no longer propagated between them. The observers shared by all links with `Rng`
+/// sources are left in place. The observers can be registered again afterwards, by adding a
+/// [`crate::plugin::LinkedEntropySources`] plugin or with
+/// [`crate::commands::RngCommandsExt::link_entropy_sources`].
+pub fn remove_rng_observers<Source: Component, Target: Component, Rng: EntropySource>(
+    world: &mut World,
+) {
+    let Some(observers) = world
+        .get_resource_mut::<RngObservers>()
+        .and_then(|mut registry| {
+            registry
+                .0
+                .remove(&RngObservers::key::<Source, Target, Rng>())
+        })
+    else {
+        return;
+    };
+
+    for observer in observers {
+        world.despawn(observer);
+    }
+}
+
+/// Registers the observers added by [`crate::plugin::EntropyPlugin`] for `Rng` sources, unless
+/// they have already been registered.
+pub(crate) fn register_global_observers<Rng: EntropySource>(world: &mut World)
+where
+    Rng::Seed: Sync + Send + Clone,
+{
+    let key = TypeId::of::<SeedFromGlobal<Rng>>();
+
+    if world
+        .get_resource::<RngObservers>()
+        .is_some_and(|registry| registry.0.contains_key(&key))
+    {
+        return;
+    }
+
+    let observers = vec![
+        world.add_observer(seed_from_global::<Rng>).id(),
+        world.add_observer(reseed::<Rng>).id(),
+        world.add_observer(reseed_on_enable::<Rng>).id(),
+        world.add_observer(fill_extra_entropy::<Rng>).id(),
+    ];
+
+    world
+        .get_resource_or_init::<RngObservers>()
+        .0
+        .insert(key, observers);
+}
+
+/// Registers the observers added by `add` for linking `Source` to `Target` entities with `Rng`
+/// sources, unless observers have already been registered for them.
+pub(crate) fn register_observers<Source: Component, Target: Component, Rng: EntropySource>(
+    world: &mut World,
+    add: fn(&mut World) -> Vec<Entity>,
+) {
+    if has_rng_observers::<Source, Target, Rng>(world) {
+        return;
+    }
+
+    let observers = add(world);
+
+    world
+        .get_resource_or_init::<RngObservers>()
+        .0
+        .insert(RngObservers::key::<Source, Target, Rng>(), observers);
+}
+
+/// Registers the observers for linking `Source` to `Target` entities with `Rng` sources, unless
+/// they have already been registered. The [`seed_from_parent`] observer is shared by all links
+/// with `Rng` sources, so it is only registered once per `Rng`.
+pub fn register_linked_observers<Source: Component, Target: Component, Rng: EntropySource>(
+    world: &mut World,
+) where
+    Rng::Seed: Sync + Send + Clone,
+{
+    let parent = TypeId::of::<SeedFromParent<Rng>>();
+
+    if !world
+        .get_resource::<RngObservers>()
+        .is_some_and(|registry| registry.0.contains_key(&parent))
+    {
+        let observer = world.add_observer(seed_from_parent::<Rng>).id();
+
+        world
+            .get_resource_or_init::<RngObservers>()
+            .0
+            .insert(parent, vec![observer]);
+    }
+
+    register_observers::<Source, Target, Rng>(world, |world| {
+        vec![
+            world
+                .add_observer(seed_children::<Source, Target, Rng>)
+                .id(),
+            world.add_observer(link_targets::<Source, Target, Rng>).id(),
+        ]
+    });
+}
+
+/// How entities gaining a marker component are given an RNG source by
+/// [`crate::plugin::AutoRngPlugin`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum AutoRngLink {
+    /// Seed the entity from the [`Global`] source and link it as a target of the [`Global`]
+    /// source, so it is reseeded whenever the [`Global`] source is reseeded.
+    #[default]
+    Global,
+    /// Seed the entity from the [`Global`] source without linking it, so it keeps its seed when
+    /// the [`Global`] source is reseeded.
+    Unlinked,
+    /// Seed the entity from the source with the given [`RngLabel`](crate::label::RngLabel), and
+    /// record that source as its [`RngParent`], so [`SeedFromParent`] reseeds it from there.
+    /// Entities are left unseeded if no source has the label.
+    Label(Cow<'static, str>),
+}
+
+/// The [`AutoRngLink`] used for `Marker` entities with `Rng` sources.
+#[derive(Resource)]
+pub(crate) struct AutoRngConfig<Marker, Rng> {
+    pub(crate) link: AutoRngLink,
+    pub(crate) _marker: PhantomData<(Marker, Rng)>,
+}
+
+/// Observer System for giving entities that gain a `Marker` component an RNG source, as configured
+/// by [`crate::plugin::AutoRngPlugin`]. Entities that already have an [`RngSeed`] are left alone,
+/// so explicitly seeded entities keep their seeds.
+pub(crate) fn auto_seed<Marker: Component, Rng: EntropySource>(
+    trigger: Trigger<OnInsert, Marker>,
+    q_unseeded: Query<(), Without<RngSeed<Rng>>>,
+    q_linked: Query<&RngLinkIndex, (With<Marker>, With<RngParent<Rng>>)>,
+    mut q_sources: Query<(
+        Entity,
+        &mut Entropy<Rng>,
+        Has<RngChildren<Rng>>,
+        Has<Global>,
+    )>,
+    labels: Option<Res<RngLabelIndex>>,
+    config: Res<AutoRngConfig<Marker, Rng>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let target = trigger.target();
+
+    if !q_unseeded.contains(target) {
+        return;
+    }
+
+    match &config.link {
+        AutoRngLink::Global => {
+            let Some((global, _, has_children, _)) = q_sources.iter().find(|(.., global)| *global)
+            else {
+                return;
+            };
+
+            let index = next_link_index(q_linked.iter().map(Some));
+
+            commands
+                .entity(target)
+                .insert((RngParent::<Rng>::new(global), RngLinkIndex(index)));
+
+            if !has_children {
+                commands
+                    .entity(global)
+                    .insert(RngChildren::<Rng>::default());
+            }
+
+            commands.trigger_targets(SeedFromGlobal::<Rng>::default(), target);
+        }
+        AutoRngLink::Unlinked => {
+            commands.trigger_targets(SeedFromGlobal::<Rng>::default(), target);
+        }
+        AutoRngLink::Label(label) => {
+            let source = labels.and_then(|labels| labels.get(label));
+
+            let Some((source, mut rng, has_children, _)) =
+                source.and_then(|source| q_sources.get_mut(source).ok())
+            else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("No RNG source is labelled {label}, so {target} was not seeded");
+
+                return;
+            };
+
+            commands
+                .entity(target)
+                .insert((rng.fork_seed(), RngParent::<Rng>::new(source)));
+
+            if !has_children {
+                commands
+                    .entity(source)
+                    .insert(RngChildren::<Rng>::default());
+            }
+        }
+    }
+}
+
+/// Observer System for deriving the [`RngExtraEntropy`] requested by an entity's
+/// [`RngLinkRequest`] whenever it is seeded.
+pub fn fill_extra_entropy<Rng: EntropySource>(
+    trigger: Trigger<OnInsert, RngSeed<Rng>>,
+    q_requests: Populated<(&RngSeed<Rng>, &RngLinkRequest)>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Sync + Send + Clone,
+{
+    let target = trigger.target();
+
+    if let Ok((seed, request)) = q_requests.get(target) {
+        commands.entity(target).insert(request.derive(seed));
+    }
+}
+
 /// Observer system for reseeding a target RNG on an entity with a provided seed value.
 pub fn reseed<Rng: EntropySource>(trigger: Trigger<ReseedRng<Rng>>, mut commands: Commands)
 where
@@ -116,16 +695,76 @@ where
     }
 }
 
-/// Observer System for pulling in a new seed from a GlobalEntropy source
+/// Observer System for pulling in a new seed from a GlobalEntropy source, or from the source
+/// entity chosen by the event. Triggers an [`RngError`] on the target if the chosen entity has no
+/// `Rng` source.
 pub fn seed_from_global<Rng: EntropySource>(
     trigger: Trigger<SeedFromGlobal<Rng>>,
-    mut source: GlobalEntropy<Rng>,
+    q_global: Query<Entity, (With<Global>, With<Entropy<Rng>>)>,
+    mut q_sources: Query<&mut Entropy<Rng>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let target = trigger.target();
+
+    let source = match trigger.source() {
+        Some(source) => source,
+        // As with `GlobalEntropy`, nothing is reseeded unless there is exactly one global source.
+        None => {
+            let mut globals = q_global.iter();
+
+            match (globals.next(), globals.next()) {
+                (Some(global), None) => global,
+                _ => return,
+            }
+        }
+    };
+
+    let Ok(mut rng) = q_sources.get_mut(source) else {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("{source} has no RNG source, so {target} was not reseeded");
+
+        commands.trigger_targets(RngError::MissingSource(source), target);
+
+        return;
+    };
+
+    if let Some(mut entity) = commands.get_entity(target) {
+        entity.insert(rng.fork_seed());
+    }
+}
+
+/// Observer System for reseeding entities marked with [`ReseedOnEnable`] when they are re-enabled,
+/// from their linked parent source if they have one, otherwise from the global source.
+pub fn reseed_on_enable<Rng: EntropySource>(
+    trigger: Trigger<OnRemove, Disabled>,
+    q_enabled: Query<Option<&RngParent<Rng>>, (With<ReseedOnEnable<Rng>>, With<Disabled>)>,
+    mut q_sources: Query<
+        (Entity, &mut Entropy<Rng>, Has<Global>, Has<Disabled>),
+        Or<(With<RngChildren<Rng>>, With<Global>)>,
+    >,
     mut commands: Commands,
 ) where
     Rng::Seed: Send + Sync + Clone,
 {
-    if let Some(mut entity) = commands.get_entity(trigger.target()) {
-        entity.insert(source.fork_seed());
+    let target = trigger.target();
+
+    let Ok(parent) = q_enabled.get(target) else {
+        return;
+    };
+
+    // Linked sources may themselves be disabled, so the source query must not skip them.
+    let seed = q_sources
+        .iter_mut()
+        .find(|(source, _, global, _)| match parent {
+            Some(parent) => *source == parent.entity(),
+            None => *global,
+        })
+        .map(|(_, mut rng, ..)| rng.fork_seed());
+
+    if let Some(seed) = seed {
+        commands.entity(target).insert(seed);
     }
 }
 
@@ -151,13 +790,18 @@ pub fn seed_from_parent<Rng: EntropySource>(
 
 /// Observer System for handling seed propagation from source Rng to all child entities. This observer
 /// will only run if there is a single source entity and also if there are target entities to seed.
+/// Disabled targets are reseeded as well, so they stay in step with their source.
 pub fn seed_children<Source: Component, Target: Component, Rng: EntropySource>(
     trigger: Trigger<OnInsert, Entropy<Rng>>,
     q_source: Single<
         (Entity, &mut Entropy<Rng>),
         (With<Source>, With<RngChildren<Rng>>, Without<Target>),
     >,
-    q_target: Populated<Entity, (With<Target>, With<RngParent<Rng>>, Without<Source>)>,
+    q_target: Populated<
+        (Entity, Option<&RngLinkIndex>, Has<Disabled>),
+        (With<Target>, With<RngParent<Rng>>, Without<Source>),
+    >,
+    #[cfg(feature = "cascade_trace")] trace: Option<ResMut<CascadeTrace>>,
     mut commands: Commands,
 ) where
     Rng::Seed: Send + Sync + Clone,
@@ -166,12 +810,72 @@ pub fn seed_children<Source: Component, Target: Component, Rng: EntropySource>(
     // Check whether the triggered entity is a source entity. If not, do nothing otherwise we
     // will keep triggering and cause a stack overflow.
     if source == trigger.target() {
-        let batch: Vec<(Entity, RngSeed<Rng>)> = q_target
-            .iter()
-            .map(|target| (target, rng.fork_seed()))
-            .collect();
+        let batch = fork_by_link_index(
+            q_target.iter().map(|(target, index, _)| (target, index)),
+            || rng.fork_seed(),
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?source,
+            targets = batch.len(),
+            "propagating seeds to linked targets"
+        );
+
+        #[cfg(feature = "cascade_trace")]
+        crate::cascade_trace::record(trace, source, &batch);
+
+        commands.insert_batch(batch);
+        commands.trigger_targets(LinkedReseeded::<Rng>::default(), source);
+    }
+}
+
+/// Observer System for handling seed propagation from a `SourceRng` source to all child entities
+/// of a different `Rng` algorithm, with each target's seed forked from the source as a
+/// `Rng` seed. As inserting the seeds inserts the targets' [`Entropy`], any targets that are
+/// themselves sources continue the cascade with their own observers. As with [`seed_children`],
+/// this will only run if there is a single source entity and if there are target entities to seed,
+/// and disabled targets are reseeded as well.
+pub fn seed_children_as<
+    Source: Component,
+    Target: Component,
+    SourceRng: EntropySource,
+    Rng: EntropySource,
+>(
+    trigger: Trigger<OnInsert, Entropy<SourceRng>>,
+    q_source: Single<
+        (Entity, &mut Entropy<SourceRng>),
+        (With<Source>, With<RngChildren<Rng>>, Without<Target>),
+    >,
+    q_target: Populated<
+        (Entity, Option<&RngLinkIndex>, Has<Disabled>),
+        (With<Target>, With<RngParent<Rng>>, Without<Source>),
+    >,
+    #[cfg(feature = "cascade_trace")] trace: Option<ResMut<CascadeTrace>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let (source, mut rng) = q_source.into_inner();
+
+    if source == trigger.target() {
+        let batch = fork_by_link_index(
+            q_target.iter().map(|(target, index, _)| (target, index)),
+            || rng.fork_as_seed::<Rng>(),
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?source,
+            targets = batch.len(),
+            "propagating seeds to linked targets of another algorithm"
+        );
+
+        #[cfg(feature = "cascade_trace")]
+        crate::cascade_trace::record(trace, source, &batch);
 
         commands.insert_batch(batch);
+        commands.trigger_targets(LinkedReseeded::<Rng>::default(), source);
     }
 }
 
@@ -181,28 +885,338 @@ pub fn seed_children<Source: Component, Target: Component, Rng: EntropySource>(
 pub fn link_targets<Source: Component, Target: Component, Rng: EntropySource>(
     _trigger: Trigger<LinkRngSourceToTarget<Source, Target, Rng>>,
     q_source: Single<Entity, (With<Source>, Without<Target>)>,
-    q_target: Populated<Entity, (With<Target>, Without<Source>)>,
+    q_target: Populated<(Entity, Option<&RngLinkIndex>), (With<Target>, Without<Source>)>,
     mut commands: Commands,
 ) {
     let parent = q_source.into_inner();
 
-    let mut targets = q_target.iter();
+    let mut next = next_link_index(q_target.iter().map(|(_, index)| index));
+    let mut linked = Vec::new();
+    let mut indexed = Vec::new();
 
-    if targets.size_hint().0 == 1 {
-        let target = targets.next().unwrap();
+    for (target, index) in q_target.iter() {
+        linked.push((target, RngParent::<Rng>::new(parent)));
 
-        commands
-            .entity(target)
-            .insert(RngParent::<Rng>::new(parent));
-    } else {
-        let targets: Vec<_> = targets
-            .map(|target| (target, RngParent::<Rng>::new(parent)))
-            .collect();
-
-        commands.insert_batch(targets);
+        if index.is_none() {
+            indexed.push((target, RngLinkIndex(next)));
+            next += 1;
+        }
     }
 
+    commands.insert_batch(linked);
+    commands.insert_batch(indexed);
+
     commands
         .entity(parent)
         .insert(RngChildren::<Rng>::default());
 }
+
+/// Entity observer system for handling seed propagation from a source Rng to its linked target
+/// entities. Attached to source entities by [`link_targets_scoped`], so that it only runs when a
+/// linked source is reseeded.
+pub fn seed_children_scoped<Source: Component, Target: Component, Rng: EntropySource>(
+    trigger: Trigger<OnInsert, Entropy<Rng>>,
+    mut q_source: Query<&mut Entropy<Rng>, (With<Source>, With<RngChildren<Rng>>, Without<Target>)>,
+    q_target: Populated<
+        (
+            Entity,
+            &RngParent<Rng>,
+            Option<&RngLinkIndex>,
+            Has<Disabled>,
+        ),
+        (With<Target>, Without<Source>),
+    >,
+    #[cfg(feature = "cascade_trace")] trace: Option<ResMut<CascadeTrace>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let source = trigger.target();
+
+    if let Ok(mut rng) = q_source.get_mut(source) {
+        let batch = fork_by_link_index(
+            q_target
+                .iter()
+                .filter(|(_, parent, ..)| parent.entity() == source)
+                .map(|(target, _, index, _)| (target, index)),
+            || rng.fork_seed(),
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?source,
+            targets = batch.len(),
+            "propagating seeds to linked targets"
+        );
+
+        #[cfg(feature = "cascade_trace")]
+        crate::cascade_trace::record(trace, source, &batch);
+
+        commands.insert_batch(batch);
+        commands.trigger_targets(LinkedReseeded::<Rng>::default(), source);
+    }
+}
+
+/// Observer System for handling linking a source Rng with all target entities, attaching entity
+/// observers for seed propagation to the linked entities rather than relying on global observers.
+/// Entities that are already linked do not have their observers attached again. As with
+/// [`link_targets`], this will only run if there is a single source entity and if there are
+/// target entities to link with.
+pub fn link_targets_scoped<Source: Component, Target: Component, Rng: EntropySource>(
+    _trigger: Trigger<LinkRngSourceToTarget<Source, Target, Rng>>,
+    q_source: Single<(Entity, Has<RngChildren<Rng>>), (With<Source>, Without<Target>)>,
+    q_target: Populated<
+        (Entity, Option<&RngLinkIndex>, Has<RngParent<Rng>>),
+        (With<Target>, Without<Source>),
+    >,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let (parent, linked) = q_source.into_inner();
+
+    let mut next = next_link_index(q_target.iter().map(|(_, index, _)| index));
+
+    for (target, index, linked) in q_target.iter() {
+        let mut target = commands.entity(target);
+
+        target.insert(RngParent::<Rng>::new(parent));
+
+        if index.is_none() {
+            target.insert(RngLinkIndex(next));
+            next += 1;
+        }
+
+        if !linked {
+            target.observe(seed_from_parent::<Rng>);
+        }
+    }
+
+    let mut parent = commands.entity(parent);
+
+    parent.insert(RngChildren::<Rng>::default());
+
+    if !linked {
+        parent.observe(seed_children_scoped::<Source, Target, Rng>);
+    }
+}
+
+/// Returns the index each target's stream is assigned by, which is its [`RngLinkIndex`], or for
+/// targets without one, or with one above [`RngLinkIndex::MAX`], the next free indices in entity
+/// order.
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+fn stream_indices<'a>(
+    targets: impl Iterator<Item = (Entity, Option<&'a RngLinkIndex>)>,
+) -> Vec<(Entity, u32)> {
+    let (indexed, mut unindexed): (Vec<_>, Vec<_>) = targets
+        .map(|(target, index)| (target, RngLinkIndex::seeding_index(index)))
+        .partition(|(_, index)| index.is_some());
+
+    let mut next = indexed
+        .iter()
+        .filter_map(|(_, index)| *index)
+        .map(|index| index + 1)
+        .max()
+        .unwrap_or(0);
+
+    unindexed.sort_unstable_by_key(|(target, _)| *target);
+
+    indexed
+        .into_iter()
+        .map(|(target, index)| (target, index.unwrap_or(0)))
+        .chain(unindexed.into_iter().map(|(target, _)| {
+            next += 1;
+            (target, next - 1)
+        }))
+        .collect()
+}
+
+/// Creates the components for a target linked to `source` at `index`, sharing the source's key
+/// but set to its own stream at the start of the stream. The seed is recorded, so that the
+/// assigned stream is not reinitialised from it.
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+fn assign_stream<Rng: ChaChaStream>(
+    source: &Entropy<Rng>,
+    index: u32,
+) -> (
+    RngSeed<Rng>,
+    RecordedSeed<Rng>,
+    Entropy<Rng>,
+    RngStream<Rng>,
+)
+where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let mut rng = source.inner().clone();
+    let stream = rng.get_stream().wrapping_add(u64::from(index) + 1);
+
+    rng.set_stream(stream);
+    rng.set_word_pos(0);
+
+    (
+        RngSeed::<Rng>::from_seed(rng.get_key()),
+        RecordedSeed::<Rng>::default(),
+        Entropy::new(rng),
+        RngStream::<Rng>::new(stream),
+    )
+}
+
+/// Observer System for assigning a target its own stream of its parent's ChaCha source. The
+/// target's stream is given by its [`RngLinkIndex`], so it is the same stream
+/// [`seed_children_streams`] assigns it. The parent source is not advanced.
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+pub fn stream_from_parent<Rng: ChaChaStream>(
+    trigger: Trigger<SeedFromParent<Rng>>,
+    q_linked: Populated<(Entity, &RngParent<Rng>, Option<&RngLinkIndex>)>,
+    q_parents: Populated<&Entropy<Rng>, With<RngChildren<Rng>>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let target = trigger.target();
+
+    let Ok((_, parent, _)) = q_linked.get(target) else {
+        return;
+    };
+
+    let Ok(rng) = q_parents.get(parent.entity()) else {
+        return;
+    };
+
+    let siblings = q_linked
+        .iter()
+        .filter(|(_, sibling, _)| sibling.entity() == parent.entity())
+        .map(|(sibling, _, index)| (sibling, index));
+
+    if let Some((_, index)) = stream_indices(siblings)
+        .into_iter()
+        .find(|(sibling, _)| *sibling == target)
+    {
+        commands.entity(target).insert(assign_stream(rng, index));
+    }
+}
+
+/// Observer System for propagating a ChaCha source to all child entities by assigning each its
+/// own stream of the source's key, instead of drawing seeds from the source. Streams are assigned
+/// by each target's [`RngLinkIndex`], so targets restored from a save with new entity ids are
+/// assigned the same streams. As with [`seed_children`], this will only run if there is a single
+/// source entity and if there are target entities to seed.
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+pub fn seed_children_streams<Source: Component, Target: Component, Rng: ChaChaStream>(
+    trigger: Trigger<OnInsert, Entropy<Rng>>,
+    q_source: Single<
+        (Entity, &Entropy<Rng>),
+        (With<Source>, With<RngChildren<Rng>>, Without<Target>),
+    >,
+    q_target: Populated<
+        (Entity, Option<&RngLinkIndex>, Has<Disabled>),
+        (With<Target>, With<RngParent<Rng>>, Without<Source>),
+    >,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let (source, rng) = q_source.into_inner();
+
+    if source == trigger.target() {
+        let batch: Vec<_> =
+            stream_indices(q_target.iter().map(|(target, index, _)| (target, index)))
+                .into_iter()
+                .map(|(target, index)| (target, assign_stream(rng, index)))
+                .collect();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?source,
+            targets = batch.len(),
+            "propagating seeds to linked targets"
+        );
+
+        commands.insert_batch(batch);
+        commands.trigger_targets(LinkedReseeded::<Rng>::default(), source);
+    }
+}
+
+/// Observer System for setting a target's assigned [`RngStream`] again when its source is
+/// reinitialised from its [`RngSeed`], as the seed alone only holds the key.
+#[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+pub(crate) fn restore_stream<Rng: ChaChaStream>(
+    trigger: Trigger<OnInsert, RngSeed<Rng>>,
+    q_streams: Query<(&RngSeed<Rng>, &RngStream<Rng>), Without<RecordedSeed<Rng>>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let target = trigger.target();
+
+    let Ok((seed, stream)) = q_streams.get(target) else {
+        return;
+    };
+
+    let mut rng = Rng::from_seed(seed.clone_seed());
+
+    rng.set_stream(stream.stream());
+
+    commands.entity(target).insert(Entropy::new(rng));
+}
+
+/// Observer System for deriving a new seed for the current entity from its parent's seed and its
+/// [`RngLinkKey`]. The parent source is not advanced. Entities without a key are not reseeded.
+pub fn keyed_from_parent<Rng: EntropySource>(
+    trigger: Trigger<SeedFromParent<Rng>>,
+    q_linked: Populated<(&RngParent<Rng>, &RngLinkKey)>,
+    q_parents: Populated<&RngSeed<Rng>, With<RngChildren<Rng>>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let target = trigger.target();
+
+    if let Ok((parent, key)) = q_linked.get(target) {
+        if let Ok(seed) = q_parents.get(parent.entity()) {
+            commands.entity(target).insert(key.derive_seed(seed));
+        }
+    }
+}
+
+/// Observer System for propagating a source's seed to all child entities by deriving each
+/// target's seed from the source's seed and the target's [`RngLinkKey`], instead of drawing seeds
+/// in order. Targets without a key are not reseeded. As with [`seed_children`], this will only
+/// run if there is a single source entity and if there are target entities to seed.
+pub fn seed_children_keyed<Source: Component, Target: Component, Rng: EntropySource>(
+    trigger: Trigger<OnInsert, Entropy<Rng>>,
+    q_source: Single<
+        (Entity, &RngSeed<Rng>),
+        (With<Source>, With<RngChildren<Rng>>, Without<Target>),
+    >,
+    q_target: Populated<
+        (Entity, &RngLinkKey, Has<Disabled>),
+        (With<Target>, With<RngParent<Rng>>, Without<Source>),
+    >,
+    #[cfg(feature = "cascade_trace")] trace: Option<ResMut<CascadeTrace>>,
+    mut commands: Commands,
+) where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let (source, seed) = q_source.into_inner();
+
+    if source == trigger.target() {
+        let batch: Vec<(Entity, RngSeed<Rng>)> = q_target
+            .iter()
+            .map(|(target, key, _)| (target, key.derive_seed(seed)))
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?source,
+            targets = batch.len(),
+            "propagating seeds to linked targets"
+        );
+
+        #[cfg(feature = "cascade_trace")]
+        crate::cascade_trace::record(trace, source, &batch);
+
+        commands.insert_batch(batch);
+        commands.trigger_targets(LinkedReseeded::<Rng>::default(), source);
+    }
+}
@@ -0,0 +1,327 @@
+use alloc::vec::Vec;
+
+use bevy_ecs::{
+    entity::Entity,
+    query::{QueryData, QueryFilter, QueryItem},
+    system::Query,
+};
+use bevy_prng::EntropySource;
+use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+use crate::{component::Entropy, traits::ForkableInnerSeed, util::derive_seed};
+
+/// The number of entities processed by each task of [`RngParIterExt`]. Chunks are formed from
+/// the matched entities sorted by their [`Entity`] bits, so this value is part of the determinism
+/// contract of [`RngParIterExt::par_iter_with_rng`]: changing it changes the generated output.
+pub const PAR_RNG_CHUNK_SIZE: usize = 64;
+
+/// Extension trait for iterating queries in parallel while providing each item with a
+/// deterministically derived RNG, without every entity needing its own
+/// [`crate::component::Entropy`] component.
+///
+/// The query must have [`Entity`] as the first element of its data. Items are collected and
+/// sorted by their [`Entity`] bits, then split into chunks of [`PAR_RNG_CHUNK_SIZE`] entities
+/// with each chunk processed as a task on the [`ComputeTaskPool`]. Because RNGs are derived
+/// from the provided base seed and entity bits, never from thread ids or task order, the output
+/// is the same regardless of thread count and scheduling.
+///
+/// Two modes of derivation are available:
+///
+/// - [`RngParIterExt::par_iter_with_rng`] derives one RNG per chunk, keyed by the chunk's first
+///   entity, which is then used for every entity in that chunk in sorted order. This is cheaper,
+///   but an entity's output depends on which other entities share its chunk, so spawning or
+///   despawning entities can change the output for others.
+/// - [`RngParIterExt::par_iter_with_entity_rng`] derives one RNG per entity (the base seed mixed
+///   with the entity's bits). This is the strongest guarantee: an entity's output depends only on
+///   the base seed and the entity itself.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{GlobalSeed, RngParIterExt, SeedSource};
+/// use rand_core::RngCore;
+///
+/// #[derive(Component)]
+/// struct Jitter(u32);
+///
+/// fn jitter_all(mut q_jitter: Query<(Entity, &mut Jitter)>, seed: GlobalSeed<WyRand>) {
+///     let seed = seed.clone_seed();
+///
+///     q_jitter.par_iter_with_entity_rng::<WyRand, _>(&seed, |(_, mut jitter), rng| {
+///         jitter.0 = rng.next_u32() % 8;
+///     });
+/// }
+/// ```
+pub trait RngParIterExt<D: QueryData> {
+    /// Iterate over all items in parallel, with one RNG derived from `seed` per chunk of
+    /// [`PAR_RNG_CHUNK_SIZE`] entities.
+    fn par_iter_with_rng<'a, R, Func>(&'a mut self, seed: &R::Seed, func: Func)
+    where
+        R: EntropySource,
+        R::Seed: Send + Sync + Clone,
+        QueryItem<'a, D>: Send,
+        Func: Fn((Entity, QueryItem<'a, D>), &mut R) + Send + Sync;
+
+    /// Iterate over all items in parallel, with one RNG derived from `seed` per entity.
+    fn par_iter_with_entity_rng<'a, R, Func>(&'a mut self, seed: &R::Seed, func: Func)
+    where
+        R: EntropySource,
+        R::Seed: Send + Sync + Clone,
+        QueryItem<'a, D>: Send,
+        Func: Fn((Entity, QueryItem<'a, D>), &mut R) + Send + Sync;
+}
+
+impl<D: QueryData, F: QueryFilter> RngParIterExt<D> for Query<'_, '_, (Entity, D), F> {
+    fn par_iter_with_rng<'a, R, Func>(&'a mut self, seed: &R::Seed, func: Func)
+    where
+        R: EntropySource,
+        R::Seed: Send + Sync + Clone,
+        QueryItem<'a, D>: Send,
+        Func: Fn((Entity, QueryItem<'a, D>), &mut R) + Send + Sync,
+    {
+        for_each_chunked(
+            self.iter_mut(),
+            ComputeTaskPool::get_or_init(TaskPool::default),
+            seed,
+            Derivation::PerChunk,
+            &func,
+        );
+    }
+
+    fn par_iter_with_entity_rng<'a, R, Func>(&'a mut self, seed: &R::Seed, func: Func)
+    where
+        R: EntropySource,
+        R::Seed: Send + Sync + Clone,
+        QueryItem<'a, D>: Send,
+        Func: Fn((Entity, QueryItem<'a, D>), &mut R) + Send + Sync,
+    {
+        for_each_chunked(
+            self.iter_mut(),
+            ComputeTaskPool::get_or_init(TaskPool::default),
+            seed,
+            Derivation::PerEntity,
+            &func,
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Derivation {
+    PerChunk,
+    PerEntity,
+}
+
+fn for_each_chunked<T, R, Func>(
+    items: impl Iterator<Item = (Entity, T)>,
+    pool: &TaskPool,
+    seed: &R::Seed,
+    derivation: Derivation,
+    func: &Func,
+) where
+    T: Send,
+    R: EntropySource,
+    R::Seed: Send + Sync + Clone,
+    Func: Fn((Entity, T), &mut R) + Sync,
+{
+    let mut items: Vec<_> = items.collect();
+
+    // Query iteration order depends on storage layout, so sort before chunking.
+    items.sort_unstable_by_key(|(entity, _)| *entity);
+
+    let mut items = items.into_iter();
+
+    pool.scope(|scope| loop {
+        let chunk: Vec<_> = items.by_ref().take(PAR_RNG_CHUNK_SIZE).collect();
+
+        let Some(&(first, _)) = chunk.first() else {
+            break;
+        };
+
+        scope.spawn(async move {
+            let mut rng = R::from_seed(derive_seed::<R>(seed, first.to_bits()));
+
+            for item in chunk {
+                if let Derivation::PerEntity = derivation {
+                    rng = R::from_seed(derive_seed::<R>(seed, item.0.to_bits()));
+                }
+
+                func(item, &mut rng);
+            }
+        });
+    });
+}
+
+impl<R: EntropySource + 'static> Entropy<R>
+where
+    R::Seed: Send + Sync + Clone + AsMut<[u8]> + Default,
+{
+    /// Fills `dest` with random bytes in parallel on the [`ComputeTaskPool`], splitting it into
+    /// chunks of `chunk_len` bytes, each filled by its own RNG. A base seed is forked from this
+    /// source, advancing it by one fork, and each chunk's RNG is derived from the base seed and
+    /// the chunk's index, so the output only depends on this source's state and `chunk_len`,
+    /// never on the thread count or scheduling. The output differs from
+    /// [`Entropy::fill_bytes_bulk`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is zero.
+    ///
+    /// ```
+    /// use bevy_prng::ChaCha8Rng;
+    /// use bevy_rand::prelude::Entropy;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut heightmap = vec![0u8; 1 << 20];
+    ///
+    /// Entropy::<ChaCha8Rng>::from_seed([1; 32]).fill_bytes_par(&mut heightmap, 64 * 1024);
+    /// ```
+    pub fn fill_bytes_par(&mut self, dest: &mut [u8], chunk_len: usize) {
+        fill_chunked(
+            self,
+            dest,
+            chunk_len,
+            ComputeTaskPool::get_or_init(TaskPool::default),
+        );
+    }
+}
+
+fn fill_chunked<R>(rng: &mut Entropy<R>, dest: &mut [u8], chunk_len: usize, pool: &TaskPool)
+where
+    R: EntropySource + 'static,
+    R::Seed: Send + Sync + Clone + AsMut<[u8]> + Default,
+{
+    assert!(chunk_len > 0, "chunk_len must be non-zero");
+
+    let base = rng.fork_inner_seed();
+    let base = &base;
+
+    pool.scope(|scope| {
+        for (index, chunk) in dest.chunks_mut(chunk_len).enumerate() {
+            scope.spawn(async move {
+                R::from_seed(derive_seed::<R>(base, index as u64)).fill_bytes(chunk);
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use bevy_ecs::{
+        component::Component,
+        world::{Mut, World},
+    };
+    use bevy_prng::WyRand;
+    use bevy_tasks::TaskPoolBuilder;
+    use rand_core::RngCore;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Output(u64);
+
+    fn run_with_threads(threads: usize, derivation: Derivation) -> Vec<(Entity, u64)> {
+        let pool = TaskPoolBuilder::new().num_threads(threads).build();
+        let mut world = World::new();
+
+        for _ in 0..500 {
+            world.spawn(Output(0));
+        }
+
+        let mut query = world.query::<(Entity, &mut Output)>();
+
+        for_each_chunked(
+            query.iter_mut(&mut world),
+            &pool,
+            &[3; 8],
+            derivation,
+            &|(_, mut output): (Entity, Mut<Output>), rng: &mut WyRand| {
+                output.0 = rng.next_u64();
+            },
+        );
+
+        let mut query = world.query::<(Entity, &Output)>();
+
+        let mut results: Vec<_> = query
+            .iter(&world)
+            .map(|(entity, output)| (entity, output.0))
+            .collect();
+
+        results.sort_unstable();
+
+        results
+    }
+
+    #[test]
+    fn same_output_regardless_of_thread_count() {
+        for derivation in [Derivation::PerChunk, Derivation::PerEntity] {
+            let single = run_with_threads(1, derivation);
+            let multi = run_with_threads(4, derivation);
+
+            assert_eq!(single, multi);
+            assert!(single.iter().all(|(_, output)| *output != 0));
+        }
+    }
+
+    #[test]
+    fn parallel_fill_is_the_same_regardless_of_thread_count() {
+        use bevy_prng::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let fill = |threads: usize| {
+            let pool = TaskPoolBuilder::new().num_threads(threads).build();
+            let mut rng = Entropy::<ChaCha8Rng>::from_seed([5; 32]);
+            let mut dest = vec![0u8; 10_000];
+
+            fill_chunked(&mut rng, &mut dest, 1024, &pool);
+
+            (dest, rng.next_u64())
+        };
+
+        let (single, single_next) = fill(1);
+        let (multi, multi_next) = fill(4);
+
+        assert_eq!(single, multi);
+        assert_eq!(single_next, multi_next);
+
+        // Every chunk, including the shorter last one, is filled from its own stream
+        assert_ne!(single[..1024], single[1024..2048]);
+        assert!(single[9216..].iter().any(|byte| *byte != 0));
+    }
+
+    #[test]
+    fn per_entity_output_is_independent_of_other_entities() {
+        let mut world = World::new();
+        let pool = TaskPoolBuilder::new().num_threads(2).build();
+
+        let others: Vec<Entity> = world
+            .spawn_batch(vec![Output(0), Output(0), Output(0)])
+            .collect();
+        let target = world.spawn(Output(0)).id();
+
+        let run = |world: &mut World| {
+            let mut query = world.query::<(Entity, &mut Output)>();
+
+            for_each_chunked(
+                query.iter_mut(world),
+                &pool,
+                &[9; 8],
+                Derivation::PerEntity,
+                &|(_, mut output): (Entity, Mut<Output>), rng: &mut WyRand| {
+                    output.0 = rng.next_u64();
+                },
+            );
+
+            world.get::<Output>(target).unwrap().0
+        };
+
+        let shared = run(&mut world);
+
+        for entity in others {
+            world.despawn(entity);
+        }
+
+        assert_eq!(shared, run(&mut world));
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(feature = "thread_local_entropy")]
+use bevy_ecs::system::{Local, SystemParam};
+use bevy_ecs::{
+    query::{With, Without},
+    system::Query,
+};
+#[cfg(feature = "thread_local_entropy")]
+use bevy_prng::ThreadLocalEntropy;
+#[cfg(feature = "thread_local_entropy")]
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{component::Entropy, global::Global, seed::RngSeed, ui::UiRng};
+
+/// A query filter for all sources of a given [`bevy_prng::EntropySource`], including the
+/// [`Global`] source.
+pub type AnySeeded<R> = With<RngSeed<R>>;
+
+/// A query filter for all sources of a given [`bevy_prng::EntropySource`], excluding the
+/// [`Global`] source and the [`UiRng`] source. Useful for systems picking from "all the RNG
+/// entities", which would otherwise also pick up the global source entities.
+pub type NonGlobal<R> = (With<RngSeed<R>>, Without<Global>, Without<UiRng>);
+
+/// A helper query to yield the [`Entropy`] of every source for a given
+/// [`bevy_prng::EntropySource`], except the [`Global`] and [`UiRng`] sources.
+pub type NonGlobalEntropy<'w, 's, R> = Query<'w, 's, &'static mut Entropy<R>, NonGlobal<R>>;
+
+/// A [`SystemParam`] for intentionally non-deterministic randomness, such as purely cosmetic
+/// effects. It draws from the [`ThreadLocalEntropy`] source of whichever thread the system runs
+/// on, and never touches any [`Entropy`] component, so using it can't perturb the determinism of
+/// any other system. It also accesses no data, so it never conflicts with other systems.
+///
+/// The thread local source is accessed anew on every call, so the param holds no thread local
+/// handle and is safe to use from any thread.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_rand::params::LocalEntropy;
+/// use rand_core::RngCore;
+///
+/// #[derive(Component)]
+/// struct Sparkle(u32);
+///
+/// fn twinkle(mut rng: LocalEntropy, mut q_sparkles: Query<&mut Sparkle>) {
+///     for mut sparkle in q_sparkles.iter_mut() {
+///         sparkle.0 = rng.next_u32();
+///     }
+/// }
+/// ```
+#[cfg(feature = "thread_local_entropy")]
+#[derive(SystemParam)]
+pub struct LocalEntropy<'s> {
+    // Only used to make this a SystemParam, as it requires no access.
+    _state: Local<'s, ()>,
+}
+
+#[cfg(feature = "thread_local_entropy")]
+impl RngCore for LocalEntropy<'_> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        ThreadLocalEntropy::new().next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        ThreadLocalEntropy::new().next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        ThreadLocalEntropy::new().fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        ThreadLocalEntropy::new().try_fill_bytes(dest)
+    }
+}
+
+#[cfg(feature = "thread_local_entropy")]
+impl CryptoRng for LocalEntropy<'_> {}
+
+#[cfg(all(test, feature = "thread_local_entropy"))]
+mod tests {
+    use bevy_ecs::{
+        schedule::{LogLevel, Schedule, ScheduleBuildSettings},
+        world::World,
+    };
+    use bevy_prng::WyRand;
+
+    use crate::global::GlobalEntropy;
+
+    use super::*;
+
+    #[test]
+    fn local_entropy_never_conflicts() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+
+        world.spawn((Entropy::<WyRand>::default(), Global));
+
+        schedule
+            .set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                ..Default::default()
+            })
+            .add_systems((
+                |mut rng: LocalEntropy| {
+                    rng.next_u32();
+                },
+                |mut rng: LocalEntropy| {
+                    rng.next_u64();
+                },
+                |mut rng: GlobalEntropy<WyRand>| {
+                    rng.next_u32();
+                },
+            ));
+
+        schedule.run(&mut world);
+    }
+}
@@ -0,0 +1,368 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use bevy_ecs::{
+    component::ComponentId,
+    prelude::{Resource, World},
+    world::{EntityRef, EntityWorldMut},
+};
+use bevy_prng::{EntropySeed, EntropySource};
+use bevy_reflect::TypePath;
+use rand_core::SeedableRng;
+
+use crate::{
+    component::Entropy,
+    seed::{RecordedSeed, RngSeed},
+    traits::{ForkableInnerSeed, SeedSource},
+};
+
+/// The state of a single RNG source as plain bytes, for saving and restoring sources without
+/// knowing their algorithm. Extracted and applied with the [`RngPersistenceEntry`] of the
+/// source's algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SerializedRngState {
+    /// Only the source's seed. Applying it reseeds the source as usual, restarting its sequence
+    /// from the seed and propagating new seeds to any linked targets. Useful for saves that only
+    /// keep seeds, such as level seeds.
+    Seed(Vec<u8>),
+    /// The source's seed, if it has one, along with a seed forked from a copy of its state at
+    /// the time it was extracted. Applying it records the seed without reinitialising the source
+    /// or reseeding any linked targets, and reinitialises the source from `resume`.
+    ///
+    /// As the full state of an algorithm can't be captured as bytes in general, a restored source
+    /// continues with a different sequence to the one the saved source itself goes on to draw.
+    /// Every restore of the same state draws the same sequence though, so loading a save always
+    /// plays out the same way.
+    Resume {
+        /// The source's seed, or `None` for a source with its seed detached, which has one
+        /// recorded for it on being restored.
+        seed: Option<Vec<u8>>,
+        /// The seed to reinitialise the source from.
+        resume: Vec<u8>,
+    },
+}
+
+/// The components and operations for persisting the sources of a single RNG algorithm, from
+/// [`RngPersistence`].
+#[derive(Clone, Copy)]
+pub struct RngPersistenceEntry {
+    seed_component: ComponentId,
+    entropy_component: ComponentId,
+    extract: fn(EntityRef) -> Option<SerializedRngState>,
+    apply: fn(EntityWorldMut, SerializedRngState),
+}
+
+impl RngPersistenceEntry {
+    /// Returns the [`ComponentId`] of the algorithm's [`RngSeed`] component.
+    #[inline]
+    pub fn seed_component(&self) -> ComponentId {
+        self.seed_component
+    }
+
+    /// Returns the [`ComponentId`] of the algorithm's [`Entropy`] component.
+    #[inline]
+    pub fn entropy_component(&self) -> ComponentId {
+        self.entropy_component
+    }
+
+    /// Returns the [`SerializedRngState::Resume`] state of the entity's source of this
+    /// algorithm, if it has one. The source itself is left untouched.
+    #[inline]
+    pub fn extract(&self, entity: EntityRef) -> Option<SerializedRngState> {
+        (self.extract)(entity)
+    }
+
+    /// Restores a source of this algorithm on the entity from `state`, applying any commands
+    /// queued by restoring it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any seed in `state` has the wrong length for this algorithm.
+    #[inline]
+    pub fn apply(&self, entity: EntityWorldMut, state: SerializedRngState) {
+        (self.apply)(entity, state);
+    }
+
+    fn new<R: EntropySource + 'static>(world: &mut World) -> Self
+    where
+        R::Seed: EntropySeed,
+    {
+        Self {
+            seed_component: world.register_component::<RngSeed<R>>(),
+            entropy_component: world.register_component::<Entropy<R>>(),
+            extract: |entity| {
+                let rng = entity.get::<Entropy<R>>()?;
+
+                Some(SerializedRngState::Resume {
+                    seed: entity
+                        .get::<RngSeed<R>>()
+                        .map(|seed| seed.as_ref().to_vec()),
+                    resume: rng.clone().fork_inner_seed().as_ref().to_vec(),
+                })
+            },
+            apply: |mut entity, state| match state {
+                SerializedRngState::Seed(seed) => {
+                    entity.insert(RngSeed::<R>::from_seed(to_seed::<R>(&seed)));
+                }
+                SerializedRngState::Resume { seed, resume } => {
+                    let rng = Entropy::<R>::from_seed(to_seed::<R>(&resume));
+
+                    match seed {
+                        Some(seed) => entity.insert((
+                            RngSeed::<R>::from_seed(to_seed::<R>(&seed)),
+                            RecordedSeed::<R>::default(),
+                            rng,
+                        )),
+                        None => entity.insert(rng),
+                    };
+                }
+            },
+        }
+    }
+}
+
+fn to_seed<R: EntropySource>(bytes: &[u8]) -> R::Seed
+where
+    R::Seed: EntropySeed,
+{
+    let mut seed = R::Seed::default();
+
+    seed.as_mut().copy_from_slice(bytes);
+
+    seed
+}
+
+/// Resource of the [`RngPersistenceEntry`] of every RNG algorithm, keyed by the algorithm's full
+/// type path, for third-party save crates to find and persist RNG state without depending on
+/// the algorithms' types. Populated by [`crate::plugin::RngTypesPlugin`].
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use bevy_app::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{
+///     persistence::{RngPersistence, SerializedRngState},
+///     prelude::EntropyPlugin,
+/// };
+///
+/// fn save(world: &World, entity: Entity) -> BTreeMap<String, SerializedRngState> {
+///     let persistence = world.resource::<RngPersistence>();
+///
+///     persistence
+///         .iter()
+///         .filter_map(|(rng, entry)| Some((rng.to_owned(), entry.extract(world.entity(entity))?)))
+///         .collect()
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(EntropyPlugin::<WyRand>::default());
+///
+/// assert!(app.world().resource::<RngPersistence>().get("bevy_prng::WyRand").is_some());
+/// ```
+#[derive(Default, Clone, Resource)]
+pub struct RngPersistence {
+    entries: BTreeMap<&'static str, RngPersistenceEntry>,
+}
+
+impl RngPersistence {
+    /// Returns the entry for the algorithm with the full type path `rng`, such as
+    /// `bevy_prng::WyRand`.
+    #[inline]
+    pub fn get(&self, rng: &str) -> Option<&RngPersistenceEntry> {
+        self.entries.get(rng)
+    }
+
+    /// Returns an iterator over every algorithm's full type path and entry, ordered by type path.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &RngPersistenceEntry)> {
+        self.entries.iter().map(|(rng, entry)| (*rng, entry))
+    }
+}
+
+/// Adds the [`RngPersistenceEntry`] for `R` to the world's [`RngPersistence`], adding the
+/// resource if it is missing.
+pub(crate) fn register_rng_persistence<R: EntropySource + 'static>(world: &mut World)
+where
+    R::Seed: EntropySeed,
+{
+    let entry = RngPersistenceEntry::new::<R>(world);
+
+    world
+        .get_resource_or_init::<RngPersistence>()
+        .entries
+        .insert(<R as TypePath>::type_path(), entry);
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use bevy_app::App;
+    use bevy_ecs::prelude::{Entity, With};
+    use bevy_prng::{ChaCha8Rng, WyRand};
+    use rand_core::RngCore;
+
+    use crate::{global::Global, plugin::EntropyPlugin};
+
+    use super::*;
+
+    type SaveFile = Vec<BTreeMap<String, SerializedRngState>>;
+
+    /// A toy save system, persisting every RNG source by its algorithm's type path.
+    fn save(world: &mut World) -> SaveFile {
+        let persistence = world.resource::<RngPersistence>().clone();
+        let mut entities: Vec<_> = world.query::<Entity>().iter(world).collect();
+
+        entities.sort();
+
+        entities
+            .into_iter()
+            .map(|entity| {
+                persistence
+                    .iter()
+                    .filter_map(|(rng, entry)| {
+                        Some((rng.to_string(), entry.extract(world.entity(entity))?))
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            })
+            .filter(|states| !states.is_empty())
+            .collect()
+    }
+
+    /// Restores the saved sources onto fresh entities, in the order they were saved.
+    fn load(world: &mut World, save: &SaveFile) -> Vec<Entity> {
+        let persistence = world.resource::<RngPersistence>().clone();
+
+        save.iter()
+            .map(|states| {
+                let entity = world.spawn_empty().id();
+
+                for (rng, state) in states {
+                    persistence
+                        .get(rng)
+                        .unwrap()
+                        .apply(world.entity_mut(entity), state.clone());
+                }
+
+                entity
+            })
+            .collect()
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+        ));
+
+        app
+    }
+
+    #[test]
+    fn entries_point_at_the_rng_components() {
+        let mut app = app();
+        let world = app.world_mut();
+        let persistence = world.resource::<RngPersistence>().clone();
+
+        assert_eq!(
+            persistence.iter().map(|(rng, _)| rng).collect::<Vec<_>>(),
+            ["bevy_prng::ChaCha8Rng", "bevy_prng::WyRand"]
+        );
+
+        let entry = persistence.get("bevy_prng::WyRand").unwrap();
+
+        assert_eq!(
+            Some(entry.seed_component()),
+            world.component_id::<RngSeed<WyRand>>()
+        );
+        assert_eq!(
+            Some(entry.entropy_component()),
+            world.component_id::<Entropy<WyRand>>()
+        );
+    }
+
+    #[test]
+    fn worlds_round_trip_through_a_save_system() {
+        let mut app = app();
+        let world = app.world_mut();
+
+        let both = world
+            .spawn((
+                RngSeed::<WyRand>::from_seed([3; 8]),
+                RngSeed::<ChaCha8Rng>::from_seed([4; 32]),
+            ))
+            .id();
+
+        world.get_mut::<Entropy<WyRand>>(both).unwrap().next_u32();
+
+        let saved = save(world);
+
+        // Both global sources and the entity with both algorithms are saved
+        assert_eq!(saved.len(), 3);
+        assert_eq!(saved.iter().map(BTreeMap::len).sum::<usize>(), 4);
+
+        let mut restored_app = App::new();
+
+        restored_app.add_plugins((
+            EntropyPlugin::<WyRand>::default(),
+            EntropyPlugin::<ChaCha8Rng>::default(),
+        ));
+
+        let restored_world = restored_app.world_mut();
+
+        restored_world
+            .query_filtered::<Entity, With<Global>>()
+            .iter(restored_world)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|global| restored_world.entity_mut(global).despawn());
+
+        let restored = load(restored_world, &saved);
+
+        // The seeds are restored as they were, and the sources resume the same way every time
+        let both_restored = restored[saved.len() - 1];
+
+        assert_eq!(
+            restored_world.get::<RngSeed<WyRand>>(both_restored),
+            Some(&RngSeed::from_seed([3; 8]))
+        );
+        assert_eq!(
+            restored_world.get::<RngSeed<ChaCha8Rng>>(both_restored),
+            Some(&RngSeed::from_seed([4; 32]))
+        );
+        let resumed = load(restored_world, &saved);
+
+        for (first, second) in restored.into_iter().zip(resumed) {
+            assert_eq!(
+                restored_world.get::<Entropy<WyRand>>(first),
+                restored_world.get::<Entropy<WyRand>>(second)
+            );
+            assert_eq!(
+                restored_world.get::<Entropy<ChaCha8Rng>>(first),
+                restored_world.get::<Entropy<ChaCha8Rng>>(second)
+            );
+        }
+    }
+
+    #[test]
+    fn seed_states_reseed_as_usual() {
+        let mut app = app();
+        let world = app.world_mut();
+        let entity = world.spawn(RngSeed::<WyRand>::from_seed([3; 8])).id();
+        let persistence = world.resource::<RngPersistence>().clone();
+
+        persistence.get("bevy_prng::WyRand").unwrap().apply(
+            world.entity_mut(entity),
+            SerializedRngState::Seed([5; 8].to_vec()),
+        );
+
+        assert_eq!(
+            world.get::<Entropy<WyRand>>(entity),
+            Some(&Entropy::from_seed([5; 8]))
+        );
+    }
+}
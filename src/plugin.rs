@@ -1,16 +1,49 @@
 #[cfg(feature = "experimental")]
-use std::marker::PhantomData;
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
 
-use crate::{component::Entropy, global::Global, seed::RngSeed, traits::SeedSource};
+use crate::{
+    component::Entropy,
+    global::{
+        fork_resource_mirror, mirror_global_seed, Global, GlobalEntropyResource,
+        ResourceMirrorPolicy,
+    },
+    label::RngLabel,
+    persistence::register_rng_persistence,
+    provider::{RngCapabilities, RngProvider},
+    seed::{
+        ForkCounters, RecordedSeed, ReflectSeedSource, RngSeed, SeedDispenser, SeedFallbackPolicy,
+    },
+    short_seed::ShortSeed,
+    traits::{ForkableInnerSeed, SeedSource},
+    util::mix_seed,
+};
 use bevy_app::{App, Plugin};
 #[cfg(feature = "experimental")]
 use bevy_ecs::prelude::Component;
+#[cfg(feature = "experimental")]
+use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy_ecs::{
+    prelude::{Entity, Resource, With, World},
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
 use bevy_prng::{EntropySeed, EntropySource};
+use rand_core::CryptoRng;
 
 /// Plugin for integrating a PRNG that implements `RngCore` into
 /// the bevy engine, registering types for a global resource and
 /// entropy components.
 ///
+/// This is a bundle of [`RngTypesPlugin`], [`RngObserversPlugin`] and [`GlobalRngPlugin`], for
+/// registering the types, registering the hooks and observers, and spawning the [`Global`]
+/// source respectively. Apps that only need some of these, such as sub-apps or apps spawning
+/// their own global source, can add the individual plugins instead.
+///
+/// Any [`Entropy`] inserted without an [`RngSeed`] will have one recorded for it, forked from
+/// the state of the inserted [`Entropy`]. The [`Entropy`] itself is left untouched, so its
+/// output remains the same, while the recorded seed allows the source to be found by
+/// [`RngSeed`] queries and reseeded like any other.
+///
 /// ```
 /// use bevy_app::prelude::*;
 /// use bevy_ecs::prelude::*;
@@ -33,7 +66,10 @@ use bevy_prng::{EntropySeed, EntropySource};
 /// }
 /// ```
 pub struct EntropyPlugin<R: EntropySource + 'static> {
-    seed: Option<R::Seed>,
+    global: GlobalRngPlugin<R>,
+    reflection: ReflectionScope,
+    #[cfg(feature = "experimental")]
+    requests: Option<InternedScheduleLabel>,
 }
 
 impl<R: EntropySource + 'static> EntropyPlugin<R>
@@ -45,14 +81,110 @@ where
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self { seed: None }
+        Self {
+            global: GlobalRngPlugin::new(),
+            reflection: ReflectionScope::Full,
+            #[cfg(feature = "experimental")]
+            requests: None,
+        }
     }
 
     /// Configures the plugin instance to have a set seed for the
     /// global entropy resource.
     #[inline]
     pub fn with_seed(seed: R::Seed) -> Self {
-        Self { seed: Some(seed) }
+        Self {
+            global: GlobalRngPlugin::with_seed(seed),
+            reflection: ReflectionScope::Full,
+            #[cfg(feature = "experimental")]
+            requests: None,
+        }
+    }
+
+    /// Configures the plugin instance to seed the global entropy resource from a named seed,
+    /// such as `"banana"`, as hashed by [`RngSeed::from_phrase_hash`].
+    #[inline]
+    pub fn with_named_seed(name: &str) -> Self {
+        Self {
+            global: GlobalRngPlugin::with_named_seed(name),
+            reflection: ReflectionScope::Full,
+            #[cfg(feature = "experimental")]
+            requests: None,
+        }
+    }
+
+    /// Configures the plugin instance to seed the global entropy resource from a
+    /// [`ShortSeed`], as expanded by [`ShortSeed::expand`].
+    #[inline]
+    pub fn with_short_seed(code: &ShortSeed) -> Self
+    where
+        R::Seed: EntropySeed,
+    {
+        Self::with_seed(code.expand::<R>())
+    }
+
+    /// Configures how the global source is seeded if no seed was given and entropy can't be
+    /// sourced from the OS. Defaults to [`SeedFallbackPolicy::Panic`].
+    #[inline]
+    #[must_use]
+    pub fn with_seed_fallback(mut self, policy: SeedFallbackPolicy) -> Self {
+        self.global = self.global.with_seed_fallback(policy);
+        self
+    }
+
+    /// Configures the global source of this plugin to back the "fast" slot of the
+    /// [`RngProvider`].
+    #[inline]
+    #[must_use]
+    pub fn provide_fast(mut self) -> Self {
+        self.global = self.global.provide_fast();
+        self
+    }
+
+    /// Configures the global source of this plugin to back the "secure" slot of the
+    /// [`RngProvider`]. Only available for PRNG algorithms implementing [`CryptoRng`].
+    #[inline]
+    #[must_use]
+    pub fn provide_secure(mut self) -> Self
+    where
+        R: CryptoRng,
+    {
+        self.global = self.global.provide_secure();
+        self
+    }
+
+    /// Configures the plugin to also insert a [`GlobalEntropyResource`], forked once from the
+    /// global source at startup and kept in sync with it according to `policy`. See
+    /// [`ResourceMirrorPolicy`] for the determinism implications of each policy.
+    #[inline]
+    #[must_use]
+    pub fn with_resource_mirror(mut self, policy: ResourceMirrorPolicy) -> Self {
+        self.global = self.global.with_resource_mirror(policy);
+        self
+    }
+
+    /// Configures which types the plugin registers for reflection, and so which parts of `R`
+    /// sources can be read through reflection, such as by exporting scenes. Defaults to
+    /// [`ReflectionScope::Full`]. See [`RngTypesPlugin::with_scope`].
+    #[inline]
+    #[must_use]
+    pub fn with_reflection(mut self, scope: ReflectionScope) -> Self {
+        self.reflection = scope;
+        self
+    }
+
+    /// Configures the plugin to reseed sources with [`crate::requests::SeedRequest`] events,
+    /// applied by the [`crate::requests::apply_seed_requests`] system in `schedule`, instead of
+    /// registering the reseeding observers. Seeds propagated this way are the same as with the
+    /// observers, but only change when the system runs. Without the observers,
+    /// [`crate::observers::ReseedOnEnable`] and [`crate::observers::RngLinkRequest`] have no
+    /// effect.
+    #[cfg(feature = "experimental")]
+    #[inline]
+    #[must_use]
+    pub fn with_event_driven_reseeding(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.requests = Some(schedule.intern());
+        self
     }
 }
 
@@ -70,44 +202,643 @@ where
     R::Seed: EntropySeed,
 {
     fn build(&self, app: &mut App) {
-        app.register_type::<Entropy<R>>()
-            .register_type::<RngSeed<R>>()
-            .register_type::<R::Seed>();
+        #[cfg(feature = "experimental")]
+        if let Some(schedule) = self.requests {
+            register_entropy_hooks::<R>(app.world_mut());
 
-        let world = app.world_mut();
+            app.add_plugins((
+                RngTypesPlugin::<R>::with_scope(self.reflection),
+                self.global.clone(),
+            ))
+            .add_event::<crate::requests::SeedRequest<R>>()
+            .add_systems(schedule, crate::requests::apply_seed_requests::<R>);
 
-        world.register_component_hooks::<RngSeed<R>>();
+            return;
+        }
 
-        world.spawn((
-            self.seed
-                .clone()
-                .map_or_else(RngSeed::<R>::from_entropy, RngSeed::<R>::from_seed),
-            Global,
+        app.add_plugins((
+            RngTypesPlugin::<R>::with_scope(self.reflection),
+            RngObserversPlugin::<R>::default(),
+            self.global.clone(),
         ));
+    }
+}
 
-        world.flush();
+/// Which types are registered for reflection for `R` sources, by [`RngTypesPlugin`] and
+/// [`EntropyPlugin::with_reflection`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReflectionScope {
+    /// Register every type, including [`Entropy`] and [`GlobalEntropyResource`], so the full
+    /// internal state of sources can be read and written through reflection, such as in exported
+    /// scenes.
+    #[default]
+    Full,
+    /// Register only the seed types, leaving out [`Entropy`] and [`GlobalEntropyResource`], so the
+    /// internal state of sources, such as the keystream of a ChaCha source, can't be read through
+    /// reflection. [`RngSeed`] is registered as a reflected component instead, so exported scenes
+    /// carry the seeds of sources in place of their state, with the sources being reinitialised
+    /// from their seeds when the scenes are spawned.
+    SeedsOnly,
+    /// Register nothing for reflection. Algorithms registered this way are not found by the
+    /// reflection based tooling, such as [`crate::replay`] and [`crate::console`].
+    None,
+}
 
-        #[cfg(feature = "experimental")]
-        app.add_observer(crate::observers::seed_from_global::<R>)
-            .add_observer(crate::observers::reseed::<R>);
+/// Plugin for registering the reflected types for `R` sources: [`Entropy`], [`RngSeed`],
+/// [`GlobalEntropyResource`], [`SeedDispenser`] and the seed type itself, along with
+/// [`ForkCounters`] and [`RngLabel`], and adding the algorithm to the
+/// [`crate::persistence::RngPersistence`] resource. Can be added any number of times, such as by
+/// several plugins that each need the types.
+///
+/// Which types are registered can be narrowed with [`RngTypesPlugin::with_scope`]. As types can
+/// only ever be added to the registry, the registrations of every instance added for `R` add up,
+/// so all instances should be added with the same scope.
+pub struct RngTypesPlugin<R: EntropySource + 'static> {
+    scope: ReflectionScope,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource + 'static> Default for RngTypesPlugin<R> {
+    fn default() -> Self {
+        Self::with_scope(ReflectionScope::Full)
+    }
+}
+
+impl<R: EntropySource + 'static> RngTypesPlugin<R> {
+    /// Creates a plugin instance registering the types within `scope`.
+    #[inline]
+    #[must_use]
+    pub fn with_scope(scope: ReflectionScope) -> Self {
+        Self {
+            scope,
+            _rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for RngTypesPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        register_rng_types::<R>(app.world_mut(), self.scope);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// Plugin for registering the component hooks and observers for `R` sources, without spawning a
+/// [`Global`] source, such as for apps that spawn their own or load it from a scene. Records an
+/// [`RngSeed`] for any [`Entropy`] inserted without one, and with the `experimental` feature,
+/// registers the observers for reseeding sources from the [`Global`] source. Can be added any
+/// number of times, with the hooks and observers only registered once.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{Global, RngObserversPlugin, RngSeed, RngTypesPlugin, SeedSource};
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins((
+///     RngTypesPlugin::<WyRand>::default(),
+///     RngObserversPlugin::<WyRand>::default(),
+/// ));
+///
+/// app.world_mut()
+///     .spawn((Global, RngSeed::<WyRand>::from_seed(42u64.to_le_bytes())));
+/// ```
+pub struct RngObserversPlugin<R: EntropySource + 'static>(PhantomData<R>);
+
+impl<R: EntropySource + 'static> Default for RngObserversPlugin<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for RngObserversPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        register_rng_hooks::<R>(app.world_mut());
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// Registers the reflected types for `R` sources in the world's [`AppTypeRegistry`], adding the
+/// registry if it is missing, along with their [`crate::persistence::RngPersistence`] entry.
+/// Only the types within `scope` are registered.
+fn register_rng_types<R: EntropySource + 'static>(world: &mut World, scope: ReflectionScope)
+where
+    R::Seed: EntropySeed,
+{
+    register_rng_persistence::<R>(world);
+
+    if scope == ReflectionScope::None {
+        return;
+    }
+
+    let registry = world.get_resource_or_init::<AppTypeRegistry>().clone();
+    let mut registry = registry.write();
+
+    registry.register::<RngSeed<R>>();
+    registry.register::<SeedDispenser<R>>();
+    registry.register::<ForkCounters>();
+    registry.register::<RngLabel>();
+    registry.register::<R::Seed>();
+
+    registry.register_type_data::<RngSeed<R>, ReflectSeedSource>();
+
+    if scope == ReflectionScope::Full {
+        registry.register::<Entropy<R>>();
+        registry.register::<GlobalEntropyResource<R>>();
+
+        #[cfg(feature = "serialize")]
+        crate::legacy::register_legacy_rng_paths::<R>(&mut registry);
+    } else {
+        registry.register_type_data::<RngSeed<R>, ReflectComponent>();
+    }
+
+    #[cfg(feature = "experimental")]
+    {
+        registry.register::<crate::observers::RngLinkIndex>();
+        registry.register::<crate::observers::RngLinkRequest>();
+        registry.register::<crate::observers::RngExtraEntropy>();
+    }
+}
+
+/// Registers the hooks and observers for `R` sources, unless they have already been registered.
+fn register_rng_hooks<R: EntropySource + 'static>(world: &mut World)
+where
+    R::Seed: EntropySeed,
+{
+    register_entropy_hooks::<R>(world);
+
+    #[cfg(feature = "experimental")]
+    crate::observers::register_global_observers::<R>(world);
+}
+
+/// Registers the hooks for `R` sources, without any observers, unless they have already been
+/// registered.
+fn register_entropy_hooks<R: EntropySource + 'static>(world: &mut World)
+where
+    R::Seed: EntropySeed,
+{
+    if !world.contains_resource::<RngHooksRegistered<R>>() {
+        world.insert_resource(RngHooksRegistered::<R>(PhantomData));
+        world.register_component::<RngSeed<R>>();
+
+        // Record an `RngSeed` for any `Entropy` inserted without one, so that all sources
+        // have a seed. The seed is forked from a copy of the source, leaving its state
+        // untouched.
+        world
+            .register_component_hooks::<Entropy<R>>()
+            .try_on_insert(|mut world, entity, _| {
+                if world.get::<RngSeed<R>>(entity).is_some() {
+                    return;
+                }
+
+                let Some(seed) = world
+                    .get::<Entropy<R>>(entity)
+                    .map(|rng| rng.clone().fork_inner_seed())
+                else {
+                    return;
+                };
+
+                world
+                    .commands()
+                    .entity(entity)
+                    .insert((RngSeed::<R>::from_seed(seed), RecordedSeed::<R>::default()));
+            });
+    }
+}
+
+/// Marks that the hooks for `R` sources have been registered, as hooks can't be registered again
+/// once sources have been spawned.
+#[derive(Resource)]
+struct RngHooksRegistered<R>(PhantomData<R>);
+
+/// Plugin for spawning the [`Global`] `R` source, seeded with a set seed or from entropy, and
+/// optionally backing a slot of the [`RngProvider`]. If the world already has a [`Global`] `R`
+/// source, such as one spawned by an earlier instance of this plugin, no other is spawned, so the
+/// plugin can be added any number of times.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{GlobalRngPlugin, RngObserversPlugin, RngTypesPlugin};
+///
+/// App::new().add_plugins((
+///     RngTypesPlugin::<WyRand>::default(),
+///     RngObserversPlugin::<WyRand>::default(),
+///     GlobalRngPlugin::<WyRand>::with_seed([1; 8]).provide_fast(),
+/// ));
+/// ```
+pub struct GlobalRngPlugin<R: EntropySource + 'static> {
+    seed: Option<R::Seed>,
+    fallback: SeedFallbackPolicy,
+    fast: bool,
+    secure: bool,
+    mirror: Option<ResourceMirrorPolicy>,
+}
+
+impl<R: EntropySource + 'static> GlobalRngPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// Creates a new plugin instance configured for randomised,
+    /// non-deterministic seeding of the global source.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            seed: None,
+            fallback: SeedFallbackPolicy::Panic,
+            fast: false,
+            secure: false,
+            mirror: None,
+        }
+    }
+
+    /// Configures the plugin instance to have a set seed for the global source.
+    #[inline]
+    pub fn with_seed(seed: R::Seed) -> Self {
+        Self {
+            seed: Some(seed),
+            ..Self::new()
+        }
+    }
+
+    /// Configures the plugin instance to seed the global source from a named seed, such as
+    /// `"banana"`, as hashed by [`RngSeed::from_phrase_hash`].
+    #[inline]
+    pub fn with_named_seed(name: &str) -> Self {
+        Self::with_seed(RngSeed::<R>::from_phrase_hash(name).clone_seed())
+    }
+
+    /// Configures how the global source is seeded if no seed was given and entropy can't be
+    /// sourced from the OS. Defaults to [`SeedFallbackPolicy::Panic`].
+    #[inline]
+    #[must_use]
+    pub fn with_seed_fallback(mut self, policy: SeedFallbackPolicy) -> Self {
+        self.fallback = policy;
+        self
+    }
+
+    /// Configures the global source of this plugin to back the "fast" slot of the
+    /// [`RngProvider`].
+    #[inline]
+    #[must_use]
+    pub fn provide_fast(mut self) -> Self {
+        self.fast = true;
+        self
+    }
+
+    /// Configures the global source of this plugin to back the "secure" slot of the
+    /// [`RngProvider`]. Only available for PRNG algorithms implementing [`CryptoRng`].
+    #[inline]
+    #[must_use]
+    pub fn provide_secure(mut self) -> Self
+    where
+        R: CryptoRng,
+    {
+        self.secure = true;
+        self
+    }
+
+    /// Configures the plugin to also insert a [`GlobalEntropyResource`], forked once from the
+    /// global source at startup and kept in sync with it according to `policy`. If the resource
+    /// already exists, it is left as is.
+    #[inline]
+    #[must_use]
+    pub fn with_resource_mirror(mut self, policy: ResourceMirrorPolicy) -> Self {
+        self.mirror = Some(policy);
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> Default for GlobalRngPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: EntropySource + 'static> Clone for GlobalRngPlugin<R>
+where
+    R::Seed: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            seed: self.seed.clone(),
+            fallback: self.fallback,
+            fast: self.fast,
+            secure: self.secure,
+            mirror: self.mirror,
+        }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for GlobalRngPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+
+        let has_global = world
+            .query_filtered::<(), (With<RngSeed<R>>, With<Global>)>()
+            .iter(world)
+            .next()
+            .is_some();
+
+        if !has_global {
+            world.spawn((
+                self.seed.clone().map_or_else(
+                    || RngSeed::<R>::from_entropy_with(self.fallback),
+                    RngSeed::<R>::from_seed,
+                ),
+                Global,
+            ));
+
+            world.flush();
+        }
+
+        if let Some(policy) = self.mirror {
+            if !world.contains_resource::<GlobalEntropyResource<R>>() {
+                fork_resource_mirror::<R>(world);
+
+                if policy == ResourceMirrorPolicy::MirrorSeed {
+                    world.add_observer(mirror_global_seed::<R>);
+                }
+            }
+        }
+
+        let mut provider = world.get_resource_or_init::<RngProvider>();
+
+        if self.fast {
+            provider.set_fast::<R>();
+        }
+
+        if self.secure {
+            provider.set_secure::<R>();
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        let capabilities = RngCapabilities::from_world(app.world());
+
+        app.insert_resource(capabilities);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// How [`clone_rng_setup`] seeds the [`Global`] source of the destination world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloneRngPolicy {
+    /// The destination's [`Global`] source has the same seed and current state as the source
+    /// world's, so it draws exactly what the source world would draw next.
+    Mirror,
+    /// The destination's [`Global`] source is seeded with a seed forked from a copy of the source
+    /// world's [`Global`] source and mixed with the given key, so copies made with different keys
+    /// diverge from the source world and from each other, deterministically. The source world's
+    /// [`Global`] source is not advanced.
+    Fork(u64),
+}
+
+/// Sets up `R` sources in `dst` from the setup of `src`, as a programmatic version of
+/// [`EntropyPlugin`] for worlds outside of an [`App`], such as headless simulation copies for AI
+/// lookahead. Registers the reflected types, hooks and observers for `R` sources in `dst`, and
+/// spawns or reseeds its [`Global`] source from the [`Global`] source of `src` according to
+/// `policy`. If `src` has no [`Global`] `R` source, only the types, hooks and observers are set
+/// up. `src` is left untouched.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{clone_rng_setup, CloneRngPolicy, EntropyPlugin};
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]));
+///
+/// let lookaheads: Vec<World> = (0..4)
+///     .map(|key| {
+///         let mut world = World::new();
+///
+///         clone_rng_setup::<WyRand>(app.world(), &mut world, CloneRngPolicy::Fork(key));
+///
+///         world
+///     })
+///     .collect();
+/// ```
+pub fn clone_rng_setup<R: EntropySource + 'static>(
+    src: &World,
+    dst: &mut World,
+    policy: CloneRngPolicy,
+) where
+    R::Seed: EntropySeed,
+{
+    register_rng_types::<R>(dst, ReflectionScope::Full);
+    register_rng_hooks::<R>(dst);
+
+    let Some((seed, rng)) = src
+        .try_query_filtered::<(&RngSeed<R>, &Entropy<R>), With<Global>>()
+        .and_then(|mut query| {
+            query
+                .iter(src)
+                .next()
+                .map(|(seed, rng)| (seed.clone(), rng.clone()))
+        })
+    else {
+        return;
+    };
+
+    let (seed, rng) = match policy {
+        CloneRngPolicy::Mirror => (seed, Some(rng)),
+        CloneRngPolicy::Fork(key) => (
+            RngSeed::from_seed(mix_seed::<R>(&rng.clone().fork_inner_seed(), key)),
+            None,
+        ),
+    };
+
+    let global = dst
+        .query_filtered::<Entity, (With<RngSeed<R>>, With<Global>)>()
+        .iter(dst)
+        .next();
+
+    let global = match global {
+        Some(global) => {
+            dst.entity_mut(global).insert(seed);
+            global
+        }
+        None => dst.spawn((seed, Global)).id(),
+    };
+
+    dst.flush();
+
+    if let Some(rng) = rng {
+        dst.entity_mut(global).insert(rng);
     }
 }
 
 /// Plugin for setting up linked RNG sources
+///
+/// By default, the observers for propagating seeds are registered globally, so they run for every
+/// insert of an [`Entropy`] component. With [`LinkedEntropySources::scoped`], the observers are
+/// instead attached to the source and target entities when they are linked, so only linked
+/// entities pay the cost. Both modes propagate seeds in exactly the same way.
+///
+/// For ChaCha based sources, [`LinkedEntropySources::with_stream_assignment`] propagates by giving
+/// each target its own stream of the source's key instead of drawing seeds from the source. With
+/// [`LinkedEntropySources::with_stable_keys`], each target's seed is derived from the source's
+/// seed and the target's own key, so adding or removing targets does not affect the others.
+///
+/// With [`LinkedEntropySources::from_source_rng`], the source is a different algorithm to its
+/// targets, such as a `ChaCha8Rng` global seeding `WyRand` squad leaders, which can in turn be
+/// linked to their own `WyRand` targets, so a single reseed cascades through the whole chain.
+///
+/// Instead of adding this plugin, the global observers can also be registered lazily, the first
+/// time sources are linked with [`crate::commands::RngCommandsExt::link_entropy_sources`].
+/// Observers for the same `Source`, `Target` and `Rng` are only registered once, by whichever
+/// plugin or command registers them first, and are tracked in [`crate::observers::RngObservers`].
 #[cfg(feature = "experimental")]
 pub struct LinkedEntropySources<Source: Component, Target: Component, Rng: EntropySource + 'static>
 {
+    scoped: bool,
+    mode: Option<fn(&mut World) -> Vec<Entity>>,
     rng: PhantomData<Rng>,
     source: PhantomData<Source>,
     target: PhantomData<Target>,
 }
 
+#[cfg(feature = "experimental")]
+impl<Source: Component, Target: Component, Rng: EntropySource + 'static>
+    LinkedEntropySources<Source, Target, Rng>
+{
+    /// Creates a new plugin instance that attaches its observers to linked entities, instead of
+    /// registering them globally.
+    #[inline]
+    #[must_use]
+    pub fn scoped() -> Self {
+        Self {
+            scoped: true,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new plugin instance that derives each linked target's seed from the source's
+    /// seed and the target's [`crate::observers::RngLinkKey`], instead of drawing seeds from the
+    /// source in order. Targets without a key are not reseeded. The observers are registered
+    /// globally.
+    #[inline]
+    #[must_use]
+    pub fn with_stable_keys() -> Self
+    where
+        Rng::Seed: Send + Sync + Clone,
+    {
+        Self {
+            mode: Some(|world| {
+                vec![
+                    world
+                        .add_observer(crate::observers::keyed_from_parent::<Rng>)
+                        .id(),
+                    world
+                        .add_observer(crate::observers::seed_children_keyed::<Source, Target, Rng>)
+                        .id(),
+                    world
+                        .add_observer(crate::observers::link_targets::<Source, Target, Rng>)
+                        .id(),
+                ]
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new plugin instance where the `Source` entity propagates from its `SourceRng`
+    /// source instead, forking a `Rng` seed for each linked target, so that links can cross
+    /// algorithms. Only seeds propagated from the source cross algorithms, as
+    /// [`crate::observers::SeedFromParent`] can only be used between sources of the same
+    /// algorithm. The observers are registered globally.
+    #[inline]
+    #[must_use]
+    pub fn from_source_rng<SourceRng: EntropySource + 'static>() -> Self
+    where
+        Rng::Seed: Send + Sync + Clone,
+    {
+        Self {
+            mode: Some(|world| {
+                vec![
+                    world
+                        .add_observer(
+                            crate::observers::seed_children_as::<Source, Target, SourceRng, Rng>,
+                        )
+                        .id(),
+                    world
+                        .add_observer(crate::observers::link_targets::<Source, Target, Rng>)
+                        .id(),
+                ]
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new plugin instance that gives each linked target an independent stream of the
+    /// source's key, with [`crate::observers::RngStream`] recording the assigned stream. Targets
+    /// are assigned streams by their [`crate::observers::RngLinkIndex`], and the source is never
+    /// advanced by propagation. The observers are registered globally.
+    #[cfg(any(feature = "chacha8", feature = "chacha12", feature = "chacha20"))]
+    #[inline]
+    #[must_use]
+    pub fn with_stream_assignment() -> Self
+    where
+        Rng: bevy_prng::ChaChaStream,
+        Rng::Seed: Send + Sync + Clone,
+    {
+        Self {
+            mode: Some(|world| {
+                vec![
+                    world
+                        .add_observer(crate::observers::stream_from_parent::<Rng>)
+                        .id(),
+                    world
+                        .add_observer(
+                            crate::observers::seed_children_streams::<Source, Target, Rng>,
+                        )
+                        .id(),
+                    world
+                        .add_observer(crate::observers::link_targets::<Source, Target, Rng>)
+                        .id(),
+                    world
+                        .add_observer(crate::observers::restore_stream::<Rng>)
+                        .id(),
+                ]
+            }),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(feature = "experimental")]
 impl<Source: Component, Target: Component, Rng: EntropySource + 'static> Default
     for LinkedEntropySources<Source, Target, Rng>
 {
     fn default() -> Self {
         Self {
+            scoped: false,
+            mode: None,
             rng: PhantomData,
             source: PhantomData,
             target: PhantomData,
@@ -122,8 +853,100 @@ where
     Rng::Seed: Send + Sync + Clone,
 {
     fn build(&self, app: &mut App) {
-        app.add_observer(crate::observers::seed_from_parent::<Rng>)
-            .add_observer(crate::observers::seed_children::<Source, Target, Rng>)
-            .add_observer(crate::observers::link_targets::<Source, Target, Rng>);
+        let world = app.world_mut();
+
+        if let Some(add_mode_observers) = self.mode {
+            crate::observers::register_observers::<Source, Target, Rng>(world, add_mode_observers);
+        } else if self.scoped {
+            crate::observers::register_observers::<Source, Target, Rng>(world, |world| {
+                vec![world
+                    .add_observer(crate::observers::link_targets_scoped::<Source, Target, Rng>)
+                    .id()]
+            });
+        } else {
+            crate::observers::register_linked_observers::<Source, Target, Rng>(world);
+        }
+    }
+}
+
+/// Plugin for automatically giving every entity that gains a `Marker` component, such as
+/// `Monster`, an `Rng` source, without writing the observer by hand. By default, entities are
+/// seeded from the [`Global`] source and linked to it, so reseeding the [`Global`] source reseeds
+/// them as well. With [`AutoRngPlugin::unlinked`], entities are only seeded, and with
+/// [`AutoRngPlugin::linked_to_label`], they are seeded from and linked to a labelled source
+/// instead. Entities that already have an [`RngSeed`] when gaining the marker are left alone.
+///
+/// As seeds are forked from the source in the order entities gain the marker, spawning the same
+/// entities in the same order yields the same seeds.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{plugin::AutoRngPlugin, prelude::EntropyPlugin};
+///
+/// #[derive(Component)]
+/// struct Monster;
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::with_seed([1; 8]),
+///     AutoRngPlugin::<Monster, WyRand>::default(),
+/// ));
+/// ```
+#[cfg(feature = "experimental")]
+pub struct AutoRngPlugin<Marker: Component, Rng: EntropySource + 'static> {
+    link: crate::observers::AutoRngLink,
+    marker: PhantomData<Marker>,
+    rng: PhantomData<Rng>,
+}
+
+#[cfg(feature = "experimental")]
+impl<Marker: Component, Rng: EntropySource + 'static> AutoRngPlugin<Marker, Rng> {
+    /// Configures the plugin to seed entities from the [`Global`] source without linking them to
+    /// it, so they keep their seeds when the [`Global`] source is reseeded.
+    #[inline]
+    #[must_use]
+    pub fn unlinked(mut self) -> Self {
+        self.link = crate::observers::AutoRngLink::Unlinked;
+        self
+    }
+
+    /// Configures the plugin to seed entities from the source labelled `label` with an
+    /// [`RngLabel`], linking them to it as their [`crate::observers::RngParent`]. Entities gaining
+    /// the marker while no source has the label are left unseeded.
+    #[inline]
+    #[must_use]
+    pub fn linked_to_label(mut self, label: impl Into<alloc::borrow::Cow<'static, str>>) -> Self {
+        self.link = crate::observers::AutoRngLink::Label(label.into());
+        self
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl<Marker: Component, Rng: EntropySource + 'static> Default for AutoRngPlugin<Marker, Rng> {
+    fn default() -> Self {
+        Self {
+            link: crate::observers::AutoRngLink::default(),
+            marker: PhantomData,
+            rng: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl<Marker: Component, Rng: EntropySource + 'static> Plugin for AutoRngPlugin<Marker, Rng>
+where
+    Rng::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        if self.link == crate::observers::AutoRngLink::Global {
+            crate::observers::register_linked_observers::<Global, Marker, Rng>(app.world_mut());
+        }
+
+        app.insert_resource(crate::observers::AutoRngConfig::<Marker, Rng> {
+            link: self.link.clone(),
+            _marker: PhantomData,
+        })
+        .add_observer(crate::observers::auto_seed::<Marker, Rng>);
     }
 }
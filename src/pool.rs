@@ -0,0 +1,231 @@
+use alloc::collections::VecDeque;
+use core::marker::PhantomData;
+
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::prelude::{Query, ResMut, Resource, With};
+use bevy_prng::EntropySource;
+use rand_core::SeedableRng;
+
+use crate::{
+    component::Entropy,
+    global::Global,
+    seed::RngSeed,
+    traits::{ForkableInnerSeed, SeedSource},
+};
+
+/// A pool of pre-generated seeds for the `R` PRNG algorithm, for spawning many RNG sources at once
+/// without needing access to the [`Global`] source. Added and refilled by [`EntropyPoolPlugin`].
+///
+/// Seeds are taken in the order they were generated, so the seed a given entity gets depends on
+/// the order seeds are taken from the pool. To remain deterministic, the systems taking from the
+/// pool must run in a consistent order. When the pool is exhausted, taking yields `None` rather
+/// than falling back to the [`Global`] source.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyPool, RngCommandsExt};
+///
+/// #[derive(Component)]
+/// struct Particle;
+///
+/// fn explode(mut commands: Commands, mut pool: ResMut<EntropyPool<WyRand>>) {
+///     for _ in 0..2000 {
+///         if commands.spawn_from_pool(&mut pool, Particle).is_none() {
+///             break;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Resource)]
+pub struct EntropyPool<R: EntropySource> {
+    seeds: VecDeque<R::Seed>,
+    capacity: usize,
+    watermark: usize,
+}
+
+impl<R: EntropySource> EntropyPool<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// Creates a new, empty pool holding up to `capacity` seeds, to be refilled once fewer than
+    /// `watermark` seeds remain.
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: usize, watermark: usize) -> Self {
+        Self {
+            seeds: VecDeque::with_capacity(capacity),
+            capacity,
+            watermark: watermark.min(capacity),
+        }
+    }
+
+    /// Takes the next seed from the pool, or `None` if the pool is exhausted.
+    #[inline]
+    pub fn take_seed(&mut self) -> Option<RngSeed<R>> {
+        self.seeds.pop_front().map(RngSeed::from_seed)
+    }
+
+    /// Takes the next seed from the pool as an [`Entropy`], or `None` if the pool is exhausted.
+    #[inline]
+    pub fn take_rng(&mut self) -> Option<Entropy<R>> {
+        self.seeds.pop_front().map(Entropy::from_seed)
+    }
+
+    /// Returns the number of seeds remaining in the pool.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.seeds.len()
+    }
+
+    /// Returns whether the pool is exhausted.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.seeds.is_empty()
+    }
+
+    /// Returns the maximum number of seeds the pool holds.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of remaining seeds below which the pool is refilled.
+    #[inline]
+    pub fn watermark(&self) -> usize {
+        self.watermark
+    }
+
+    /// Returns whether the pool has fallen below its watermark and needs refilling.
+    #[inline]
+    fn needs_refill(&self) -> bool {
+        self.seeds.len() < self.watermark
+    }
+
+    /// Refills the pool to capacity with seeds forked sequentially from `source`.
+    fn refill(&mut self, source: &mut Entropy<R>) {
+        let needed = self.capacity - self.seeds.len();
+
+        self.seeds
+            .extend((0..needed).map(|_| source.fork_inner_seed()));
+    }
+}
+
+/// Plugin for adding an [`EntropyPool`] for `R`, refilled from the [`Global`] `R` source in the
+/// [`First`] schedule whenever it is below its watermark. As refills always happen at the same
+/// point of the frame, the pooled seeds are as deterministic as the global source.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyPlugin, EntropyPoolPlugin};
+///
+/// App::new()
+///     .add_plugins((
+///         EntropyPlugin::<WyRand>::default(),
+///         EntropyPoolPlugin::<WyRand>::new(2048, 512),
+///     ));
+/// ```
+pub struct EntropyPoolPlugin<R: EntropySource> {
+    capacity: usize,
+    watermark: usize,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> EntropyPoolPlugin<R> {
+    /// Creates a new plugin instance for a pool holding up to `capacity` seeds, refilled once
+    /// fewer than `watermark` seeds remain.
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: usize, watermark: usize) -> Self {
+        Self {
+            capacity,
+            watermark,
+            _rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for EntropyPoolPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EntropyPool::<R>::new(self.capacity, self.watermark))
+            .add_systems(First, refill_pool::<R>);
+    }
+}
+
+fn refill_pool<R: EntropySource + 'static>(
+    mut pool: ResMut<EntropyPool<R>>,
+    mut q_global: Query<&mut Entropy<R>, With<Global>>,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    if !pool.needs_refill() {
+        return;
+    }
+
+    if let Some(mut source) = q_global.iter_mut().next() {
+        pool.refill(&mut source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_prng::WyRand;
+
+    use crate::plugin::EntropyPlugin;
+
+    use super::*;
+
+    fn take_all(app: &mut App) -> Vec<[u8; 8]> {
+        let mut pool = app.world_mut().resource_mut::<EntropyPool<WyRand>>();
+
+        core::iter::from_fn(|| pool.take_seed().map(|seed| seed.clone_seed())).collect()
+    }
+
+    #[test]
+    fn pool_refills_from_global() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            EntropyPoolPlugin::<WyRand>::new(4, 2),
+        ));
+
+        let mut global = Entropy::<WyRand>::from_seed([1; 8]);
+
+        app.update();
+
+        let first: Vec<_> = (0..4).map(|_| global.fork_inner_seed()).collect();
+
+        // Exhausting the pool yields `None` without touching the global source
+        assert_eq!(take_all(&mut app), first);
+        assert!(app
+            .world_mut()
+            .resource_mut::<EntropyPool<WyRand>>()
+            .take_rng()
+            .is_none());
+
+        app.update();
+
+        let mut pool = app.world_mut().resource_mut::<EntropyPool<WyRand>>();
+
+        assert_eq!(pool.len(), 4);
+        assert_eq!(
+            pool.take_rng(),
+            Some(Entropy::from_seed(global.fork_inner_seed()))
+        );
+
+        let second: Vec<_> = (0..3).map(|_| global.fork_inner_seed()).collect();
+
+        // Above the watermark, the pool is not refilled
+        app.update();
+
+        assert_eq!(app.world().resource::<EntropyPool<WyRand>>().len(), 3);
+        assert_eq!(take_all(&mut app), second);
+    }
+}
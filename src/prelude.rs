@@ -1,18 +1,110 @@
-pub use crate::component::Entropy;
-pub use crate::plugin::EntropyPlugin;
+pub use crate::app::AppRngExt;
+pub use crate::burst::BurstRng;
+pub use crate::commands::{
+    ReseedWorldPolicy, Reseeded, RngCommandsExt, RngEntityCommands, RngEntityCommandsAnyExt,
+    WithRng,
+};
+pub use crate::component::{AsEntropyMut, BoxedEntropy, Entropy, EntropyChangeExt, SimulationRng};
+pub use crate::derived::{DerivedFromRng, DerivedRngPlugin, RngDerivedInvalidated};
 pub use crate::global::*;
-pub use crate::seed::RngSeed;
+pub use crate::label::{RngLabel, RngLabelIndex};
+pub use crate::noise::{NoiseSeeds, NoiseSeedsPlugin};
+pub use crate::parallel::RngParIterExt;
+pub use crate::params::{AnySeeded, NonGlobal, NonGlobalEntropy};
+pub use crate::plugin::{
+    clone_rng_setup, CloneRngPolicy, EntropyPlugin, GlobalRngPlugin, ReflectionScope,
+    RngObserversPlugin, RngTypesPlugin,
+};
+pub use crate::pool::{EntropyPool, EntropyPoolPlugin};
+pub use crate::provider::{RequireRng, RngCapabilities, RngProvider, RngQuality};
+pub use crate::seed::{
+    ForkCounters, ReservedSeeds, RngSeed, SeedDispenser, SeedFallbackPolicy, SeedToken,
+};
+pub use crate::select::RngSelectExt;
+pub use crate::short_seed::ShortSeed;
+pub use crate::startup::{StartupForks, StartupSeedBudget, StartupSeedBudgetPlugin};
+pub use crate::sync::{ApplyGlobalSeed, GlobalSeedChanged, SeedSyncPlugin};
+pub use crate::temporary::TemporarySeedExt;
 pub use crate::traits::{
     ForkableAsRng, ForkableAsSeed, ForkableInnerRng, ForkableInnerSeed, ForkableRng, ForkableSeed,
     SeedSource,
 };
+pub use crate::ui::{UiEntropy, UiEntropyPlugin, UiRng, UiRngEntity};
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub use crate::{shuffle::ShuffleBag, weighted::WeightedIndexTable};
+pub use bevy_prng::Seed512;
+
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub use crate::legacy::LegacyGlobalMigrationPlugin;
+
+#[cfg(feature = "rand_distr")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_distr")))]
+pub use crate::distributions::{ExpParams, NormalParams, PoissonParams};
+
+#[cfg(feature = "bevy_math")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+pub use crate::{
+    jitter::{FrameJitter, FrameJitterPlugin},
+    math::RngMathExt,
+    scatter::{ScatterConfig, ScatterPlugin, ScatterSpawner, Scattered},
+};
+
+#[cfg(feature = "bevy_color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_color")))]
+pub use crate::color::{OklchRanges, RngColorExt};
+
+#[cfg(feature = "bevy_state")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_state")))]
+pub use crate::state::{SeedPolicy, StateSeedPlugin};
+
+#[cfg(feature = "bevy_asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_asset")))]
+pub use crate::asset::{SeedConfig, SeedConfigPlugin};
+
+#[cfg(feature = "bevy_time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_time")))]
+pub use crate::auto_reseed::{AutoReseedPlugin, NextReseed};
+
+#[cfg(all(feature = "bevy_time", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "bevy_time", feature = "std"))))]
+pub use crate::chance::{ChancePerSecond, RandomTimer, RandomTimerPlugin, TimerDistribution};
+
+#[cfg(feature = "bevy_diagnostic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_diagnostic")))]
+pub use crate::frame::FrameRng;
+
+#[cfg(feature = "bevy_scene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_scene")))]
+pub use crate::scene::{SceneRngPlugin, SceneRngPolicy};
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+pub use crate::token::EntropyUuidExt;
+
+#[cfg(feature = "thread_local_entropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "thread_local_entropy")))]
+pub use crate::params::LocalEntropy;
+#[cfg(feature = "thread_local_entropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "thread_local_entropy")))]
+pub use bevy_prng::ThreadLocalEntropy;
+
 #[cfg(feature = "wyrand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
 pub use bevy_prng::WyRand;
 
-#[cfg(feature = "rand_chacha")]
-#[cfg_attr(docsrs, doc(cfg(feature = "rand_chacha")))]
-pub use bevy_prng::{ChaCha12Rng, ChaCha20Rng, ChaCha8Rng};
+#[cfg(feature = "chacha8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha8")))]
+pub use bevy_prng::ChaCha8Rng;
+
+#[cfg(feature = "chacha12")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha12")))]
+pub use bevy_prng::ChaCha12Rng;
+
+#[cfg(feature = "chacha20")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha20")))]
+pub use bevy_prng::ChaCha20Rng;
 
 #[cfg(feature = "rand_pcg")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand_pcg")))]
@@ -21,7 +113,7 @@ pub use bevy_prng::{Pcg32, Pcg64, Pcg64Mcg};
 #[cfg(feature = "rand_xoshiro")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand_xoshiro")))]
 pub use bevy_prng::{
-    Seed512, Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar, Xoroshiro64Star,
+    Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar, Xoroshiro64Star,
     Xoroshiro64StarStar, Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar, Xoshiro256Plus,
     Xoshiro256PlusPlus, Xoshiro256StarStar, Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar,
 };
@@ -0,0 +1,449 @@
+use alloc::{format, string::String, vec::Vec};
+use core::panic::Location;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::{Commands, Entity, Resource, With, World};
+use bevy_prng::EntropySource;
+
+use crate::{
+    component::Entropy,
+    global::Global,
+    traits::{ForkableInnerSeed, ForkableSeed},
+};
+
+/// The functions backing a slot of the [`RngProvider`], captured from a concrete PRNG algorithm
+/// at registration.
+#[derive(Debug, Clone, Copy)]
+struct ProviderEntry {
+    algorithm: &'static str,
+    fork_seed: fn(&mut World) -> Option<Vec<u8>>,
+    insert_rng: fn(&mut World, Entity),
+}
+
+impl ProviderEntry {
+    fn new<R: EntropySource + 'static>() -> Self
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        Self {
+            algorithm: R::type_path(),
+            fork_seed: fork_global_seed::<R>,
+            insert_rng: insert_forked_rng::<R>,
+        }
+    }
+}
+
+/// Resource allowing plugins to create RNG sources matching the app's policy, without needing to
+/// know which PRNG algorithms the app uses.
+///
+/// The app chooses which [`Global`] sources back the "fast" and "secure" slots with
+/// [`crate::plugin::EntropyPlugin::provide_fast`] and
+/// [`crate::plugin::EntropyPlugin::provide_secure`]. Plugins then only need to depend on
+/// `bevy_rand` and request sources from the provider. All sources are forked from the backing
+/// global source, so they are deterministic if the global source is. If a slot has not been
+/// configured, requests for it yield nothing.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_rand::prelude::RngProvider;
+///
+/// #[derive(Component)]
+/// struct Particle;
+///
+/// fn spawn_particle(mut commands: Commands) {
+///     let particle = commands.spawn(Particle).id();
+///
+///     RngProvider::spawn_fast_rng(&mut commands, particle);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Resource)]
+pub struct RngProvider {
+    fast: Option<ProviderEntry>,
+    secure: Option<ProviderEntry>,
+}
+
+impl RngProvider {
+    pub(crate) fn set_fast<R: EntropySource + 'static>(&mut self)
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.fast = Some(ProviderEntry::new::<R>());
+    }
+
+    pub(crate) fn set_secure<R: EntropySource + 'static>(&mut self)
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        self.secure = Some(ProviderEntry::new::<R>());
+    }
+
+    /// Returns the type path of the PRNG algorithm backing the fast slot, if configured.
+    #[inline]
+    pub fn fast_algorithm(&self) -> Option<&'static str> {
+        self.fast.map(|entry| entry.algorithm)
+    }
+
+    /// Returns the type path of the PRNG algorithm backing the secure slot, if configured.
+    #[inline]
+    pub fn secure_algorithm(&self) -> Option<&'static str> {
+        self.secure.map(|entry| entry.algorithm)
+    }
+
+    /// Forks a seed from the fast global source, as raw bytes.
+    pub fn fork_fast_seed(world: &mut World) -> Option<Vec<u8>> {
+        let entry = world.get_resource::<Self>()?.fast?;
+
+        (entry.fork_seed)(world)
+    }
+
+    /// Forks a seed from the secure global source, as raw bytes.
+    pub fn fork_secure_seed(world: &mut World) -> Option<Vec<u8>> {
+        let entry = world.get_resource::<Self>()?.secure?;
+
+        (entry.fork_seed)(world)
+    }
+
+    /// Gives `entity` an RNG source of the fast algorithm, seeded from the fast global source
+    /// when the command is applied.
+    pub fn spawn_fast_rng(commands: &mut Commands, entity: Entity) {
+        commands.queue(move |world: &mut World| {
+            if let Some(entry) = world
+                .get_resource::<Self>()
+                .and_then(|provider| provider.fast)
+            {
+                (entry.insert_rng)(world, entity);
+            }
+        });
+    }
+
+    /// Gives `entity` an RNG source of the secure algorithm, seeded from the secure global source
+    /// when the command is applied.
+    pub fn spawn_secure_rng(commands: &mut Commands, entity: Entity) {
+        commands.queue(move |world: &mut World| {
+            if let Some(entry) = world
+                .get_resource::<Self>()
+                .and_then(|provider| provider.secure)
+            {
+                (entry.insert_rng)(world, entity);
+            }
+        });
+    }
+}
+
+/// Quality of RNG that a plugin can require from the app, matching the slots of the
+/// [`RngProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngQuality {
+    /// A fast, non-cryptographic RNG, backing [`RngProvider::spawn_fast_rng`].
+    Fast,
+    /// A cryptographically secure RNG, backing [`RngProvider::spawn_secure_rng`].
+    Secure,
+}
+
+impl RngQuality {
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Fast => "a fast RNG",
+            Self::Secure => "a secure RNG",
+        }
+    }
+
+    fn remedy(self) -> &'static str {
+        match self {
+            Self::Fast => "add `EntropyPlugin::<R>::default().provide_fast()`",
+            Self::Secure => {
+                "add `EntropyPlugin::<R>::default().provide_secure()` with a `CryptoRng` algorithm \
+                 such as `ChaCha8Rng`"
+            }
+        }
+    }
+}
+
+/// Resource describing which qualities of RNG the app provides, and with which PRNG algorithms,
+/// so that plugins can branch on them at runtime. Inserted when the app finishes building by
+/// [`crate::plugin::EntropyPlugin`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct RngCapabilities {
+    fast: Option<&'static str>,
+    secure: Option<&'static str>,
+}
+
+impl RngCapabilities {
+    /// Returns whether the app provides an RNG of the given quality.
+    #[inline]
+    pub fn supports(&self, quality: RngQuality) -> bool {
+        self.algorithm(quality).is_some()
+    }
+
+    /// Returns the type path of the PRNG algorithm providing the given quality, if any.
+    #[inline]
+    pub fn algorithm(&self, quality: RngQuality) -> Option<&'static str> {
+        match quality {
+            RngQuality::Fast => self.fast,
+            RngQuality::Secure => self.secure,
+        }
+    }
+
+    pub(crate) fn from_world(world: &World) -> Self {
+        world
+            .get_resource::<RngProvider>()
+            .map(|provider| Self {
+                fast: provider.fast_algorithm(),
+                secure: provider.secure_algorithm(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Requirements recorded with [`RequireRng`], with where they were required from.
+#[derive(Default, Resource)]
+struct RngRequirements(Vec<(RngQuality, &'static Location<'static>)>);
+
+/// Extension for [`App`] allowing plugins to declare the quality of RNG they need, without
+/// depending on a concrete PRNG algorithm. Called from a plugin's [`Plugin::build`], the
+/// requirements are validated once the app finishes building, panicking with a list of all unmet
+/// requirements and how to satisfy them.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyPlugin, RequireRng};
+///
+/// struct DebrisPlugin;
+///
+/// impl Plugin for DebrisPlugin {
+///     fn build(&self, app: &mut App) {
+///         app.require_fast_rng();
+///     }
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins((
+///     EntropyPlugin::<WyRand>::default().provide_fast(),
+///     DebrisPlugin,
+/// ));
+/// app.finish();
+/// ```
+pub trait RequireRng {
+    /// Requires the app to provide a fast RNG with [`crate::plugin::EntropyPlugin::provide_fast`].
+    fn require_fast_rng(&mut self) -> &mut Self;
+
+    /// Requires the app to provide a secure RNG with
+    /// [`crate::plugin::EntropyPlugin::provide_secure`].
+    fn require_secure_rng(&mut self) -> &mut Self;
+}
+
+impl RequireRng for App {
+    #[track_caller]
+    fn require_fast_rng(&mut self) -> &mut Self {
+        require_rng(self, RngQuality::Fast, Location::caller());
+        self
+    }
+
+    #[track_caller]
+    fn require_secure_rng(&mut self) -> &mut Self {
+        require_rng(self, RngQuality::Secure, Location::caller());
+        self
+    }
+}
+
+fn require_rng(app: &mut App, quality: RngQuality, location: &'static Location<'static>) {
+    app.world_mut()
+        .get_resource_or_init::<RngRequirements>()
+        .0
+        .push((quality, location));
+
+    if !app.is_plugin_added::<RngRequirementsPlugin>() {
+        app.add_plugins(RngRequirementsPlugin);
+    }
+}
+
+/// Validates the recorded [`RngRequirements`] once the app finishes building.
+struct RngRequirementsPlugin;
+
+impl Plugin for RngRequirementsPlugin {
+    fn build(&self, _app: &mut App) {}
+
+    fn finish(&self, app: &mut App) {
+        let capabilities = RngCapabilities::from_world(app.world());
+
+        let unmet: String = app
+            .world()
+            .resource::<RngRequirements>()
+            .0
+            .iter()
+            .filter(|(quality, _)| !capabilities.supports(*quality))
+            .map(|(quality, location)| {
+                format!(
+                    "\n- {}, required at {location}: {}",
+                    quality.describe(),
+                    quality.remedy()
+                )
+            })
+            .collect();
+
+        assert!(unmet.is_empty(), "unmet RNG requirements:{unmet}");
+    }
+}
+
+fn fork_global_seed<R: EntropySource + 'static>(world: &mut World) -> Option<Vec<u8>>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    world
+        .query_filtered::<&mut Entropy<R>, With<Global>>()
+        .iter_mut(world)
+        .next()
+        .map(|mut source| source.fork_inner_seed().as_mut().to_vec())
+}
+
+fn insert_forked_rng<R: EntropySource + 'static>(world: &mut World, entity: Entity)
+where
+    R::Seed: Send + Sync + Clone,
+{
+    let seed = world
+        .query_filtered::<&mut Entropy<R>, With<Global>>()
+        .iter_mut(world)
+        .next()
+        .map(|mut source| source.fork_seed());
+
+    if let Some(seed) = seed.filter(|_| world.entities().contains(entity)) {
+        world.entity_mut(entity).insert(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::Update;
+    use bevy_ecs::prelude::Component;
+    use bevy_prng::{ChaCha8Rng, WyRand};
+    use rand_core::SeedableRng;
+
+    use crate::{plugin::EntropyPlugin, seed::RngSeed, traits::SeedSource};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Particle;
+
+    #[derive(Component)]
+    struct Token;
+
+    /// A third-party plugin system, knowing nothing of the app's PRNG algorithms.
+    fn spawn_from_provider(mut commands: Commands) {
+        let particle = commands.spawn(Particle).id();
+        let token = commands.spawn(Token).id();
+
+        RngProvider::spawn_fast_rng(&mut commands, particle);
+        RngProvider::spawn_secure_rng(&mut commands, token);
+    }
+
+    #[test]
+    fn provider_uses_app_algorithms() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]).provide_fast(),
+            EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]).provide_secure(),
+        ))
+        .add_systems(Update, spawn_from_provider);
+
+        app.update();
+
+        let provider = app.world().resource::<RngProvider>();
+
+        assert_eq!(provider.fast_algorithm(), Some("bevy_prng::WyRand"));
+        assert_eq!(provider.secure_algorithm(), Some("bevy_prng::ChaCha8Rng"));
+
+        let particle = app
+            .world_mut()
+            .query_filtered::<&RngSeed<WyRand>, With<Particle>>()
+            .iter(app.world())
+            .next()
+            .unwrap()
+            .clone_seed();
+        let token = app
+            .world_mut()
+            .query_filtered::<&RngSeed<ChaCha8Rng>, With<Token>>()
+            .iter(app.world())
+            .next()
+            .unwrap()
+            .clone_seed();
+
+        let mut fast = Entropy::<WyRand>::from_seed([1; 8]);
+        let mut secure = Entropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        assert_eq!(particle, fast.fork_inner_seed());
+        assert_eq!(token, secure.fork_inner_seed());
+
+        assert_eq!(
+            RngProvider::fork_fast_seed(app.world_mut()),
+            Some(fast.fork_inner_seed().to_vec())
+        );
+    }
+
+    /// A third-party plugin needing a secure RNG, without knowing the app's PRNG algorithms.
+    struct TokenPlugin;
+
+    impl Plugin for TokenPlugin {
+        fn build(&self, app: &mut App) {
+            app.require_secure_rng();
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "unmet RNG requirements:\n- a secure RNG, required at src/provider.rs"
+    )]
+    fn unmet_requirements_fail_when_finished() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]).provide_fast(),
+            TokenPlugin,
+        ));
+
+        app.finish();
+    }
+
+    #[test]
+    fn met_requirements_expose_capabilities() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            TokenPlugin,
+            EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]).provide_secure(),
+        ));
+
+        app.finish();
+
+        let capabilities = app.world().resource::<RngCapabilities>();
+
+        assert!(capabilities.supports(RngQuality::Secure));
+        assert!(!capabilities.supports(RngQuality::Fast));
+        assert_eq!(
+            capabilities.algorithm(RngQuality::Secure),
+            Some("bevy_prng::ChaCha8Rng")
+        );
+    }
+
+    #[test]
+    fn unconfigured_slots_yield_nothing() {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]))
+            .add_systems(Update, spawn_from_provider);
+
+        app.update();
+
+        assert_eq!(RngProvider::fork_secure_seed(app.world_mut()), None);
+        assert_eq!(
+            app.world_mut()
+                .query_filtered::<Entity, With<Entropy<WyRand>>>()
+                .iter(app.world())
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::prelude::{Entity, Resource, With, Without, World};
+use bevy_prng::EntropySource;
+use rand_core::SeedableRng;
+
+use crate::{
+    component::Entropy,
+    seed::{RecordedSeed, RngSeed, SeedlessSource},
+    traits::{ForkableInnerSeed, SeedSource},
+};
+
+/// An entity whose [`RngSeed`] and [`Entropy`] components have fallen out of sync, as found by
+/// [`validate_rng_entities`]. Normally the seed's hooks keep both in step, but editor tooling,
+/// migrations or other component surgery can bypass them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RngInconsistency {
+    /// The entity has an [`RngSeed`] but no [`Entropy`] to draw from.
+    MissingEntropy(Entity),
+    /// The entity has an [`Entropy`] but no [`RngSeed`], without its seed having been detached
+    /// with [`crate::commands::RngEntityCommands::detach_seed`].
+    MissingSeed(Entity),
+}
+
+impl RngInconsistency {
+    /// Returns the inconsistent entity.
+    #[inline]
+    pub fn entity(&self) -> Entity {
+        match *self {
+            Self::MissingEntropy(entity) | Self::MissingSeed(entity) => entity,
+        }
+    }
+}
+
+/// How [`repair_rng_entities`] repairs an [`RngInconsistency::MissingSeed`]. Entities missing
+/// their [`Entropy`] always have it recreated from their seed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OrphanEntropyPolicy {
+    /// Record a seed forked from a copy of the [`Entropy`], leaving its state untouched, the same
+    /// as when an [`Entropy`] is inserted without a seed.
+    #[default]
+    RecordSeed,
+    /// Remove the orphaned [`Entropy`].
+    Remove,
+}
+
+/// Returns every entity whose `R` seed and source are out of sync, ordered by entity.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{prelude::*, repair::{validate_rng_entities, RngInconsistency}};
+///
+/// let mut world = World::new();
+///
+/// let entity = world.spawn(RngSeed::<WyRand>::from_seed([1; 8])).id();
+///
+/// assert!(validate_rng_entities::<WyRand>(&mut world).is_empty());
+///
+/// world.entity_mut(entity).remove::<Entropy<WyRand>>();
+///
+/// assert_eq!(
+///     validate_rng_entities::<WyRand>(&mut world),
+///     vec![RngInconsistency::MissingEntropy(entity)]
+/// );
+/// ```
+pub fn validate_rng_entities<R: EntropySource + 'static>(world: &mut World) -> Vec<RngInconsistency>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    let mut inconsistencies: Vec<_> = world
+        .query_filtered::<Entity, (With<RngSeed<R>>, Without<Entropy<R>>)>()
+        .iter(world)
+        .map(RngInconsistency::MissingEntropy)
+        .collect();
+
+    inconsistencies.extend(
+        world
+            .query_filtered::<Entity, (
+                With<Entropy<R>>,
+                Without<RngSeed<R>>,
+                Without<SeedlessSource<R>>,
+            )>()
+            .iter(world)
+            .map(RngInconsistency::MissingSeed),
+    );
+
+    inconsistencies.sort_unstable_by_key(RngInconsistency::entity);
+
+    inconsistencies
+}
+
+/// Repairs every entity whose `R` seed and source are out of sync, returning the repairs made.
+/// Entities missing their [`Entropy`] have it recreated from their seed, without reseeding any
+/// linked sources, while entities missing their seed are repaired according to `policy`. Each
+/// repair is logged as a warning with the `tracing` feature.
+pub fn repair_rng_entities<R: EntropySource + 'static>(
+    world: &mut World,
+    policy: OrphanEntropyPolicy,
+) -> Vec<RngInconsistency>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    let inconsistencies = validate_rng_entities::<R>(world);
+
+    for inconsistency in &inconsistencies {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            "Repairing {inconsistency:?} for {} with {policy:?}",
+            core::any::type_name::<R>()
+        );
+
+        match *inconsistency {
+            RngInconsistency::MissingEntropy(entity) => {
+                let seed = world.get::<RngSeed<R>>(entity).unwrap().clone_seed();
+
+                world
+                    .entity_mut(entity)
+                    .insert(Entropy::<R>::from_seed(seed));
+            }
+            RngInconsistency::MissingSeed(entity) => match policy {
+                OrphanEntropyPolicy::RecordSeed => {
+                    let seed = world
+                        .get::<Entropy<R>>(entity)
+                        .unwrap()
+                        .clone()
+                        .fork_inner_seed();
+
+                    world
+                        .entity_mut(entity)
+                        .insert((RngSeed::<R>::from_seed(seed), RecordedSeed::<R>::default()));
+                }
+                OrphanEntropyPolicy::Remove => {
+                    world.entity_mut(entity).remove::<Entropy<R>>();
+                }
+            },
+        }
+    }
+
+    inconsistencies
+}
+
+/// Plugin for checking `R` sources at the end of every frame and repairing any entities whose
+/// seed and source have fallen out of sync, with [`repair_rng_entities`]. Must be added after
+/// the [`crate::plugin::EntropyPlugin`] for `R`.
+///
+/// With [`RngRepairPlugin::panic_in_debug`], inconsistencies panic in builds with debug assertions
+/// instead of being repaired, to catch the code causing them during development, while release
+/// builds still repair them.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{
+///     prelude::EntropyPlugin,
+///     repair::{OrphanEntropyPolicy, RngRepairPlugin},
+/// };
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     RngRepairPlugin::<WyRand>::new(OrphanEntropyPolicy::Remove).panic_in_debug(),
+/// ));
+/// ```
+pub struct RngRepairPlugin<R: EntropySource> {
+    policy: OrphanEntropyPolicy,
+    panic_in_debug: bool,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> Default for RngRepairPlugin<R> {
+    fn default() -> Self {
+        Self::new(OrphanEntropyPolicy::default())
+    }
+}
+
+impl<R: EntropySource> RngRepairPlugin<R> {
+    /// Creates the plugin, repairing entities missing their seed with `policy`.
+    #[inline]
+    #[must_use]
+    pub fn new(policy: OrphanEntropyPolicy) -> Self {
+        Self {
+            policy,
+            panic_in_debug: false,
+            _rng: PhantomData,
+        }
+    }
+
+    /// Panics on finding any inconsistencies in builds with debug assertions, rather than
+    /// repairing them.
+    #[inline]
+    #[must_use]
+    pub fn panic_in_debug(mut self) -> Self {
+        self.panic_in_debug = true;
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for RngRepairPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.insert_resource(RngRepairConfig::<R> {
+            policy: self.policy,
+            panic: self.panic_in_debug && cfg!(debug_assertions),
+            _rng: PhantomData,
+        })
+        .add_systems(Last, repair_rng_entities_system::<R>);
+    }
+}
+
+#[derive(Resource)]
+struct RngRepairConfig<R> {
+    policy: OrphanEntropyPolicy,
+    panic: bool,
+    _rng: PhantomData<R>,
+}
+
+fn repair_rng_entities_system<R: EntropySource + 'static>(world: &mut World)
+where
+    R::Seed: Send + Sync + Clone,
+{
+    let config = world.resource::<RngRepairConfig<R>>();
+    let (policy, panic) = (config.policy, config.panic);
+
+    if panic {
+        let inconsistencies = validate_rng_entities::<R>(world);
+
+        assert!(
+            inconsistencies.is_empty(),
+            "{} sources are inconsistent: {inconsistencies:?}",
+            core::any::type_name::<R>()
+        );
+    } else {
+        repair_rng_entities::<R>(world, policy);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+
+    use crate::{commands::RngCommandsExt, plugin::EntropyPlugin};
+
+    use super::*;
+
+    fn inconsistent_app(plugin: RngRepairPlugin<WyRand>) -> (App, Entity, Entity) {
+        let mut app = App::new();
+
+        app.add_plugins((EntropyPlugin::<WyRand>::with_seed([1; 8]), plugin));
+
+        let world = app.world_mut();
+
+        let missing_entropy = world.spawn(RngSeed::<WyRand>::from_seed([2; 8])).id();
+        let missing_seed = world.spawn(RngSeed::<WyRand>::from_seed([3; 8])).id();
+
+        world
+            .entity_mut(missing_entropy)
+            .remove::<Entropy<WyRand>>();
+        world
+            .commands()
+            .rng_entity::<WyRand>(missing_seed)
+            .detach_seed();
+        world.flush();
+
+        // A detached seed is expected, so remove the marker to simulate the seed being stripped
+        world
+            .entity_mut(missing_seed)
+            .remove::<SeedlessSource<WyRand>>();
+
+        (app, missing_entropy, missing_seed)
+    }
+
+    #[test]
+    fn detached_seeds_are_consistent() {
+        let mut world = World::new();
+
+        let entity = world.spawn(RngSeed::<WyRand>::from_seed([3; 8])).id();
+
+        world.commands().rng_entity::<WyRand>(entity).detach_seed();
+        world.flush();
+
+        assert!(validate_rng_entities::<WyRand>(&mut world).is_empty());
+
+        world
+            .entity_mut(entity)
+            .insert(RngSeed::<WyRand>::from_seed([4; 8]));
+
+        assert!(world.get::<SeedlessSource<WyRand>>(entity).is_none());
+    }
+
+    #[test]
+    fn inconsistencies_are_detected() {
+        let (mut app, missing_entropy, missing_seed) = inconsistent_app(RngRepairPlugin::default());
+
+        assert_eq!(
+            validate_rng_entities::<WyRand>(app.world_mut()),
+            [
+                RngInconsistency::MissingEntropy(missing_entropy),
+                RngInconsistency::MissingSeed(missing_seed),
+            ]
+        );
+    }
+
+    #[test]
+    fn repairs_record_orphaned_seeds() {
+        let (mut app, missing_entropy, missing_seed) = inconsistent_app(RngRepairPlugin::default());
+
+        let orphan = app
+            .world()
+            .get::<Entropy<WyRand>>(missing_seed)
+            .unwrap()
+            .clone();
+
+        app.update();
+
+        let world = app.world_mut();
+
+        assert!(validate_rng_entities::<WyRand>(world).is_empty());
+        assert_eq!(
+            world.get::<Entropy<WyRand>>(missing_entropy),
+            Some(&Entropy::from_seed([2; 8]))
+        );
+        assert_eq!(
+            world.get::<RngSeed<WyRand>>(missing_seed),
+            Some(&RngSeed::from_seed(orphan.clone().fork_inner_seed()))
+        );
+        // Recording a seed leaves the orphaned source's state untouched
+        assert_eq!(world.get::<Entropy<WyRand>>(missing_seed), Some(&orphan));
+    }
+
+    #[test]
+    fn repairs_remove_orphaned_entropy() {
+        let (mut app, missing_entropy, missing_seed) =
+            inconsistent_app(RngRepairPlugin::new(OrphanEntropyPolicy::Remove));
+
+        app.update();
+
+        let world = app.world_mut();
+
+        assert!(validate_rng_entities::<WyRand>(world).is_empty());
+        assert!(world.get::<Entropy<WyRand>>(missing_entropy).is_some());
+        assert!(world.get::<Entropy<WyRand>>(missing_seed).is_none());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "sources are inconsistent")]
+    fn debug_builds_can_panic_instead() {
+        let (mut app, ..) = inconsistent_app(RngRepairPlugin::default().panic_in_debug());
+
+        app.update();
+    }
+}
@@ -0,0 +1,445 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, hash::Hasher};
+
+use bevy_ecs::prelude::World;
+
+use crate::{
+    seed::ReflectSeedSource,
+    util::{to_hex, StableHasher},
+};
+
+/// The seed half of a replay file: everything needed to put a world's sources back into the
+/// state they were in when a recording started, so that playing back the recorded inputs makes
+/// the same random decisions.
+///
+/// Captured with [`capture_replay_header`] before any inputs are recorded, then applied with
+/// [`apply_replay_header`] and checked with [`verify_replay_header`] before playing them back.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::EntropyPlugin;
+/// use bevy_rand::replay::{apply_replay_header, capture_replay_header, verify_replay_header};
+///
+/// let mut recording = App::new();
+///
+/// recording.add_plugins(EntropyPlugin::<WyRand>::default());
+///
+/// let header = capture_replay_header(recording.world());
+///
+/// let mut playback = App::new();
+///
+/// playback.add_plugins(EntropyPlugin::<WyRand>::default());
+///
+/// apply_replay_header(playback.world_mut(), &header).unwrap();
+///
+/// assert_eq!(verify_replay_header(playback.world(), &header), Ok(()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayHeader {
+    /// The version of `bevy_rand` the replay was recorded with.
+    pub version: String,
+    /// The seed of each algorithm's [`Global`](crate::global::Global) source, keyed by the
+    /// algorithm's full type path.
+    pub seeds: BTreeMap<String, Vec<u8>>,
+    /// A checksum of the seeds of every source in the world, if one was captured. See
+    /// [`replay_checksum`].
+    pub checksum: Option<u64>,
+}
+
+impl ReplayHeader {
+    /// Removes the checksum, so that only the global seeds are verified.
+    #[inline]
+    pub fn without_checksum(self) -> Self {
+        Self {
+            checksum: None,
+            ..self
+        }
+    }
+}
+
+/// The ways a world can fail to match a [`ReplayHeader`], as returned by [`verify_replay_header`]
+/// and [`apply_replay_header`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayMismatch {
+    /// The replay was recorded with a different version of `bevy_rand`, whose algorithms may
+    /// not produce the same values.
+    Version {
+        /// The version the replay was recorded with.
+        expected: String,
+        /// The version in use.
+        found: String,
+    },
+    /// The replay has a seed for an algorithm without a [`Global`](crate::global::Global) source
+    /// in the world.
+    MissingRng(String),
+    /// The world has a [`Global`](crate::global::Global) source for an algorithm the replay has
+    /// no seed for.
+    UnexpectedRng(String),
+    /// The replay's seed for an algorithm is not the length of that algorithm's seeds.
+    SeedLength {
+        /// The full type path of the algorithm.
+        rng: String,
+        /// The length of the algorithm's seeds.
+        expected: usize,
+        /// The length of the replay's seed.
+        found: usize,
+    },
+    /// An algorithm's [`Global`](crate::global::Global) source has a different seed to the
+    /// replay's.
+    Seed {
+        /// The full type path of the algorithm.
+        rng: String,
+        /// The replay's seed.
+        expected: Vec<u8>,
+        /// The seed of the world's source.
+        found: Vec<u8>,
+    },
+    /// The seeds of the world's sources have a different checksum to the replay's.
+    Checksum {
+        /// The replay's checksum.
+        expected: u64,
+        /// The checksum of the world's sources.
+        found: u64,
+    },
+}
+
+impl fmt::Display for ReplayMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Version { expected, found } => write!(
+                f,
+                "replay was recorded with bevy_rand {expected}, but {found} is in use"
+            ),
+            Self::MissingRng(rng) => {
+                write!(f, "replay has a seed for {rng}, but it has no global source")
+            }
+            Self::UnexpectedRng(rng) => {
+                write!(f, "{rng} has a global source, but the replay has no seed for it")
+            }
+            Self::SeedLength {
+                rng,
+                expected,
+                found,
+            } => write!(
+                f,
+                "replay's seed for {rng} is {found} bytes long, but {rng} seeds are {expected} bytes"
+            ),
+            Self::Seed {
+                rng,
+                expected,
+                found,
+            } => write!(
+                f,
+                "global {rng} source has seed {}, but the replay has seed {}",
+                to_hex(found),
+                to_hex(expected)
+            ),
+            Self::Checksum { expected, found } => write!(
+                f,
+                "sources have checksum {found:016x}, but the replay has checksum {expected:016x}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ReplayMismatch {}
+
+/// Captures a [`ReplayHeader`] from the world's [`Global`](crate::global::Global) sources of
+/// every algorithm registered with [`crate::plugin::RngTypesPlugin`], along with a
+/// [`replay_checksum`] of every source in the world.
+pub fn capture_replay_header(world: &World) -> ReplayHeader {
+    let seeds = ReflectSeedSource::registered(world)
+        .iter()
+        .filter_map(|source| {
+            let global = source.global(world)?;
+
+            source
+                .seed(world, global)
+                .map(|seed| (source.rng_type_path().to_owned(), seed))
+        })
+        .collect();
+
+    ReplayHeader {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        seeds,
+        checksum: Some(replay_checksum(world)),
+    }
+}
+
+/// Reseeds the world's [`Global`](crate::global::Global) sources with the seeds in `header`,
+/// propagating the new seeds to any linked sources. Sources of algorithms the header has no
+/// seed for are left alone, and the header's version and checksum are not checked, so call
+/// [`verify_replay_header`] afterwards to make sure the world matches.
+///
+/// Returns an error without reseeding anything if any of the header's algorithms have no
+/// [`Global`](crate::global::Global) source, or if any of its seeds are the wrong length.
+pub fn apply_replay_header(world: &mut World, header: &ReplayHeader) -> Result<(), ReplayMismatch> {
+    let sources = ReflectSeedSource::registered(world);
+    let mut reseeds = Vec::with_capacity(header.seeds.len());
+
+    for (rng, seed) in &header.seeds {
+        let source = sources
+            .iter()
+            .find(|source| source.rng_type_path() == rng)
+            .ok_or_else(|| ReplayMismatch::MissingRng(rng.clone()))?;
+        let global = source
+            .global(world)
+            .ok_or_else(|| ReplayMismatch::MissingRng(rng.clone()))?;
+
+        if seed.len() != source.seed_len() {
+            return Err(ReplayMismatch::SeedLength {
+                rng: rng.clone(),
+                expected: source.seed_len(),
+                found: seed.len(),
+            });
+        }
+
+        reseeds.push((source, global, seed));
+    }
+
+    for (source, global, seed) in reseeds {
+        source.reseed(world, global, seed);
+    }
+
+    Ok(())
+}
+
+/// Checks that the world's sources match `header`: that it was recorded with this version of
+/// `bevy_rand`, that the world has a [`Global`](crate::global::Global) source with the same seed
+/// for exactly the header's algorithms, and that the seeds of every source match the header's
+/// checksum, if it has one. Returns the first mismatch found.
+pub fn verify_replay_header(world: &World, header: &ReplayHeader) -> Result<(), ReplayMismatch> {
+    let version = env!("CARGO_PKG_VERSION");
+
+    if header.version != version {
+        return Err(ReplayMismatch::Version {
+            expected: header.version.clone(),
+            found: version.to_string(),
+        });
+    }
+
+    let captured = capture_replay_header(world);
+
+    for (rng, expected) in &header.seeds {
+        let found = captured
+            .seeds
+            .get(rng)
+            .ok_or_else(|| ReplayMismatch::MissingRng(rng.clone()))?;
+
+        if found != expected {
+            return Err(ReplayMismatch::Seed {
+                rng: rng.clone(),
+                expected: expected.clone(),
+                found: found.clone(),
+            });
+        }
+    }
+
+    if let Some(rng) = captured
+        .seeds
+        .keys()
+        .find(|rng| !header.seeds.contains_key(*rng))
+    {
+        return Err(ReplayMismatch::UnexpectedRng(rng.clone()));
+    }
+
+    match (header.checksum, captured.checksum) {
+        (Some(expected), Some(found)) if expected != found => {
+            Err(ReplayMismatch::Checksum { expected, found })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns a checksum of the seeds of every source in the world, of every algorithm registered
+/// with [`crate::plugin::RngTypesPlugin`]. Entities are not part of the checksum, so worlds that
+/// allocate entities differently still match as long as their sources have the same seeds.
+///
+/// The checksum is stable across platforms and versions of `bevy_rand`.
+pub fn replay_checksum(world: &World) -> u64 {
+    let mut hasher = StableHasher::default();
+
+    for source in ReflectSeedSource::registered(world) {
+        let mut seeds: Vec<_> = source
+            .seeds(world)
+            .into_iter()
+            .map(|(_, seed)| seed)
+            .collect();
+
+        seeds.sort_unstable();
+
+        hasher.write(source.rng_type_path().as_bytes());
+        hasher.write_usize(seeds.len());
+        seeds.iter().for_each(|seed| hasher.write(seed));
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::prelude::With;
+    use bevy_prng::{ChaCha8Rng, WyRand};
+    use rand_core::RngCore;
+
+    use crate::{
+        component::Entropy, global::Global, plugin::EntropyPlugin, seed::RngSeed,
+        traits::SeedSource,
+    };
+
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<ChaCha8Rng>::default(),
+            EntropyPlugin::<WyRand>::default(),
+        ));
+
+        app
+    }
+
+    fn next_values(app: &mut App) -> (u64, u64) {
+        let world = app.world_mut();
+        let chacha = world
+            .query_filtered::<&mut Entropy<ChaCha8Rng>, With<Global>>()
+            .single_mut(world)
+            .unwrap()
+            .next_u64();
+        let wyrand = world
+            .query_filtered::<&mut Entropy<WyRand>, With<Global>>()
+            .single_mut(world)
+            .unwrap()
+            .next_u64();
+
+        (chacha, wyrand)
+    }
+
+    #[test]
+    fn applied_headers_replay_the_same_values() {
+        let mut recording = app();
+        let header = capture_replay_header(recording.world());
+
+        assert_eq!(header.seeds.len(), 2);
+        assert!(header.seeds.contains_key("bevy_prng::ChaCha8Rng"));
+
+        let mut playback = app();
+
+        assert!(verify_replay_header(playback.world(), &header).is_err());
+
+        apply_replay_header(playback.world_mut(), &header).unwrap();
+
+        assert_eq!(verify_replay_header(playback.world(), &header), Ok(()));
+        assert_eq!(next_values(&mut recording), next_values(&mut playback));
+    }
+
+    #[test]
+    fn tampered_headers_are_detected() {
+        let recording = app();
+        let mut header = capture_replay_header(recording.world());
+
+        header
+            .seeds
+            .get_mut("bevy_prng::WyRand")
+            .unwrap()
+            .iter_mut()
+            .for_each(|byte| *byte = !*byte);
+
+        let expected = header.seeds["bevy_prng::WyRand"].clone();
+        let found = capture_replay_header(recording.world()).seeds["bevy_prng::WyRand"].clone();
+
+        assert_eq!(
+            verify_replay_header(recording.world(), &header),
+            Err(ReplayMismatch::Seed {
+                rng: "bevy_prng::WyRand".into(),
+                expected,
+                found,
+            })
+        );
+
+        header.version = "0.0.0".into();
+
+        assert!(matches!(
+            verify_replay_header(recording.world(), &header),
+            Err(ReplayMismatch::Version { .. })
+        ));
+    }
+
+    #[test]
+    fn extra_sources_fail_the_checksum() {
+        let recording = app();
+        let header = capture_replay_header(recording.world());
+
+        let mut playback = app();
+
+        apply_replay_header(playback.world_mut(), &header).unwrap();
+        playback
+            .world_mut()
+            .spawn(RngSeed::<WyRand>::from_seed([7; 8]));
+
+        assert!(matches!(
+            verify_replay_header(playback.world(), &header),
+            Err(ReplayMismatch::Checksum { .. })
+        ));
+        assert_eq!(
+            verify_replay_header(playback.world(), &header.clone().without_checksum()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn mismatched_algorithms_are_reported() {
+        let recording = app();
+        let mut header = capture_replay_header(recording.world()).without_checksum();
+
+        let mut playback = App::new();
+
+        playback.add_plugins(EntropyPlugin::<WyRand>::default());
+
+        assert_eq!(
+            apply_replay_header(playback.world_mut(), &header),
+            Err(ReplayMismatch::MissingRng("bevy_prng::ChaCha8Rng".into()))
+        );
+
+        header.seeds.remove("bevy_prng::ChaCha8Rng");
+        header
+            .seeds
+            .insert("bevy_prng::WyRand".into(), alloc::vec![1, 2, 3]);
+
+        assert_eq!(
+            apply_replay_header(playback.world_mut(), &header),
+            Err(ReplayMismatch::SeedLength {
+                rng: "bevy_prng::WyRand".into(),
+                expected: 8,
+                found: 3,
+            })
+        );
+
+        header.seeds.clear();
+
+        assert_eq!(
+            verify_replay_header(playback.world(), &header),
+            Err(ReplayMismatch::UnexpectedRng("bevy_prng::WyRand".into()))
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn headers_round_trip_through_ron() {
+        let header = capture_replay_header(app().world());
+
+        let serialized = ron::to_string(&header).unwrap();
+        let deserialized: ReplayHeader = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, header);
+    }
+}
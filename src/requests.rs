@@ -0,0 +1,190 @@
+//! Event-driven reseeding, for apps that prefer seeds to propagate at explicit points in their
+//! schedules rather than through observers.
+//!
+//! Instead of triggering [`SeedFromGlobal`], [`SeedFromParent`] or [`ReseedRng`] observer
+//! events, send [`SeedRequest`] events, which are applied in order when the
+//! [`apply_seed_requests`] system runs. Reseeding a linked source then propagates new seeds to its
+//! linked targets within the same system, following the [`RngParent`] links between them, so no
+//! observers are involved at any point. For the same requests in the same frame, the resulting
+//! seeds are the same as with the observers.
+//!
+//! [`crate::plugin::EntropyPlugin::with_event_driven_reseeding`] sets this up in place of the
+//! reseeding observers. Sources can still be linked with
+//! [`crate::commands::RngEntityCommands::fork_child`], or by inserting the link components
+//! directly, but not with the linking observers of [`crate::plugin::LinkedEntropySources`], as
+//! its observers would propagate seeds a second time.
+//!
+//! [`SeedFromGlobal`]: crate::observers::SeedFromGlobal
+//! [`SeedFromParent`]: crate::observers::SeedFromParent
+//! [`ReseedRng`]: crate::observers::ReseedRng
+
+use alloc::vec::Vec;
+
+use bevy_ecs::{
+    entity_disabling::Disabled,
+    event::Events,
+    prelude::{Entity, Event, With, World},
+    query::Has,
+};
+use bevy_prng::EntropySource;
+
+use crate::{
+    component::Entropy,
+    global::Global,
+    observers::{fork_by_link_index, LinkedReseeded, RngChildren, RngLinkIndex, RngParent},
+    seed::RngSeed,
+    traits::{ForkableSeed, SeedSource},
+};
+
+/// Buffered event requesting a new seed for an entity's `Rng` source, applied by
+/// [`apply_seed_requests`].
+#[derive(Debug, Event)]
+pub enum SeedRequest<Rng: EntropySource> {
+    /// Reseeds the entity with a seed forked from the [`Global`] source, as with
+    /// [`SeedFromGlobal`](crate::observers::SeedFromGlobal).
+    FromGlobal(Entity),
+    /// Reseeds the entity with a seed forked from its linked parent source, as with
+    /// [`SeedFromParent`](crate::observers::SeedFromParent). Entities without a parent are not
+    /// reseeded.
+    FromParent(Entity),
+    /// Reseeds the entity with the given seed, as with
+    /// [`ReseedRng`](crate::observers::ReseedRng).
+    Reseed(Entity, Rng::Seed),
+}
+
+/// Exclusive system applying every pending [`SeedRequest`] for `Rng` sources in the order they
+/// were sent, propagating new seeds from each reseeded source to its linked targets, and in turn
+/// to their own targets. As with the observers, targets are seeded in link index order, including
+/// disabled targets, and [`LinkedReseeded`] is triggered on each source once its targets have
+/// been seeded. Requests for entities that no longer exist are skipped.
+///
+/// Requests are drained as they are applied, so only one instance of this system should be
+/// added for each `Rng`.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{prelude::*, requests::SeedRequest};
+///
+/// #[derive(Component)]
+/// struct Spawner;
+///
+/// fn reseed_spawners(
+///     mut requests: EventWriter<SeedRequest<WyRand>>,
+///     q_spawners: Query<Entity, With<Spawner>>,
+/// ) {
+///     requests.write_batch(q_spawners.iter().map(SeedRequest::FromGlobal));
+/// }
+///
+/// App::new()
+///     .add_plugins(EntropyPlugin::<WyRand>::default().with_event_driven_reseeding(PostUpdate))
+///     .add_systems(Update, reseed_spawners);
+/// ```
+pub fn apply_seed_requests<Rng: EntropySource>(world: &mut World)
+where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let Some(mut events) = world.get_resource_mut::<Events<SeedRequest<Rng>>>() else {
+        return;
+    };
+
+    let requests: Vec<_> = events.drain().collect();
+
+    for request in requests {
+        let (entity, seed) = match request {
+            SeedRequest::FromGlobal(entity) => (entity, fork_from_global::<Rng>(world)),
+            SeedRequest::FromParent(entity) => (entity, fork_from_parent::<Rng>(world, entity)),
+            SeedRequest::Reseed(entity, seed) => (entity, Some(RngSeed::from_seed(seed))),
+        };
+
+        if let Some(seed) = seed {
+            reseed_linked::<Rng>(world, entity, seed);
+        }
+    }
+}
+
+/// Forks a seed from the [`Global`] `Rng` source, if there is one.
+fn fork_from_global<Rng: EntropySource>(world: &mut World) -> Option<RngSeed<Rng>>
+where
+    Rng::Seed: Send + Sync + Clone,
+{
+    world
+        .query_filtered::<&mut Entropy<Rng>, With<Global>>()
+        .iter_mut(world)
+        .next()
+        .map(|mut global| global.fork_seed())
+}
+
+/// Forks a seed from the linked parent of `entity`, if it has one.
+fn fork_from_parent<Rng: EntropySource>(world: &mut World, entity: Entity) -> Option<RngSeed<Rng>>
+where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let parent = world.get::<RngParent<Rng>>(entity)?.entity();
+
+    world
+        .query_filtered::<&mut Entropy<Rng>, With<RngChildren<Rng>>>()
+        .get_mut(world, parent)
+        .ok()
+        .map(|mut rng| rng.fork_seed())
+}
+
+/// Inserts `seed` into `entity`, then propagates seeds to its linked targets, and so on down
+/// the links.
+fn reseed_linked<Rng: EntropySource>(world: &mut World, entity: Entity, seed: RngSeed<Rng>)
+where
+    Rng::Seed: Send + Sync + Clone,
+{
+    let Ok(mut source) = world.get_entity_mut(entity) else {
+        return;
+    };
+
+    source.insert(seed);
+    world.flush();
+
+    let mut sources = alloc::vec![entity];
+
+    while let Some(source) = sources.pop() {
+        if world.get::<RngChildren<Rng>>(source).is_none() {
+            continue;
+        }
+
+        let targets: Vec<_> = world
+            .query::<(
+                Entity,
+                &RngParent<Rng>,
+                Option<&RngLinkIndex>,
+                Has<Disabled>,
+            )>()
+            .iter(world)
+            .filter(|(_, parent, ..)| parent.entity() == source)
+            .map(|(target, _, index, _)| (target, index.copied()))
+            .collect();
+
+        let Some(mut rng) = world.get_mut::<Entropy<Rng>>(source) else {
+            continue;
+        };
+
+        let batch = fork_by_link_index(
+            targets
+                .iter()
+                .map(|(target, index)| (*target, index.as_ref())),
+            || rng.fork_seed(),
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?source,
+            targets = batch.len(),
+            "propagating seeds to linked targets"
+        );
+
+        // Targets are pushed in reverse so that they are propagated from in seeding order.
+        sources.extend(batch.iter().rev().map(|(target, _)| *target));
+
+        world.insert_batch(batch);
+        world.flush();
+        world.trigger_targets(LinkedReseeded::<Rng>::default(), source);
+    }
+}
@@ -0,0 +1,409 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    hierarchy::ChildOf,
+    prelude::{Commands, Component, Entity, Query},
+};
+use bevy_math::{
+    primitives::{Circle, Cuboid, Rectangle, Sphere},
+    ShapeSample, Vec2, Vec3,
+};
+use bevy_prng::EntropySource;
+use rand_core::RngCore;
+
+use crate::{component::Entropy, util::random_range_f32_exact};
+
+/// A point that can be scattered by [`scatter_points`], implemented for [`Vec2`] and [`Vec3`].
+pub trait ScatterPoint: Copy + Send + Sync + 'static {
+    /// Returns the squared distance between `self` and `other`.
+    fn distance_squared(self, other: Self) -> f32;
+}
+
+impl ScatterPoint for Vec2 {
+    #[inline]
+    fn distance_squared(self, other: Self) -> f32 {
+        Vec2::distance_squared(self, other)
+    }
+}
+
+impl ScatterPoint for Vec3 {
+    #[inline]
+    fn distance_squared(self, other: Self) -> f32 {
+        Vec3::distance_squared(self, other)
+    }
+}
+
+/// A shape [`scatter_points`] can scatter points within, implemented for [`Rectangle`],
+/// [`Cuboid`], [`Circle`] and [`Sphere`]. Unlike [`ShapeSample::sample_interior`], points are
+/// sampled with [`random_range_f32_exact`] on each axis, so they are bit-identical on every
+/// platform and across `rand` versions.
+pub trait ScatterShape: ShapeSample {
+    /// Samples a point from the shape's interior, centered on the origin.
+    fn sample_interior_exact<R: RngCore + ?Sized>(&self, rng: &mut R) -> Self::Output;
+}
+
+/// Samples a coordinate in `[-half_extent, half_extent)`, or zero for a flat axis.
+#[inline]
+fn sample_axis<R: RngCore + ?Sized>(rng: &mut R, half_extent: f32) -> f32 {
+    if half_extent > 0.0 {
+        random_range_f32_exact(rng, -half_extent, half_extent)
+    } else {
+        0.0
+    }
+}
+
+impl ScatterShape for Rectangle {
+    fn sample_interior_exact<R: RngCore + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let x = sample_axis(rng, self.half_size.x);
+        let y = sample_axis(rng, self.half_size.y);
+
+        Vec2::new(x, y)
+    }
+}
+
+impl ScatterShape for Cuboid {
+    fn sample_interior_exact<R: RngCore + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let x = sample_axis(rng, self.half_size.x);
+        let y = sample_axis(rng, self.half_size.y);
+        let z = sample_axis(rng, self.half_size.z);
+
+        Vec3::new(x, y, z)
+    }
+}
+
+impl ScatterShape for Circle {
+    /// Samples points within the circle's bounding square until one lies within the circle, so
+    /// no trigonometry is involved.
+    fn sample_interior_exact<R: RngCore + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        loop {
+            let point = Rectangle::from_length(2.0 * self.radius).sample_interior_exact(rng);
+
+            if point.length_squared() <= self.radius * self.radius {
+                return point;
+            }
+        }
+    }
+}
+
+impl ScatterShape for Sphere {
+    /// Samples points within the sphere's bounding cube until one lies within the sphere, so no
+    /// trigonometry is involved.
+    fn sample_interior_exact<R: RngCore + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        loop {
+            let point = Cuboid::from_length(2.0 * self.radius).sample_interior_exact(rng);
+
+            if point.length_squared() <= self.radius * self.radius {
+                return point;
+            }
+        }
+    }
+}
+
+/// How [`scatter_points`] scatters points within a shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScatterConfig<S> {
+    /// The number of points to scatter.
+    pub count: usize,
+    /// The shape to scatter points within, centered on the origin.
+    pub shape: S,
+    /// The minimum distance between any two points.
+    pub min_distance: f32,
+    /// The number of candidates sampled for each point before giving up on placing any more.
+    pub max_attempts: usize,
+}
+
+impl<S> ScatterConfig<S> {
+    /// Creates a config scattering `count` points within `shape`, at least `min_distance` apart,
+    /// with up to 30 attempts to place each point.
+    #[inline]
+    pub fn new(count: usize, shape: S, min_distance: f32) -> Self {
+        Self {
+            count,
+            shape,
+            min_distance,
+            max_attempts: 30,
+        }
+    }
+}
+
+/// Scatters up to [`ScatterConfig::count`] points within the config's shape, each at least
+/// [`ScatterConfig::min_distance`] away from the others, by rejection sampling.
+///
+/// Candidates are sampled from the shape's interior one at a time with
+/// [`ScatterShape::sample_interior_exact`], and the first candidate far enough away from every
+/// point placed so far is placed. If none of [`ScatterConfig::max_attempts`] candidates fit, no
+/// more points are placed, so fewer than [`ScatterConfig::count`] points may be returned for
+/// crowded shapes.
+///
+/// The same RNG state and config always scatter the same points. Candidates are sampled with
+/// bit-exact helpers and distances are compared squared, with no rounding beyond IEEE 754
+/// arithmetic, so the same points are scattered on every platform.
+///
+/// ```
+/// use bevy_math::primitives::Rectangle;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::scatter::{scatter_points, ScatterConfig};
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = WyRand::seed_from_u64(7);
+/// let config = ScatterConfig::new(16, Rectangle::new(20.0, 20.0), 2.0);
+///
+/// let trees = scatter_points(&mut rng, &config);
+///
+/// assert_eq!(trees.len(), 16);
+/// ```
+pub fn scatter_points<S>(rng: &mut impl RngCore, config: &ScatterConfig<S>) -> Vec<S::Output>
+where
+    S: ScatterShape,
+    S::Output: ScatterPoint,
+{
+    let min_distance_squared = config.min_distance * config.min_distance;
+    let mut points = Vec::with_capacity(config.count);
+
+    while points.len() < config.count {
+        let placed = (0..config.max_attempts)
+            .map(|_| config.shape.sample_interior_exact(rng))
+            .find(|candidate: &S::Output| {
+                points
+                    .iter()
+                    .all(|&point| candidate.distance_squared(point) >= min_distance_squared)
+            });
+
+        match placed {
+            Some(point) => points.push(point),
+            None => break,
+        }
+    }
+
+    points
+}
+
+/// Component for scattering children within a shape around its entity, placed by
+/// [`ScatterPlugin`] with the entity's own [`Entropy`] source. Each child is spawned with a
+/// [`Scattered`] component holding its point, after which the spawner is removed, so add
+/// observers or systems for [`Scattered`] children to turn the points into anything else.
+#[derive(Debug, Clone, Component)]
+pub struct ScatterSpawner<S: Send + Sync + 'static> {
+    /// How the children are scattered.
+    pub config: ScatterConfig<S>,
+}
+
+impl<S: Send + Sync + 'static> ScatterSpawner<S> {
+    /// Creates a spawner scattering children as with `config`.
+    #[inline]
+    pub fn new(config: ScatterConfig<S>) -> Self {
+        Self { config }
+    }
+}
+
+/// The point a child of a [`ScatterSpawner`] was scattered at, relative to its parent.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct Scattered<P: ScatterPoint>(pub P);
+
+/// Plugin for spawning the children of every [`ScatterSpawner<S>`] with an [`Entropy<R>`]
+/// source in [`Update`], drawing their points from the spawner's source with
+/// [`scatter_points`]. Children are spawned in the order their points were scattered.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_math::primitives::Rectangle;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{
+///     prelude::{EntropyPlugin, RngSeed, SeedSource},
+///     scatter::{ScatterConfig, ScatterPlugin, ScatterSpawner},
+/// };
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     ScatterPlugin::<Rectangle, WyRand>::default(),
+/// ));
+///
+/// app.world_mut().spawn((
+///     RngSeed::<WyRand>::from_u64(3),
+///     ScatterSpawner::new(ScatterConfig::new(8, Rectangle::new(10.0, 10.0), 1.0)),
+/// ));
+///
+/// app.update();
+/// ```
+pub struct ScatterPlugin<S, R> {
+    _marker: PhantomData<fn() -> (S, R)>,
+}
+
+impl<S, R> Default for ScatterPlugin<S, R> {
+    fn default() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, R> Plugin for ScatterPlugin<S, R>
+where
+    S: ScatterShape + Send + Sync + 'static,
+    S::Output: ScatterPoint,
+    R: EntropySource + 'static,
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spawn_scattered::<S, R>);
+    }
+}
+
+fn spawn_scattered<S, R>(
+    mut commands: Commands,
+    mut q_spawners: Query<(Entity, &ScatterSpawner<S>, &mut Entropy<R>)>,
+) where
+    S: ScatterShape + Send + Sync + 'static,
+    S::Output: ScatterPoint,
+    R: EntropySource + 'static,
+{
+    for (spawner, config, mut rng) in q_spawners.iter_mut() {
+        for point in scatter_points(&mut *rng, &config.config) {
+            commands.spawn((Scattered(point), ChildOf(spawner)));
+        }
+
+        commands.entity(spawner).remove::<ScatterSpawner<S>>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::{Children, World};
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use crate::{
+        plugin::EntropyPlugin, seed::RngSeed, traits::SeedSource, util::random_range_f32_exact,
+    };
+
+    use super::*;
+
+    fn assert_spaced<P: ScatterPoint>(points: &[P], min_distance: f32) {
+        for (index, &a) in points.iter().enumerate() {
+            for &b in &points[index + 1..] {
+                assert!(a.distance_squared(b) >= min_distance * min_distance);
+            }
+        }
+    }
+
+    #[test]
+    fn pinned_scatter() {
+        let mut rng = WyRand::from_seed([5; 8]);
+        let config = ScatterConfig::new(4, Rectangle::new(10.0, 10.0), 2.0);
+
+        let points = scatter_points(&mut rng, &config);
+
+        assert_eq!(
+            points,
+            [
+                Vec2::new(-2.2065878, -1.2011802),
+                Vec2::new(0.9404421, -1.0172331),
+                Vec2::new(3.2401361, 1.3886127),
+                Vec2::new(4.3649254, 4.724638),
+            ]
+        );
+        assert_spaced(&points, 2.0);
+        assert_eq!(
+            scatter_points(&mut WyRand::from_seed([5; 8]), &config),
+            points
+        );
+
+        let mut rng = WyRand::from_seed([5; 8]);
+        let config = ScatterConfig::new(3, Sphere::new(4.0), 1.0);
+
+        assert_eq!(
+            scatter_points(&mut rng, &config),
+            [
+                Vec3::new(-1.7652702, -0.9609442, 0.75235367),
+                Vec3::new(-0.8137865, 2.5921087, 1.1108904),
+                Vec3::new(0.29685783, 1.1984606, 3.6558342),
+            ]
+        );
+    }
+
+    #[test]
+    fn candidates_are_sampled_exactly() {
+        let mut rng = WyRand::from_seed([5; 8]);
+        let mut expected = rng.clone();
+
+        let point = Cuboid::new(2.0, 4.0, 0.0).sample_interior_exact(&mut rng);
+
+        assert_eq!(
+            point,
+            Vec3::new(
+                random_range_f32_exact(&mut expected, -1.0, 1.0),
+                random_range_f32_exact(&mut expected, -2.0, 2.0),
+                0.0
+            )
+        );
+        assert_eq!(rng, expected);
+    }
+
+    #[test]
+    fn points_keep_their_distance() {
+        let mut rng = WyRand::from_seed([9; 8]);
+
+        let flat = scatter_points(
+            &mut rng,
+            &ScatterConfig::new(64, Rectangle::new(20.0, 20.0), 1.5),
+        );
+        let deep = scatter_points(
+            &mut rng,
+            &ScatterConfig::new(64, Cuboid::new(10.0, 10.0, 10.0), 1.5),
+        );
+
+        assert_eq!(flat.len(), 64);
+        assert_eq!(deep.len(), 64);
+        assert_spaced(&flat, 1.5);
+        assert_spaced(&deep, 1.5);
+
+        // Crowded shapes stop early rather than breaking the spacing
+        let crowded = scatter_points(
+            &mut rng,
+            &ScatterConfig::new(64, Rectangle::new(4.0, 4.0), 2.0),
+        );
+
+        assert!(crowded.len() < 64);
+        assert_spaced(&crowded, 2.0);
+    }
+
+    #[test]
+    fn spawners_place_children_from_their_source() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            ScatterPlugin::<Rectangle, WyRand>::default(),
+        ));
+
+        let config = ScatterConfig::new(8, Rectangle::new(10.0, 10.0), 1.0);
+        let spawner = app
+            .world_mut()
+            .spawn((
+                RngSeed::<WyRand>::from_u64(3),
+                ScatterSpawner::new(config.clone()),
+            ))
+            .id();
+
+        app.update();
+
+        let world: &World = app.world();
+        let expected = scatter_points(
+            &mut WyRand::from_seed(RngSeed::<WyRand>::from_u64(3).clone_seed()),
+            &config,
+        );
+        let children: Vec<_> = world
+            .get::<Children>(spawner)
+            .unwrap()
+            .iter()
+            .map(|child| world.get::<Scattered<Vec2>>(*child).unwrap().0)
+            .collect();
+
+        assert_eq!(children, expected);
+        assert!(world.get::<ScatterSpawner<Rectangle>>(spawner).is_none());
+    }
+}
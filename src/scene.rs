@@ -0,0 +1,240 @@
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+use bevy_app::{App, Plugin};
+use bevy_ecs::{
+    name::Name,
+    prelude::{Commands, Component, Query, ReflectComponent, Res, Resource, Single, Trigger, With},
+    query::Or,
+};
+use bevy_prng::{EntropySeed, EntropySource};
+use bevy_reflect::Reflect;
+use bevy_scene::{SceneInstanceReady, SceneSpawner};
+
+use crate::{
+    component::Entropy,
+    global::Global,
+    seed::RngSeed,
+    traits::{ForkableSeed, SeedSource},
+    util::{hash_label, mix_seed},
+};
+
+/// How the RNG sources of a newly spawned scene instance are seeded. Placed on the root entity of
+/// a scene instance, such as alongside its `SceneRoot`, otherwise the default policy of the
+/// [`SceneRngPlugin`] applies.
+///
+/// ```
+/// use bevy_asset::Handle;
+/// use bevy_ecs::prelude::*;
+/// use bevy_rand::prelude::SceneRngPolicy;
+/// use bevy_scene::{Scene, SceneRoot};
+///
+/// fn spawn_camp(mut commands: Commands, camp: Local<Handle<Scene>>) {
+///     commands.spawn((SceneRoot(camp.clone()), SceneRngPolicy::ReseedFromGlobal));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component, Reflect)]
+#[reflect(Debug, PartialEq, Component)]
+pub enum SceneRngPolicy {
+    /// Keep the seeds serialized in the scene, so every instance has the same RNG sources.
+    #[default]
+    KeepSerialized,
+    /// Reseed every RNG source in the instance with a seed forked from the [`crate::global::Global`]
+    /// source, so every instance differs.
+    ReseedFromGlobal,
+    /// Derive the seed of each RNG source in the instance from the [`crate::global::Global`] seed
+    /// and the entity's [`Name`], so same-named entities are seeded the same in every instance,
+    /// without advancing the global source. Unnamed entities are reseeded as with
+    /// [`SceneRngPolicy::ReseedFromGlobal`].
+    DeriveFromGlobalByName,
+}
+
+/// Plugin for applying [`SceneRngPolicy`] to the `R` sources of every scene instance, once the
+/// instance is ready. Instances without a policy on their root use the plugin's default policy,
+/// which is [`SceneRngPolicy::KeepSerialized`] unless configured otherwise.
+///
+/// Sources are reseeded in entity order, so instances spawned in the same order from the same
+/// [`crate::global::Global`] seed are seeded the same way.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyPlugin, SceneRngPlugin, SceneRngPolicy};
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::default(),
+///     SceneRngPlugin::<WyRand>::default().with_default_policy(SceneRngPolicy::ReseedFromGlobal),
+/// ));
+/// ```
+pub struct SceneRngPlugin<R: EntropySource> {
+    default_policy: SceneRngPolicy,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> Default for SceneRngPlugin<R> {
+    fn default() -> Self {
+        Self {
+            default_policy: SceneRngPolicy::default(),
+            _rng: PhantomData,
+        }
+    }
+}
+
+impl<R: EntropySource> SceneRngPlugin<R> {
+    /// Configures the policy for instances without a [`SceneRngPolicy`] on their root.
+    #[inline]
+    #[must_use]
+    pub fn with_default_policy(mut self, policy: SceneRngPolicy) -> Self {
+        self.default_policy = policy;
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for SceneRngPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        app.register_type::<SceneRngPolicy>()
+            .insert_resource(DefaultSceneRngPolicy::<R>(self.default_policy, PhantomData))
+            .add_observer(apply_scene_rng_policy::<R>);
+    }
+}
+
+#[derive(Resource)]
+struct DefaultSceneRngPolicy<R>(SceneRngPolicy, PhantomData<R>);
+
+fn apply_scene_rng_policy<R: EntropySource + 'static>(
+    trigger: Trigger<SceneInstanceReady>,
+    spawner: Res<SceneSpawner>,
+    default_policy: Res<DefaultSceneRngPolicy<R>>,
+    q_policy: Query<&SceneRngPolicy>,
+    q_sources: Query<Option<&Name>, Or<(With<Entropy<R>>, With<RngSeed<R>>)>>,
+    global: Single<(&mut Entropy<R>, &RngSeed<R>), With<Global>>,
+    mut commands: Commands,
+) where
+    R::Seed: EntropySeed,
+{
+    let (mut global, global_seed) = global.into_inner();
+
+    let policy = q_policy
+        .get(trigger.target())
+        .copied()
+        .unwrap_or(default_policy.0);
+
+    if policy == SceneRngPolicy::KeepSerialized {
+        return;
+    }
+
+    let mut sources: Vec<_> = spawner
+        .iter_instance_entities(trigger.event().instance_id)
+        .filter_map(|entity| q_sources.get(entity).ok().map(|name| (entity, name)))
+        .collect();
+
+    sources.sort_unstable_by_key(|(entity, _)| *entity);
+
+    for (entity, name) in sources {
+        let seed = match (policy, name) {
+            (SceneRngPolicy::DeriveFromGlobalByName, Some(name)) => RngSeed::<R>::from_seed(
+                mix_seed::<R>(global_seed.get_seed(), hash_label(name.as_str())),
+            ),
+            _ => global.fork_seed(),
+        };
+
+        commands.entity(entity).insert(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::String};
+
+    use bevy_app::TaskPoolPlugin;
+    use bevy_asset::{AssetPlugin, Assets};
+    use bevy_ecs::{entity::Entity, world::World};
+    use bevy_prng::WyRand;
+    use bevy_scene::{Scene, ScenePlugin, SceneRoot};
+    use rand_core::{RngCore, SeedableRng};
+
+    use crate::plugin::EntropyPlugin;
+
+    use super::*;
+
+    /// Spawns two instances of a scene of bandits with the given policy, returning the first
+    /// output of each source in each instance by name.
+    fn spawn_camps(policy: SceneRngPolicy) -> [BTreeMap<String, u64>; 2] {
+        let mut app = App::new();
+
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            SceneRngPlugin::<WyRand>::default(),
+        ))
+        .register_type::<Name>();
+
+        let mut camp = World::new();
+
+        for name in ["leader", "scout", "guard"] {
+            camp.spawn((Name::new(name), Entropy::<WyRand>::from_seed([9; 8])));
+        }
+
+        let camp = app
+            .world_mut()
+            .resource_mut::<Assets<Scene>>()
+            .add(Scene::new(camp));
+
+        let mut seen = Vec::new();
+
+        [(); 2].map(|_| {
+            app.world_mut().spawn((SceneRoot(camp.clone()), policy));
+            app.update();
+
+            let world = app.world_mut();
+            let seeds: Vec<_> = world
+                .query::<(Entity, &Name, &Entropy<WyRand>)>()
+                .iter(world)
+                .filter(|(entity, ..)| !seen.contains(entity))
+                .map(|(entity, name, rng)| (entity, name.as_str().into(), rng.clone().next_u64()))
+                .collect();
+
+            seen.extend(seeds.iter().map(|(entity, ..)| *entity));
+
+            seeds
+                .into_iter()
+                .map(|(_, name, seed)| (name, seed))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn reseeded_instances_differ() {
+        let [first, second] = spawn_camps(SceneRngPolicy::ReseedFromGlobal);
+
+        assert_eq!(first.len(), 3);
+
+        for (name, seed) in &first {
+            assert_ne!(second[name], *seed);
+        }
+    }
+
+    #[test]
+    fn instances_derived_by_name_match() {
+        let [first, second] = spawn_camps(SceneRngPolicy::DeriveFromGlobalByName);
+
+        assert_eq!(first.len(), 3);
+        assert_eq!(first, second);
+        assert_ne!(first["leader"], first["scout"]);
+    }
+
+    #[test]
+    fn serialized_sources_are_kept() {
+        let [first, second] = spawn_camps(SceneRngPolicy::KeepSerialized);
+
+        let serialized = Entropy::<WyRand>::from_seed([9; 8]).next_u64();
+
+        assert_eq!(first, second);
+        assert!(first.values().all(|output| *output == serialized));
+    }
+}
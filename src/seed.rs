@@ -1,20 +1,37 @@
-use core::{marker::PhantomData, ops::Deref};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    sync::atomic::{AtomicBool, Ordering as AtomicOrdering},
+};
 
 use bevy_ecs::{
     component::{Immutable, StorageType},
-    prelude::Component,
+    prelude::{AppTypeRegistry, Component, Entity, ReflectResource, Resource, With, World},
 };
-use bevy_prng::EntropySource;
-use bevy_reflect::Reflect;
+use bevy_prng::{EntropySeed, EntropySource};
+use bevy_reflect::{FromType, Reflect, TypePath};
 use rand_core::SeedableRng;
 
-use crate::{component::Entropy, traits::SeedSource};
+use crate::{
+    component::Entropy,
+    global::Global,
+    traits::{ForkableInnerSeed, SeedSource},
+    util::{hash_label, mix_into, mix_seed, mix_u64, splitmix64, StableHasher},
+};
 
 /// The initial seed/state for an [`Entropy`]. Adding this component to an `Entity` will cause
 /// an `Entropy` to be initialised as well. To force a reseed, just insert this component to an
 /// `Entity` to overwrite the old value, and the `Entropy` will be overwritten with the new seed
 /// in turn.
 ///
+/// The `Entropy` is inserted by a command queued from the seed's hook, which is applied as part
+/// of the same flush as the seed's insertion. So once the command inserting the seed has been
+/// applied, the `Entropy` is available to any following commands, even within the same batch.
+///
 /// ## Examples
 ///
 /// Randomised Seed via `Default`:
@@ -34,7 +51,7 @@ use crate::{component::Entropy, traits::SeedSource};
 ///         ));
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+#[derive(Debug, Clone, Reflect)]
 pub struct RngSeed<R: EntropySource> {
     seed: R::Seed,
     #[reflect(ignore)]
@@ -76,28 +93,527 @@ where
     fn register_component_hooks(hooks: &mut bevy_ecs::component::ComponentHooks) {
         hooks
             .on_insert(|mut world, entity, _| {
-                let seed = world
-                    .get::<RngSeed<R>>(entity)
-                    .map(|seed| seed.clone_seed())
-                    .unwrap();
+                // The source has a seed again.
+                if world.get::<SeedlessSource<R>>(entity).is_some() {
+                    world
+                        .commands()
+                        .entity(entity)
+                        .remove::<SeedlessSource<R>>();
+                }
+
+                // The seed was recorded from an existing `Entropy`, so it must not be overwritten.
+                if world.get::<RecordedSeed<R>>(entity).is_some() {
+                    world.commands().entity(entity).remove::<RecordedSeed<R>>();
+                    return;
+                }
+
+                let seed = world.get::<RngSeed<R>>(entity).unwrap();
+
+                #[cfg(feature = "tracing")]
+                tracing::trace!(?entity, %seed, "initialising entropy from seed");
+
+                let seed = seed.clone_seed();
                 world
                     .commands()
                     .entity(entity)
                     .insert(Entropy::<R>::from_seed(seed));
             })
             .on_remove(|mut world, entity, _| {
+                // The seed is being detached, so the `Entropy` must be kept.
+                if world.get::<DetachedSeed<R>>(entity).is_some() {
+                    world
+                        .commands()
+                        .entity(entity)
+                        .remove::<DetachedSeed<R>>()
+                        .insert(SeedlessSource::<R>::default());
+                    return;
+                }
+
                 world.commands().entity(entity).remove::<Entropy<R>>();
             });
     }
 }
 
+/// Marker for an [`RngSeed`] that was recorded for an already existing [`Entropy`], which must
+/// not be reinitialised from the seed.
+#[derive(Component)]
+pub(crate) struct RecordedSeed<R: EntropySource>(PhantomData<R>);
+
+impl<R: EntropySource> Default for RecordedSeed<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Marker for an [`RngSeed`] that is being detached from its [`Entropy`], which must not be
+/// removed along with the seed.
+#[derive(Component)]
+pub(crate) struct DetachedSeed<R: EntropySource>(PhantomData<R>);
+
+impl<R: EntropySource> Default for DetachedSeed<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Marker for an [`Entropy`] whose seed was detached, so that it is not reported as missing its
+/// seed by [`crate::repair::validate_rng_entities`].
+#[derive(Component)]
+pub(crate) struct SeedlessSource<R: EntropySource>(PhantomData<R>);
+
+impl<R: EntropySource> Default for SeedlessSource<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: EntropySource> PartialEq for RngSeed<R>
+where
+    R::Seed: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.seed == other.seed
+    }
+}
+
+impl<R: EntropySource> Eq for RngSeed<R> where R::Seed: Eq {}
+
+/// Hashes the seed bytes, so that seeds can key maps regardless of the PRNG algorithm.
+impl<R: EntropySource> Hash for RngSeed<R>
+where
+    R::Seed: AsRef<[u8]>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.seed.as_ref().hash(state);
+    }
+}
+
+impl<R: EntropySource> PartialOrd for RngSeed<R>
+where
+    R::Seed: AsRef<[u8]> + Eq,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders seeds by comparing their bytes lexicographically, which is stable across platforms.
+impl<R: EntropySource> Ord for RngSeed<R>
+where
+    R::Seed: AsRef<[u8]> + Eq,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.seed.as_ref().cmp(other.seed.as_ref())
+    }
+}
+
+impl<R: EntropySource> RngSeed<R>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    /// Create a new instance of [`RngSeed`] from a `u64` value. The first 8 bytes of the seed are
+    /// the little endian bytes of `value`, so that 8 byte seeds round trip through their
+    /// alternate [`Display`](fmt::Display) form. Any remaining bytes are filled by expanding
+    /// `value` with `SplitMix64`.
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngSeed;
+    ///
+    /// let seed = RngSeed::<WyRand>::from_u64(42);
+    ///
+    /// assert_eq!(format!("{seed:#}"), "42");
+    /// ```
+    #[must_use]
+    pub fn from_u64(value: u64) -> Self {
+        Self::from_le_value(&value.to_le_bytes(), value)
+    }
+
+    /// Create a new instance of [`RngSeed`] from a `u32` value. The first 4 bytes of the seed are
+    /// the little endian bytes of `value`, so that [`RngSeed::as_u32`] returns it again. Any
+    /// remaining bytes are filled by expanding `value` with `SplitMix64`, as with
+    /// [`RngSeed::from_u64`].
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngSeed;
+    ///
+    /// let seed = RngSeed::<WyRand>::from_u32(42);
+    ///
+    /// assert_eq!(seed.as_bytes()[..4], 42u32.to_le_bytes());
+    /// assert_eq!(seed.as_u32(), 42);
+    /// ```
+    #[must_use]
+    pub fn from_u32(value: u32) -> Self {
+        Self::from_le_value(&value.to_le_bytes(), u64::from(value))
+    }
+
+    /// Creates a seed starting with the little endian bytes of a value, filling any remaining
+    /// bytes by expanding `state` with `SplitMix64`.
+    fn from_le_value(value: &[u8], mut state: u64) -> Self {
+        let mut seed = R::Seed::default();
+        let (head, tail) = {
+            let bytes = seed.as_mut();
+            let split = bytes.len().min(value.len());
+
+            bytes.split_at_mut(split)
+        };
+
+        head.copy_from_slice(&value[..head.len()]);
+
+        for chunk in tail.chunks_mut(8) {
+            chunk.copy_from_slice(&splitmix64(&mut state).to_le_bytes()[..chunk.len()]);
+        }
+
+        Self::from_seed(seed)
+    }
+
+    /// Create a new instance of [`RngSeed`] from a phrase typed in by players, such as
+    /// `"banana"`, for named seeds. The mapping is pinned and will never change between versions
+    /// or platforms, so a phrase always yields the same seed.
+    ///
+    /// The UTF-8 bytes of `phrase` are hashed with 64-bit FNV-1a, and the hash is expanded with
+    /// `SplitMix64` to fill the seed, 8 little endian bytes at a time. Unlike
+    /// [`RngSeed::from_u64`] and the hex [`Display`](fmt::Display) form, this is one way: the
+    /// phrase can't be recovered from the seed, so keep the phrase around to show it again. As
+    /// the hash is not cryptographic, phrases are only suitable for picking seeds, not for
+    /// keeping them secret.
+    ///
+    /// No Unicode normalization is applied, so phrases that look the same but are encoded
+    /// differently, such as a precomposed `"é"` and an `"e"` followed by a combining accent, yield
+    /// different seeds. Normalize the phrase beforehand if that matters.
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngSeed;
+    ///
+    /// assert_eq!(
+    ///     RngSeed::<WyRand>::from_phrase_hash("banana"),
+    ///     RngSeed::<WyRand>::from_phrase_hash("banana")
+    /// );
+    /// assert_ne!(
+    ///     RngSeed::<WyRand>::from_phrase_hash("banana"),
+    ///     RngSeed::<WyRand>::from_phrase_hash("Banana")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_phrase_hash(phrase: &str) -> Self {
+        let mut seed = R::Seed::default();
+        let mut state = hash_label(phrase);
+
+        for chunk in seed.as_mut().chunks_mut(8) {
+            chunk.copy_from_slice(&splitmix64(&mut state).to_le_bytes()[..chunk.len()]);
+        }
+
+        Self::from_seed(seed)
+    }
+}
+
+impl<R: EntropySource> RngSeed<R>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    /// Create a new instance of [`RngSeed`] from entropy, as with [`SeedSource::from_entropy`],
+    /// but handling an unavailable OS entropy source with the given [`SeedFallbackPolicy`].
+    /// Entropy is sourced from [`bevy_prng::ThreadLocalEntropy`] with the `thread_local_entropy`
+    /// feature, then from the OS, before the policy applies.
+    #[must_use]
+    pub fn from_entropy_with(policy: SeedFallbackPolicy) -> Self {
+        match policy {
+            SeedFallbackPolicy::Panic => Self::from_entropy(),
+            SeedFallbackPolicy::Deterministic => Self::from_fallible_entropy(fill_from_entropy),
+        }
+    }
+
+    /// Seeds from `fill`, falling back to the [`SeedFallbackPolicy::FALLBACK_SEED`] if it fails.
+    fn from_fallible_entropy(fill: impl FnOnce(&mut [u8]) -> Result<(), getrandom::Error>) -> Self {
+        let mut seed = R::Seed::default();
+
+        match fill(seed.as_mut()) {
+            Ok(()) => Self::from_seed(seed),
+            Err(_error) => {
+                FALLBACK_USED.store(true, AtomicOrdering::Relaxed);
+
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    "Unable to source entropy for seeding ({_error}), falling back to a fixed seed. \
+                    All sources seeded this way will produce the same output on every run!"
+                );
+
+                #[cfg(all(feature = "std", not(feature = "tracing")))]
+                std::eprintln!(
+                    "bevy_rand: Unable to source entropy for seeding ({_error}), falling back to a \
+                    fixed seed. All sources seeded this way will produce the same output on every run!"
+                );
+
+                Self::from_u64(SeedFallbackPolicy::FALLBACK_SEED)
+            }
+        }
+    }
+}
+
+/// Fills `dest` from [`bevy_prng::ThreadLocalEntropy`] with the `thread_local_entropy` feature,
+/// falling back to the OS entropy source if the thread local source can't be seeded.
+fn fill_from_entropy(dest: &mut [u8]) -> Result<(), getrandom::Error> {
+    #[cfg(feature = "thread_local_entropy")]
+    {
+        use bevy_prng::ThreadLocalEntropy;
+        use rand_core::RngCore;
+
+        if ThreadLocalEntropy::new().try_fill_bytes(dest).is_ok() {
+            return Ok(());
+        }
+    }
+
+    getrandom::getrandom(dest)
+}
+
+/// Whether a seed has fallen back to the [`SeedFallbackPolicy::FALLBACK_SEED`], reported by
+/// [`SeedFallbackPolicy::fallback_used`] regardless of enabled features.
+static FALLBACK_USED: AtomicBool = AtomicBool::new(false);
+
+/// Policy for seeding sources from entropy when no OS entropy source is available, such as on
+/// locked down platforms. Configured for the [`Global`](crate::global::Global) source with
+/// [`crate::plugin::EntropyPlugin::with_seed_fallback`], or used directly with
+/// [`RngSeed::from_entropy_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SeedFallbackPolicy {
+    /// Panic if entropy can't be sourced, as with [`SeedSource::from_entropy`].
+    #[default]
+    Panic,
+    /// Fall back to a fixed seed derived from [`SeedFallbackPolicy::FALLBACK_SEED`] with
+    /// [`RngSeed::from_u64`]. Sources seeded this way are not random, producing the same output
+    /// on every run, so the fallback is always reported: it is logged as an error with the
+    /// `tracing` feature, or printed to stderr without it when `std` is available, and
+    /// [`SeedFallbackPolicy::fallback_used`] returns `true` from then on.
+    Deterministic,
+}
+
+impl SeedFallbackPolicy {
+    /// The value fallback seeds are derived from.
+    pub const FALLBACK_SEED: u64 = 0x6265_7679_5F72_616E;
+
+    /// Returns whether any seed has fallen back to the [`SeedFallbackPolicy::FALLBACK_SEED`]
+    /// since the program started, so that the fallback can be checked for without logging.
+    #[inline]
+    pub fn fallback_used() -> bool {
+        FALLBACK_USED.load(AtomicOrdering::Relaxed)
+    }
+}
+
+impl<R: EntropySource> RngSeed<R> {
+    /// Returns the bytes of the seed.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        self.seed.as_ref()
+    }
+
+    /// Returns the seed as a `u64`, interpreting its bytes as little endian so that the value is
+    /// the same on all platforms. Only available for 8 byte seeds, such as those of
+    /// [`bevy_prng::WyRand`], for which it is the inverse of [`RngSeed::from_u64`].
+    #[inline]
+    pub fn as_u64(&self) -> u64
+    where
+        R::Seed: FixedLenSeed<8>,
+    {
+        u64::from_le_bytes(self.seed.to_array())
+    }
+
+    /// Returns the first 4 bytes of the seed as a little endian `u32`, so that the value is the
+    /// same on all platforms. This is the inverse of [`RngSeed::from_u32`] for seeds of any length.
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{RngSeed, SeedSource};
+    ///
+    /// let seed = RngSeed::<WyRand>::from_seed([1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// assert_eq!(seed.as_u32(), 0x0403_0201);
+    /// ```
+    #[inline]
+    pub fn as_u32(&self) -> u32
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        let mut bytes = [0; 4];
+        let head = &self.seed.as_ref()[..4];
+
+        bytes.copy_from_slice(head);
+
+        u32::from_le_bytes(bytes)
+    }
+
+    /// Derives a `u64` seed for an external library, such as a noise generator, from this seed
+    /// and `label`. The derivation is pure and stable across versions and platforms, so the same
+    /// seed and label always derive the same value, while different labels derive unrelated
+    /// values. No RNG is advanced.
+    ///
+    /// ```
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::RngSeed;
+    ///
+    /// let seed = RngSeed::<WyRand>::from_u64(42);
+    ///
+    /// assert_ne!(
+    ///     seed.derive_noise_seed_u64("terrain"),
+    ///     seed.derive_noise_seed_u64("caves")
+    /// );
+    /// ```
+    pub fn derive_noise_seed_u64(&self, label: &str) -> u64
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        mix_u64(self.seed.as_ref(), hash_label(label))
+    }
+
+    /// Derives a `u32` seed for an external library from this seed and `label`, as the upper
+    /// half of [`RngSeed::derive_noise_seed_u64`].
+    #[inline]
+    pub fn derive_noise_seed_u32(&self, label: &str) -> u32
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        (self.derive_noise_seed_u64(label) >> 32) as u32
+    }
+
+    /// Derives a seed for a different algorithm `B` from this seed, mixing every byte of this
+    /// seed together with the type path of `B`, so that deriving for different algorithms from
+    /// the same seed gives unrelated seeds. This is a derivation, not a truncation or extension
+    /// of the bytes, so seeds of any length can be derived from one another, and as the mixer is
+    /// fixed, the same seed always derives the same seed across versions and platforms.
+    ///
+    /// ```
+    /// use bevy_prng::{ChaCha8Rng, WyRand};
+    /// use bevy_rand::prelude::RngSeed;
+    ///
+    /// let seed = RngSeed::<ChaCha8Rng>::from_phrase_hash("level 1");
+    ///
+    /// assert_eq!(seed.derive_for::<WyRand>(), seed.derive_for::<WyRand>());
+    /// ```
+    #[must_use]
+    pub fn derive_for<B: EntropySource>(&self) -> RngSeed<B>
+    where
+        R::Seed: AsRef<[u8]>,
+        B::Seed: Sync + Send + Clone,
+    {
+        let mut hasher = StableHasher::default();
+
+        hasher.write(b"bevy_rand::derive_for:");
+        hasher.write(B::type_path().as_bytes());
+
+        let mut seed = B::Seed::default();
+
+        mix_into(self.seed.as_ref(), hasher.finish(), seed.as_mut());
+
+        RngSeed::from_seed(seed)
+    }
+}
+
+/// Error for when a slice of bytes is not the length of the seed it is converted into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedLengthError {
+    /// The length of the seed in bytes.
+    pub expected: usize,
+    /// The length of the slice.
+    pub found: usize,
+}
+
+impl fmt::Display for SeedLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "seed is {} bytes long, expected {} bytes",
+            self.found, self.expected
+        )
+    }
+}
+
+impl core::error::Error for SeedLengthError {}
+
+/// Copies the bytes of a seed from a slice of exactly the seed's length.
+///
+/// ```
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{prelude::RngSeed, seed::SeedLengthError};
+///
+/// let seed = RngSeed::<WyRand>::try_from(&[1, 2, 3, 4, 5, 6, 7, 8][..]).unwrap();
+///
+/// assert_eq!(seed.as_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+/// assert_eq!(
+///     RngSeed::<WyRand>::try_from(&[1, 2, 3][..]),
+///     Err(SeedLengthError { expected: 8, found: 3 })
+/// );
+/// ```
+impl<R: EntropySource> TryFrom<&[u8]> for RngSeed<R>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    type Error = SeedLengthError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let mut seed = R::Seed::default();
+
+        if seed.as_mut().len() != bytes.len() {
+            return Err(SeedLengthError {
+                expected: seed.as_mut().len(),
+                found: bytes.len(),
+            });
+        }
+
+        seed.as_mut().copy_from_slice(bytes);
+
+        Ok(Self::from_seed(seed))
+    }
+}
+
+/// Seeds with a fixed length of `N` bytes, for viewing seeds as integers of the same size.
+pub trait FixedLenSeed<const N: usize> {
+    /// Returns a copy of the seed's bytes.
+    fn to_array(&self) -> [u8; N];
+}
+
+impl<const N: usize> FixedLenSeed<N> for [u8; N] {
+    #[inline]
+    fn to_array(&self) -> [u8; N] {
+        *self
+    }
+}
+
+/// Formats the seed as lowercase hex bytes. With the alternate flag (`{:#}`), 8 byte seeds are
+/// formatted as their little endian `u64` value instead, as accepted by [`RngSeed::from_u64`].
+impl<R: EntropySource> fmt::Display for RngSeed<R>
+where
+    R::Seed: Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Seeds are only guaranteed to expose their bytes mutably, so format a copy.
+        let mut seed = self.seed.clone();
+        let bytes: &[u8] = seed.as_mut();
+
+        if f.alternate() {
+            if let Ok(bytes) = <[u8; 8]>::try_from(bytes) {
+                return write!(f, "{}", u64::from_le_bytes(bytes));
+            }
+        }
+
+        bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
 impl<R: EntropySource> Default for RngSeed<R>
 where
     R::Seed: Sync + Send + Clone,
 {
     #[inline]
     fn default() -> Self {
-        Self::from_entropy()
+        Self::from_entropy_with(SeedFallbackPolicy::default())
     }
 }
 
@@ -112,47 +628,966 @@ where
     }
 }
 
-#[cfg(test)]
-mod tests {
-    #[cfg(feature = "serialize")]
-    #[test]
-    fn reflection_serialization_round_trip_works() {
-        use super::*;
-
-        use bevy_prng::WyRand;
-        use bevy_reflect::{
-            serde::{TypedReflectDeserializer, TypedReflectSerializer},
-            FromReflect, GetTypeRegistration, TypeRegistry,
-        };
-        use ron::to_string;
-        use serde::de::DeserializeSeed;
-
-        let mut registry = TypeRegistry::default();
-        registry.register::<RngSeed<WyRand>>();
-        registry.register::<[u8; 8]>();
+/// A set of seeds drawn up front from a source, handed out by stable index to entities spawned
+/// later. As all seeds are drawn at once, the seed each entity receives depends only on its
+/// index, not on when or in what order the entities end up being spawned.
+///
+/// Reserve seeds immediately from an [`Entropy`] with [`ReservedSeeds::reserve`], or with
+/// [`crate::commands::RngEntityCommands::reserve_seeds`], which draws the seeds when the command
+/// is applied and stores them as a resource. The [`Global`](crate::global::Global) source can be
+/// used with either, via [`GlobalEntropy`](crate::global::GlobalEntropy) or
+/// [`GlobalSource`](crate::global::GlobalSource) respectively. With the `serialize` feature, the
+/// reservation can be saved along with the rest of the game state.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{GlobalEntropy, ReservedSeeds};
+///
+/// #[derive(Component)]
+/// struct Enemy(usize);
+///
+/// fn load_level(mut commands: Commands, mut global: GlobalEntropy<WyRand>) {
+///     commands.insert_resource(ReservedSeeds::reserve(&mut global, 10));
+/// }
+///
+/// fn spawn_enemy(mut commands: Commands, reserved: Res<ReservedSeeds<WyRand>>) {
+///     let index = 3;
+///
+///     if let Some(seed) = reserved.take(index) {
+///         commands.spawn((Enemy(index), seed));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Resource)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(bound(
+        serialize = "R::Seed: serde::Serialize",
+        deserialize = "R::Seed: serde::Deserialize<'de>"
+    ))
+)]
+pub struct ReservedSeeds<R: EntropySource> {
+    seeds: Vec<R::Seed>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    rng: PhantomData<R>,
+}
 
-        let registered_type = RngSeed::<WyRand>::get_type_registration();
+impl<R: EntropySource> ReservedSeeds<R>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    /// Reserves `count` seeds by forking them sequentially from `source`.
+    pub fn reserve(source: &mut Entropy<R>, count: usize) -> Self {
+        Self {
+            seeds: (0..count).map(|_| source.fork_inner_seed()).collect(),
+            rng: PhantomData,
+        }
+    }
 
-        let val = RngSeed::<WyRand>::from_seed(u64::MAX.to_ne_bytes());
+    /// Returns the seed reserved for `index`, or `None` if `index` is out of range. The same
+    /// seed is returned each time for a given `index`.
+    #[inline]
+    pub fn take(&self, index: usize) -> Option<RngSeed<R>> {
+        self.seeds.get(index).cloned().map(RngSeed::from_seed)
+    }
 
-        let ser = TypedReflectSerializer::new(&val, &registry);
+    /// Returns the number of reserved seeds.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.seeds.len()
+    }
 
-        let serialized = to_string(&ser).unwrap();
+    /// Returns whether no seeds are reserved.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.seeds.is_empty()
+    }
+}
 
-        assert_eq!(&serialized, "(seed:(255,255,255,255,255,255,255,255))");
+/// A lightweight token carrying a seed forked for an `R` source, for embedding in events and
+/// messages instead of a whole forked RNG. Only the seed is carried, which for PRNGs with large
+/// states such as the ChaCha family is much smaller than the RNG itself, and the RNG is only
+/// reconstructed by the consumer with [`SeedToken::into_rng`]. Tokens are `Copy` when the seed
+/// is, and can be serialized with the `serialize` feature.
+///
+/// Forked with [`ForkableSeed::fork_token`](crate::traits::ForkableSeed::fork_token). The RNG
+/// reconstructed from the token is the same as the one
+/// [`ForkableInnerRng::fork_inner`](crate::traits::ForkableInnerRng::fork_inner) would have forked
+/// from the source in the same state, so switching an event from a forked RNG to a token keeps
+/// its outcomes the same.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use bevy_rand::prelude::{ForkableSeed, GlobalEntropy, SeedToken};
+/// use rand_core::RngCore;
+///
+/// #[derive(Event)]
+/// struct Turn {
+///     rng: SeedToken<ChaCha8Rng>,
+/// }
+///
+/// fn start_turn(mut commands: Commands, mut global: GlobalEntropy<ChaCha8Rng>) {
+///     commands.trigger(Turn {
+///         rng: global.fork_token(),
+///     });
+/// }
+///
+/// fn take_turn(trigger: Trigger<Turn>) {
+///     let mut rng = trigger.event().rng.into_rng();
+///
+///     println!("Rolled a {}", rng.next_u32() % 6 + 1);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(bound(
+        serialize = "R::Seed: serde::Serialize",
+        deserialize = "R::Seed: serde::Deserialize<'de>"
+    ))
+)]
+pub struct SeedToken<R: EntropySource> {
+    seed: R::Seed,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    rng: PhantomData<R>,
+}
 
-        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+impl<R: EntropySource> Copy for SeedToken<R> where R::Seed: Copy {}
 
-        let de = TypedReflectDeserializer::new(&registered_type, &registry);
+impl<R: EntropySource> SeedToken<R> {
+    /// Creates a token carrying `seed`.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: R::Seed) -> Self {
+        Self {
+            seed,
+            rng: PhantomData,
+        }
+    }
 
-        let value = de.deserialize(&mut deserializer).unwrap();
+    /// Returns the seed carried by the token.
+    #[inline]
+    pub fn seed(&self) -> &R::Seed {
+        &self.seed
+    }
 
-        assert!(value.is_dynamic());
-        assert!(value.represents::<RngSeed<WyRand>>());
-        assert!(value.try_downcast_ref::<RngSeed<WyRand>>().is_none());
+    /// Consumes the token, returning the carried seed.
+    #[inline]
+    pub fn into_seed(self) -> R::Seed {
+        self.seed
+    }
 
-        let recreated = RngSeed::<WyRand>::from_reflect(value.as_ref()).unwrap();
+    /// Consumes the token, reconstructing the RNG from the carried seed.
+    #[inline]
+    pub fn into_rng(self) -> R {
+        R::from_seed(self.seed)
+    }
+}
 
-        assert_eq!(val.clone_seed(), recreated.clone_seed());
+/// A dispenser of child seeds, handing out the i-th seed deterministically from a base seed and
+/// a counter, without maintaining any RNG state. Similar in concept to a `SeedSequence`.
+///
+/// Each seed is the base seed and index mixed through a fixed `SplitMix64` based mixer, so the
+/// seed at a given index is stable across platforms and versions, and can be looked up with
+/// [`SeedDispenser::seed_at`] without advancing the dispenser. The counter is reflected, so it
+/// persists along with the rest of the game state.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{RngCommandsExt, SeedDispenser};
+///
+/// #[derive(Component)]
+/// struct Spawner;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((Spawner, SeedDispenser::<WyRand>::new([7; 8])));
+/// }
+///
+/// fn spawn_minion(mut commands: Commands, q_spawner: Query<Entity, With<Spawner>>) {
+///     for spawner in q_spawner.iter() {
+///         let minion = commands.spawn_empty().id();
+///
+///         commands
+///             .rng_entity::<WyRand>(minion)
+///             .reseed_from_dispenser(spawner);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Component, Reflect)]
+pub struct SeedDispenser<R: EntropySource>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    base: R::Seed,
+    counter: u64,
+    #[reflect(ignore)]
+    rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> SeedDispenser<R>
+where
+    R::Seed: Sync + Send + Clone,
+{
+    /// Creates a new dispenser from a `base` seed, starting at index `0`.
+    #[inline]
+    #[must_use]
+    pub fn new(base: R::Seed) -> Self {
+        Self {
+            base,
+            counter: 0,
+            rng: PhantomData,
+        }
+    }
+
+    /// Returns the seed at the current index, advancing the dispenser to the next index.
+    pub fn next_seed(&mut self) -> R::Seed {
+        let seed = self.seed_at(self.counter);
+
+        self.counter = self.counter.wrapping_add(1);
+
+        seed
+    }
+
+    /// Returns the seed at `index`, without advancing the dispenser.
+    #[inline]
+    pub fn seed_at(&self, index: u64) -> R::Seed {
+        mix_seed::<R>(&self.base, index)
+    }
+
+    /// Returns the index of the next seed to be dispensed.
+    #[inline]
+    pub fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Returns the base seed of the dispenser.
+    #[inline]
+    pub fn base(&self) -> &R::Seed {
+        &self.base
+    }
+}
+
+/// Per-label counters for isolated forks, which derive child seeds from a source's [`RngSeed`]
+/// rather than drawing from its [`Entropy`]. Each isolated fork for a label mixes the seed, the
+/// label and the label's counter, and then increments the counter, so bursty consumers such as
+/// wave spawners never advance the source itself: other systems drawing from it see the same
+/// sequence no matter how many isolated forks were made. Counters are kept per label, so they are
+/// shared between sources, and are serializable so they persist with the rest of the game state.
+///
+/// Isolated forks are usually made with [`RngEntityCommands::fork_isolated`], which adds this
+/// resource if it is missing.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{ForkCounters, GlobalSeed};
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn spawn_wave(
+///     mut commands: Commands,
+///     global: GlobalSeed<WyRand>,
+///     mut counters: ResMut<ForkCounters>,
+/// ) {
+///     for _ in 0..5 {
+///         commands.spawn((Enemy, counters.fork_isolated(*global, "wave")));
+///     }
+/// }
+/// ```
+///
+/// [`RngEntityCommands::fork_isolated`]: crate::commands::RngEntityCommands::fork_isolated
+#[derive(Debug, Clone, Default, PartialEq, Eq, Resource, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Debug, PartialEq, Resource)]
+pub struct ForkCounters {
+    counters: BTreeMap<String, u64>,
+}
+
+impl ForkCounters {
+    /// Returns a seed derived from `seed`, `label` and the label's counter, incrementing the
+    /// counter. The same seed, label and counter always derive the same seed.
+    pub fn fork_isolated<R: EntropySource>(&mut self, seed: &RngSeed<R>, label: &str) -> RngSeed<R>
+    where
+        R::Seed: Sync + Send + Clone,
+    {
+        let counter = self.counters.entry(label.into()).or_default();
+        let seed = mix_seed::<R>(&mix_seed::<R>(seed.get_seed(), hash_label(label)), *counter);
+
+        *counter = counter.wrapping_add(1);
+
+        RngSeed::from_seed(seed)
+    }
+
+    /// Returns the number of isolated forks made for `label`.
+    #[inline]
+    pub fn counter(&self, label: &str) -> u64 {
+        self.counters.get(label).copied().unwrap_or_default()
+    }
+}
+
+/// Type data for operating on [`RngSeed`] components without knowing their RNG algorithm, such
+/// as from a developer console or when recording replays. Registered for each `RngSeed<R>` by
+/// [`crate::plugin::RngTypesPlugin`].
+#[derive(Clone)]
+pub struct ReflectSeedSource {
+    rng_type_path: fn() -> &'static str,
+    rng_short_type_path: fn() -> &'static str,
+    seed_len: fn() -> usize,
+    from_u64: fn(u64) -> Vec<u8>,
+    seed: fn(&World, Entity) -> Option<Vec<u8>>,
+    seeds: fn(&World) -> Vec<(Entity, Vec<u8>)>,
+    reseed: fn(&mut World, Entity, &[u8]),
+    remove: fn(&mut World, Entity),
+    global: fn(&World) -> Option<Entity>,
+    fork_from_global: fn(&mut World) -> Option<Vec<u8>>,
+    from_entropy: fn() -> Vec<u8>,
+}
+
+impl ReflectSeedSource {
+    /// Returns the [`ReflectSeedSource`] of every RNG algorithm registered in the world's
+    /// [`AppTypeRegistry`], ordered by type path.
+    pub fn registered(world: &World) -> Vec<Self> {
+        let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+            return Vec::new();
+        };
+
+        let registry = registry.read();
+        let mut sources: Vec<_> = registry
+            .iter_with_data::<Self>()
+            .map(|(_, source)| source.clone())
+            .collect();
+
+        sources.sort_by_key(Self::rng_type_path);
+
+        sources
+    }
+
+    /// Returns the full type path of the RNG algorithm, such as `bevy_prng::ChaCha8Rng`.
+    #[inline]
+    pub fn rng_type_path(&self) -> &'static str {
+        (self.rng_type_path)()
+    }
+
+    /// Returns the short type path of the RNG algorithm, such as `ChaCha8Rng`.
+    #[inline]
+    pub fn rng_short_type_path(&self) -> &'static str {
+        (self.rng_short_type_path)()
+    }
+
+    /// Returns the length of the algorithm's seeds in bytes.
+    #[inline]
+    pub fn seed_len(&self) -> usize {
+        (self.seed_len)()
+    }
+
+    /// Returns the bytes of a seed of this algorithm expanded from `value`, as with
+    /// [`RngSeed::from_u64`].
+    #[inline]
+    pub fn seed_from_u64(&self, value: u64) -> Vec<u8> {
+        (self.from_u64)(value)
+    }
+
+    /// Returns the bytes of a seed of this algorithm sourced from entropy, as with
+    /// [`SeedSource::from_entropy`].
+    #[inline]
+    pub fn seed_from_entropy(&self) -> Vec<u8> {
+        (self.from_entropy)()
+    }
+
+    /// Returns the bytes of `entity`'s seed, if it has a source of this algorithm.
+    #[inline]
+    pub fn seed(&self, world: &World, entity: Entity) -> Option<Vec<u8>> {
+        (self.seed)(world, entity)
+    }
+
+    /// Returns every entity with a source of this algorithm, along with the bytes of its seed.
+    #[inline]
+    pub fn seeds(&self, world: &World) -> Vec<(Entity, Vec<u8>)> {
+        (self.seeds)(world)
+    }
+
+    /// Reseeds `entity` with a seed of this algorithm made from `bytes`, applying any commands
+    /// queued by reseeding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is not [`ReflectSeedSource::seed_len`] bytes long, or if `entity` does
+    /// not exist.
+    #[inline]
+    pub fn reseed(&self, world: &mut World, entity: Entity, bytes: &[u8]) {
+        (self.reseed)(world, entity, bytes);
+    }
+
+    /// Removes `entity`'s source of this algorithm, along with its seed if it has one, applying
+    /// any commands queued by the removal. Does nothing if `entity` does not exist.
+    #[inline]
+    pub fn remove(&self, world: &mut World, entity: Entity) {
+        (self.remove)(world, entity);
+    }
+
+    /// Returns the [`Global`] source entity of this algorithm, if there is one.
+    #[inline]
+    pub fn global(&self, world: &World) -> Option<Entity> {
+        (self.global)(world)
+    }
+
+    /// Returns the bytes of a seed forked from the [`Global`] source of this algorithm, if there
+    /// is one.
+    #[inline]
+    pub fn fork_from_global(&self, world: &mut World) -> Option<Vec<u8>> {
+        (self.fork_from_global)(world)
+    }
+}
+
+impl<R: EntropySource> FromType<RngSeed<R>> for ReflectSeedSource
+where
+    R::Seed: EntropySeed,
+{
+    fn from_type() -> Self {
+        Self {
+            rng_type_path: <R as TypePath>::type_path,
+            rng_short_type_path: <R as TypePath>::short_type_path,
+            seed_len: || R::Seed::default().as_ref().len(),
+            from_u64: |value| RngSeed::<R>::from_u64(value).as_ref().to_vec(),
+            seed: |world, entity| {
+                world
+                    .get::<RngSeed<R>>(entity)
+                    .map(|seed| seed.as_ref().to_vec())
+            },
+            seeds: |world| {
+                world
+                    .try_query::<(Entity, &RngSeed<R>)>()
+                    .map(|mut query| {
+                        query
+                            .iter(world)
+                            .map(|(entity, seed)| (entity, seed.as_ref().to_vec()))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            },
+            reseed: |world, entity, bytes| {
+                let mut seed = R::Seed::default();
+
+                seed.as_mut().copy_from_slice(bytes);
+
+                world
+                    .entity_mut(entity)
+                    .insert(RngSeed::<R>::from_seed(seed));
+                world.flush();
+            },
+            remove: |world, entity| {
+                if let Ok(mut entity) = world.get_entity_mut(entity) {
+                    entity.remove::<(RngSeed<R>, Entropy<R>, SeedlessSource<R>)>();
+                    world.flush();
+                }
+            },
+            global: |world| {
+                world
+                    .try_query_filtered::<Entity, (With<RngSeed<R>>, With<Global>)>()
+                    .and_then(|mut query| query.iter(world).next())
+            },
+            fork_from_global: |world| {
+                world
+                    .query_filtered::<&mut Entropy<R>, With<Global>>()
+                    .iter_mut(world)
+                    .next()
+                    .map(|mut global| global.fork_inner_seed().as_ref().to_vec())
+            },
+            from_entropy: || RngSeed::<R>::from_entropy().as_ref().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn reflection_serialization_round_trip_works() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+        use bevy_reflect::{
+            serde::{TypedReflectDeserializer, TypedReflectSerializer},
+            FromReflect, GetTypeRegistration, TypeRegistry,
+        };
+        use ron::to_string;
+        use serde::de::DeserializeSeed;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<RngSeed<WyRand>>();
+        registry.register::<[u8; 8]>();
+
+        let registered_type = RngSeed::<WyRand>::get_type_registration();
+
+        let val = RngSeed::<WyRand>::from_seed(u64::MAX.to_ne_bytes());
+
+        let ser = TypedReflectSerializer::new(&val, &registry);
+
+        let serialized = to_string(&ser).unwrap();
+
+        assert_eq!(&serialized, "(seed:(255,255,255,255,255,255,255,255))");
+
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+
+        let de = TypedReflectDeserializer::new(&registered_type, &registry);
+
+        let value = de.deserialize(&mut deserializer).unwrap();
+
+        assert!(value.is_dynamic());
+        assert!(value.represents::<RngSeed<WyRand>>());
+        assert!(value.try_downcast_ref::<RngSeed<WyRand>>().is_none());
+
+        let recreated = RngSeed::<WyRand>::from_reflect(value.as_ref()).unwrap();
+
+        assert_eq!(val.clone_seed(), recreated.clone_seed());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn reserved_seeds_serde_round_trip() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+
+        let mut source = Entropy::<WyRand>::from_seed([5; 8]);
+        let reserved = ReservedSeeds::<WyRand>::reserve(&mut source, 3);
+
+        let serialized = ron::to_string(&reserved).unwrap();
+        let recreated: ReservedSeeds<WyRand> = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(reserved, recreated);
+        assert_eq!(recreated.len(), 3);
+    }
+
+    #[test]
+    fn seed_tokens_reconstruct_forked_rngs() {
+        use super::*;
+
+        use crate::traits::{ForkableInnerRng, ForkableSeed};
+        use bevy_prng::{ChaCha8Rng, WyRand};
+        use rand_core::RngCore;
+
+        let mut source = Entropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut embedded = source.clone();
+
+        // The consumer side RNG is the same as embedding a whole forked RNG would have given
+        let mut from_token = source.fork_token().into_rng();
+        let mut forked = embedded.fork_inner();
+
+        assert_eq!(from_token.next_u64(), forked.next_u64());
+        assert_eq!(from_token, forked);
+        assert_eq!(source, embedded);
+
+        let token = source.fork_token();
+
+        assert_eq!(Entropy::from(token), Entropy::new(embedded.fork_inner()));
+
+        // Tokens are `Copy` when seeds are
+        let token = Entropy::<WyRand>::from_seed([8; 8]).fork_token();
+        let copy = token;
+
+        assert_eq!(token.into_seed(), *copy.seed());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn seed_token_serde_round_trip() {
+        use super::*;
+
+        use crate::traits::ForkableSeed;
+        use bevy_prng::WyRand;
+
+        let token = Entropy::<WyRand>::from_seed([9; 8]).fork_token();
+
+        let serialized = ron::to_string(&token).unwrap();
+        let recreated: SeedToken<WyRand> = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(recreated, token);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn fork_counters_serde_round_trip() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+
+        let seed = RngSeed::<WyRand>::from_seed([6; 8]);
+        let mut counters = ForkCounters::default();
+
+        counters.fork_isolated(&seed, "wave");
+        counters.fork_isolated(&seed, "wave");
+        counters.fork_isolated(&seed, "loot");
+
+        let serialized = ron::to_string(&counters).unwrap();
+        let mut recreated: ForkCounters = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(recreated, counters);
+        assert_eq!(recreated.counter("wave"), 2);
+        assert_eq!(
+            recreated.fork_isolated(&seed, "wave"),
+            counters.fork_isolated(&seed, "wave")
+        );
+    }
+
+    #[test]
+    fn dispenser_seed_at_matches_next_seed() {
+        use super::*;
+
+        use alloc::vec::Vec;
+        use bevy_prng::ChaCha8Rng;
+
+        let mut dispenser = SeedDispenser::<ChaCha8Rng>::new([1; 32]);
+        let other = SeedDispenser::<ChaCha8Rng>::new([2; 32]);
+
+        let dispensed: Vec<_> = (0..8).map(|_| dispenser.next_seed()).collect();
+
+        assert_eq!(dispenser.counter(), 8);
+
+        for (index, seed) in dispensed.iter().enumerate() {
+            assert_eq!(&dispenser.seed_at(index as u64), seed);
+            assert_ne!(&other.seed_at(index as u64), seed);
+        }
+
+        // Different base seeds must not simply give offset copies of the same sequence
+        let diff =
+            |a: [u8; 32], b: [u8; 32]| -> Vec<u8> { a.iter().zip(b).map(|(a, b)| a ^ b).collect() };
+
+        assert_ne!(
+            diff(dispensed[0], other.seed_at(0)),
+            diff(dispensed[1], other.seed_at(1))
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn dispenser_counter_persists_across_save_and_load() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+        use bevy_reflect::{
+            serde::{TypedReflectDeserializer, TypedReflectSerializer},
+            FromReflect, GetTypeRegistration, TypeRegistry,
+        };
+        use serde::de::DeserializeSeed;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<SeedDispenser<WyRand>>();
+        registry.register::<[u8; 8]>();
+
+        let mut dispenser = SeedDispenser::<WyRand>::new([9; 8]);
+
+        dispenser.next_seed();
+        dispenser.next_seed();
+
+        let serialized =
+            ron::to_string(&TypedReflectSerializer::new(&dispenser, &registry)).unwrap();
+
+        let registered_type = SeedDispenser::<WyRand>::get_type_registration();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let value = TypedReflectDeserializer::new(&registered_type, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut loaded = SeedDispenser::<WyRand>::from_reflect(value.as_ref()).unwrap();
+
+        assert_eq!(loaded, dispenser);
+        assert_eq!(loaded.next_seed(), dispenser.next_seed());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn seed512_reflection_serialization_round_trip() {
+        use super::*;
+
+        use bevy_prng::{Seed512, Xoshiro512StarStar};
+        use bevy_reflect::{
+            serde::{TypedReflectDeserializer, TypedReflectSerializer},
+            FromReflect, GetTypeRegistration, TypeRegistry,
+        };
+        use ron::to_string;
+        use serde::de::DeserializeSeed;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<RngSeed<Xoshiro512StarStar>>();
+        registry.register::<Seed512>();
+
+        let registered_type = RngSeed::<Xoshiro512StarStar>::get_type_registration();
+
+        let val = RngSeed::<Xoshiro512StarStar>::from_seed(Seed512::from([255; 64]));
+
+        let ser = TypedReflectSerializer::new(&val, &registry);
+
+        let serialized = to_string(&ser).unwrap();
+
+        let bytes = ["255"; 64].join(",");
+
+        assert_eq!(serialized, alloc::format!("(seed:({bytes}))"));
+
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+
+        let de = TypedReflectDeserializer::new(&registered_type, &registry);
+
+        let value = de.deserialize(&mut deserializer).unwrap();
+
+        let recreated = RngSeed::<Xoshiro512StarStar>::from_reflect(value.as_ref()).unwrap();
+
+        assert_eq!(val, recreated);
+    }
+
+    #[test]
+    fn seed512_reseeds_like_array_seeds() {
+        use super::*;
+
+        use bevy_ecs::world::World;
+        use bevy_prng::{Seed512, Xoshiro512StarStar};
+        use rand_core::RngCore;
+
+        let mut world = World::new();
+
+        let mut seed = Seed512::default();
+        seed.as_mut()[0] = 1;
+
+        let entity = world
+            .spawn(RngSeed::<Xoshiro512StarStar>::from_seed(seed.clone()))
+            .id();
+
+        world.flush();
+
+        let mut expected = Entropy::<Xoshiro512StarStar>::from_seed(seed);
+        let mut rng = world
+            .get::<Entropy<Xoshiro512StarStar>>(entity)
+            .unwrap()
+            .clone();
+
+        assert_eq!(rng.next_u64(), expected.next_u64());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn seeds_key_hash_maps() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+        use std::collections::HashMap;
+
+        let mut levels = HashMap::new();
+
+        levels.insert(RngSeed::<WyRand>::from_seed([1; 8]), "meadow");
+        levels.insert(RngSeed::<WyRand>::from_seed([2; 8]), "cavern");
+
+        assert_eq!(
+            levels.get(&RngSeed::<WyRand>::from_seed([2; 8])),
+            Some(&"cavern")
+        );
+        assert_eq!(levels.get(&RngSeed::<WyRand>::from_seed([3; 8])), None);
+    }
+
+    #[test]
+    fn seeds_sort_by_bytes() {
+        use super::*;
+
+        use alloc::{vec, vec::Vec};
+        use bevy_prng::ChaCha8Rng;
+
+        let mut a = [0; 32];
+        a[0] = 2;
+        let mut b = [0; 32];
+        b[31] = 9;
+
+        let mut seeds: Vec<_> = vec![a, [1; 32], b, [0; 32]]
+            .into_iter()
+            .map(RngSeed::<ChaCha8Rng>::from_seed)
+            .collect();
+
+        seeds.sort();
+
+        let sorted: Vec<_> = seeds.iter().map(RngSeed::clone_seed).collect();
+
+        assert_eq!(sorted, vec![[0; 32], b, [1; 32], a]);
+    }
+
+    #[test]
+    fn seeds_display_as_hex() {
+        use super::*;
+
+        use alloc::{format, string::ToString};
+        use bevy_prng::{ChaCha8Rng, WyRand};
+
+        let wyrand = RngSeed::<WyRand>::from_seed([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+        let chacha = RngSeed::<ChaCha8Rng>::from_seed([0xa5; 32]);
+
+        assert_eq!(format!("{wyrand}"), "0123456789abcdef");
+        assert_eq!(format!("{wyrand:#}"), 0xefcd_ab89_6745_2301u64.to_string());
+        assert_eq!(format!("{chacha}"), "a5".repeat(32));
+        // Only 8 byte seeds have a u64 form
+        assert_eq!(format!("{chacha:#}"), "a5".repeat(32));
+    }
+
+    #[test]
+    fn phrase_hashes_are_pinned() {
+        use super::*;
+
+        use alloc::format;
+        use bevy_prng::{ChaCha8Rng, WyRand};
+
+        let wyrand = |phrase| format!("{}", RngSeed::<WyRand>::from_phrase_hash(phrase));
+        let chacha = |phrase| format!("{}", RngSeed::<ChaCha8Rng>::from_phrase_hash(phrase));
+
+        assert_eq!(wyrand("banana"), "42acefa5a0734d37");
+        assert_eq!(wyrand("minecraft"), "97bfa2adbb3df36e");
+        assert_eq!(
+            chacha("banana"),
+            "42acefa5a0734d37879810784e8b4e927c01fe0d6459a2c1a7641bfb120cbe77"
+        );
+        assert_eq!(
+            chacha("minecraft"),
+            "97bfa2adbb3df36efbd514eca8111f8c3e2d9a2752f40cadbb9ada624f08c4c9"
+        );
+
+        // Phrases are hashed as given, without Unicode normalization
+        assert_eq!(wyrand("caf\u{e9}"), "391022020024db78");
+        assert_eq!(wyrand("cafe\u{301}"), "aa1ad447f4e6e3bc");
+    }
+
+    #[test]
+    fn u64_view_is_little_endian() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+
+        let seed = RngSeed::<WyRand>::from_seed([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+
+        assert_eq!(seed.as_u64(), 0xefcd_ab89_6745_2301);
+        assert_eq!(seed.as_bytes(), &seed.clone_seed());
+        assert_eq!(RngSeed::<WyRand>::from_u64(seed.as_u64()), seed);
+    }
+
+    #[test]
+    fn u32_view_is_little_endian() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+
+        let seed = RngSeed::<WyRand>::from_u32(0x6745_2301);
+
+        // The value is followed by the start of its `SplitMix64` expansion
+        assert_eq!(
+            seed.as_bytes(),
+            [0x01, 0x23, 0x45, 0x67, 0xc4, 0x01, 0xff, 0x92]
+        );
+        assert_eq!(seed.as_u32(), 0x6745_2301);
+        assert_eq!(RngSeed::<WyRand>::from_u32(seed.as_u32()), seed);
+    }
+
+    #[test]
+    fn u64_display_round_trips_through_from_u64() {
+        use super::*;
+
+        use alloc::format;
+        use bevy_prng::{ChaCha8Rng, WyRand};
+
+        for value in [0, 42, u64::MAX] {
+            let seed = RngSeed::<WyRand>::from_u64(value);
+            let parsed: u64 = format!("{seed:#}").parse().unwrap();
+
+            assert_eq!(parsed, value);
+            assert_eq!(RngSeed::<WyRand>::from_u64(parsed), seed);
+        }
+
+        let chacha = RngSeed::<ChaCha8Rng>::from_u64(42);
+
+        assert_eq!(chacha[..8], 42u64.to_le_bytes());
+        assert_ne!(chacha[8..], [0; 24]);
+    }
+
+    #[test]
+    fn unavailable_entropy_falls_back_to_fixed_seed() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+
+        let seed = RngSeed::<WyRand>::from_fallible_entropy(|_| Err(getrandom::Error::UNSUPPORTED));
+
+        assert_eq!(seed, RngSeed::from_u64(SeedFallbackPolicy::FALLBACK_SEED));
+        assert!(SeedFallbackPolicy::fallback_used());
+
+        // With entropy available, the fallback is not used
+        let seed = RngSeed::<WyRand>::from_fallible_entropy(|dest| {
+            dest.fill(7);
+            Ok(())
+        });
+
+        assert_eq!(seed.clone_seed(), [7; 8]);
+
+        // The chained entropy sources are available here
+        assert_ne!(
+            RngSeed::<WyRand>::from_entropy_with(SeedFallbackPolicy::Deterministic),
+            RngSeed::from_u64(SeedFallbackPolicy::FALLBACK_SEED)
+        );
+    }
+
+    #[test]
+    fn seeds_derive_for_other_algorithms() {
+        use super::*;
+
+        use bevy_prng::{ChaCha8Rng, WyRand, Xoshiro256PlusPlus, Xoshiro256StarStar};
+
+        let chacha = RngSeed::<ChaCha8Rng>::from_seed(core::array::from_fn(|i| i as u8));
+
+        let wyrand = chacha.derive_for::<WyRand>();
+        let xoshiro = chacha.derive_for::<Xoshiro256StarStar>();
+
+        // Derivations are pinned, as changing them would change the seeds of existing saves
+        assert_eq!(wyrand.clone_seed(), [13, 71, 176, 154, 51, 136, 123, 217]);
+        assert_eq!(
+            xoshiro.clone_seed(),
+            [
+                50, 19, 81, 113, 161, 11, 23, 232, 248, 150, 38, 199, 165, 190, 196, 152, 65, 110,
+                187, 232, 176, 17, 27, 146, 69, 157, 91, 106, 179, 106, 183, 197
+            ]
+        );
+
+        // Different targets derive unrelated seeds, even with the same seed length
+        assert_ne!(wyrand.as_bytes(), &xoshiro[..8]);
+        assert_ne!(
+            xoshiro.as_bytes(),
+            chacha.derive_for::<Xoshiro256PlusPlus>().as_bytes()
+        );
+        assert_ne!(chacha.derive_for::<ChaCha8Rng>(), chacha);
+    }
+
+    #[test]
+    fn seeds_convert_from_slices_of_the_same_length() {
+        use super::*;
+
+        use bevy_prng::WyRand;
+
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        assert_eq!(
+            RngSeed::<WyRand>::try_from(&bytes[..8]),
+            Ok(RngSeed::from_seed([1, 2, 3, 4, 5, 6, 7, 8]))
+        );
+        assert_eq!(
+            RngSeed::<WyRand>::try_from(&bytes[..]),
+            Err(SeedLengthError {
+                expected: 8,
+                found: 9
+            })
+        );
+        assert_eq!(
+            RngSeed::<WyRand>::try_from(&bytes[..0]),
+            Err(SeedLengthError {
+                expected: 8,
+                found: 0
+            })
+        );
     }
 }
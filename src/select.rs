@@ -0,0 +1,227 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use bevy_ecs::entity::Entity;
+use rand_core::RngCore;
+
+pub use crate::util::{stable_key, stable_sample};
+
+/// Extension trait for choosing or shuffling items yielded from queries in a way that is stable
+/// regardless of the order the items are provided in.
+///
+/// Query iteration order depends on archetype and table layout, which is not stable between runs
+/// or after spawning/despawning entities. Feeding query iterators directly into `rand`'s
+/// `IteratorRandom::choose` or `SliceRandom::shuffle` therefore makes the output depend on that
+/// order, even with a deterministically seeded RNG. The methods on this trait first sort the
+/// provided items by a stable key (the [`Entity`] bits by default) before sampling, so the same
+/// seed and the same logical set of items always yields the same result.
+///
+/// ## Cost
+///
+/// All methods collect the items into a [`Vec`]. `choose_stable*` then runs in `O(n)` via a
+/// partial selection, while `shuffle_stable*` sorts in `O(n log n)` before an `O(n)` shuffle.
+/// Index sampling is always done over `u64` so the output is the same on 32-bit and 64-bit
+/// platforms.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{GlobalEntropy, RngSelectExt};
+///
+/// #[derive(Component)]
+/// struct Enemy(u32);
+///
+/// fn pick_target(q_enemies: Query<(Entity, &Enemy)>, mut rng: GlobalEntropy<WyRand>) {
+///     if let Some((entity, enemy)) = rng.choose_stable(q_enemies.iter()) {
+///         println!("Targeting {entity} with {} hp", enemy.0);
+///     }
+/// }
+/// ```
+pub trait RngSelectExt: RngCore {
+    /// Choose a single item from `items`, ordering candidates by their [`Entity`] bits
+    /// before sampling. Returns `None` if `items` is empty.
+    fn choose_stable<T>(
+        &mut self,
+        items: impl IntoIterator<Item = (Entity, T)>,
+    ) -> Option<(Entity, T)> {
+        choose_by(self, items, by_entity)
+    }
+
+    /// Choose a single item from `items`, ordering candidates by the key returned by `key`
+    /// before sampling. Candidates with equal keys are ordered by their [`Entity`] bits, so
+    /// the ordering is always total. Returns `None` if `items` is empty.
+    fn choose_stable_by_key<T, K: Ord>(
+        &mut self,
+        items: impl IntoIterator<Item = (Entity, T)>,
+        mut key: impl FnMut(&(Entity, T)) -> K,
+    ) -> Option<(Entity, T)> {
+        choose_by(self, items, |a, b| {
+            key(a).cmp(&key(b)).then_with(|| by_entity(a, b))
+        })
+    }
+
+    /// Shuffle `items` into a new [`Vec`], ordering them by their [`Entity`] bits before
+    /// shuffling.
+    fn shuffle_stable<T>(
+        &mut self,
+        items: impl IntoIterator<Item = (Entity, T)>,
+    ) -> Vec<(Entity, T)> {
+        shuffle_by(self, items, by_entity)
+    }
+
+    /// Shuffle `items` into a new [`Vec`], ordering them by the key returned by `key` before
+    /// shuffling. Items with equal keys are ordered by their [`Entity`] bits, so the ordering is
+    /// always total.
+    fn shuffle_stable_by_key<T, K: Ord>(
+        &mut self,
+        items: impl IntoIterator<Item = (Entity, T)>,
+        mut key: impl FnMut(&(Entity, T)) -> K,
+    ) -> Vec<(Entity, T)> {
+        shuffle_by(self, items, |a, b| {
+            key(a).cmp(&key(b)).then_with(|| by_entity(a, b))
+        })
+    }
+}
+
+impl<R: RngCore + ?Sized> RngSelectExt for R {}
+
+#[inline]
+fn by_entity<T>(a: &(Entity, T), b: &(Entity, T)) -> Ordering {
+    a.0.to_bits().cmp(&b.0.to_bits())
+}
+
+fn choose_by<R: RngCore + ?Sized, T>(
+    rng: &mut R,
+    items: impl IntoIterator<Item = (Entity, T)>,
+    compare: impl FnMut(&(Entity, T), &(Entity, T)) -> Ordering,
+) -> Option<(Entity, T)> {
+    let mut items: Vec<_> = items.into_iter().collect();
+
+    if items.is_empty() {
+        return None;
+    }
+
+    let index = gen_index(rng, items.len());
+
+    // Only the chosen position needs to be in its sorted place, so a partial
+    // selection avoids paying for a full sort.
+    items.select_nth_unstable_by(index, compare);
+
+    Some(items.swap_remove(index))
+}
+
+fn shuffle_by<R: RngCore + ?Sized, T>(
+    rng: &mut R,
+    items: impl IntoIterator<Item = (Entity, T)>,
+    compare: impl FnMut(&(Entity, T), &(Entity, T)) -> Ordering,
+) -> Vec<(Entity, T)> {
+    let mut items: Vec<_> = items.into_iter().collect();
+
+    items.sort_unstable_by(compare);
+
+    // Fisher-Yates shuffle over the now stably ordered items.
+    for i in (1..items.len()).rev() {
+        let j = gen_index(rng, i + 1);
+        items.swap(i, j);
+    }
+
+    items
+}
+
+/// Generates an unbiased index in the range `0..ubound`, always sampling over `u64`
+/// so that the output does not depend on the platform's pointer width.
+#[inline]
+pub(crate) fn gen_index<R: RngCore + ?Sized>(rng: &mut R, ubound: usize) -> usize {
+    let range = ubound as u64;
+    // Widening multiply with rejection of the biased zone (Lemire's method).
+    let zone = (range << range.leading_zeros()).wrapping_sub(1);
+
+    loop {
+        let product = u128::from(rng.next_u64()) * u128::from(range);
+
+        if (product as u64) <= zone {
+            return (product >> 64) as usize;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn entities() -> Vec<(Entity, u32)> {
+        (0..10u32)
+            .map(|i| (Entity::from_raw(i * 3 + 1), i))
+            .collect()
+    }
+
+    #[test]
+    fn shuffle_is_order_independent() {
+        let forward = entities();
+        let mut reversed = entities();
+        reversed.reverse();
+
+        let mut rng1 = WyRand::from_seed([7; 8]);
+        let mut rng2 = WyRand::from_seed([7; 8]);
+
+        let a = rng1.shuffle_stable(forward);
+        let b = rng2.shuffle_stable(reversed);
+
+        assert_eq!(a, b);
+        // Both sources should have been advanced by the same amount
+        assert_eq!(rng1, rng2);
+    }
+
+    #[test]
+    fn shuffle_by_key_is_order_independent() {
+        let forward = entities();
+        let mut interleaved = entities();
+        interleaved.rotate_left(4);
+
+        let mut rng1 = WyRand::from_seed([3; 8]);
+        let mut rng2 = WyRand::from_seed([3; 8]);
+
+        // Keys collide, so ties must be broken by the entity bits.
+        let a = rng1.shuffle_stable_by_key(forward, |(_, value)| *value % 3);
+        let b = rng2.shuffle_stable_by_key(interleaved, |(_, value)| *value % 3);
+
+        assert_eq!(a, b);
+
+        let mut sorted: Vec<_> = a.iter().map(|(_, value)| *value).collect();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn choose_is_order_independent() {
+        let forward = entities();
+        let mut reversed = entities();
+        reversed.reverse();
+
+        let mut rng1 = WyRand::from_seed([42; 8]);
+        let mut rng2 = WyRand::from_seed([42; 8]);
+
+        for _ in 0..20 {
+            assert_eq!(
+                rng1.choose_stable(forward.iter().copied()),
+                rng2.choose_stable(reversed.iter().copied())
+            );
+        }
+    }
+
+    #[test]
+    fn choose_from_empty() {
+        let mut rng = WyRand::from_seed([1; 8]);
+        let before = rng.clone();
+
+        assert_eq!(rng.choose_stable(vec![] as Vec<(Entity, u32)>), None);
+        // No entropy should be consumed for an empty set
+        assert_eq!(rng, before);
+    }
+}
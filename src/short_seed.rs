@@ -0,0 +1,329 @@
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+use bevy_prng::{EntropySeed, EntropySource};
+use rand_core::RngCore;
+
+use crate::{
+    select::gen_index,
+    util::{hash_label, mix_into},
+};
+
+/// A short, shareable code standing in for a seed, such as `"7KQ2MX"`, for world seeds that
+/// players can read out to each other. Codes are drawn from an alphabet, by default
+/// [`ShortSeed::DEFAULT_ALPHABET`], and displayed with a trailing checksum character, so that
+/// mistyped codes are rejected by [`ShortSeed::parse`] rather than quietly giving another world.
+///
+/// [`ShortSeed::expand`] turns a code into a full seed for any algorithm, with a stable mixer
+/// that will never change between versions or platforms, so a code always expands to the same
+/// seed.
+///
+/// # Collisions
+///
+/// Expanding a code is a compression by design: a code can only ever give as many distinct
+/// seeds as there are codes, such as about a billion for 6 characters of the default alphabet,
+/// far fewer than an algorithm has seeds. Different codes expand to different seeds in all
+/// practical cases, but as the expansion is a hash, two codes may in principle collide, with a
+/// chance of about one in 2^64 for any given pair when expanding to seeds of 8 bytes or more.
+/// Games expecting many players should therefore still expect some to share codes by chance,
+/// and should use full seeds for anything where seeds must be unguessable.
+///
+/// ```
+/// use bevy_prng::WyRand;
+/// use bevy_rand::short_seed::ShortSeed;
+/// use rand_core::SeedableRng;
+///
+/// let code = ShortSeed::random(&mut WyRand::from_seed([1; 8]), 6);
+/// let shared = code.to_string();
+///
+/// assert_eq!(shared.len(), 7);
+///
+/// let parsed: ShortSeed = shared.parse().unwrap();
+///
+/// assert_eq!(parsed.expand::<WyRand>(), code.expand::<WyRand>());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShortSeed {
+    code: String,
+    alphabet: &'static str,
+}
+
+/// The error returned when parsing a [`ShortSeed`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortSeedError {
+    /// The code was empty, or had only a checksum character.
+    TooShort,
+    /// The code had a character not in the alphabet.
+    InvalidCharacter(char),
+    /// The checksum character did not match the rest of the code, so the code was mistyped.
+    ChecksumMismatch,
+}
+
+impl fmt::Display for ShortSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort => f.write_str("short seed is too short"),
+            Self::InvalidCharacter(character) => {
+                write!(f, "short seed has an invalid character: {character:?}")
+            }
+            Self::ChecksumMismatch => f.write_str("short seed checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for ShortSeedError {}
+
+impl ShortSeed {
+    /// The default alphabet of Crockford's base 32, leaving out `I`, `L`, `O` and `U` to avoid
+    /// codes that are easily misread or misheard.
+    pub const DEFAULT_ALPHABET: &'static str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    /// Generates a code of `len` characters from [`ShortSeed::DEFAULT_ALPHABET`], drawn from
+    /// `rng`.
+    pub fn random(rng: &mut (impl RngCore + ?Sized), len: usize) -> Self {
+        Self::random_with_alphabet(rng, len, Self::DEFAULT_ALPHABET)
+    }
+
+    /// Generates a code of `len` characters from `alphabet`, drawn from `rng`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` has fewer than two characters, isn't ASCII, or has any character
+    /// more than once.
+    pub fn random_with_alphabet(
+        rng: &mut (impl RngCore + ?Sized),
+        len: usize,
+        alphabet: &'static str,
+    ) -> Self {
+        assert_valid_alphabet(alphabet);
+
+        let code = (0..len)
+            .map(|_| char::from(alphabet.as_bytes()[gen_index(rng, alphabet.len())]))
+            .collect();
+
+        Self { code, alphabet }
+    }
+
+    /// Parses a code with its checksum character, as displayed, from
+    /// [`ShortSeed::DEFAULT_ALPHABET`]. Lowercase letters are accepted, as the alphabet has only
+    /// uppercase ones.
+    pub fn parse(code: &str) -> Result<Self, ShortSeedError> {
+        Self::parse_with_alphabet(code, Self::DEFAULT_ALPHABET)
+    }
+
+    /// Parses a code with its checksum character, as displayed, from `alphabet`. If the alphabet
+    /// has no lowercase letters, lowercase letters are accepted as their uppercase ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` has fewer than two characters, isn't ASCII, or has any character
+    /// more than once.
+    pub fn parse_with_alphabet(code: &str, alphabet: &'static str) -> Result<Self, ShortSeedError> {
+        assert_valid_alphabet(alphabet);
+
+        let uppercase = !alphabet.bytes().any(|byte| byte.is_ascii_lowercase());
+        let mut code: String = code
+            .chars()
+            .map(|character| match uppercase {
+                true => character.to_ascii_uppercase(),
+                false => character,
+            })
+            .collect();
+
+        if let Some(invalid) = code
+            .chars()
+            .find(|character| !character.is_ascii() || !alphabet.contains(*character))
+        {
+            return Err(ShortSeedError::InvalidCharacter(invalid));
+        }
+
+        let checksum = code.pop().ok_or(ShortSeedError::TooShort)?;
+
+        if code.is_empty() {
+            return Err(ShortSeedError::TooShort);
+        }
+
+        let seed = Self { code, alphabet };
+
+        if seed.checksum() != checksum {
+            return Err(ShortSeedError::ChecksumMismatch);
+        }
+
+        Ok(seed)
+    }
+
+    /// Returns the code, without its checksum character.
+    #[inline]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the checksum character of the code, a character of the alphabet picked by a
+    /// stable hash of the code, so a mistyped code is rejected unless the checksum happens to
+    /// match, a chance of one in the alphabet's length.
+    pub fn checksum(&self) -> char {
+        let index = hash_label(&self.code) % self.alphabet.len() as u64;
+
+        char::from(self.alphabet.as_bytes()[index as usize])
+    }
+
+    /// Expands the code into a seed for `R`, by mixing the code's characters into a seed of the
+    /// right length with the same stable mixer as [`crate::seed::RngSeed::derive_for`]. The same
+    /// code always expands to the same seed, whichever alphabet it was drawn from. See the
+    /// [collisions](ShortSeed#collisions) section for the limits of the expansion.
+    pub fn expand<R: EntropySource>(&self) -> R::Seed
+    where
+        R::Seed: EntropySeed,
+    {
+        let mut seed = R::Seed::default();
+
+        mix_into(
+            self.code.as_bytes(),
+            hash_label("bevy_rand::short_seed"),
+            seed.as_mut(),
+        );
+
+        seed
+    }
+}
+
+impl fmt::Display for ShortSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.code, self.checksum())
+    }
+}
+
+impl FromStr for ShortSeed {
+    type Err = ShortSeedError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::parse(code)
+    }
+}
+
+fn assert_valid_alphabet(alphabet: &str) {
+    let bytes = alphabet.as_bytes();
+
+    assert!(
+        bytes.len() >= 2
+            && alphabet.is_ascii()
+            && bytes
+                .iter()
+                .enumerate()
+                .all(|(index, byte)| !bytes[index + 1..].contains(byte)),
+        "short seed alphabets must have at least two unique ASCII characters: {alphabet:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use bevy_prng::{ChaCha8Rng, WyRand};
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn codes_round_trip() {
+        let mut rng = WyRand::from_seed([4; 8]);
+
+        for len in 1..12 {
+            let code = ShortSeed::random(&mut rng, len);
+            let shared = code.to_string();
+
+            assert_eq!(code.code().len(), len);
+            assert_eq!(shared.len(), len + 1);
+            assert!(shared
+                .chars()
+                .all(|character| ShortSeed::DEFAULT_ALPHABET.contains(character)));
+
+            let parsed = ShortSeed::parse(&shared).unwrap();
+
+            assert_eq!(parsed, code);
+            assert_eq!(ShortSeed::parse(&shared.to_lowercase()).unwrap(), code);
+            assert_eq!(parsed.expand::<ChaCha8Rng>(), code.expand::<ChaCha8Rng>());
+        }
+
+        let binary = ShortSeed::random_with_alphabet(&mut rng, 16, "01");
+
+        assert_eq!(
+            ShortSeed::parse_with_alphabet(&binary.to_string(), "01"),
+            Ok(binary)
+        );
+    }
+
+    #[test]
+    fn mistyped_codes_are_rejected() {
+        let shared = ShortSeed::random(&mut WyRand::from_seed([4; 8]), 6).to_string();
+        let checksum = shared.chars().last().unwrap();
+        let mistyped = ShortSeed::DEFAULT_ALPHABET
+            .chars()
+            .find(|&character| character != checksum)
+            .unwrap();
+
+        assert_eq!(
+            ShortSeed::parse(&alloc::format!("{}{mistyped}", &shared[..6])),
+            Err(ShortSeedError::ChecksumMismatch)
+        );
+        assert_eq!(
+            ShortSeed::parse("7KQ2MI"),
+            Err(ShortSeedError::InvalidCharacter('I'))
+        );
+        assert_eq!(ShortSeed::parse(""), Err(ShortSeedError::TooShort));
+        assert_eq!(ShortSeed::parse("7"), Err(ShortSeedError::TooShort));
+    }
+
+    #[test]
+    fn pinned_expansion() {
+        let code = ShortSeed::parse("7kq2mxd").unwrap();
+
+        assert_eq!(code.code(), "7KQ2MX");
+        assert_eq!(code.checksum(), 'D');
+        assert_eq!(
+            code.expand::<WyRand>(),
+            [219, 193, 218, 194, 224, 144, 246, 3]
+        );
+        assert_eq!(
+            code.expand::<ChaCha8Rng>(),
+            [
+                219, 193, 218, 194, 224, 144, 246, 3, 55, 105, 129, 86, 231, 173, 96, 208, 48, 210,
+                226, 68, 166, 115, 156, 146, 201, 174, 187, 115, 201, 187, 141, 101
+            ]
+        );
+    }
+
+    #[test]
+    fn short_seeds_seed_sources() {
+        use bevy_app::App;
+        use bevy_ecs::prelude::{Entity, With};
+
+        use crate::{
+            commands::RngCommandsExt, global::Global, plugin::EntropyPlugin, seed::RngSeed,
+            traits::SeedSource,
+        };
+
+        let code = ShortSeed::parse("7KQ2MXD").unwrap();
+        let expected = RngSeed::<WyRand>::from_seed(code.expand::<WyRand>());
+
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<WyRand>::with_short_seed(&code));
+
+        let world = app.world_mut();
+        let global = world
+            .query_filtered::<Entity, With<Global>>()
+            .single(world)
+            .unwrap();
+        let entity = world.spawn(RngSeed::<WyRand>::from_seed([1; 8])).id();
+
+        world
+            .commands()
+            .rng_entity::<WyRand>(entity)
+            .reseed_short(&code);
+        world.flush();
+
+        assert_eq!(world.get::<RngSeed<WyRand>>(global), Some(&expected));
+        assert_eq!(world.get::<RngSeed<WyRand>>(entity), Some(&expected));
+    }
+}
@@ -0,0 +1,264 @@
+use alloc::vec::Vec;
+
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_prng::EntropySource;
+use bevy_reflect::Reflect;
+use rand::seq::SliceRandom;
+use rand_core::RngCore;
+
+use crate::component::Entropy;
+
+/// A bag of items drawn without replacement in a shuffled order, reshuffling once every item has
+/// been drawn, with a provided RNG source such as an entity's own [`Entropy`]. Useful for decks of
+/// cards, spawn rotations and the like, where every item should come up once per cycle.
+///
+/// The bag is shuffled on the first draw after it is created or [`reset`](ShuffleBag::reset), and
+/// again on the first draw after it is exhausted, so the same seed and number of draws always
+/// yield the same sequence. The current order and position in the bag are reflected and
+/// serialized, so a saved bag resumes drawing exactly where it left off.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{Entropy, ShuffleBag};
+///
+/// #[derive(Component)]
+/// struct Deck(ShuffleBag<u8>);
+///
+/// fn deal(mut q_decks: Query<(&mut Deck, &mut Entropy<WyRand>)>) {
+///     for (mut deck, mut rng) in q_decks.iter_mut() {
+///         let card = deck.0.draw_with_entity_rng(&mut rng);
+///
+///         println!("Dealt: {card}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(
+        try_from = "ShuffleBagState<T>",
+        bound(deserialize = "T: serde::Deserialize<'de>")
+    )
+)]
+#[reflect(Component)]
+pub struct ShuffleBag<T> {
+    items: Vec<T>,
+    order: Vec<usize>,
+    cursor: usize,
+}
+
+impl<T> ShuffleBag<T> {
+    /// Creates a new bag from `items`, to be shuffled on the first draw. Returns `None` if
+    /// `items` is empty.
+    pub fn new(items: Vec<T>) -> Option<Self> {
+        if items.is_empty() {
+            return None;
+        }
+
+        let order = (0..items.len()).collect();
+        let cursor = items.len();
+
+        Some(Self {
+            items,
+            order,
+            cursor,
+        })
+    }
+
+    /// Returns the items in the bag, in the order they were added.
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the number of items in the bag.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the bag has no items. A bag is never empty, so this only returns `true`
+    /// if the bag was emptied through reflection.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of items left to draw before the bag is reshuffled.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.order.len().saturating_sub(self.cursor)
+    }
+
+    /// Returns an iterator over the items left to draw before the bag is reshuffled, in the
+    /// order they will be drawn.
+    pub fn peek_remaining(&self) -> impl Iterator<Item = &T> {
+        self.order
+            .get(self.cursor..)
+            .unwrap_or_default()
+            .iter()
+            .map(|&index| &self.items[index])
+    }
+
+    /// Puts all items back in the bag in their original order, to be shuffled on the next
+    /// draw, as if the bag was newly created.
+    pub fn reset(&mut self) {
+        self.order = (0..self.items.len()).collect();
+        self.cursor = self.items.len();
+    }
+
+    /// Draws the next item from the bag with the provided RNG source, first reshuffling the
+    /// bag with it if every item has been drawn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bag was made invalid by modifying it through reflection.
+    pub fn draw(&mut self, rng: &mut impl RngCore) -> &T {
+        if self.cursor >= self.order.len() {
+            self.order.shuffle(rng);
+            self.cursor = 0;
+        }
+
+        let index = self.order[self.cursor];
+
+        self.cursor += 1;
+
+        &self.items[index]
+    }
+
+    /// Draws the next item from the bag with an [`Entropy`] source, typically the one belonging
+    /// to the same entity as this bag. This is the same as [`ShuffleBag::draw`], but allows
+    /// passing `&mut Mut<Entropy<R>>` from a query without explicit derefs.
+    #[inline]
+    pub fn draw_with_entity_rng<R: EntropySource + 'static>(&mut self, rng: &mut Entropy<R>) -> &T {
+        self.draw(rng)
+    }
+}
+
+/// The serialized shape of [`ShuffleBag`], validated upon deserialization.
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+struct ShuffleBagState<T> {
+    items: Vec<T>,
+    order: Vec<usize>,
+    cursor: usize,
+}
+
+#[cfg(feature = "serialize")]
+impl<T> TryFrom<ShuffleBagState<T>> for ShuffleBag<T> {
+    type Error = &'static str;
+
+    fn try_from(value: ShuffleBagState<T>) -> Result<Self, Self::Error> {
+        let len = value.items.len();
+
+        if len == 0 {
+            return Err("a shuffle bag must have at least one item");
+        }
+
+        let mut seen = alloc::vec![false; len];
+
+        let is_permutation = value.order.len() == len
+            && value
+                .order
+                .iter()
+                .all(|&index| index < len && !core::mem::replace(&mut seen[index], true));
+
+        if !is_permutation || value.cursor > len {
+            return Err("the shuffle bag order is invalid");
+        }
+
+        Ok(Self {
+            items: value.items,
+            order: value.order,
+            cursor: value.cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn full_cycles_are_permutations() {
+        let mut bag = ShuffleBag::new((0..10u8).collect()).unwrap();
+        let mut rng = Entropy::<WyRand>::from_seed([3; 8]);
+
+        assert!(ShuffleBag::<u8>::new(Vec::new()).is_none());
+
+        for _ in 0..3 {
+            let mut cycle: Vec<u8> = (0..10)
+                .map(|_| *bag.draw_with_entity_rng(&mut rng))
+                .collect();
+
+            assert_eq!(bag.remaining(), 0);
+
+            cycle.sort_unstable();
+
+            assert_eq!(cycle, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn reshuffles_are_deterministic() {
+        let draws = |seed| {
+            let mut bag = ShuffleBag::new(alloc::vec!['a', 'b', 'c', 'd']).unwrap();
+            let mut rng = WyRand::from_seed(seed);
+
+            (0..10).map(|_| *bag.draw(&mut rng)).collect::<Vec<_>>()
+        };
+
+        let sequence = draws([5; 8]);
+
+        assert_eq!(sequence, draws([5; 8]));
+        assert_ne!(sequence[..4], sequence[4..8]);
+
+        let mut bag = ShuffleBag::new(alloc::vec!['a', 'b', 'c', 'd']).unwrap();
+        let mut rng = WyRand::from_seed([5; 8]);
+
+        bag.draw(&mut rng);
+
+        assert_eq!(
+            bag.peek_remaining().copied().collect::<Vec<_>>(),
+            sequence[1..4]
+        );
+
+        bag.reset();
+
+        assert_eq!(
+            bag,
+            ShuffleBag::new(alloc::vec!['a', 'b', 'c', 'd']).unwrap()
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_round_trip_resumes_mid_bag() {
+        let mut bag = ShuffleBag::new(alloc::vec![1u8, 2, 3, 4, 5]).unwrap();
+        let mut rng = WyRand::from_seed([9; 8]);
+
+        bag.draw(&mut rng);
+        bag.draw(&mut rng);
+
+        let serialized = ron::to_string(&bag).unwrap();
+        let mut deserialized: ShuffleBag<u8> = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(bag, deserialized);
+
+        let mut resumed_rng = rng.clone();
+
+        for _ in 0..8 {
+            assert_eq!(bag.draw(&mut rng), deserialized.draw(&mut resumed_rng));
+        }
+
+        // Invalid bags are rejected
+        assert!(ron::from_str::<ShuffleBag<u8>>("(items:[],order:[],cursor:0)").is_err());
+        assert!(ron::from_str::<ShuffleBag<u8>>("(items:[1,2],order:[0,0],cursor:0)").is_err());
+        assert!(ron::from_str::<ShuffleBag<u8>>("(items:[1,2],order:[1,0],cursor:3)").is_err());
+    }
+}
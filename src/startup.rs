@@ -0,0 +1,268 @@
+use core::marker::PhantomData;
+
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::{
+    prelude::{Commands, ResMut, Resource, With},
+    system::SystemParam,
+};
+use bevy_prng::{EntropySeed, EntropySource};
+
+use crate::{
+    component::Entropy,
+    global::{Global, GlobalEntropy},
+    seed::{ForkCounters, RngSeed},
+    traits::ForkableSeed,
+};
+
+/// Resource of a block of seeds reserved from the [`Global`] `R` source when the
+/// [`StartupSeedBudgetPlugin`] is built, serving keyed forks during the startup schedules.
+///
+/// Forks are derived from the reserved seed, the key and the number of forks made with that key
+/// so far, as with [`ForkCounters::fork_isolated`], so they don't depend on the order systems run
+/// in. Moving a spawning system between `PreStartup`, `Startup` and `PostStartup`, or adding
+/// other startup systems that fork from the global source, leaves the seeds of its entities
+/// unchanged as long as its keys stay the same. Removed in [`First`], once startup has completed.
+#[derive(Resource)]
+pub struct StartupSeedBudget<R: EntropySource>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    seed: RngSeed<R>,
+    counters: ForkCounters,
+}
+
+impl<R: EntropySource> StartupSeedBudget<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// Creates a budget serving forks derived from `seed`.
+    #[inline]
+    pub fn new(seed: RngSeed<R>) -> Self {
+        Self {
+            seed,
+            counters: ForkCounters::default(),
+        }
+    }
+
+    /// Returns the seed reserved for the budget.
+    #[inline]
+    pub fn seed(&self) -> &RngSeed<R> {
+        &self.seed
+    }
+
+    /// Returns a seed derived from the reserved seed, `key` and the number of forks made with
+    /// `key` so far. Keys are best named after the system forking and what it forks for, such as
+    /// `"spawn_level::enemies"`, so that each system has its own sequence of seeds.
+    pub fn fork_keyed(&mut self, key: &str) -> RngSeed<R> {
+        self.counters.fork_isolated(&self.seed, key)
+    }
+}
+
+/// A [`SystemParam`] for forking seeds by key while the [`StartupSeedBudget`] for `R` exists,
+/// falling back to forking from the [`Global`] `R` source as usual once it is removed after
+/// startup, or if the [`StartupSeedBudgetPlugin`] was never added.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::startup::StartupForks;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn spawn_enemies(mut commands: Commands, mut forks: StartupForks<WyRand>) {
+///     for _ in 0..5 {
+///         commands.spawn((Enemy, forks.fork_startup_keyed("spawn_enemies")));
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct StartupForks<'w, R: EntropySource + 'static>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    budget: Option<ResMut<'w, StartupSeedBudget<R>>>,
+    global: GlobalEntropy<'w, R>,
+}
+
+impl<R: EntropySource + 'static> StartupForks<'_, R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// Returns whether forks are currently served by the [`StartupSeedBudget`].
+    #[inline]
+    pub fn is_budgeted(&self) -> bool {
+        self.budget.is_some()
+    }
+
+    /// Forks a seed with [`StartupSeedBudget::fork_keyed`] while the budget exists, or from the
+    /// [`Global`] source otherwise, ignoring `key`.
+    pub fn fork_startup_keyed(&mut self, key: &str) -> RngSeed<R> {
+        match self.budget.as_mut() {
+            Some(budget) => budget.fork_keyed(key),
+            None => self.global.fork_seed(),
+        }
+    }
+}
+
+/// Plugin for serving keyed forks during the startup schedules from a [`StartupSeedBudget`],
+/// reserved by forking a seed once from the [`Global`] `R` source when the plugin is built, so
+/// entities spawned at startup get the same seeds whichever startup schedule spawns them. Forks
+/// made with [`StartupForks`] fall back to the global source once startup has completed.
+///
+/// Must be added after the [`crate::plugin::EntropyPlugin`] for `R`. Forks made directly from
+/// the global source, such as with [`crate::global::GlobalEntropy`], are unaffected, so only
+/// systems using [`StartupForks`] are independent of the startup order.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{prelude::EntropyPlugin, startup::StartupSeedBudgetPlugin};
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::with_seed([1; 8]),
+///     StartupSeedBudgetPlugin::<WyRand>::default(),
+/// ));
+/// ```
+pub struct StartupSeedBudgetPlugin<R: EntropySource>(PhantomData<R>);
+
+impl<R: EntropySource> Default for StartupSeedBudgetPlugin<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for StartupSeedBudgetPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        let seed = world
+            .query_filtered::<&mut Entropy<R>, With<Global>>()
+            .single_mut(world)
+            .expect("StartupSeedBudgetPlugin must be added after the EntropyPlugin for its RNG")
+            .fork_seed();
+
+        app.insert_resource(StartupSeedBudget::new(seed))
+            .add_systems(First, end_startup_budget::<R>);
+    }
+}
+
+fn end_startup_budget<R: EntropySource + 'static>(
+    mut commands: Commands,
+    budget: Option<ResMut<StartupSeedBudget<R>>>,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    if budget.is_some() {
+        commands.remove_resource::<StartupSeedBudget<R>>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_app::{PostStartup, PreStartup, Startup, Update};
+    use bevy_ecs::{prelude::Component, schedule::ScheduleLabel};
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use crate::plugin::EntropyPlugin;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Spawned(u32);
+
+    fn spawn_keyed(mut commands: Commands, mut forks: StartupForks<WyRand>) {
+        for index in 0..3 {
+            commands.spawn((Spawned(index), forks.fork_startup_keyed("spawn_keyed")));
+        }
+    }
+
+    fn spawn_unkeyed(mut commands: Commands, mut global: GlobalEntropy<WyRand>) {
+        commands.spawn((Spawned(10), global.fork_seed()));
+    }
+
+    fn seeds(
+        budgeted: bool,
+        keyed: impl ScheduleLabel,
+        unkeyed: impl ScheduleLabel,
+    ) -> Vec<(u32, RngSeed<WyRand>)> {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]));
+
+        if budgeted {
+            app.add_plugins(StartupSeedBudgetPlugin::<WyRand>::default());
+        }
+
+        app.add_systems(keyed, spawn_keyed)
+            .add_systems(unkeyed, spawn_unkeyed);
+
+        app.update();
+
+        let world = app.world_mut();
+        let mut seeds: Vec<_> = world
+            .query::<(&Spawned, &RngSeed<WyRand>)>()
+            .iter(world)
+            .filter(|(spawned, _)| spawned.0 < 10)
+            .map(|(spawned, seed)| (spawned.0, seed.clone()))
+            .collect();
+
+        seeds.sort_by_key(|(index, _)| *index);
+
+        seeds
+    }
+
+    #[test]
+    fn startup_seeds_ignore_the_startup_order() {
+        let pre_startup = seeds(true, PreStartup, Startup);
+
+        assert_eq!(pre_startup.len(), 3);
+        assert_eq!(seeds(true, Startup, PreStartup), pre_startup);
+        assert_eq!(seeds(true, PostStartup, PreStartup), pre_startup);
+
+        // Without the budget, the keyed forks depend on the other system's draw
+        assert_ne!(
+            seeds(false, PreStartup, Startup),
+            seeds(false, Startup, PreStartup)
+        );
+    }
+
+    #[test]
+    fn forks_fall_back_to_the_global_after_startup() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            StartupSeedBudgetPlugin::<WyRand>::default(),
+        ))
+        .add_systems(Update, spawn_keyed);
+
+        assert!(app.world().contains_resource::<StartupSeedBudget<WyRand>>());
+
+        app.update();
+
+        assert!(!app.world().contains_resource::<StartupSeedBudget<WyRand>>());
+
+        // The budget's reservation is the first fork, and the rest come from the global source
+        let mut global = Entropy::<WyRand>::from_seed([1; 8]);
+        let reserved = global.fork_seed();
+        let expected: Vec<_> = (0..3).map(|index| (index, global.fork_seed())).collect();
+
+        let world = app.world_mut();
+        let mut spawned: Vec<_> = world
+            .query::<(&Spawned, &RngSeed<WyRand>)>()
+            .iter(world)
+            .map(|(spawned, seed)| (spawned.0, seed.clone()))
+            .collect();
+
+        spawned.sort_by_key(|(index, _)| *index);
+
+        assert_eq!(spawned, expected);
+        assert!(spawned.iter().all(|(_, seed)| *seed != reserved));
+    }
+}
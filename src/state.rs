@@ -0,0 +1,119 @@
+use alloc::vec::Vec;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::{Commands, Entity, Query, With};
+use bevy_prng::EntropySource;
+use bevy_state::state::{OnEnter, States};
+
+use crate::{
+    global::Global,
+    seed::RngSeed,
+    traits::SeedSource,
+    util::{derive_seed, hash_label},
+};
+
+/// How a [`Global`] source should be reseeded when entering a state with [`StateSeedPlugin`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeedPolicy<R: EntropySource> {
+    /// Reseed with a fixed seed value.
+    Fixed(R::Seed),
+    /// Reseed with a random seed from the OS entropy source. This is not deterministic.
+    FromOsRng,
+    /// Reseed with a seed derived from the global source's current seed and a label. The
+    /// derivation is deterministic, so re-entering the state yields a new seed derived from the
+    /// previous one, with the same sequence of seeds for the same initial seed.
+    DeriveFromGlobal(&'static str),
+}
+
+/// Plugin for reseeding the [`Global`] `R` source whenever a configured state of `S` is entered.
+/// Multiple states can be configured, and one plugin can be added per PRNG algorithm.
+///
+/// As the reseed is performed by inserting a new [`RngSeed`] into the global source, any
+/// targets linked to the global source with [`crate::plugin::LinkedEntropySources`] will be
+/// reseeded in turn.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyPlugin, SeedPolicy, StateSeedPlugin};
+/// use bevy_state::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, States)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     NewRun,
+/// }
+///
+/// App::new()
+///     .add_plugins((
+///         EntropyPlugin::<WyRand>::with_seed(42u64.to_ne_bytes()),
+///         StateSeedPlugin::<GameState, WyRand>::new()
+///             .on_enter(GameState::NewRun, SeedPolicy::DeriveFromGlobal("run")),
+///     ));
+/// ```
+pub struct StateSeedPlugin<S: States, R: EntropySource> {
+    policies: Vec<(S, SeedPolicy<R>)>,
+}
+
+impl<S: States, R: EntropySource> StateSeedPlugin<S, R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// Creates a new plugin instance with no configured states.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            policies: Vec::new(),
+        }
+    }
+
+    /// Configures the global source to be reseeded with `policy` when entering `state`.
+    #[must_use]
+    pub fn on_enter(mut self, state: S, policy: SeedPolicy<R>) -> Self {
+        self.policies.push((state, policy));
+        self
+    }
+}
+
+impl<S: States, R: EntropySource> Default for StateSeedPlugin<S, R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: States, R: EntropySource + 'static> Plugin for StateSeedPlugin<S, R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        for (state, policy) in self.policies.iter().cloned() {
+            app.add_systems(OnEnter(state), reseed_global::<R>(policy));
+        }
+    }
+}
+
+fn reseed_global<R: EntropySource + 'static>(
+    policy: SeedPolicy<R>,
+) -> impl Fn(Commands, Query<(Entity, &RngSeed<R>), With<Global>>) + Send + Sync + 'static
+where
+    R::Seed: Send + Sync + Clone,
+{
+    move |mut commands, q_global| {
+        for (entity, seed) in q_global.iter() {
+            let seed = match &policy {
+                SeedPolicy::Fixed(seed) => RngSeed::<R>::from_seed(seed.clone()),
+                SeedPolicy::FromOsRng => RngSeed::<R>::from_entropy(),
+                SeedPolicy::DeriveFromGlobal(label) => {
+                    RngSeed::<R>::from_seed(derive_seed::<R>(seed.get_seed(), hash_label(label)))
+                }
+            };
+
+            commands.entity(entity).insert(seed);
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! A reproducible world of linked sources for profiling and testing seed propagation, built
+//! entirely through the public APIs, and doubling as an example of how to structure large RNG
+//! hierarchies.
+//!
+//! Sources are arranged in layers below the [`Global`] source. Each source in a layer is the
+//! parent of its own targets in the next layer, created with
+//! [`RngEntityCommands::fork_child`](crate::commands::RngEntityCommands::fork_child), so that
+//! reseeding the [`Global`] source cascades through every layer. As the propagation observers
+//! pick their sources and targets by component, layers alternate between two marker components,
+//! letting each parent be linked with a single [`seed_children_scoped`] entity observer however
+//! deep the hierarchy goes.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use bevy_app::App;
+use bevy_ecs::prelude::{Component, Entity, With, World};
+use bevy_prng::{EntropySeed, EntropySource};
+
+use crate::{
+    commands::RngCommandsExt, global::Global, observers::seed_children_scoped,
+    plugin::EntropyPlugin, seed::RngSeed, traits::SeedSource,
+};
+
+/// The shape of a world built by [`build_stress_world`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StressConfig {
+    /// The number of sources linked directly to the [`Global`] source.
+    pub sources: usize,
+    /// The number of targets linked to each source in every layer but the last.
+    pub targets_per_source: usize,
+    /// The number of layers below the [`Global`] source, including the first layer of
+    /// [`StressConfig::sources`].
+    pub depth: usize,
+    /// The seed of the [`Global`] source, expanded as with [`RngSeed::from_u64`].
+    pub seed: u64,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        Self {
+            sources: 8,
+            targets_per_source: 8,
+            depth: 3,
+            seed: 0,
+        }
+    }
+}
+
+impl StressConfig {
+    /// Returns the number of sources in `layer`, counting from zero for the layer linked to the
+    /// [`Global`] source.
+    #[inline]
+    pub fn layer_len(&self, layer: usize) -> usize {
+        self.sources * self.targets_per_source.pow(layer as u32)
+    }
+}
+
+/// A world built by [`build_stress_world`], along with handles to its sources.
+pub struct StressWorld<R: EntropySource> {
+    /// The app holding the world, with an [`EntropyPlugin`] for `R`.
+    pub app: App,
+    /// The [`Global`] `R` source at the root of the hierarchy.
+    pub global: Entity,
+    /// The sources in each layer, in the order they were spawned. The targets of the source at
+    /// index `i` of a layer are at indices `i * targets_per_source..(i + 1) * targets_per_source`
+    /// of the next layer.
+    pub layers: Vec<Vec<Entity>>,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource + 'static> StressWorld<R>
+where
+    R::Seed: EntropySeed,
+{
+    /// Reseeds the [`Global`] source with `seed`, expanded as with [`RngSeed::from_u64`],
+    /// cascading new seeds through every layer.
+    pub fn reseed(&mut self, seed: u64) {
+        let world = self.app.world_mut();
+
+        world
+            .entity_mut(self.global)
+            .insert(RngSeed::<R>::from_u64(seed));
+        world.flush();
+    }
+
+    /// Returns the seeds of every source in each layer, in the same order as
+    /// [`StressWorld::layers`].
+    pub fn seeds(&self) -> Vec<Vec<RngSeed<R>>> {
+        let world = self.app.world();
+
+        self.layers
+            .iter()
+            .map(|layer| {
+                layer
+                    .iter()
+                    .map(|&entity| world.get::<RngSeed<R>>(entity).unwrap().clone())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Marker for sources in even layers.
+#[derive(Component, Default)]
+struct EvenLayer;
+
+/// Marker for sources in odd layers.
+#[derive(Component, Default)]
+struct OddLayer;
+
+/// Builds an app with a [`Global`] `R` source and [`StressConfig::depth`] layers of linked
+/// sources below it, shaped by `config`. The same config always builds a world with the same
+/// seeds.
+///
+/// ```
+/// use bevy_prng::WyRand;
+/// use bevy_rand::stress::{build_stress_world, StressConfig};
+///
+/// let mut stress = build_stress_world::<WyRand>(StressConfig {
+///     sources: 4,
+///     targets_per_source: 4,
+///     depth: 3,
+///     seed: 1,
+/// });
+///
+/// assert_eq!(stress.layers[2].len(), 64);
+///
+/// stress.reseed(2);
+/// ```
+pub fn build_stress_world<R: EntropySource + 'static>(config: StressConfig) -> StressWorld<R>
+where
+    R::Seed: EntropySeed,
+{
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<R>::with_seed(
+        RngSeed::<R>::from_u64(config.seed).clone_seed(),
+    ));
+
+    let world = app.world_mut();
+    let global = world
+        .query_filtered::<Entity, With<Global>>()
+        .single(world)
+        .unwrap();
+
+    let mut layers = Vec::with_capacity(config.depth);
+    let mut parents = alloc::vec![global];
+
+    for layer in 0..config.depth {
+        let children = match (layer, layer % 2) {
+            (0, _) => link_layer::<Global, EvenLayer, R>(world, &parents, config.sources),
+            (_, 0) => {
+                link_layer::<OddLayer, EvenLayer, R>(world, &parents, config.targets_per_source)
+            }
+            _ => link_layer::<EvenLayer, OddLayer, R>(world, &parents, config.targets_per_source),
+        };
+
+        layers.push(children.clone());
+        parents = children;
+    }
+
+    StressWorld {
+        app,
+        global,
+        layers,
+        _rng: PhantomData,
+    }
+}
+
+/// Spawns `count` targets for each of `parents`, forked from and linked to their parent, and
+/// attaches the observer propagating each parent's seeds to its targets.
+fn link_layer<Source: Component, Target: Component + Default, R: EntropySource + 'static>(
+    world: &mut World,
+    parents: &[Entity],
+    count: usize,
+) -> Vec<Entity>
+where
+    R::Seed: EntropySeed,
+{
+    let mut children = Vec::with_capacity(parents.len() * count);
+    let mut commands = world.commands();
+
+    for &parent in parents {
+        let mut parent = commands.rng_entity::<R>(parent);
+
+        for _ in 0..count {
+            children.push(parent.fork_child(Target::default()).id());
+        }
+
+        parent
+            .entity_commands()
+            .observe(seed_children_scoped::<Source, Target, R>);
+    }
+
+    world.flush();
+
+    children
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+
+    use crate::observers::{RngChildren, RngParent};
+
+    use super::*;
+
+    const CONFIG: StressConfig = StressConfig {
+        sources: 3,
+        targets_per_source: 4,
+        depth: 3,
+        seed: 7,
+    };
+
+    #[test]
+    fn layers_have_the_configured_shape() {
+        let stress = build_stress_world::<WyRand>(CONFIG);
+        let world = stress.app.world();
+
+        assert_eq!(
+            stress.layers.iter().map(Vec::len).collect::<Vec<_>>(),
+            [3, 12, 48]
+        );
+        assert_eq!(
+            (0..3)
+                .map(|layer| CONFIG.layer_len(layer))
+                .collect::<Vec<_>>(),
+            [3, 12, 48]
+        );
+
+        let mut parents = alloc::vec![stress.global];
+
+        for layer in &stress.layers {
+            let targets = layer.len() / parents.len();
+
+            for (index, &entity) in layer.iter().enumerate() {
+                assert_eq!(
+                    world
+                        .get::<RngParent<WyRand>>(entity)
+                        .map(RngParent::entity),
+                    Some(parents[index / targets])
+                );
+            }
+
+            for &parent in &parents {
+                assert!(world.get::<RngChildren<WyRand>>(parent).is_some());
+            }
+
+            parents = layer.clone();
+        }
+
+        assert!(parents
+            .iter()
+            .all(|&leaf| world.get::<RngChildren<WyRand>>(leaf).is_none()));
+    }
+
+    #[test]
+    fn cascades_reach_every_layer_deterministically() {
+        let mut stress = build_stress_world::<WyRand>(CONFIG);
+        let built = stress.seeds();
+
+        assert_eq!(built, build_stress_world::<WyRand>(CONFIG).seeds());
+
+        stress.reseed(8);
+
+        let reseeded = stress.seeds();
+
+        for (built, reseeded) in built.iter().zip(&reseeded) {
+            assert!(built.iter().zip(reseeded).all(|(old, new)| old != new));
+        }
+
+        // Cascades leave the links intact, and reseeding with the same seed repeats them
+        let mut other = build_stress_world::<WyRand>(StressConfig { seed: 9, ..CONFIG });
+
+        other.reseed(8);
+
+        assert_eq!(other.seeds(), reseeded);
+        assert_eq!(other.layers.len(), 3);
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::{string::String, vec::Vec};
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy_ecs::prelude::{
+    Changed, Commands, Entity, Event, EventReader, EventWriter, Query, ResMut, Resource, With,
+};
+use bevy_prng::EntropySource;
+
+use crate::{global::Global, seed::RngSeed, traits::SeedSource};
+
+/// Event emitted by [`SeedSyncPlugin`] whenever the [`Global`] `R` source's seed changes, for
+/// broadcasting to other peers.
+///
+/// The serialized form contains no generics, only the type path of `R`, the raw seed bytes and the
+/// generation of the change, so it can be sent over the network as is.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound = ""))]
+pub struct GlobalSeedChanged<R: EntropySource> {
+    /// The type path of the PRNG algorithm the seed is for.
+    pub rng: String,
+    /// The raw bytes of the new seed.
+    pub seed_bytes: Vec<u8>,
+    /// The generation of the seed change, increasing with each change.
+    pub generation: u32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    _rng: PhantomData<R>,
+}
+
+/// Event consumed by [`SeedSyncPlugin`] to reseed the local [`Global`] `R` source, such as with
+/// a [`GlobalSeedChanged`] event received from a server.
+///
+/// The seed is only applied if its generation is newer than the last applied generation, so
+/// events arriving out of order cannot revert to an older seed. Local seed changes do not affect
+/// this, so the first received seed is always applied. Events for a different PRNG
+/// algorithm, or with the wrong number of seed bytes, are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound = ""))]
+pub struct ApplyGlobalSeed<R: EntropySource> {
+    /// The type path of the PRNG algorithm the seed is for.
+    pub rng: String,
+    /// The raw bytes of the seed to apply.
+    pub seed_bytes: Vec<u8>,
+    /// The generation of the seed.
+    pub generation: u32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> ApplyGlobalSeed<R> {
+    /// Creates a new event for applying `seed` with the given `generation`.
+    pub fn new(mut seed: R::Seed, generation: u32) -> Self {
+        Self {
+            rng: String::from(R::type_path()),
+            seed_bytes: seed.as_mut().to_vec(),
+            generation,
+            _rng: PhantomData,
+        }
+    }
+
+    fn to_seed(&self) -> Option<R::Seed> {
+        let mut seed = R::Seed::default();
+
+        if self.rng != R::type_path() || self.seed_bytes.len() != seed.as_mut().len() {
+            return None;
+        }
+
+        seed.as_mut().copy_from_slice(&self.seed_bytes);
+
+        Some(seed)
+    }
+}
+
+impl<R: EntropySource> From<GlobalSeedChanged<R>> for ApplyGlobalSeed<R> {
+    fn from(changed: GlobalSeedChanged<R>) -> Self {
+        Self {
+            rng: changed.rng,
+            seed_bytes: changed.seed_bytes,
+            generation: changed.generation,
+            _rng: PhantomData,
+        }
+    }
+}
+
+/// Resource tracking the seed generation of the [`Global`] `R` source for [`SeedSyncPlugin`].
+#[derive(Debug, Resource)]
+pub struct SeedGeneration<R: EntropySource> {
+    generation: u32,
+    last_applied: Option<u32>,
+    pending: bool,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> SeedGeneration<R> {
+    /// Returns the generation of the current global seed.
+    #[inline]
+    pub fn get(&self) -> u32 {
+        self.generation
+    }
+
+    /// Returns the generation of the last seed applied with [`ApplyGlobalSeed`], if any.
+    #[inline]
+    pub fn last_applied(&self) -> Option<u32> {
+        self.last_applied
+    }
+}
+
+impl<R: EntropySource> Default for SeedGeneration<R> {
+    fn default() -> Self {
+        Self {
+            generation: 0,
+            last_applied: None,
+            pending: false,
+            _rng: PhantomData,
+        }
+    }
+}
+
+/// Plugin for synchronising the [`Global`] `R` source's seed between peers, such as from a server
+/// to its clients.
+///
+/// Whenever the global seed changes, a [`GlobalSeedChanged`] event is emitted for the networking
+/// layer to broadcast. Incoming [`ApplyGlobalSeed`] events reseed the local global source, which
+/// in turn reseeds any targets linked to it with [`crate::plugin::LinkedEntropySources`]. Applied
+/// seeds are re-emitted as [`GlobalSeedChanged`] with the same generation rather than a new one.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{ApplyGlobalSeed, EntropyPlugin, GlobalSeedChanged, SeedSyncPlugin};
+///
+/// fn broadcast(mut changes: EventReader<GlobalSeedChanged<WyRand>>) {
+///     for change in changes.read() {
+///         // Send `change` to clients, which then send it as `ApplyGlobalSeed::from(change)`
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins((
+///         EntropyPlugin::<WyRand>::default(),
+///         SeedSyncPlugin::<WyRand>::default(),
+///     ))
+///     .add_systems(Last, broadcast);
+/// ```
+pub struct SeedSyncPlugin<R: EntropySource> {
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource> Default for SeedSyncPlugin<R> {
+    fn default() -> Self {
+        Self { _rng: PhantomData }
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for SeedSyncPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeedGeneration<R>>()
+            .add_event::<GlobalSeedChanged<R>>()
+            .add_event::<ApplyGlobalSeed<R>>()
+            .add_systems(PreUpdate, apply_global_seed::<R>)
+            .add_systems(PostUpdate, emit_global_seed_changes::<R>);
+    }
+}
+
+fn apply_global_seed<R: EntropySource + 'static>(
+    mut incoming: EventReader<ApplyGlobalSeed<R>>,
+    mut generation: ResMut<SeedGeneration<R>>,
+    q_global: Query<Entity, (With<Global>, With<RngSeed<R>>)>,
+    mut commands: Commands,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    let newest = incoming
+        .read()
+        .filter(|event| {
+            generation
+                .last_applied
+                .map_or(true, |last| event.generation > last)
+        })
+        .filter_map(|event| Some((event.generation, event.to_seed()?)))
+        .max_by_key(|(generation, _)| *generation);
+
+    if let Some((newest, seed)) = newest {
+        generation.generation = newest;
+        generation.last_applied = Some(newest);
+        generation.pending = true;
+
+        for entity in q_global.iter() {
+            commands
+                .entity(entity)
+                .insert(RngSeed::<R>::from_seed(seed.clone()));
+        }
+    }
+}
+
+fn emit_global_seed_changes<R: EntropySource + 'static>(
+    mut outgoing: EventWriter<GlobalSeedChanged<R>>,
+    mut generation: ResMut<SeedGeneration<R>>,
+    q_global: Query<&RngSeed<R>, (With<Global>, Changed<RngSeed<R>>)>,
+) where
+    R::Seed: Send + Sync + Clone,
+{
+    for seed in q_global.iter() {
+        if !core::mem::take(&mut generation.pending) {
+            generation.generation += 1;
+        }
+
+        outgoing.write(GlobalSeedChanged {
+            rng: String::from(R::type_path()),
+            seed_bytes: seed.clone_seed().as_mut().to_vec(),
+            generation: generation.generation,
+            _rng: PhantomData,
+        });
+    }
+}
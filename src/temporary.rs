@@ -0,0 +1,253 @@
+use bevy_ecs::prelude::{Entity, With, World};
+use bevy_prng::EntropySource;
+use rand_core::SeedableRng;
+
+use crate::{
+    component::Entropy,
+    global::Global,
+    seed::{RecordedSeed, RngSeed},
+    traits::SeedSource,
+};
+
+/// Extension trait for [`World`], for running code with the [`Global`] source temporarily
+/// seeded with a specific seed, such as for rendering a photo mode shot or a thumbnail the same
+/// way every time, without affecting the values the rest of the game draws.
+pub trait TemporarySeedExt {
+    /// Seeds the [`Global`] `R` source with `seed`, runs `f`, and then restores the source's
+    /// [`Entropy`] and [`RngSeed`] exactly as they were, returning the output of `f`. The outer
+    /// sequence of values drawn from the [`Global`] source is the same as if `f` had never run.
+    ///
+    /// The temporary seed is applied and restored without triggering the cascade of new seeds to
+    /// any linked targets, so linked sources keep drawing from their own seeds throughout, and
+    /// anything they draw while `f` runs is not restored. The source is restored once `f`
+    /// returns, whatever it returns, so an `f` returning early with an `Err` still restores it,
+    /// but a panicking `f` does not. If there is no [`Global`] `R` source, `f` is run as is.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{EntropyPlugin, GlobalEntropy, TemporarySeedExt};
+    /// use bevy_app::App;
+    /// use rand_core::RngCore;
+    ///
+    /// let mut app = App::new();
+    ///
+    /// app.add_plugins(EntropyPlugin::<WyRand>::with_seed(1u64.to_le_bytes()));
+    ///
+    /// let thumbnail = |world: &mut World| {
+    ///     world
+    ///         .run_system_cached(|mut global: GlobalEntropy<WyRand>| global.next_u32())
+    ///         .unwrap()
+    /// };
+    ///
+    /// let world = app.world_mut();
+    ///
+    /// assert_eq!(
+    ///     world.with_temporary_seed::<WyRand, _>(42u64.to_le_bytes(), thumbnail),
+    ///     world.with_temporary_seed::<WyRand, _>(42u64.to_le_bytes(), thumbnail),
+    /// );
+    /// ```
+    fn with_temporary_seed<R: EntropySource, Ret>(
+        &mut self,
+        seed: R::Seed,
+        f: impl FnOnce(&mut World) -> Ret,
+    ) -> Ret
+    where
+        R::Seed: Send + Sync + Clone;
+}
+
+impl TemporarySeedExt for World {
+    fn with_temporary_seed<R: EntropySource, Ret>(
+        &mut self,
+        seed: R::Seed,
+        f: impl FnOnce(&mut World) -> Ret,
+    ) -> Ret
+    where
+        R::Seed: Send + Sync + Clone,
+    {
+        let Some((global, entropy, recorded)) = self
+            .query_filtered::<(Entity, &Entropy<R>, Option<&RngSeed<R>>), With<Global>>()
+            .iter(self)
+            .next()
+            .map(|(global, entropy, recorded)| (global, entropy.clone(), recorded.cloned()))
+        else {
+            return f(self);
+        };
+
+        if recorded.is_some() {
+            set_seed_silently::<R>(self, global, seed.clone());
+        }
+
+        *self.get_mut::<Entropy<R>>(global).unwrap() = Entropy::from_seed(seed);
+
+        let output = f(self);
+
+        if self.get_entity(global).is_ok() {
+            if let Some(recorded) = recorded {
+                set_seed_silently::<R>(self, global, recorded.clone_seed());
+            }
+
+            if let Some(mut current) = self.get_mut::<Entropy<R>>(global) {
+                *current = entropy;
+            }
+        }
+
+        output
+    }
+}
+
+/// Inserts `seed` into `entity` without reinitialising its [`Entropy`] from the seed, and so
+/// without triggering the cascade of new seeds to any linked targets.
+fn set_seed_silently<R: EntropySource>(world: &mut World, entity: Entity, seed: R::Seed)
+where
+    R::Seed: Send + Sync + Clone,
+{
+    world
+        .entity_mut(entity)
+        .insert((RngSeed::<R>::from_seed(seed), RecordedSeed::<R>::default()));
+    world.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_app::App;
+    use bevy_ecs::prelude::Resource;
+    use bevy_prng::WyRand;
+    use rand_core::RngCore;
+
+    use crate::{commands::RngCommandsExt, plugin::EntropyPlugin};
+
+    use super::*;
+
+    #[derive(Resource)]
+    struct Drawn(Vec<u32>);
+
+    fn app(seed: u64) -> App {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<WyRand>::with_seed(seed.to_le_bytes()));
+
+        app
+    }
+
+    fn draw(world: &mut World, count: usize) -> Vec<u32> {
+        let mut global = world
+            .query_filtered::<&mut Entropy<WyRand>, With<Global>>()
+            .single_mut(world)
+            .unwrap();
+
+        (0..count).map(|_| global.next_u32()).collect()
+    }
+
+    fn global_seed(world: &mut World) -> RngSeed<WyRand> {
+        world
+            .query_filtered::<&RngSeed<WyRand>, With<Global>>()
+            .single(world)
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn outer_sequence_is_unaffected() {
+        let expected = draw(app(1).world_mut(), 8);
+        let expected_inner = draw(app(42).world_mut(), 4);
+
+        let mut app = app(1);
+        let world = app.world_mut();
+        let seed = global_seed(world);
+
+        let mut outer = draw(world, 4);
+        let inner = world.with_temporary_seed::<WyRand, _>(42u64.to_le_bytes(), |world| {
+            assert_eq!(global_seed(world), RngSeed::from_u64(42));
+
+            draw(world, 4)
+        });
+
+        outer.extend(draw(world, 4));
+
+        assert_eq!(inner, expected_inner);
+        assert_eq!(outer, expected);
+        assert_eq!(global_seed(world), seed);
+    }
+
+    #[test]
+    fn early_returns_still_restore() {
+        let expected = draw(app(1).world_mut(), 4);
+
+        let mut app = app(1);
+        let world = app.world_mut();
+
+        let result = world.with_temporary_seed::<WyRand, _>(42u64.to_le_bytes(), |world| {
+            draw(world, 3);
+
+            Err::<(), _>("thumbnail failed")
+        });
+
+        assert_eq!(result, Err("thumbnail failed"));
+        assert_eq!(draw(world, 4), expected);
+    }
+
+    #[test]
+    fn commands_run_with_the_temporary_seed() {
+        let expected = draw(app(1).world_mut(), 4);
+        let expected_inner = draw(app(42).world_mut(), 4);
+
+        let mut app = app(1);
+        let world = app.world_mut();
+
+        world
+            .commands()
+            .with_temporary_seed::<WyRand>(42u64.to_le_bytes(), |world| {
+                let values = draw(world, 4);
+
+                world.insert_resource(Drawn(values));
+            });
+        world.flush();
+
+        assert_eq!(world.resource::<Drawn>().0, expected_inner);
+        assert_eq!(draw(world, 4), expected);
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn linked_targets_are_not_reseeded() {
+        use bevy_ecs::prelude::Component;
+
+        use crate::observers::seed_children_scoped;
+
+        #[derive(Component)]
+        struct Target;
+
+        let mut app = app(1);
+        let world = app.world_mut();
+        let global = world
+            .query_filtered::<Entity, With<Global>>()
+            .single(world)
+            .unwrap();
+
+        let mut commands = world.commands();
+        let mut parent = commands.rng_entity::<WyRand>(global);
+        let target = parent.fork_child(Target).id();
+
+        parent
+            .entity_commands()
+            .observe(seed_children_scoped::<Global, Target, WyRand>);
+        world.flush();
+
+        let seed = world.get::<RngSeed<WyRand>>(target).unwrap().clone();
+
+        world.with_temporary_seed::<WyRand, _>(42u64.to_le_bytes(), |_| {});
+
+        assert_eq!(world.get::<RngSeed<WyRand>>(target), Some(&seed));
+
+        // Whereas reseeding the global source normally cascades to the target
+        world
+            .entity_mut(global)
+            .insert(RngSeed::<WyRand>::from_u64(42));
+        world.flush();
+
+        assert_ne!(world.get::<RngSeed<WyRand>>(target), Some(&seed));
+    }
+}
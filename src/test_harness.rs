@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+
+use bevy_app::{App, Plugins};
+use bevy_ecs::prelude::{Component, Entity, With, World};
+use bevy_prng::{EntropySeed, EntropySource};
+
+use crate::{global::Global, plugin::EntropyPlugin, seed::RngSeed, traits::SeedSource};
+
+/// A headless [`App`] builder for deterministic tests, wrapping the setup and query patterns
+/// that RNG tests otherwise repeat: adding seeded [`EntropyPlugin`]s, linking sources, stepping
+/// frames and reading back seeds.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{prelude::GlobalEntropy, test_harness::DeterministicApp, traits::ForkableSeed};
+///
+/// #[derive(Component)]
+/// struct Npc;
+///
+/// let mut app = DeterministicApp::new().with_rng::<WyRand>([1; 8]);
+///
+/// app.app_mut().add_systems(
+///     bevy_app::Startup,
+///     |mut commands: Commands, mut global: GlobalEntropy<WyRand>| {
+///         for _ in 0..3 {
+///             commands.spawn((Npc, global.fork_seed()));
+///         }
+///     },
+/// );
+///
+/// app.run_frames(1);
+///
+/// assert_eq!(app.global_seed::<WyRand>(), [1; 8]);
+/// assert_eq!(app.seeds_of::<Npc, WyRand>().len(), 3);
+/// ```
+pub struct DeterministicApp {
+    app: App,
+}
+
+impl Default for DeterministicApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeterministicApp {
+    /// Creates a new, empty headless app.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { app: App::new() }
+    }
+
+    /// Adds an [`EntropyPlugin`] for `R`, with its [`Global`] source seeded with `seed`.
+    #[must_use]
+    pub fn with_rng<R: EntropySource + 'static>(mut self, seed: R::Seed) -> Self
+    where
+        R::Seed: EntropySeed,
+    {
+        self.app.add_plugins(EntropyPlugin::<R>::with_seed(seed));
+        self
+    }
+
+    /// Adds the [`crate::plugin::LinkedEntropySources`] plugin for linking `Source` to `Target`
+    /// entities with `R` sources.
+    #[cfg(feature = "experimental")]
+    #[must_use]
+    pub fn with_relations<Source: Component, Target: Component, R: EntropySource + 'static>(
+        mut self,
+    ) -> Self
+    where
+        R::Seed: EntropySeed,
+    {
+        self.app
+            .add_plugins(crate::plugin::LinkedEntropySources::<Source, Target, R>::default());
+        self
+    }
+
+    /// Adds any other plugins needed by the test.
+    #[must_use]
+    pub fn with_plugins<M>(mut self, plugins: impl Plugins<M>) -> Self {
+        self.app.add_plugins(plugins);
+        self
+    }
+
+    /// Spawns a `Source` entity and `count` `Target` entities, then links them and seeds the
+    /// source from the [`Global`] `R` source, cascading seeds to the targets. Returns the source
+    /// entity.
+    #[cfg(feature = "experimental")]
+    pub fn spawn_linked<Source, Target, R>(&mut self, count: usize) -> Entity
+    where
+        Source: Component + Default,
+        Target: Component + Default,
+        R: EntropySource + 'static,
+        R::Seed: EntropySeed,
+    {
+        use crate::{commands::RngCommandsExt, observers::LinkRngSourceToTarget};
+
+        let world = self.app.world_mut();
+
+        world.spawn_batch(core::iter::repeat_with(Target::default).take(count));
+
+        let source = world.spawn(Source::default()).id();
+
+        world.trigger(LinkRngSourceToTarget::<Source, Target, R>::default());
+        world.commands().reseed_from_global::<R>(source);
+        world.flush();
+
+        source
+    }
+
+    /// Runs `frames` updates of the app.
+    pub fn run_frames(&mut self, frames: usize) -> &mut Self {
+        for _ in 0..frames {
+            self.app.update();
+        }
+
+        self
+    }
+
+    /// Returns the seed of the [`Global`] `R` source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`Global`] `R` source.
+    pub fn global_seed<R: EntropySource + 'static>(&mut self) -> R::Seed
+    where
+        R::Seed: EntropySeed,
+    {
+        let world = self.app.world_mut();
+
+        world
+            .query_filtered::<&RngSeed<R>, With<Global>>()
+            .iter(world)
+            .next()
+            .map(RngSeed::clone_seed)
+            .expect("no Global source for the RNG")
+    }
+
+    /// Returns the [`Global`] `R` source entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`Global`] `R` source.
+    pub fn global_entity<R: EntropySource + 'static>(&mut self) -> Entity
+    where
+        R::Seed: EntropySeed,
+    {
+        let world = self.app.world_mut();
+
+        world
+            .query_filtered::<Entity, (With<Global>, With<RngSeed<R>>)>()
+            .iter(world)
+            .next()
+            .expect("no Global source for the RNG")
+    }
+
+    /// Returns the `R` seeds of all entities with a `Marker` component, in entity order.
+    pub fn seeds_of<Marker: Component, R: EntropySource + 'static>(&mut self) -> Vec<R::Seed>
+    where
+        R::Seed: EntropySeed,
+    {
+        let world = self.app.world_mut();
+
+        let mut seeds: Vec<_> = world
+            .query_filtered::<(Entity, &RngSeed<R>), With<Marker>>()
+            .iter(world)
+            .map(|(entity, seed)| (entity, seed.clone_seed()))
+            .collect();
+
+        seeds.sort_unstable_by_key(|(entity, _)| *entity);
+
+        seeds.into_iter().map(|(_, seed)| seed).collect()
+    }
+
+    /// Returns the wrapped [`App`].
+    #[inline]
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns the wrapped [`App`] mutably, for adding systems or anything else not covered by
+    /// the builder.
+    #[inline]
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Returns the app's [`World`].
+    #[inline]
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    /// Returns the app's [`World`] mutably.
+    #[inline]
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Unwraps the builder into its [`App`].
+    #[inline]
+    pub fn into_app(self) -> App {
+        self.app
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::string::String;
+
+use rand_core::{CryptoRng, RngCore};
+use uuid::{Builder, Uuid};
+
+/// Extension trait for generating UUIDs and random tokens from any RNG source, such as
+/// [`crate::component::Entropy`], [`crate::global::GlobalEntropy`] or the inner PRNGs.
+///
+/// When drawn from a deterministically seeded source, the generated values are deterministic
+/// as well. That is desirable for things like save-slot ids that must be reproducible, but means
+/// such values should not be relied upon for secrecy. For values that must be hard to predict,
+/// use [`EntropyUuidExt::new_secure_uuid`], which is only available for sources that implement
+/// [`CryptoRng`] such as the `ChaCha` PRNGs.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use bevy_rand::prelude::{EntropyUuidExt, GlobalEntropy};
+///
+/// fn create_save_slot(mut rng: GlobalEntropy<ChaCha8Rng>) {
+///     let slot_id = rng.new_uuid_v4();
+///     let session = rng.random_token_hex::<16>();
+///
+///     println!("Slot {slot_id}, session {session}");
+/// }
+/// ```
+///
+/// Non-cryptographic sources are unable to generate secure UUIDs:
+/// ```compile_fail
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{Entropy, EntropyUuidExt};
+///
+/// let mut rng = Entropy::<WyRand>::default();
+///
+/// let id = rng.new_secure_uuid();
+/// ```
+pub trait EntropyUuidExt: RngCore {
+    /// Generate a random version 4 UUID.
+    #[inline]
+    fn new_uuid_v4(&mut self) -> Uuid {
+        Builder::from_random_bytes(self.random_token()).into_uuid()
+    }
+
+    /// Generate a random version 4 UUID from a cryptographically secure source.
+    #[inline]
+    fn new_secure_uuid(&mut self) -> Uuid
+    where
+        Self: CryptoRng,
+    {
+        self.new_uuid_v4()
+    }
+
+    /// Generate a random token of `N` bytes.
+    #[inline]
+    fn random_token<const N: usize>(&mut self) -> [u8; N] {
+        let mut token = [0u8; N];
+
+        self.fill_bytes(&mut token);
+
+        token
+    }
+
+    /// Generate a random token of `N` bytes, formatted as a lowercase hex string
+    /// of `2 * N` characters.
+    fn random_token_hex<const N: usize>(&mut self) -> String {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        let token = self.random_token::<N>();
+        let mut output = String::with_capacity(N * 2);
+
+        for byte in token {
+            output.push(HEX[usize::from(byte >> 4)] as char);
+            output.push(HEX[usize::from(byte & 0xf)] as char);
+        }
+
+        output
+    }
+}
+
+impl<R: RngCore + ?Sized> EntropyUuidExt for R {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use bevy_prng::{ChaCha8Rng, WyRand};
+    use rand_core::SeedableRng;
+    use uuid::Version;
+
+    use crate::component::Entropy;
+
+    use super::*;
+
+    #[test]
+    fn deterministic_uuids() {
+        let mut rng = Entropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let first = rng.new_uuid_v4();
+        let second = rng.new_secure_uuid();
+
+        assert_eq!(first.get_version(), Some(Version::Random));
+        assert_eq!(second.get_version(), Some(Version::Random));
+        assert_ne!(first, second);
+
+        let mut rng = Entropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        assert_eq!(rng.new_uuid_v4(), first);
+        assert_eq!(rng.new_uuid_v4(), second);
+    }
+
+    #[test]
+    fn pinned_uuid_output() {
+        let mut rng = Entropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        assert_eq!(
+            rng.new_uuid_v4().to_string(),
+            "e4d0cb59-3fbc-4e39-a643-81da8b37f5d4"
+        );
+    }
+
+    #[test]
+    fn tokens() {
+        let mut rng = WyRand::from_seed([1; 8]);
+
+        let token = rng.random_token_hex::<4>();
+
+        assert_eq!(token.len(), 8);
+        assert_eq!(&token, "9d45ae0b");
+
+        let mut rng = WyRand::from_seed([1; 8]);
+
+        assert_eq!(
+            rng.random_token::<4>()
+                .iter()
+                .map(|byte| alloc::format!("{byte:02x}"))
+                .collect::<String>(),
+            token
+        );
+    }
+}
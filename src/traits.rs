@@ -1,6 +1,8 @@
 use bevy_prng::EntropySource;
 use rand_core::{RngCore, SeedableRng};
 
+use crate::{burst::BurstRng, component::BoxedEntropy, seed::SeedToken};
+
 /// Trait for implementing Forking behaviour for [`crate::component::Entropy`].
 /// Forking creates a new RNG instance using a generated seed from the original source. If the original is seeded with a known
 /// seed, this process is deterministic.
@@ -26,7 +28,11 @@ pub trait ForkableRng: EcsEntropy {
     ///         ));
     /// }
     /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
     fn fork_rng(&mut self) -> Self::Output {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
         Self::Output::from_rng(self).unwrap()
     }
 }
@@ -58,7 +64,11 @@ pub trait ForkableAsRng: EcsEntropy {
     ///         ));
     /// }
     /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
     fn fork_as<T: EntropySource>(&mut self) -> Self::Output<T> {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
         Self::Output::<_>::from_rng(self).unwrap()
     }
 }
@@ -91,9 +101,36 @@ pub trait ForkableInnerRng: EcsEntropy {
     ///     do_random_action(&mut source);
     /// }
     /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
     fn fork_inner(&mut self) -> Self::Output {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
         Self::Output::from_rng(self).unwrap()
     }
+
+    /// Fork the original instance to yield a new type-erased [`BoxedEntropy`] instance with a
+    /// generated seed, for handing to code that doesn't know which PRNG algorithm is in use.
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_rand::prelude::{BoxedEntropy, GlobalEntropy, ForkableInnerRng};
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// #[derive(Component)]
+    /// struct Source;
+    ///
+    /// fn setup_source(mut commands: Commands, mut global: GlobalEntropy<ChaCha8Rng>) {
+    ///     commands
+    ///         .spawn((
+    ///             Source,
+    ///             global.fork_boxed(),
+    ///         ));
+    /// }
+    /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
+    fn fork_boxed(&mut self) -> BoxedEntropy {
+        BoxedEntropy::new(self.fork_inner())
+    }
 }
 
 /// Trait for implementing forking behaviour for [`crate::component::Entropy`].
@@ -124,13 +161,68 @@ where
     ///         ));
     /// }
     /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
     fn fork_seed(&mut self) -> Self::Output {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
         let mut seed = S::Seed::default();
 
         self.fill_bytes(seed.as_mut());
 
         Self::Output::from_seed(seed)
     }
+
+    /// Fork a new seed from the original entropy source as a lightweight [`SeedToken`], for
+    /// embedding in events and messages instead of a whole forked RNG. The consumer reconstructs
+    /// the RNG with [`SeedToken::into_rng`].
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use bevy_rand::prelude::{GlobalEntropy, ForkableSeed, SeedToken};
+    ///
+    /// #[derive(Event)]
+    /// struct Spawned(SeedToken<ChaCha8Rng>);
+    ///
+    /// fn spawn_enemy(mut commands: Commands, mut global: GlobalEntropy<ChaCha8Rng>) {
+    ///     commands.trigger(Spawned(global.fork_token()));
+    /// }
+    /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
+    fn fork_token(&mut self) -> SeedToken<S> {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
+        let mut seed = S::Seed::default();
+
+        self.fill_bytes(seed.as_mut());
+
+        SeedToken::new(seed)
+    }
+
+    /// Fork a [`BurstRng`] from the original entropy source, for deriving any number of values
+    /// for a burst of particles while advancing the source by a single fork.
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_prng::WyRand;
+    /// use bevy_rand::prelude::{GlobalEntropy, ForkableSeed};
+    ///
+    /// fn explode(mut global: GlobalEntropy<WyRand>) {
+    ///     let burst = global.fork_burst();
+    ///     let sizes: Vec<f32> = (0..100).map(|index| burst.f32_for(index, 0)).collect();
+    /// }
+    /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
+    fn fork_burst(&mut self) -> BurstRng<S> {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
+        let mut seed = S::Seed::default();
+
+        self.fill_bytes(seed.as_mut());
+
+        BurstRng::new(&seed)
+    }
 }
 
 /// Trait for implementing Forking behaviour for [`crate::component::Entropy`].
@@ -162,10 +254,14 @@ pub trait ForkableAsSeed<S: EntropySource>: EcsEntropy {
     ///         ));
     /// }
     /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
     fn fork_as_seed<T: EntropySource>(&mut self) -> Self::Output<T>
     where
         T::Seed: Send + Sync + Clone,
     {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
         let mut seed = T::Seed::default();
 
         self.fill_bytes(seed.as_mut());
@@ -202,7 +298,11 @@ where
     ///         ));
     /// }
     /// ```
+    #[cfg_attr(feature = "fork_tracking", track_caller)]
     fn fork_inner_seed(&mut self) -> Self::Output {
+        #[cfg(feature = "fork_tracking")]
+        crate::fork_tracking::record_fork(core::panic::Location::caller());
+
         let mut seed = Self::Output::default();
 
         self.fill_bytes(seed.as_mut());
@@ -236,7 +336,7 @@ where
 
         #[cfg(feature = "thread_local_entropy")]
         {
-            use crate::thread_local_entropy::ThreadLocalEntropy;
+            use bevy_prng::ThreadLocalEntropy;
 
             ThreadLocalEntropy::new().fill_bytes(dest.as_mut());
         }
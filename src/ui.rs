@@ -0,0 +1,266 @@
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Component, With},
+    system::Single,
+};
+use bevy_prng::{EntropySeed, EntropySource};
+
+use crate::{
+    component::Entropy,
+    plugin::{RngObserversPlugin, RngTypesPlugin},
+    seed::{RngSeed, SeedFallbackPolicy},
+    traits::SeedSource,
+};
+
+/// A marker component for the UI source of an [`bevy_prng::EntropySource`], a global source
+/// dedicated to UI and layout randomness such as animations, kept apart from the
+/// [`crate::global::Global`] gameplay source. Spawned by [`UiEntropyPlugin`].
+///
+/// The UI source is never drawn from or reseeded by anything targeting gameplay sources: it is
+/// not the [`crate::global::Global`] source, so observers such as
+/// [`SeedFromGlobal`](crate::observers::SeedFromGlobal) never fork from it, and it is excluded
+/// from [`NonGlobal`](crate::params::NonGlobal) queries and
+/// [`reseed_world`](crate::commands::RngCommandsExt::reseed_world). Using it as much or as little
+/// as needed, such as while gameplay is paused, therefore never changes gameplay randomness.
+#[derive(Debug, Component)]
+pub struct UiRng;
+
+/// A helper query to yield the [`UiRng`] source for a given [`EntropySource`]. This returns the
+/// [`Entropy`] component to generate new random numbers from.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::UiEntropy;
+/// use rand_core::RngCore;
+///
+/// #[derive(Component)]
+/// struct Wobble(f32);
+///
+/// fn wobble_buttons(mut rng: UiEntropy<WyRand>, mut q_buttons: Query<&mut Wobble>) {
+///     for mut wobble in q_buttons.iter_mut() {
+///         wobble.0 = (rng.next_u32() % 100) as f32 / 100.0;
+///     }
+/// }
+/// ```
+pub type UiEntropy<'w, T> = Single<'w, &'static mut Entropy<T>, With<UiRng>>;
+
+/// A helper query to yield the [`UiRng`] source for a given [`EntropySource`]. This returns the
+/// [`Entity`] id to modify the source with via commands.
+pub type UiRngEntity<'w, T> = Single<'w, Entity, (With<RngSeed<T>>, With<UiRng>)>;
+
+/// Plugin for spawning the [`UiRng`] source for `R`, seeded from OS entropy by default, as UI
+/// randomness rarely needs to be reproducible. Also adds the [`RngTypesPlugin`] and
+/// [`RngObserversPlugin`] for `R`, so it can be used with or without an
+/// [`crate::plugin::EntropyPlugin`]. If the world already has a [`UiRng`] `R` source, no other is
+/// spawned.
+///
+/// ```
+/// use bevy_app::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{EntropyPlugin, UiEntropyPlugin};
+///
+/// App::new().add_plugins((
+///     EntropyPlugin::<WyRand>::with_seed([1; 8]),
+///     UiEntropyPlugin::<WyRand>::default(),
+/// ));
+/// ```
+pub struct UiEntropyPlugin<R: EntropySource + 'static> {
+    seed: Option<R::Seed>,
+    fallback: SeedFallbackPolicy,
+    _rng: PhantomData<R>,
+}
+
+impl<R: EntropySource + 'static> UiEntropyPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    /// Creates a new plugin instance configured for seeding the UI source from OS entropy.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            seed: None,
+            fallback: SeedFallbackPolicy::Panic,
+            _rng: PhantomData,
+        }
+    }
+
+    /// Configures the plugin instance to have a set seed for the UI source, such as for
+    /// reproducing UI bugs.
+    #[inline]
+    pub fn with_seed(seed: R::Seed) -> Self {
+        Self {
+            seed: Some(seed),
+            ..Self::new()
+        }
+    }
+
+    /// Configures how the UI source is seeded if no seed was given and entropy can't be sourced
+    /// from the OS. Defaults to [`SeedFallbackPolicy::Panic`].
+    #[inline]
+    #[must_use]
+    pub fn with_seed_fallback(mut self, policy: SeedFallbackPolicy) -> Self {
+        self.fallback = policy;
+        self
+    }
+}
+
+impl<R: EntropySource + 'static> Default for UiEntropyPlugin<R>
+where
+    R::Seed: Send + Sync + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: EntropySource + 'static> Plugin for UiEntropyPlugin<R>
+where
+    R::Seed: EntropySeed,
+{
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            RngTypesPlugin::<R>::default(),
+            RngObserversPlugin::<R>::default(),
+        ));
+
+        let world = app.world_mut();
+
+        let has_ui = world
+            .query_filtered::<(), (With<RngSeed<R>>, With<UiRng>)>()
+            .iter(world)
+            .next()
+            .is_some();
+
+        if !has_ui {
+            world.spawn((
+                self.seed.clone().map_or_else(
+                    || RngSeed::<R>::from_entropy_with(self.fallback),
+                    RngSeed::<R>::from_seed,
+                ),
+                UiRng,
+            ));
+
+            world.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_app::Update;
+    use bevy_ecs::prelude::{Commands, Query, ResMut, Resource};
+    use bevy_prng::WyRand;
+    use rand_core::{RngCore, SeedableRng};
+
+    use crate::{
+        commands::{ReseedWorldPolicy, RngCommandsExt},
+        global::{Global, GlobalEntropy, GlobalSource},
+        params::NonGlobalEntropy,
+        plugin::EntropyPlugin,
+        traits::ForkableSeed,
+    };
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Enemy;
+
+    /// Runs a few frames of gameplay, drawing from the UI source `ui_draws` times per frame, and
+    /// returns the gameplay outputs.
+    fn play(ui_draws: usize) -> Vec<u64> {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            UiEntropyPlugin::<WyRand>::with_seed([2; 8]),
+        ))
+        .init_resource::<Outputs>()
+        .add_systems(
+            Update,
+            (
+                move |mut ui: UiEntropy<WyRand>| {
+                    for _ in 0..ui_draws {
+                        ui.next_u64();
+                    }
+                },
+                |mut commands: Commands, mut global: GlobalEntropy<WyRand>| {
+                    commands.spawn((Enemy, global.fork_seed()));
+                },
+                |mut q_sources: NonGlobalEntropy<WyRand>, mut outputs: ResMut<Outputs>| {
+                    for mut source in q_sources.iter_mut() {
+                        outputs.0.push(source.next_u64());
+                    }
+                },
+            ),
+        );
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        app.world_mut()
+            .commands()
+            .reseed_world::<WyRand>([3; 8], ReseedWorldPolicy::ForkFromGlobal);
+        app.update();
+
+        let world = app.world_mut();
+        let mut outputs = core::mem::take(&mut world.resource_mut::<Outputs>().0);
+
+        outputs.sort_unstable();
+        outputs.extend(
+            world
+                .query_filtered::<&mut Entropy<WyRand>, With<Global>>()
+                .iter_mut(world)
+                .map(|mut global| global.next_u64()),
+        );
+
+        outputs
+    }
+
+    #[derive(Resource, Default)]
+    struct Outputs(Vec<u64>);
+
+    #[test]
+    fn gameplay_is_unaffected_by_ui_draws() {
+        let quiet = play(0);
+
+        assert!(!quiet.is_empty());
+        assert_eq!(quiet, play(1));
+        assert_eq!(quiet, play(50));
+    }
+
+    #[test]
+    fn ui_params_resolve_to_ui_source() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            UiEntropyPlugin::<WyRand>::with_seed([2; 8]),
+        ));
+
+        app.add_systems(
+            Update,
+            |ui: UiRngEntity<WyRand>,
+             global: GlobalSource<WyRand>,
+             mut ui_rng: UiEntropy<WyRand>,
+             q_seeds: Query<&RngSeed<WyRand>>| {
+                assert_ne!(*ui, *global);
+                assert_eq!(q_seeds.get(*ui).unwrap().clone_seed(), [2; 8]);
+                assert_eq!(q_seeds.get(*global).unwrap().clone_seed(), [1; 8]);
+                assert_eq!(
+                    ui_rng.next_u64(),
+                    Entropy::<WyRand>::from_seed([2; 8]).next_u64()
+                );
+            },
+        );
+
+        app.update();
+    }
+}
@@ -0,0 +1,530 @@
+//! Integer-domain helpers for random floats and fixed-point values that are bit-identical on every
+//! platform, for lockstep and replays, along with [`stable_key`] and [`stable_sample`] for
+//! ordering and sampling entities the same way across runs and reloads.
+//!
+//! Integer draws such as [`rand_core::RngCore::next_u32`] and `fill_bytes` are bit-identical on
+//! every platform for a given algorithm and seed, as are the helpers in this module. Float
+//! sampling through `rand`, such as `Entropy::random_range` over floats, follows `rand`'s
+//! internal algorithms, which may change between `rand` versions. Sampling from `bevy_math`
+//! shapes and rotations uses trigonometric and other functions whose results can differ between
+//! platforms, so it is not suitable for lockstep.
+
+use alloc::{format, string::String, vec::Vec};
+
+use bevy_ecs::entity::Entity;
+use bevy_prng::{EntropySeed, EntropySource};
+use rand_core::{RngCore, SeedableRng};
+
+use crate::{seed::RngSeed, select::gen_index, traits::SeedSource};
+
+/// Advances `state` and returns the next output of the `SplitMix64` generator, used as a
+/// fast, well-distributed mixer for deriving seeds from keys.
+#[inline]
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+/// Hashes `label` with 64-bit FNV-1a, a simple and stable hash that will never change between
+/// versions or platforms, for turning labels into keys for [`derive_seed`].
+pub(crate) fn hash_label(label: &str) -> u64 {
+    label.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// A [`Hasher`] using 64-bit FNV-1a, for hashing keys in a way that is stable across versions
+/// and platforms. Integers are always hashed as little endian bytes, with `usize` and `isize`
+/// values hashed as 64-bit values.
+pub(crate) struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xCBF2_9CE4_8422_2325)
+    }
+}
+
+impl core::hash::Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write(&[i]);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write(&(i as u64).to_le_bytes());
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write(&(i as i64).to_le_bytes());
+    }
+}
+
+/// Formats `bytes` as lowercase hex, the same way [`crate::seed::RngSeed`] is displayed.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Derives a new seed from `base` and `key`, by XORing the seed bytes with a `SplitMix64`
+/// stream keyed by `key`. The same base seed and key always derive the same seed, while
+/// different keys derive unrelated seeds.
+pub(crate) fn derive_seed<R: SeedableRng>(base: &R::Seed, key: u64) -> R::Seed
+where
+    R::Seed: Clone,
+{
+    let mut seed = base.clone();
+    let mut state = key;
+
+    for chunk in seed.as_mut().chunks_mut(8) {
+        let mask = splitmix64(&mut state).to_le_bytes();
+
+        chunk
+            .iter_mut()
+            .zip(mask)
+            .for_each(|(byte, mask)| *byte ^= mask);
+    }
+
+    seed
+}
+
+/// Absorbs `bytes` into a `SplitMix64` state starting from `key`, so that the resulting state
+/// depends on every byte.
+fn absorb(bytes: &[u8], key: u64) -> u64 {
+    let mut state = key;
+
+    for chunk in bytes.chunks(8) {
+        let mut word = [0; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+
+        state ^= u64::from_le_bytes(word);
+        splitmix64(&mut state);
+    }
+
+    state
+}
+
+/// Mixes `base` and `counter` into a new seed, by absorbing the seed bytes and counter into a
+/// `SplitMix64` state and squeezing the output from it. Unlike [`derive_seed`], every output
+/// byte depends on every byte of `base`, so different base seeds give unrelated sequences.
+pub(crate) fn mix_seed<R: SeedableRng>(base: &R::Seed, counter: u64) -> R::Seed
+where
+    R::Seed: Clone,
+{
+    let mut seed = base.clone();
+    let state = absorb(seed.as_mut(), counter);
+
+    squeeze(state, seed.as_mut());
+
+    seed
+}
+
+/// Mixes `bytes` and `key` into `dest`, in the same way as [`mix_seed`], but allowing `dest` to
+/// be a different length to `bytes`.
+pub(crate) fn mix_into(bytes: &[u8], key: u64, dest: &mut [u8]) {
+    squeeze(absorb(bytes, key), dest);
+}
+
+/// Fills `dest` with the output of a `SplitMix64` generator starting from `state`, 8 little
+/// endian bytes at a time.
+fn squeeze(mut state: u64, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+        let output = splitmix64(&mut state).to_le_bytes();
+
+        chunk.copy_from_slice(&output[..chunk.len()]);
+    }
+}
+
+/// Mixes `bytes` and `key` into a single `u64`, in the same way as [`mix_seed`].
+pub(crate) fn mix_u64(bytes: &[u8], key: u64) -> u64 {
+    let mut state = absorb(bytes, key);
+
+    splitmix64(&mut state)
+}
+
+/// Returns a random `f32` in `[0, 1)`, built from the top 23 bits of [`RngCore::next_u32`] as the
+/// mantissa of a float in `[1, 2)`, minus one. Both steps are exact, so the result is
+/// bit-identical on every platform, and the algorithm will never change between versions.
+///
+/// ```
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{prelude::Entropy, util::random_f32_canonical};
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = Entropy::<WyRand>::from_seed([1; 8]);
+/// let value = random_f32_canonical(&mut rng);
+///
+/// assert!((0.0..1.0).contains(&value));
+/// ```
+#[inline]
+pub fn random_f32_canonical<R: RngCore + ?Sized>(rng: &mut R) -> f32 {
+    f32::from_bits(0x3F80_0000 | (rng.next_u32() >> 9)) - 1.0
+}
+
+/// Returns a random `f32` in `[lo, hi)`, scaled from [`random_f32_canonical`] with a single
+/// multiply and add. Unlike `Entropy::random_range`, the algorithm is fixed rather than
+/// depending on `rand`'s internals, and as Rust never fuses the multiply and add into an FMA
+/// instruction, the result is bit-identical on every platform with IEEE 754 floats, such as x86
+/// with SSE2, ARM and WASM. Results that would round up to `hi` are clamped to the largest float
+/// below it.
+///
+/// # Panics
+///
+/// Panics if `lo` or `hi` are not finite, or if `lo >= hi`.
+pub fn random_range_f32_exact<R: RngCore + ?Sized>(rng: &mut R, lo: f32, hi: f32) -> f32 {
+    assert!(
+        lo.is_finite() && hi.is_finite() && lo < hi,
+        "invalid range {lo}..{hi}"
+    );
+
+    let value = lo + (hi - lo) * random_f32_canonical(rng);
+
+    value.min(next_down(hi))
+}
+
+/// Returns the largest float below the finite `value`.
+fn next_down(value: f32) -> f32 {
+    let bits = value.to_bits();
+
+    if value == 0.0 {
+        -f32::from_bits(1)
+    } else if value > 0.0 {
+        f32::from_bits(bits - 1)
+    } else {
+        f32::from_bits(bits + 1)
+    }
+}
+
+/// Returns a random integer in `0..1000`, without bias, for expressing chances in permille
+/// without floats, such as `random_permille(&mut rng) < 125` for a 12.5% chance.
+#[inline]
+pub fn random_permille<R: RngCore + ?Sized>(rng: &mut R) -> u16 {
+    gen_index(rng, 1000) as u16
+}
+
+/// Returns a random fixed-point fraction in `[0, 1)` with `FRAC` fractional bits, as the raw
+/// value in `0..2^FRAC`, taken from the top bits of [`RngCore::next_u32`]. For example, with
+/// `FRAC = 16`, a value of `0x8000` represents `0.5`. `FRAC` must be in `1..=32`, which is checked
+/// at compile time.
+#[inline]
+pub fn random_fixed<const FRAC: u32, R: RngCore + ?Sized>(rng: &mut R) -> u32 {
+    #[allow(clippy::let_unit_value)]
+    let () = FracBits::<FRAC>::VALID;
+
+    ((u64::from(rng.next_u32())) >> (32 - FRAC)) as u32
+}
+
+struct FracBits<const FRAC: u32>;
+
+impl<const FRAC: u32> FracBits<FRAC> {
+    const VALID: () = assert!(FRAC > 0 && FRAC <= 32, "FRAC must be in 1..=32");
+}
+
+/// Key for mixing seed bytes into a [`stable_key`], so that keys are unrelated to other values
+/// derived from the same seeds.
+const STABLE_KEY: u64 = 0x5EED_0F0E_DE12_0000;
+
+/// Returns a key for ordering `entity` for RNG purposes, derived from the bytes of its `seed`, or
+/// from the [`Entity`] bits if it is unseeded.
+///
+/// Entity ids are handed out in spawn order and recycled on despawn, so they differ between runs
+/// that spawn entities in a different order, and between a saved game and its reload. A source's
+/// [`RngSeed`] however is part of the deterministic state of the game, and persists with it. Keys
+/// derived from seeds therefore order the same logical set of entities the same way across runs
+/// and reloads, where ordering by entity bits would not. The same seed always gives the same key.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{prelude::RngSeed, util::stable_key};
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn turn_order(q_enemies: Query<(Entity, Option<&RngSeed<WyRand>>), With<Enemy>>) -> Vec<Entity> {
+///     let mut order: Vec<_> = q_enemies
+///         .iter()
+///         .map(|(entity, seed)| (stable_key(entity, seed), entity))
+///         .collect();
+///
+///     order.sort_unstable();
+///     order.into_iter().map(|(_, entity)| entity).collect()
+/// }
+/// ```
+pub fn stable_key<R: EntropySource>(entity: Entity, seed: Option<&RngSeed<R>>) -> u64
+where
+    R::Seed: EntropySeed,
+{
+    seed.map_or_else(
+        || entity.to_bits(),
+        |seed| mix_u64(seed.get_seed().as_ref(), STABLE_KEY),
+    )
+}
+
+/// Samples up to `n` distinct items from `items` with `rng`, in the order they were sampled. If
+/// there are fewer than `n` items, all of them are returned, shuffled.
+///
+/// Iterating a query yields entities in archetype and table order, which changes whenever
+/// entities are spawned, despawned or have components added or removed, so sampling directly from
+/// a query iterator gives different results for the same seed. This first orders the candidates
+/// by their [`stable_key`], so the same seed and the same logical set of entities always give the
+/// same sample, regardless of iteration order, spawn order or saving and reloading the game.
+/// Entities with equal keys, such as sources sharing a seed, are ordered by their [`Entity`] bits.
+///
+/// Sampling is a partial Fisher-Yates shuffle, drawing `min(n, len)` indices over `u64` so that
+/// the output is the same on 32-bit and 64-bit platforms.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::{
+///     prelude::{GlobalEntropy, RngSeed},
+///     util::stable_sample,
+/// };
+///
+/// #[derive(Component)]
+/// struct Villager(&'static str);
+///
+/// fn pick_victims(
+///     q_villagers: Query<(Entity, Option<&RngSeed<WyRand>>, &Villager)>,
+///     mut rng: GlobalEntropy<WyRand>,
+/// ) {
+///     for (entity, villager) in stable_sample(q_villagers.iter(), rng.as_mut(), 3) {
+///         println!("{} ({entity}) was picked", villager.0);
+///     }
+/// }
+/// ```
+pub fn stable_sample<'a, R, T, G>(
+    items: impl IntoIterator<Item = (Entity, Option<&'a RngSeed<R>>, T)>,
+    rng: &mut G,
+    n: usize,
+) -> Vec<(Entity, T)>
+where
+    R: EntropySource + 'static,
+    R::Seed: EntropySeed,
+    G: RngCore + ?Sized,
+{
+    let mut items: Vec<_> = items
+        .into_iter()
+        .map(|(entity, seed, item)| (stable_key(entity, seed), entity, item))
+        .collect();
+
+    items.sort_unstable_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+
+    let n = n.min(items.len());
+
+    for i in 0..n {
+        let j = i + gen_index(rng, items.len() - i);
+        items.swap(i, j);
+    }
+
+    items.truncate(n);
+
+    items
+        .into_iter()
+        .map(|(_, entity, item)| (entity, item))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::{ChaCha8Rng, WyRand};
+
+    use super::*;
+
+    #[test]
+    fn derived_seeds_are_keyed() {
+        let base = [1u8; 32];
+
+        let a = derive_seed::<ChaCha8Rng>(&base, 1);
+        let b = derive_seed::<ChaCha8Rng>(&base, 2);
+
+        assert_eq!(a, derive_seed::<ChaCha8Rng>(&base, 1));
+        assert_ne!(a, b);
+        assert_ne!(a, base);
+    }
+
+    #[test]
+    fn float_and_fixed_helpers_are_pinned() {
+        use bevy_prng::WyRand;
+
+        let mut rng = WyRand::from_seed([1; 8]);
+
+        let canonical: [u32; 3] =
+            core::array::from_fn(|_| random_f32_canonical(&mut rng).to_bits());
+        let ranged: [u32; 3] =
+            core::array::from_fn(|_| random_range_f32_exact(&mut rng, -2.5, 7.25).to_bits());
+        let permille: [u16; 3] = core::array::from_fn(|_| random_permille(&mut rng));
+        let fixed: [u32; 3] = core::array::from_fn(|_| random_fixed::<16, _>(&mut rng));
+
+        assert_eq!(canonical, [1027269696, 1062670534, 1064161822]);
+        assert_eq!(ranged, [1045165088, 1074201208, 3220110072]);
+        assert_eq!(permille, [396, 499, 223]);
+        assert_eq!(fixed, [65206, 6211, 3808]);
+        assert!(random_fixed::<32, _>(&mut rng) > 0xFFFF);
+    }
+
+    #[test]
+    fn exact_ranges_exclude_the_upper_bound() {
+        assert_eq!(next_down(1.0), 0.999_999_94);
+        assert_eq!(next_down(0.0), -f32::from_bits(1));
+        assert_eq!(next_down(-1.0), -1.000_000_1);
+
+        struct Max;
+
+        impl RngCore for Max {
+            fn next_u32(&mut self) -> u32 {
+                u32::MAX
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                u64::MAX
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                dest.fill(0xFF);
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        assert!(random_range_f32_exact(&mut Max, 1.0e7, 1.0e7 + 1.0) < 1.0e7 + 1.0);
+    }
+
+    #[test]
+    fn stable_hashes_are_little_endian() {
+        use core::hash::{Hash, Hasher};
+
+        let mut hasher = StableHasher::default();
+
+        (0x0102_0304u32, 0x0506_0708_090A_0B0Cu64).hash(&mut hasher);
+
+        let mut bytes = StableHasher::default();
+
+        bytes.write(&0x0102_0304u32.to_le_bytes());
+        bytes.write(&0x0506_0708_090A_0B0Cu64.to_le_bytes());
+
+        assert_eq!(hasher.finish(), bytes.finish());
+        assert_eq!(hasher.finish(), 0xBBA1_011D_FEE4_2E45);
+    }
+
+    #[test]
+    fn label_hash_is_stable() {
+        assert_eq!(hash_label(""), 0xCBF2_9CE4_8422_2325);
+        assert_eq!(hash_label("a"), 0xAF63_DC4C_8601_EC8C);
+    }
+
+    #[test]
+    fn sample_is_order_independent() {
+        let seeds: Vec<RngSeed<WyRand>> = (0..10u8).map(|i| RngSeed::from_seed([i; 8])).collect();
+
+        // Seeded entities keep their seeds, but have different ids in each run, as if they
+        // were spawned in a different order or reloaded from a save.
+        let first: Vec<_> = seeds
+            .iter()
+            .enumerate()
+            .map(|(i, seed)| (Entity::from_raw(i as u32), Some(seed), i))
+            .chain([(Entity::from_raw(100), None, 10)])
+            .collect();
+        let mut second: Vec<_> = seeds
+            .iter()
+            .enumerate()
+            .map(|(i, seed)| (Entity::from_raw(50 - i as u32), Some(seed), i))
+            .chain([(Entity::from_raw(100), None, 10)])
+            .collect();
+        second.rotate_left(3);
+
+        let values = |sample: Vec<(Entity, usize)>| -> Vec<usize> {
+            sample.into_iter().map(|(_, value)| value).collect()
+        };
+
+        let mut rng1 = WyRand::from_seed([9; 8]);
+        let mut rng2 = WyRand::from_seed([9; 8]);
+
+        let a = values(stable_sample(first.iter().copied(), &mut rng1, 4));
+        let b = values(stable_sample(second.iter().copied(), &mut rng2, 4));
+
+        assert_eq!(a, b);
+        assert_eq!(rng1, rng2);
+        assert_eq!(a.len(), 4);
+
+        let all = values(stable_sample(first, &mut rng1, 20));
+        let mut sorted = all.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, (0..11).collect::<Vec<_>>());
+        assert_eq!(all, values(stable_sample(second, &mut rng2, 20)));
+    }
+
+    #[test]
+    fn stable_keys_follow_seeds() {
+        let seed = RngSeed::<WyRand>::from_seed([5; 8]);
+
+        assert_eq!(
+            stable_key(Entity::from_raw(1), Some(&seed)),
+            stable_key(Entity::from_raw(2), Some(&seed))
+        );
+        assert_ne!(
+            stable_key(Entity::from_raw(1), Some(&seed)),
+            stable_key(
+                Entity::from_raw(1),
+                Some(&RngSeed::<WyRand>::from_seed([6; 8]))
+            )
+        );
+        assert_eq!(
+            stable_key::<WyRand>(Entity::from_raw(3), None),
+            Entity::from_raw(3).to_bits()
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use alloc::vec::Vec;
+
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_prng::EntropySource;
+use bevy_reflect::Reflect;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand_core::RngCore;
+
+use crate::component::Entropy;
+
+pub use rand::distributions::WeightedError;
+
+/// A table of weighted entries, sampled with a provided RNG source such as an entity's
+/// own [`Entropy`]. Useful for loot tables, spawn tables and the like.
+///
+/// Weights are `u32` values so that sampling is exact and platform independent. A table is
+/// always valid: it must contain at least one entry, and the weights must not all be zero nor
+/// sum beyond `u32::MAX`. All constructors and mutating methods validate this.
+///
+/// The underlying distribution is cached and only rebuilt on the next sample after the weights
+/// have been changed, so updating many weights at once only pays for one rebuild. The cache is
+/// not reflected nor serialized, and is rebuilt on first use after deserialization. Reflection
+/// only registers [`Component`] type data, so that the payload type is not required to implement
+/// `Debug` or `serde` traits to be reflected.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_prng::WyRand;
+/// use bevy_rand::prelude::{Entropy, WeightedIndexTable};
+///
+/// #[derive(Debug)]
+/// enum Loot {
+///     Gold,
+///     Sword,
+/// }
+///
+/// #[derive(Component)]
+/// struct Chest(WeightedIndexTable<Loot>);
+///
+/// fn open_chests(mut q_chests: Query<(&mut Chest, &mut Entropy<WyRand>)>) {
+///     for (mut chest, mut rng) in q_chests.iter_mut() {
+///         let loot = chest.0.sample_with_entity_rng(&mut rng);
+///
+///         println!("Found: {loot:?}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(
+        try_from = "WeightedEntries<T>",
+        bound(deserialize = "T: serde::Deserialize<'de>")
+    )
+)]
+#[reflect(Component)]
+pub struct WeightedIndexTable<T> {
+    items: Vec<T>,
+    weights: Vec<u32>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    distribution: Option<WeightedIndex<u32>>,
+}
+
+impl<T> WeightedIndexTable<T> {
+    /// Create a new table from a list of `(item, weight)` entries.
+    pub fn new(entries: impl IntoIterator<Item = (T, u32)>) -> Result<Self, WeightedError> {
+        let (items, weights): (Vec<_>, Vec<_>) = entries.into_iter().unzip();
+
+        let distribution = build_distribution(&weights)?;
+
+        Ok(Self {
+            items,
+            weights,
+            distribution: Some(distribution),
+        })
+    }
+
+    /// Returns the items in the table.
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the weights in the table, with each weight matching the item at the same index.
+    #[inline]
+    pub fn weights(&self) -> &[u32] {
+        &self.weights
+    }
+
+    /// Returns an iterator over each item and its weight.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, u32)> {
+        self.items.iter().zip(self.weights.iter().copied())
+    }
+
+    /// Returns the number of entries in the table.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the table has no entries. A valid table is never empty, so this only
+    /// returns `true` if the table was emptied through reflection.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Sets the weight of the entry at `index`. The change is rejected if it would leave
+    /// the table without any non-zero weights, or if `index` is out of bounds.
+    pub fn set_weight(&mut self, index: usize, weight: u32) -> Result<(), WeightedError> {
+        let previous = *self
+            .weights
+            .get(index)
+            .ok_or(WeightedError::InvalidWeight)?;
+
+        self.weights[index] = weight;
+
+        if let Err(error) = validate_weights(&self.weights) {
+            self.weights[index] = previous;
+            return Err(error);
+        }
+
+        self.distribution = None;
+
+        Ok(())
+    }
+
+    /// Appends a new entry to the table.
+    pub fn push(&mut self, item: T, weight: u32) -> Result<(), WeightedError> {
+        self.weights.push(weight);
+
+        if let Err(error) = validate_weights(&self.weights) {
+            self.weights.pop();
+            return Err(error);
+        }
+
+        self.items.push(item);
+        self.distribution = None;
+
+        Ok(())
+    }
+
+    /// Samples an item from the table with the provided RNG source, rebuilding the cached
+    /// distribution first if the weights have changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table was made invalid by modifying it through reflection.
+    pub fn sample(&mut self, rng: &mut impl RngCore) -> &T {
+        let distribution = self.distribution.get_or_insert_with(|| {
+            build_distribution(&self.weights).expect("WeightedIndexTable weights are invalid")
+        });
+
+        &self.items[distribution.sample(rng)]
+    }
+
+    /// Samples an item from the table with an [`Entropy`] source, typically the one belonging to
+    /// the same entity as this table. This is the same as [`WeightedIndexTable::sample`], but
+    /// allows passing `&mut Mut<Entropy<R>>` from a query without explicit derefs.
+    #[inline]
+    pub fn sample_with_entity_rng<R: EntropySource + 'static>(
+        &mut self,
+        rng: &mut Entropy<R>,
+    ) -> &T {
+        self.sample(rng)
+    }
+}
+
+impl<T: PartialEq> PartialEq for WeightedIndexTable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // The cached distribution is derived from the weights, so is not compared.
+        self.items == other.items && self.weights == other.weights
+    }
+}
+
+fn validate_weights(weights: &[u32]) -> Result<(), WeightedError> {
+    if weights.is_empty() {
+        return Err(WeightedError::NoItem);
+    }
+
+    let total = weights.iter().try_fold(0u32, |total, &weight| {
+        total
+            .checked_add(weight)
+            .ok_or(WeightedError::InvalidWeight)
+    })?;
+
+    if total == 0 {
+        return Err(WeightedError::AllWeightsZero);
+    }
+
+    Ok(())
+}
+
+fn build_distribution(weights: &[u32]) -> Result<WeightedIndex<u32>, WeightedError> {
+    validate_weights(weights)?;
+
+    WeightedIndex::new(weights)
+}
+
+/// The serialized shape of [`WeightedIndexTable`], validated upon deserialization.
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+struct WeightedEntries<T> {
+    items: Vec<T>,
+    weights: Vec<u32>,
+}
+
+#[cfg(feature = "serialize")]
+impl<T> TryFrom<WeightedEntries<T>> for WeightedIndexTable<T> {
+    type Error = WeightedError;
+
+    fn try_from(value: WeightedEntries<T>) -> Result<Self, Self::Error> {
+        if value.items.len() != value.weights.len() {
+            return Err(WeightedError::InvalidWeight);
+        }
+
+        Self::new(value.items.into_iter().zip(value.weights))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn validates_weights() {
+        assert_eq!(
+            WeightedIndexTable::<u8>::new([]).unwrap_err(),
+            WeightedError::NoItem
+        );
+        assert_eq!(
+            WeightedIndexTable::new([('a', 0), ('b', 0)]).unwrap_err(),
+            WeightedError::AllWeightsZero
+        );
+        assert_eq!(
+            WeightedIndexTable::new([('a', u32::MAX), ('b', 1)]).unwrap_err(),
+            WeightedError::InvalidWeight
+        );
+
+        let mut table = WeightedIndexTable::new([('a', 1), ('b', 0)]).unwrap();
+
+        assert_eq!(
+            table.set_weight(0, 0).unwrap_err(),
+            WeightedError::AllWeightsZero
+        );
+        assert_eq!(
+            table.set_weight(2, 1).unwrap_err(),
+            WeightedError::InvalidWeight
+        );
+        // Rejected changes must leave the table untouched
+        assert_eq!(table.weights(), &[1, 0]);
+    }
+
+    #[test]
+    fn deterministic_sampling() {
+        let mut table = WeightedIndexTable::new([('a', 1), ('b', 2), ('c', 7)]).unwrap();
+        let mut rng = Entropy::<WyRand>::from_seed([42; 8]);
+
+        let samples: Vec<char> = (0..10)
+            .map(|_| *table.sample_with_entity_rng(&mut rng))
+            .collect();
+
+        assert_eq!(
+            &samples,
+            &['c', 'c', 'c', 'b', 'b', 'c', 'c', 'b', 'c', 'a']
+        );
+    }
+
+    #[test]
+    fn weight_updates_rebuild_distribution() {
+        let mut table = WeightedIndexTable::new([('a', 1), ('b', 1)]).unwrap();
+        let mut rng = WyRand::from_seed([7; 8]);
+
+        table.set_weight(0, 0).unwrap();
+
+        assert!((0..50).all(|_| *table.sample(&mut rng) == 'b'));
+
+        table.push('c', 1).unwrap();
+        table.set_weight(1, 0).unwrap();
+
+        assert!((0..50).all(|_| *table.sample(&mut rng) == 'c'));
+        assert_eq!(table.len(), 3);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_round_trip() {
+        let mut table = WeightedIndexTable::new([(1u8, 5), (2, 10)]).unwrap();
+
+        let serialized = ron::to_string(&table).unwrap();
+
+        assert_eq!(&serialized, "(items:[1,2],weights:[5,10])");
+
+        let mut deserialized: WeightedIndexTable<u8> = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(table, deserialized);
+
+        let mut rng1 = WyRand::from_seed([1; 8]);
+        let mut rng2 = WyRand::from_seed([1; 8]);
+
+        for _ in 0..10 {
+            assert_eq!(table.sample(&mut rng1), deserialized.sample(&mut rng2));
+        }
+
+        // Invalid tables are rejected
+        assert!(ron::from_str::<WeightedIndexTable<u8>>("(items:[1],weights:[0])").is_err());
+        assert!(ron::from_str::<WeightedIndexTable<u8>>("(items:[1,2],weights:[1])").is_err());
+    }
+}
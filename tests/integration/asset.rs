@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use bevy_app::{prelude::*, TaskPoolPlugin};
+use bevy_asset::{
+    io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetSource, AssetSourceId,
+    },
+    AssetApp, AssetPlugin, Assets,
+};
+use bevy_ecs::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rand::{
+    asset::{SeedConfig, SeedConfigHandle, SeedConfigPlugin, SeedValue},
+    global::Global,
+    plugin::EntropyPlugin,
+    seed::RngSeed,
+    traits::SeedSource,
+};
+
+fn app_with_seeds(seeds: Option<&str>) -> App {
+    let dir = Dir::default();
+
+    if let Some(seeds) = seeds {
+        dir.insert_asset_text(Path::new("world.seeds.ron"), seeds);
+    }
+
+    let mut app = App::new();
+
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSource::build().with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+    )
+    .add_plugins((
+        TaskPoolPlugin::default(),
+        AssetPlugin::default(),
+        EntropyPlugin::<WyRand>::with_seed([1; 8]),
+        SeedConfigPlugin::<WyRand>::new("world.seeds.ron"),
+    ));
+
+    app
+}
+
+fn global_seed(app: &mut App) -> [u8; 8] {
+    app.world_mut()
+        .query_filtered::<&RngSeed<WyRand>, With<Global>>()
+        .iter(app.world())
+        .next()
+        .unwrap()
+        .clone_seed()
+}
+
+fn run_until_loaded(app: &mut App) {
+    for _ in 0..100 {
+        app.update();
+
+        let handle = app
+            .world()
+            .resource::<SeedConfigHandle<WyRand>>()
+            .handle
+            .clone();
+
+        if app
+            .world()
+            .resource::<Assets<SeedConfig>>()
+            .contains(&handle)
+        {
+            break;
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    // Let the loaded event be applied
+    app.update();
+    app.update();
+}
+
+#[test]
+fn seed_config_applies_and_reapplies_on_modification() {
+    let mut app = app_with_seeds(Some(r#"(global: Some("0x2a00000000000000"))"#));
+
+    run_until_loaded(&mut app);
+
+    assert_eq!(global_seed(&mut app), 42u64.to_le_bytes());
+
+    // Simulate a hot reload by modifying the asset
+    let handle = app
+        .world()
+        .resource::<SeedConfigHandle<WyRand>>()
+        .handle
+        .clone();
+
+    app.world_mut()
+        .resource_mut::<Assets<SeedConfig>>()
+        .get_mut(&handle)
+        .unwrap()
+        .global = Some(SeedValue::Hex("0700000000000000".into()));
+
+    app.update();
+    app.update();
+
+    assert_eq!(global_seed(&mut app), 7u64.to_le_bytes());
+
+    // An invalid seed leaves the current seed as is
+    app.world_mut()
+        .resource_mut::<Assets<SeedConfig>>()
+        .get_mut(&handle)
+        .unwrap()
+        .global = Some(SeedValue::Hex("07".into()));
+
+    app.update();
+    app.update();
+
+    assert_eq!(global_seed(&mut app), 7u64.to_le_bytes());
+}
+
+#[test]
+fn missing_seed_config_keeps_current_seed() {
+    let mut app = app_with_seeds(None);
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert_eq!(global_seed(&mut app), [1; 8]);
+}
@@ -2,13 +2,16 @@ use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_prng::{ChaCha12Rng, ChaCha8Rng, WyRand};
 use bevy_rand::{
-    global::GlobalSeed,
-    prelude::{Entropy, EntropyPlugin, ForkableAsRng, ForkableRng, GlobalEntropy},
+    global::{GlobalSeed, GlobalSource},
+    prelude::{
+        BoxedEntropy, Entropy, EntropyPlugin, ForkableAsRng, ForkableInnerRng, ForkableInnerSeed,
+        ForkableRng, ForkableSeed, GlobalEntropy, ReservedSeeds, RngCommandsExt, RngSeed,
+    },
     traits::SeedSource,
 };
 use rand::prelude::Rng;
 
-use rand_core::RngCore;
+use rand_core::{RngCore, SeedableRng};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_test::*;
@@ -131,3 +134,143 @@ fn test_parallel_determinism() {
         )
         .run();
 }
+
+/// Third-party plugins can consume randomness from [`BoxedEntropy`] without knowing the app's
+/// PRNG algorithm, while the app keeps determinism by seeding it from its own global source.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn boxed_entropy_is_seeded_by_app() {
+    #[derive(Component)]
+    struct Loot;
+
+    #[derive(Resource, Default)]
+    struct Rolls(Vec<u32>);
+
+    // The "plugin" only knows about `BoxedEntropy`
+    fn roll_loot(mut q_loot: Query<&mut BoxedEntropy, With<Loot>>, mut rolls: ResMut<Rolls>) {
+        for mut rng in q_loot.iter_mut() {
+            rolls.0.push(rng.next_u32());
+        }
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]))
+        .init_resource::<Rolls>()
+        .add_systems(
+            Startup,
+            |mut commands: Commands, mut rng: GlobalEntropy<ChaCha8Rng>| {
+                commands.spawn((Loot, rng.fork_boxed()));
+            },
+        )
+        .add_systems(Update, roll_loot);
+
+    app.update();
+    app.update();
+
+    let mut expected = Entropy::<ChaCha8Rng>::from_seed([2; 32]).fork_inner();
+
+    assert_eq!(
+        app.world().resource::<Rolls>().0,
+        [expected.next_u32(), expected.next_u32()]
+    );
+
+    let boxed = app.world_mut().query::<&BoxedEntropy>().single(app.world());
+
+    assert_eq!(boxed.algorithm(), "bevy_prng::ChaCha8Rng");
+    assert_eq!(
+        format!("{boxed:?}"),
+        "BoxedEntropy { algorithm: \"bevy_prng::ChaCha8Rng\", .. }"
+    );
+}
+
+#[derive(Component)]
+struct Enemy(usize);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reserved_seeds_are_independent_of_spawn_order() {
+    const ORDER: [usize; 10] = [7, 2, 9, 0, 4, 1, 8, 5, 3, 6];
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([3; 8]))
+        .add_systems(
+            Startup,
+            |mut commands: Commands, global: GlobalSource<WyRand>| {
+                commands.rng_entity::<WyRand>(*global).reserve_seeds(10);
+            },
+        )
+        .add_systems(
+            Update,
+            |mut commands: Commands,
+             reserved: Res<ReservedSeeds<WyRand>>,
+             mut next: Local<usize>| {
+                // Stream in a few enemies per frame, out of index order
+                for &index in ORDER.iter().skip(*next).take(3) {
+                    commands.spawn((Enemy(index), reserved.take(index).unwrap()));
+                }
+
+                *next += 3;
+            },
+        );
+
+    for _ in 0..4 {
+        app.update();
+    }
+
+    let mut global = Entropy::<WyRand>::from_seed([3; 8]);
+    let expected: Vec<_> = (0..10).map(|_| global.fork_inner_seed()).collect();
+
+    let mut q_enemies = app.world_mut().query::<(&Enemy, &RngSeed<WyRand>)>();
+
+    assert_eq!(q_enemies.iter(app.world()).count(), 10);
+
+    for (enemy, seed) in q_enemies.iter(app.world()) {
+        assert_eq!(seed.clone_seed(), expected[enemy.0]);
+    }
+
+    assert!(app
+        .world()
+        .resource::<ReservedSeeds<WyRand>>()
+        .take(10)
+        .is_none());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn spawned_entropy_is_available_to_queued_commands() {
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([5; 8]))
+        .add_systems(
+            Update,
+            |mut commands: Commands, mut global: GlobalEntropy<WyRand>| {
+                let seed = global.fork_seed();
+                let expected = Entropy::<WyRand>::from_seed(seed.clone_seed());
+                let spawned = commands.spawn(seed).id();
+
+                // The seed's hook must have initialised the entropy by the next queued command
+                commands.queue(move |world: &mut World| {
+                    let mut rng = world.get_mut::<Entropy<WyRand>>(spawned).unwrap();
+
+                    assert_eq!(*rng, expected);
+
+                    rng.next_u64();
+                });
+            },
+        );
+
+    app.update();
+
+    // Spawning directly on the world makes the entropy available straight away as well
+    let spawned = app
+        .world_mut()
+        .spawn(RngSeed::<WyRand>::from_seed([6; 8]))
+        .id();
+
+    assert_eq!(
+        app.world().get::<Entropy<WyRand>>(spawned),
+        Some(&Entropy::from_seed([6; 8]))
+    );
+}
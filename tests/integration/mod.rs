@@ -1,2 +1,10 @@
+#[cfg(feature = "bevy_asset")]
+pub mod asset;
 pub mod determinism;
+pub mod plugins;
+pub mod prelude;
 pub mod reseeding;
+#[cfg(feature = "bevy_state")]
+pub mod state;
+pub mod sync;
+pub mod type_paths;
@@ -0,0 +1,409 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rand::{
+    global::{Global, GlobalEntropy, GlobalEntropyResource, ResourceMirrorPolicy},
+    plugin::{
+        clone_rng_setup, CloneRngPolicy, EntropyPlugin, GlobalRngPlugin, ReflectionScope,
+        RngObserversPlugin, RngTypesPlugin,
+    },
+    prelude::Entropy,
+    seed::RngSeed,
+    traits::{ForkableRng, SeedSource},
+};
+use rand_core::{RngCore, SeedableRng};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+fn global_count(app: &mut App) -> usize {
+    let world = app.world_mut();
+
+    world
+        .query_filtered::<(), (With<RngSeed<WyRand>>, With<Global>)>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn user_spawned_global_without_global_plugin() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        RngTypesPlugin::<WyRand>::default(),
+        RngObserversPlugin::<WyRand>::default(),
+    ));
+
+    assert_eq!(global_count(&mut app), 0);
+    assert!(app
+        .world()
+        .resource::<AppTypeRegistry>()
+        .read()
+        .contains(core::any::TypeId::of::<RngSeed<WyRand>>()));
+
+    app.world_mut()
+        .spawn((Global, RngSeed::<WyRand>::from_seed([7; 8])));
+
+    // Sources inserted without a seed still have one recorded by the observer plugin's hook
+    let source = app
+        .world_mut()
+        .spawn(Entropy::<WyRand>::from_seed([8; 8]))
+        .id();
+
+    app.world_mut().flush();
+
+    assert!(app.world().get::<RngSeed<WyRand>>(source).is_some());
+
+    app.add_systems(Update, |mut global: GlobalEntropy<WyRand>| {
+        assert_eq!(
+            global.next_u64(),
+            WyRand::from_seed([7; 8]).next_u64(),
+            "user spawned global should be queryable as the Global source"
+        );
+    });
+
+    app.update();
+
+    assert_eq!(global_count(&mut app), 1);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn composed_plugins_match_bundle() {
+    let mut composed = App::new();
+
+    composed.add_plugins((
+        RngTypesPlugin::<WyRand>::default(),
+        RngObserversPlugin::<WyRand>::default(),
+        GlobalRngPlugin::<WyRand>::with_seed([3; 8]),
+    ));
+
+    let mut bundled = App::new();
+
+    bundled.add_plugins(EntropyPlugin::<WyRand>::with_seed([3; 8]));
+
+    let global_output = |app: &mut App| {
+        let world = app.world_mut();
+
+        world
+            .query_filtered::<&Entropy<WyRand>, With<Global>>()
+            .iter(world)
+            .next()
+            .cloned()
+            .unwrap()
+            .next_u64()
+    };
+
+    assert_eq!(global_output(&mut composed), global_output(&mut bundled));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugins_are_idempotent() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<WyRand>::with_seed([1; 8]),
+        RngTypesPlugin::<WyRand>::default(),
+        RngObserversPlugin::<WyRand>::default(),
+        GlobalRngPlugin::<WyRand>::with_seed([2; 8]),
+    ));
+
+    app.finish();
+    app.cleanup();
+
+    // The later global plugin keeps the global spawned by the bundle
+    assert_eq!(global_count(&mut app), 1);
+
+    let world = app.world_mut();
+    let seed = world
+        .query_filtered::<&RngSeed<WyRand>, With<Global>>()
+        .iter(world)
+        .next()
+        .unwrap()
+        .clone_seed();
+
+    assert_eq!(seed, [1; 8]);
+}
+
+#[derive(Resource, Default)]
+struct Draws(Vec<u64>);
+
+fn draw_from_mirror(mut rng: ResMut<GlobalEntropyResource<WyRand>>, mut draws: ResMut<Draws>) {
+    draws.0.push(rng.next_u64());
+}
+
+fn mirrored_app(policy: ResourceMirrorPolicy) -> App {
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]).with_resource_mirror(policy))
+        .init_resource::<Draws>()
+        .add_systems(Update, draw_from_mirror);
+
+    app
+}
+
+fn reseed_global(app: &mut App, seed: [u8; 8]) {
+    let world = app.world_mut();
+    let global = world
+        .query_filtered::<Entity, With<Global>>()
+        .iter(world)
+        .next()
+        .unwrap();
+
+    world
+        .entity_mut(global)
+        .insert(RngSeed::<WyRand>::from_seed(seed));
+    world.flush();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn resource_mirror_is_deterministic_under_fixed_seed() {
+    let mut app = mirrored_app(ResourceMirrorPolicy::Independent);
+
+    app.update();
+    app.update();
+
+    let mut expected = Entropy::<WyRand>::from_seed([1; 8]).fork_rng();
+
+    assert_eq!(
+        app.world().resource::<Draws>().0,
+        [expected.next_u64(), expected.next_u64()]
+    );
+
+    // The global source has been advanced by the initial fork
+    let mut global = Entropy::<WyRand>::from_seed([1; 8]);
+    global.fork_rng();
+
+    let world = app.world_mut();
+    let mut actual = world
+        .query_filtered::<&Entropy<WyRand>, With<Global>>()
+        .iter(world)
+        .next()
+        .cloned()
+        .unwrap();
+
+    assert_eq!(actual.next_u64(), global.next_u64());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn independent_mirror_ignores_global_reseed() {
+    let mut app = mirrored_app(ResourceMirrorPolicy::Independent);
+
+    app.update();
+    reseed_global(&mut app, [5; 8]);
+    app.update();
+
+    let mut expected = Entropy::<WyRand>::from_seed([1; 8]).fork_rng();
+
+    assert_eq!(
+        app.world().resource::<Draws>().0,
+        [expected.next_u64(), expected.next_u64()]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mirror_seed_follows_global_reseed() {
+    let mut app = mirrored_app(ResourceMirrorPolicy::MirrorSeed);
+
+    app.update();
+    app.update();
+    reseed_global(&mut app, [5; 8]);
+    app.update();
+
+    let mut before = Entropy::<WyRand>::from_seed([1; 8]).fork_rng();
+    let mut after = Entropy::<WyRand>::from_seed([5; 8]).fork_rng();
+
+    assert_eq!(
+        app.world().resource::<Draws>().0,
+        [before.next_u64(), before.next_u64(), after.next_u64()]
+    );
+
+    // Unrelated seed inserts leave the mirror untouched
+    app.world_mut().spawn(RngSeed::<WyRand>::from_seed([6; 8]));
+    app.world_mut().flush();
+    app.update();
+
+    assert_eq!(app.world().resource::<Draws>().0[3], after.next_u64());
+}
+
+fn world_global_draws(world: &mut World) -> [u64; 4] {
+    let mut rng = world
+        .query_filtered::<&mut Entropy<WyRand>, With<Global>>()
+        .iter_mut(world)
+        .next()
+        .unwrap();
+
+    core::array::from_fn(|_| rng.next_u64())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cloned_worlds_diverge_deterministically() {
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]));
+
+    // Advance the original mid-game before taking copies of its setup
+    world_global_draws(app.world_mut());
+
+    let clone = |app: &App, policy| {
+        let mut world = World::new();
+
+        clone_rng_setup::<WyRand>(app.world(), &mut world, policy);
+
+        world
+    };
+
+    let mut first = clone(&app, CloneRngPolicy::Fork(0));
+    let mut repeat = clone(&app, CloneRngPolicy::Fork(0));
+    let mut other = clone(&app, CloneRngPolicy::Fork(1));
+    let mut mirror = clone(&app, CloneRngPolicy::Mirror);
+
+    let first = world_global_draws(&mut first);
+
+    assert_eq!(first, world_global_draws(&mut repeat));
+    assert_ne!(first, world_global_draws(&mut other));
+
+    // Copies leave the original untouched, so a mirror draws what the original draws next
+    let mirrored = world_global_draws(&mut mirror);
+
+    assert_eq!(mirrored, world_global_draws(app.world_mut()));
+    assert_ne!(first, mirrored);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cloned_worlds_have_working_hooks() {
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]));
+
+    let mut world = World::new();
+
+    clone_rng_setup::<WyRand>(app.world(), &mut world, CloneRngPolicy::Fork(0));
+
+    // Cloning again reseeds the existing global rather than spawning another
+    clone_rng_setup::<WyRand>(app.world(), &mut world, CloneRngPolicy::Fork(1));
+
+    assert_eq!(
+        world
+            .query_filtered::<(), (With<RngSeed<WyRand>>, With<Global>)>()
+            .iter(&world)
+            .count(),
+        1
+    );
+
+    let entity = world.spawn(Entropy::<WyRand>::from_seed([2; 8])).id();
+    world.flush();
+
+    assert!(world.get::<RngSeed<WyRand>>(entity).is_some());
+    assert!(world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .contains(core::any::TypeId::of::<Entropy<WyRand>>()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn named_seeds_seed_the_global_source() {
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_named_seed("banana"));
+
+    let world = app.world_mut();
+    let seed = world
+        .query_filtered::<&RngSeed<WyRand>, With<Global>>()
+        .iter(world)
+        .next()
+        .unwrap();
+
+    assert_eq!(seed, &RngSeed::from_phrase_hash("banana"));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reflection_scopes_limit_registrations() {
+    use core::any::TypeId;
+
+    let registered = |scope: ReflectionScope| {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]).with_reflection(scope));
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+
+        [
+            TypeId::of::<Entropy<WyRand>>(),
+            TypeId::of::<WyRand>(),
+            TypeId::of::<GlobalEntropyResource<WyRand>>(),
+            TypeId::of::<RngSeed<WyRand>>(),
+            TypeId::of::<[u8; 8]>(),
+        ]
+        .map(|id| registry.contains(id))
+    };
+
+    assert_eq!(registered(ReflectionScope::Full), [true; 5]);
+    assert_eq!(
+        registered(ReflectionScope::SeedsOnly),
+        [false, false, false, true, true]
+    );
+    assert_eq!(registered(ReflectionScope::None), [false; 5]);
+}
+
+#[cfg(feature = "bevy_scene")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reflection_scopes_limit_scene_exports() {
+    use bevy_scene::DynamicSceneBuilder;
+
+    let export = |scope: ReflectionScope| {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]).with_reflection(scope));
+
+        let world = app.world_mut();
+        let entity = world.spawn(RngSeed::<WyRand>::from_seed([2; 8])).id();
+        let scene = DynamicSceneBuilder::from_world(world)
+            .extract_entity(entity)
+            .build();
+
+        let components: Vec<_> = scene.entities[0]
+            .components
+            .iter()
+            .map(|component| {
+                component
+                    .get_represented_type_info()
+                    .unwrap()
+                    .type_path_table()
+                    .short_path()
+            })
+            .collect();
+
+        let serialized = scene
+            .serialize(&world.resource::<AppTypeRegistry>().read())
+            .unwrap();
+
+        (components, serialized)
+    };
+
+    let (components, serialized) = export(ReflectionScope::Full);
+
+    assert_eq!(components, ["Entropy<WyRand>"]);
+    assert!(serialized.contains("Entropy<bevy_prng::WyRand>"));
+
+    // Only the seed is exported, without the state of the source
+    let (components, serialized) = export(ReflectionScope::SeedsOnly);
+
+    assert_eq!(components, ["RngSeed<WyRand>"]);
+    assert!(serialized.contains("RngSeed<bevy_prng::WyRand>"));
+    assert!(!serialized.contains("Entropy"));
+
+    let (components, _) = export(ReflectionScope::None);
+
+    assert!(components.is_empty());
+}
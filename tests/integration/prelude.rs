@@ -0,0 +1,112 @@
+// Compile test for the prelude, naming every export so that renames can't silently drop one.
+#![allow(unused_imports)]
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_rand::prelude::{
+    clone_rng_setup, AnySeeded, AppRngExt, ApplyGlobalSeed, AsEntropyMut, BoxedEntropy, BurstRng,
+    CloneRngPolicy, DerivedFromRng, DerivedRngPlugin, Entropy, EntropyChangeExt, EntropyPlugin,
+    EntropyPool, EntropyPoolPlugin, ForkCounters, ForkableAsRng, ForkableAsSeed, ForkableInnerRng,
+    ForkableInnerSeed, ForkableRng, ForkableSeed, Global, GlobalEntropy, GlobalEntropyResource,
+    GlobalRngPlugin, GlobalSeed, GlobalSeedChanged, GlobalSource, NoiseSeeds, NoiseSeedsPlugin,
+    NonGlobal, NonGlobalEntropy, ReflectionScope, RequireRng, ReseedWorldPolicy, Reseeded,
+    ReservedSeeds, ResourceMirrorPolicy, RngCapabilities, RngCommandsExt, RngDerivedInvalidated,
+    RngEntityCommands, RngEntityCommandsAnyExt, RngLabel, RngLabelIndex, RngObserversPlugin,
+    RngParIterExt, RngProvider, RngQuality, RngSeed, RngSelectExt, RngTypesPlugin, Seed512,
+    SeedDispenser, SeedFallbackPolicy, SeedSource, SeedSyncPlugin, SeedToken, ShortSeed,
+    SimulationRng, StartupForks, StartupSeedBudget, StartupSeedBudgetPlugin, TemporarySeedExt,
+    UiEntropy, UiEntropyPlugin, UiRng, UiRngEntity, WithRng,
+};
+use rand_core::SeedableRng;
+
+#[cfg(feature = "rand")]
+use bevy_rand::prelude::{ShuffleBag, WeightedIndexTable};
+
+#[cfg(feature = "serialize")]
+use bevy_rand::prelude::LegacyGlobalMigrationPlugin;
+
+#[cfg(feature = "rand_distr")]
+use bevy_rand::prelude::{ExpParams, NormalParams, PoissonParams};
+
+#[cfg(feature = "bevy_math")]
+use bevy_rand::prelude::{
+    FrameJitter, FrameJitterPlugin, RngMathExt, ScatterConfig, ScatterPlugin, ScatterSpawner,
+    Scattered,
+};
+
+#[cfg(feature = "bevy_color")]
+use bevy_rand::prelude::{OklchRanges, RngColorExt};
+
+#[cfg(feature = "bevy_state")]
+use bevy_rand::prelude::{SeedPolicy, StateSeedPlugin};
+
+#[cfg(feature = "bevy_asset")]
+use bevy_rand::prelude::{SeedConfig, SeedConfigPlugin};
+
+#[cfg(feature = "bevy_time")]
+use bevy_rand::prelude::{AutoReseedPlugin, NextReseed};
+
+#[cfg(all(feature = "bevy_time", feature = "std"))]
+use bevy_rand::prelude::{ChancePerSecond, RandomTimer, RandomTimerPlugin, TimerDistribution};
+
+#[cfg(feature = "bevy_diagnostic")]
+use bevy_rand::prelude::FrameRng;
+
+#[cfg(feature = "bevy_scene")]
+use bevy_rand::prelude::{SceneRngPlugin, SceneRngPolicy};
+
+#[cfg(feature = "uuid")]
+use bevy_rand::prelude::EntropyUuidExt;
+
+#[cfg(feature = "thread_local_entropy")]
+use bevy_rand::prelude::{LocalEntropy, ThreadLocalEntropy};
+
+#[cfg(feature = "wyrand")]
+use bevy_rand::prelude::WyRand;
+
+#[cfg(feature = "chacha8")]
+use bevy_rand::prelude::ChaCha8Rng;
+
+#[cfg(feature = "chacha12")]
+use bevy_rand::prelude::ChaCha12Rng;
+
+#[cfg(feature = "chacha20")]
+use bevy_rand::prelude::ChaCha20Rng;
+
+#[cfg(feature = "rand_pcg")]
+use bevy_rand::prelude::{Pcg32, Pcg64, Pcg64Mcg};
+
+#[cfg(feature = "rand_xoshiro")]
+use bevy_rand::prelude::{
+    Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar, Xoroshiro64Star,
+    Xoroshiro64StarStar, Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar, Xoshiro256Plus,
+    Xoshiro256PlusPlus, Xoshiro256StarStar, Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_query_helpers_resolve_to_the_global_source() {
+    use bevy_prng::WyRand;
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]))
+        .add_systems(
+            Update,
+            |source: GlobalSource<WyRand>,
+             seed: GlobalSeed<WyRand>,
+             rng: GlobalEntropy<WyRand>,
+             q_global: Query<(Entity, &RngSeed<WyRand>), With<Global>>| {
+                let (entity, global_seed) = q_global.iter().next().unwrap();
+
+                assert_eq!(*source, entity);
+                assert_eq!(*seed, global_seed);
+                assert_eq!(rng.as_ref(), &Entropy::<WyRand>::from_seed([1; 8]));
+            },
+        );
+
+    app.update();
+}
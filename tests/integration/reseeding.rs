@@ -3,10 +3,12 @@ use bevy_ecs::prelude::*;
 use bevy_prng::{ChaCha8Rng, WyRand};
 use bevy_rand::{
     global::{Global, GlobalEntropy},
+    params::NonGlobalEntropy,
     plugin::EntropyPlugin,
     prelude::Entropy,
     seed::RngSeed,
-    traits::{ForkableAsSeed, ForkableSeed, SeedSource},
+    test_harness::DeterministicApp,
+    traits::{ForkableAsSeed, ForkableInnerSeed, ForkableSeed, SeedSource},
 };
 use rand_core::{RngCore, SeedableRng};
 
@@ -16,58 +18,40 @@ use wasm_bindgen_test::*;
 #[test]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
 fn test_global_reseeding() {
-    let mut app = App::new();
-
     let seed = [2; 32];
 
     let rng_eq = Entropy::<ChaCha8Rng>::from_seed(seed);
 
-    app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed(seed));
-
-    {
-        let global_rng = app
-            .world_mut()
-            .query_filtered::<&Entropy<ChaCha8Rng>, With<Global>>()
-            .single(app.world());
+    let mut app = DeterministicApp::new().with_rng::<ChaCha8Rng>(seed);
 
-        // Our RNGs should be the same as each other as they were initialised with the same seed
-        assert_eq!(global_rng, &rng_eq);
-    }
+    let global_rng = |app: &mut DeterministicApp| {
+        let global = app.global_entity::<ChaCha8Rng>();
 
-    app.update();
+        app.world()
+            .get::<Entropy<ChaCha8Rng>>(global)
+            .cloned()
+            .unwrap()
+    };
 
-    {
-        let global_rng = app
-            .world_mut()
-            .query_filtered::<&Entropy<ChaCha8Rng>, With<Global>>()
-            .single(app.world());
+    // Our RNGs should be the same as each other as they were initialised with the same seed
+    assert_eq!(global_rng(&mut app), rng_eq);
 
-        // Our RNGs should remain the same as each other as we have not run the update
-        assert_eq!(global_rng, &rng_eq);
-    }
+    app.run_frames(1);
 
-    {
-        let global = app
-            .world_mut()
-            .query_filtered::<Entity, With<Global>>()
-            .single(app.world());
+    // Our RNGs should remain the same as each other as we have not run the update
+    assert_eq!(global_rng(&mut app), rng_eq);
 
-        app.world_mut()
-            .entity_mut(global)
-            .insert(RngSeed::<ChaCha8Rng>::from_seed([3; 32]));
-    }
+    let global = app.global_entity::<ChaCha8Rng>();
 
-    app.update();
+    app.world_mut()
+        .entity_mut(global)
+        .insert(RngSeed::<ChaCha8Rng>::from_seed([3; 32]));
 
-    {
-        let global_rng = app
-            .world_mut()
-            .query_filtered::<&Entropy<ChaCha8Rng>, With<Global>>()
-            .single(app.world());
+    app.run_frames(1);
 
-        // Now our RNG will not be the same, even though we did not use it directly
-        assert_ne!(global_rng, &rng_eq);
-    }
+    // Now our RNG will not be the same, even though we did not use it directly
+    assert_ne!(global_rng(&mut app), rng_eq);
+    assert_eq!(app.global_seed::<ChaCha8Rng>(), [3; 32]);
 }
 
 #[test]
@@ -86,21 +70,18 @@ fn component_fork_seed() {
                 }
             },
         )
-        .add_systems(
-            Update,
-            |mut q_rng: Query<&mut Entropy<ChaCha8Rng>, Without<Global>>| {
-                let rngs = q_rng.iter_mut();
+        .add_systems(Update, |mut q_rng: NonGlobalEntropy<ChaCha8Rng>| {
+            let rngs = q_rng.iter_mut();
 
-                assert_eq!(rngs.size_hint().0, 5);
+            assert_eq!(rngs.size_hint().0, 5);
 
-                let values: Vec<_> = rngs.map(|mut rng| rng.next_u32()).collect();
+            let values: Vec<_> = rngs.map(|mut rng| rng.next_u32()).collect();
 
-                assert_eq!(
-                    &values,
-                    &[3315785188, 1951699392, 911252207, 791343233, 1599472206]
-                );
-            },
-        );
+            assert_eq!(
+                &values,
+                &[3315785188, 1951699392, 911252207, 791343233, 1599472206]
+            );
+        });
 
     app.update();
 }
@@ -121,29 +102,96 @@ fn component_fork_as_seed() {
                 }
             },
         )
-        .add_systems(
-            Update,
-            |mut q_rng: Query<&mut Entropy<WyRand>, Without<Global>>| {
-                let rngs = q_rng.iter_mut();
-
-                assert_eq!(rngs.size_hint().0, 5);
-
-                let values: Vec<_> = rngs.map(|mut rng| rng.next_u64()).collect();
-
-                assert_eq!(
-                    &values,
-                    &[
-                        10032395693880520184,
-                        15375025802368380325,
-                        10863580644061233257,
-                        7067543572507795213,
-                        7996461288508244033
-                    ]
-                );
-            },
-        );
+        .add_systems(Update, |mut q_rng: NonGlobalEntropy<WyRand>| {
+            let rngs = q_rng.iter_mut();
+
+            assert_eq!(rngs.size_hint().0, 5);
+
+            let values: Vec<_> = rngs.map(|mut rng| rng.next_u64()).collect();
+
+            assert_eq!(
+                &values,
+                &[
+                    10032395693880520184,
+                    15375025802368380325,
+                    10863580644061233257,
+                    7067543572507795213,
+                    7996461288508244033
+                ]
+            );
+        });
+
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entropy_without_seed_records_seed() {
+    #[derive(Component)]
+    struct FromSeed;
+    #[derive(Component)]
+    struct FromEntropy;
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([2; 8]));
+
+    let from_seed = app
+        .world_mut()
+        .spawn((FromSeed, RngSeed::<WyRand>::from_seed([3; 8])))
+        .id();
+    let from_entropy = app
+        .world_mut()
+        .spawn((FromEntropy, Entropy::<WyRand>::from_seed([3; 8])))
+        .id();
+
+    app.update();
+
+    let mut q_sources = app
+        .world_mut()
+        .query_filtered::<(Entity, &RngSeed<WyRand>, &Entropy<WyRand>), Without<Global>>();
+
+    let sources: Vec<_> = q_sources
+        .iter(app.world())
+        .map(|(entity, seed, rng)| (entity, seed.clone_seed(), rng.clone()))
+        .collect();
+
+    // Both sources are visible to seed queries
+    assert_eq!(sources.len(), 2);
+
+    // The recorded seed is forked from the entropy without changing its state
+    let (_, recorded, rng) = sources
+        .iter()
+        .find(|(entity, ..)| *entity == from_entropy)
+        .unwrap();
+
+    assert_eq!(rng, &Entropy::<WyRand>::from_seed([3; 8]));
+    assert_eq!(
+        recorded,
+        &Entropy::<WyRand>::from_seed([3; 8]).fork_inner_seed()
+    );
+
+    // Both sources reseed the same way
+    for entity in [from_seed, from_entropy] {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(RngSeed::<WyRand>::from_seed([4; 8]));
+    }
 
     app.update();
+
+    for entity in [from_seed, from_entropy] {
+        assert_eq!(
+            app.world().get::<Entropy<WyRand>>(entity),
+            Some(&Entropy::<WyRand>::from_seed([4; 8]))
+        );
+        assert_eq!(
+            app.world()
+                .get::<RngSeed<WyRand>>(entity)
+                .map(RngSeed::clone_seed),
+            Some([4; 8])
+        );
+    }
 }
 
 #[test]
@@ -151,9 +199,10 @@ fn component_fork_as_seed() {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
 fn observer_global_reseeding() {
     use bevy_app::prelude::{PostUpdate, PreUpdate, Startup};
-    use bevy_ecs::prelude::{Entity, With};
+    use bevy_ecs::prelude::Entity;
     use bevy_rand::{
         observers::ReseedRng,
+        params::NonGlobal,
         seed::RngSeed,
         traits::{ForkableInnerSeed, SeedSource},
     };
@@ -173,7 +222,7 @@ fn observer_global_reseeding() {
         )
         .add_systems(
             PreUpdate,
-            |query: Query<&RngSeed<WyRand>, Without<Global>>| {
+            |query: Query<&RngSeed<WyRand>, NonGlobal<WyRand>>| {
                 let expected = [
                     2484862625678185386u64,
                     10323237495534242118,
@@ -192,7 +241,7 @@ fn observer_global_reseeding() {
         .add_systems(
             Update,
             |mut commands: Commands,
-             query: Query<Entity, (With<Entropy<WyRand>>, Without<Global>)>,
+             query: Query<Entity, NonGlobal<WyRand>>,
              mut source: GlobalEntropy<WyRand>| {
                 for e in &query {
                     commands.trigger_targets(ReseedRng::<WyRand>::new(source.fork_inner_seed()), e);
@@ -201,7 +250,7 @@ fn observer_global_reseeding() {
         )
         .add_systems(
             PostUpdate,
-            |query: Query<&RngSeed<WyRand>, Without<Global>>| {
+            |query: Query<&RngSeed<WyRand>, NonGlobal<WyRand>>| {
                 let prev_expected = [
                     2484862625678185386u64,
                     10323237495534242118,
@@ -225,82 +274,386 @@ fn observer_global_reseeding() {
 #[cfg(feature = "experimental")]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
 fn generic_observer_reseeding_from_parent() {
-    use bevy_app::prelude::{PostUpdate, PreUpdate, Startup};
-    use bevy_ecs::prelude::{Entity, With};
-    use bevy_rand::{
-        observers::{LinkRngSourceToTarget, SeedFromGlobal, SeedFromParent},
-        plugin::LinkedEntropySources,
-        seed::RngSeed,
-        traits::SeedSource,
-    };
+    observer_reseeding_from_parent(false);
+}
 
-    let seed = [2u8; 8];
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_observer_reseeding_from_parent_scoped() {
+    observer_reseeding_from_parent(true);
+}
 
-    #[derive(Component)]
+#[cfg(feature = "experimental")]
+fn observer_reseeding_from_parent(scoped: bool) {
+    use bevy_rand::{commands::RngCommandsExt, plugin::LinkedEntropySources};
+
+    #[derive(Component, Default)]
     struct Source;
-    #[derive(Component)]
+    #[derive(Component, Default)]
     struct Target;
 
-    let mut app = App::new();
+    let app = DeterministicApp::new().with_rng::<WyRand>([2; 8]);
 
-    app.add_plugins((
-        EntropyPlugin::<WyRand>::with_seed(seed),
-        LinkedEntropySources::<Source, Target, WyRand>::default(),
-    ))
-    .add_systems(Startup, |mut commands: Commands| {
-        let source = commands.spawn(Source).id();
-        commands.spawn(Target);
-
-        commands.trigger(LinkRngSourceToTarget::<Source, Target, WyRand>::default());
-        commands.trigger_targets(SeedFromGlobal::<WyRand>::default(), source);
-    })
-    .add_systems(PreUpdate, |query: Query<&RngSeed<WyRand>, With<Target>>| {
-        let expected = 6445550333322662121;
-        let seed = u64::from_ne_bytes(query.single().clone_seed());
-
-        assert_eq!(seed, expected);
-    })
-    .add_systems(PreUpdate, |query: Query<&RngSeed<WyRand>, With<Source>>| {
-        let expected = 2484862625678185386;
-        let seed = u64::from_ne_bytes(query.single().clone_seed());
-
-        assert_eq!(seed, expected);
-    })
-    .add_systems(
-        Update,
-        |mut commands: Commands, query: Query<Entity, With<Target>>| {
-            commands.trigger_targets(SeedFromParent::<WyRand>::default(), query.single());
-        },
-    )
-    .add_systems(
-        PostUpdate,
-        |query: Query<&RngSeed<WyRand>, With<Target>>| {
-            let prev_expected = 6445550333322662121;
-            let expected = 14968821102299026759;
-            let seed = u64::from_ne_bytes(query.single().clone_seed());
-
-            assert_ne!(seed, prev_expected);
-            assert_eq!(seed, expected);
-        },
+    let mut app = if scoped {
+        app.with_plugins(LinkedEntropySources::<Source, Target, WyRand>::scoped())
+    } else {
+        app.with_relations::<Source, Target, WyRand>()
+    };
+
+    app.spawn_linked::<Source, Target, WyRand>(1);
+
+    assert_eq!(
+        app.seeds_of::<Source, WyRand>(),
+        [2484862625678185386u64.to_le_bytes()]
+    );
+    assert_eq!(
+        app.seeds_of::<Target, WyRand>(),
+        [6445550333322662121u64.to_le_bytes()]
     );
 
-    app.run();
+    let world = app.world_mut();
+    let target = world
+        .query_filtered::<Entity, With<Target>>()
+        .iter(world)
+        .next()
+        .unwrap();
+
+    world.commands().reseed_from_parent::<WyRand>(target);
+    world.flush();
+
+    assert_eq!(
+        app.seeds_of::<Target, WyRand>(),
+        [14968821102299026759u64.to_le_bytes()]
+    );
 }
 
 #[test]
 #[cfg(feature = "experimental")]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
 fn generic_observer_reseeding_children() {
-    use bevy_app::prelude::{Last, PostUpdate, PreUpdate, Startup};
-    use bevy_ecs::prelude::{Component, Entity, With, Without};
+    observer_reseeding_children(false);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_observer_reseeding_children_scoped() {
+    observer_reseeding_children(true);
+}
+
+#[cfg(feature = "experimental")]
+fn observer_reseeding_children(scoped: bool) {
+    use bevy_rand::{commands::RngCommandsExt, plugin::LinkedEntropySources};
+
+    #[derive(Component, Default)]
+    struct Source;
+    #[derive(Component, Default)]
+    struct Target;
+
+    let app = DeterministicApp::new().with_rng::<WyRand>([2; 8]);
+
+    let mut app = if scoped {
+        app.with_plugins(LinkedEntropySources::<Source, Target, WyRand>::scoped())
+    } else {
+        app.with_relations::<Source, Target, WyRand>()
+    };
+
+    let source = app.spawn_linked::<Source, Target, WyRand>(5);
+
+    let initial = [
+        6445550333322662121u64,
+        14968821102299026759,
+        12617564484450995185,
+        908888629357954483,
+        6128439264405451235,
+    ]
+    .map(u64::to_le_bytes);
+
+    assert_eq!(
+        app.seeds_of::<Source, WyRand>(),
+        [2484862625678185386u64.to_le_bytes()]
+    );
+    assert_eq!(app.seeds_of::<Target, WyRand>(), initial);
+
+    app.world_mut()
+        .commands()
+        .reseed_from_global::<WyRand>(source);
+    app.run_frames(1);
+
+    let expected = [
+        2656876351602726802u64,
+        4226413670151402273,
+        2344778986622729714,
+        9109365740673988404,
+        6101264679293753504,
+    ]
+    .map(u64::to_le_bytes);
+
+    let reseeded = app.seeds_of::<Target, WyRand>();
+
+    // Must not equal the previous seeds, but should equal the expected updated seeds.
+    for (previous, actual) in initial.iter().zip(&reseeded) {
+        assert_ne!(previous, actual);
+    }
+    assert_eq!(reseeded, expected);
+
+    // Check we have the correct amount of allocated RNG entities
+    assert_eq!(app.seeds_of::<Source, WyRand>().len(), 1);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn event_driven_reseeding_children() {
+    use bevy_rand::{
+        observers::{RngChildren, RngLinkIndex, RngParent},
+        requests::SeedRequest,
+    };
+
+    #[derive(Component, Default)]
+    struct Source;
+    #[derive(Component, Default)]
+    struct Target;
+
+    let mut app = DeterministicApp::new().with_plugins(
+        EntropyPlugin::<WyRand>::with_seed([2; 8]).with_event_driven_reseeding(Update),
+    );
+
+    // Link the same way as the observers, without using them
+    let world = app.world_mut();
+    let source = world.spawn((Source, RngChildren::<WyRand>::default())).id();
+
+    for index in 0..5 {
+        world.spawn((
+            Target,
+            RngParent::<WyRand>::new(source),
+            RngLinkIndex::new(index),
+        ));
+    }
+
+    world.send_event(SeedRequest::<WyRand>::FromGlobal(source));
+
+    // Nothing is reseeded until the system runs
+    assert!(app.seeds_of::<Target, WyRand>().is_empty());
+
+    app.run_frames(1);
+
+    // The same seeds as `observer_reseeding_children`
+    let initial = [
+        6445550333322662121u64,
+        14968821102299026759,
+        12617564484450995185,
+        908888629357954483,
+        6128439264405451235,
+    ]
+    .map(u64::to_le_bytes);
+
+    assert_eq!(
+        app.seeds_of::<Source, WyRand>(),
+        [2484862625678185386u64.to_le_bytes()]
+    );
+    assert_eq!(app.seeds_of::<Target, WyRand>(), initial);
+
+    app.world_mut()
+        .send_event(SeedRequest::<WyRand>::FromGlobal(source));
+    app.run_frames(1);
+
+    let expected = [
+        2656876351602726802u64,
+        4226413670151402273,
+        2344778986622729714,
+        9109365740673988404,
+        6101264679293753504,
+    ]
+    .map(u64::to_ne_bytes);
+
+    assert_eq!(app.seeds_of::<Target, WyRand>(), expected);
+
+    // Reseeding from the parent draws from the source, as with `SeedFromParent`
+    let world = app.world_mut();
+    let target = world
+        .query_filtered::<Entity, With<Target>>()
+        .iter(world)
+        .min()
+        .unwrap();
+
+    world.send_event(SeedRequest::<WyRand>::FromParent(target));
+    app.run_frames(1);
+
+    let reseeded = app.seeds_of::<Target, WyRand>();
+
+    assert_ne!(reseeded[0], expected[0]);
+    assert_eq!(reseeded[1..], expected[1..]);
+
+    app.world_mut()
+        .send_event(SeedRequest::<WyRand>::Reseed(source, [2; 8]));
+    app.run_frames(1);
+
+    assert_eq!(app.seeds_of::<Source, WyRand>(), [[2; 8]]);
+    assert!(app
+        .seeds_of::<Target, WyRand>()
+        .iter()
+        .zip(&reseeded)
+        .all(|(new, old)| new != old));
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn link_indices_above_the_cap_are_seeded_as_unindexed() {
+    use bevy_rand::{
+        observers::{RngChildren, RngLinkIndex, RngParent},
+        requests::SeedRequest,
+    };
+
+    #[derive(Component, Default)]
+    struct Source;
+    #[derive(Component, Default)]
+    struct Target;
+
+    let mut app = DeterministicApp::new().with_plugins(
+        EntropyPlugin::<WyRand>::with_seed([2; 8]).with_event_driven_reseeding(Update),
+    );
+
+    let world = app.world_mut();
+    let source = world.spawn((Source, RngChildren::<WyRand>::default())).id();
+
+    for index in [0, 1, 2, 3, u32::MAX] {
+        world.spawn((
+            Target,
+            RngParent::<WyRand>::new(source),
+            RngLinkIndex::new(index),
+        ));
+    }
+
+    world.send_event(SeedRequest::<WyRand>::FromGlobal(source));
+    app.run_frames(1);
+
+    // The same seeds as `event_driven_reseeding_children`, without forking up to `u32::MAX`
+    let initial = [
+        6445550333322662121u64,
+        14968821102299026759,
+        12617564484450995185,
+        908888629357954483,
+        6128439264405451235,
+    ]
+    .map(u64::to_le_bytes);
+
+    assert_eq!(app.seeds_of::<Target, WyRand>(), initial);
+}
+
+#[test]
+#[cfg(all(feature = "experimental", feature = "chacha8"))]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn stream_assignment_does_not_advance_source() {
+    let (before, after) = linked_targets_after_source_draws(true);
+
+    assert_eq!(before, after);
+}
+
+#[test]
+#[cfg(all(feature = "experimental", feature = "chacha8"))]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seed_assignment_advances_source() {
+    let (before, after) = linked_targets_after_source_draws(false);
+
+    assert_ne!(before, after);
+}
+
+#[test]
+#[cfg(all(feature = "experimental", feature = "chacha8"))]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn assigned_streams_follow_link_indices_and_are_restored() {
+    use bevy_prng::ChaChaStream;
     use bevy_rand::{
-        observers::{LinkRngSourceToTarget, SeedFromGlobal},
+        observers::{LinkRngSourceToTarget, RngLinkIndex, RngStream, SeedFromGlobal},
         plugin::LinkedEntropySources,
-        seed::RngSeed,
-        traits::SeedSource,
     };
 
-    let seed = [2u8; 8];
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component)]
+    struct Target;
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+        LinkedEntropySources::<Source, Target, ChaCha8Rng>::with_stream_assignment(),
+    ));
+
+    // Link indices out of entity order, as with targets restored from a save
+    let targets: Vec<_> = [2, 0, 1]
+        .map(|index| {
+            app.world_mut()
+                .spawn((Target, RngLinkIndex::new(index)))
+                .id()
+        })
+        .into();
+    let source = app.world_mut().spawn(Source).id();
+
+    app.world_mut()
+        .commands()
+        .trigger(LinkRngSourceToTarget::<Source, Target, ChaCha8Rng>::default());
+    app.world_mut()
+        .commands()
+        .trigger_targets(SeedFromGlobal::<ChaCha8Rng>::default(), source);
+    app.world_mut().flush();
+
+    let key = app
+        .world()
+        .get::<RngSeed<ChaCha8Rng>>(source)
+        .unwrap()
+        .clone_seed();
+
+    for (target, index) in targets.iter().zip([2u64, 0, 1]) {
+        let mut expected = ChaCha8Rng::from_seed(key);
+        expected.set_stream(index + 1);
+
+        assert_eq!(
+            app.world().get::<Entropy<ChaCha8Rng>>(*target),
+            Some(&Entropy::new(expected))
+        );
+        assert_eq!(
+            app.world()
+                .get::<RngStream<ChaCha8Rng>>(*target)
+                .unwrap()
+                .stream(),
+            index + 1
+        );
+    }
+
+    // A target's source is restored from its seed and its stream
+    let seed = app
+        .world()
+        .get::<RngSeed<ChaCha8Rng>>(targets[0])
+        .unwrap()
+        .clone();
+    let restored = app
+        .world_mut()
+        .spawn((seed, RngStream::<ChaCha8Rng>::new(3)))
+        .id();
+
+    app.world_mut().flush();
+
+    assert_eq!(
+        app.world().get::<Entropy<ChaCha8Rng>>(restored),
+        app.world().get::<Entropy<ChaCha8Rng>>(targets[0])
+    );
+}
+
+/// Links three targets to a source, then has the source draw values before the targets pull
+/// from their parent again. Returns the target sources before and after the source draws.
+#[cfg(all(feature = "experimental", feature = "chacha8"))]
+fn linked_targets_after_source_draws(
+    streams: bool,
+) -> (Vec<Entropy<ChaCha8Rng>>, Vec<Entropy<ChaCha8Rng>>) {
+    use bevy_prng::ChaChaStream;
+    use bevy_rand::{
+        observers::{LinkRngSourceToTarget, RngStream, SeedFromGlobal, SeedFromParent},
+        plugin::LinkedEntropySources,
+    };
 
     #[derive(Component)]
     struct Source;
@@ -310,94 +663,1257 @@ fn generic_observer_reseeding_children() {
     let mut app = App::new();
 
     app.add_plugins((
-        EntropyPlugin::<WyRand>::with_seed(seed),
-        LinkedEntropySources::<Source, Target, WyRand>::default(),
+        EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+        if streams {
+            LinkedEntropySources::<Source, Target, ChaCha8Rng>::with_stream_assignment()
+        } else {
+            LinkedEntropySources::<Source, Target, ChaCha8Rng>::default()
+        },
     ))
     .add_systems(Startup, |mut commands: Commands| {
-        commands.spawn_batch(vec![Target; 5]);
+        commands.spawn_batch(vec![Target; 3]);
         let source = commands.spawn(Source).id();
 
-        commands.trigger(LinkRngSourceToTarget::<Source, Target, WyRand>::default());
-        commands.trigger_targets(SeedFromGlobal::<WyRand>::default(), source);
-    })
-    .add_systems(
-        PreUpdate,
-        |query: Query<&RngSeed<WyRand>, (With<Target>, Without<Global>)>| {
-            let expected = [
-                6445550333322662121u64,
-                14968821102299026759,
-                12617564484450995185,
-                908888629357954483,
-                6128439264405451235,
-            ];
-            let seeds = query.iter().map(RngSeed::<WyRand>::clone_seed);
-
-            assert_eq!(seeds.size_hint().0, 5);
-
-            expected
-                .into_iter()
-                .zip(seeds.map(u64::from_ne_bytes))
-                .for_each(|(expected, actual)| assert_eq!(expected, actual));
-        },
-    )
-    .add_systems(PreUpdate, |query: Query<&RngSeed<WyRand>, With<Source>>| {
-        let expected = 2484862625678185386u64;
-        let seeds = u64::from_ne_bytes(query.single().clone_seed());
-
-        assert_eq!(expected, seeds);
-    })
-    .add_systems(
-        Update,
-        |mut commands: Commands, query: Query<Entity, With<Source>>| {
-            for entity in &query {
-                commands.trigger_targets(SeedFromGlobal::<WyRand>::default(), entity);
-            }
-        },
-    )
-    .add_systems(
-        PostUpdate,
-        |query: Query<&RngSeed<WyRand>, (With<Target>, Without<Global>)>| {
-            let prev_expected = [
-                6445550333322662121u64,
-                14968821102299026759,
-                12617564484450995185,
-                908888629357954483,
-                6128439264405451235,
-            ];
-            let expected = [
-                2656876351602726802u64,
-                4226413670151402273,
-                2344778986622729714,
-                9109365740673988404,
-                6101264679293753504,
-            ];
-
-            prev_expected
-                .into_iter()
-                .zip(expected)
-                .zip(
-                    query
-                        .iter()
-                        .map(RngSeed::<WyRand>::clone_seed)
-                        .map(u64::from_ne_bytes),
-                )
-                .for_each(|((previous, expected), actual)| {
-                    // Must not equal the previous seeds.
-                    assert_ne!(previous, actual);
-                    // Should equal the expected updated seeds.
-                    assert_eq!(expected, actual)
-                });
-        },
+        commands.trigger(LinkRngSourceToTarget::<Source, Target, ChaCha8Rng>::default());
+        commands.trigger_targets(SeedFromGlobal::<ChaCha8Rng>::default(), source);
+    });
+
+    app.update();
+
+    let targets = |app: &mut App| {
+        let mut targets: Vec<_> = app
+            .world_mut()
+            .query_filtered::<(Entity, &Entropy<ChaCha8Rng>), With<Target>>()
+            .iter(app.world())
+            .map(|(entity, rng)| (entity, rng.clone()))
+            .collect();
+
+        targets.sort_unstable_by_key(|(entity, _)| *entity);
+
+        targets
+    };
+
+    let before = targets(&mut app);
+
+    let (source, source_seed) = app
+        .world_mut()
+        .query_filtered::<(Entity, &RngSeed<ChaCha8Rng>), With<Source>>()
+        .iter(app.world())
+        .next()
+        .map(|(entity, seed)| (entity, seed.clone_seed()))
+        .unwrap();
+
+    if streams {
+        // Propagating streams must not have advanced the source
+        assert_eq!(
+            app.world().get::<Entropy<ChaCha8Rng>>(source).unwrap(),
+            &Entropy::<ChaCha8Rng>::from_seed(source_seed)
+        );
+
+        for (index, (entity, rng)) in before.iter().enumerate() {
+            let mut expected = ChaCha8Rng::from_seed(source_seed);
+            expected.set_stream(index as u64 + 1);
+
+            assert_eq!(rng, &Entropy::new(expected));
+            assert_eq!(
+                app.world()
+                    .get::<RngStream<ChaCha8Rng>>(*entity)
+                    .unwrap()
+                    .stream(),
+                index as u64 + 1
+            );
+        }
+    }
+
+    app.world_mut()
+        .get_mut::<Entropy<ChaCha8Rng>>(source)
+        .unwrap()
+        .fill_bytes(&mut [0; 64]);
+
+    for (entity, _) in &before {
+        app.world_mut()
+            .commands()
+            .trigger_targets(SeedFromParent::<ChaCha8Rng>::default(), *entity);
+    }
+
+    app.world_mut().flush();
+
+    let after = targets(&mut app);
+
+    (
+        before.into_iter().map(|(_, rng)| rng).collect(),
+        after.into_iter().map(|(_, rng)| rng).collect(),
     )
-    .add_systems(
-        Last,
-        |source: Query<&RngSeed<WyRand>, With<Source>>,
-         children: Query<&RngSeed<WyRand>, (Without<Source>, Without<Global>)>| {
-            // Check we have the correct amount of allocated RNG entities
-            assert_eq!(source.iter().size_hint().0, 1);
-            assert_eq!(children.iter().size_hint().0, 5);
-        },
-    );
+}
 
-    app.run();
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lazy_linked_observers_are_registered_once() {
+    use bevy_ecs::observer::Observer;
+    use bevy_rand::{
+        commands::RngCommandsExt,
+        observers::{RngObservers, SeedFromGlobal},
+    };
+
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component, Clone, Copy)]
+    struct Target;
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([2; 8]))
+        .add_systems(Startup, |mut commands: Commands| {
+            commands.spawn_batch(vec![Target; 5]);
+            let source = commands.spawn(Source).id();
+
+            commands.link_entropy_sources::<Source, Target, WyRand>();
+            commands.trigger_targets(SeedFromGlobal::<WyRand>::default(), source);
+        });
+
+    app.update();
+
+    // Seeds propagate exactly as with the eagerly registered observers
+    let expected = [
+        6445550333322662121u64,
+        14968821102299026759,
+        12617564484450995185,
+        908888629357954483,
+        6128439264405451235,
+    ];
+
+    let seeds: Vec<_> = app
+        .world_mut()
+        .query_filtered::<&RngSeed<WyRand>, With<Target>>()
+        .iter(app.world())
+        .map(RngSeed::as_u64)
+        .collect();
+
+    assert_eq!(seeds, expected);
+    assert!(app
+        .world()
+        .resource::<RngObservers>()
+        .is_registered::<Source, Target, WyRand>());
+
+    let mut observers = app.world_mut().query::<&Observer>();
+    let registered = observers.iter(app.world()).count();
+
+    app.world_mut()
+        .commands()
+        .link_entropy_sources::<Source, Target, WyRand>();
+    app.world_mut().flush();
+
+    assert_eq!(observers.iter(app.world()).count(), registered);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn link_keys_are_stable() {
+    use bevy_rand::observers::RngLinkKey;
+
+    // Keys are hashed from little endian bytes, so they are the same on every platform
+    assert_eq!(
+        RngLinkKey::new(&(0x0102_0304u32, 0x0506_0708_090A_0B0Cu64)).key(),
+        0xBBA1_011D_FEE4_2E45
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn stable_keyed_targets_are_unaffected_by_insertions() {
+    use bevy_rand::{commands::RngCommandsExt, plugin::LinkedEntropySources};
+
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component)]
+    struct Target(&'static str);
+
+    fn spawn_targets(names: &'static [&'static str]) -> App {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([4; 8]),
+            LinkedEntropySources::<Source, Target, WyRand>::with_stable_keys(),
+        ))
+        .add_systems(Startup, move |mut commands: Commands| {
+            let source = commands
+                .spawn((Source, RngSeed::<WyRand>::from_seed([5; 8])))
+                .id();
+
+            commands
+                .rng_entity::<WyRand>(source)
+                .with_target_rngs_stable(names.iter().map(|name| (*name, Target(name))));
+        });
+
+        app.update();
+
+        app
+    }
+
+    fn seeds(app: &mut App) -> Vec<(&'static str, [u8; 8])> {
+        let mut seeds: Vec<_> = app
+            .world_mut()
+            .query::<(&Target, &RngSeed<WyRand>)>()
+            .iter(app.world())
+            .map(|(target, seed)| (target.0, seed.clone_seed()))
+            .collect();
+
+        seeds.sort_unstable();
+
+        seeds
+    }
+
+    let mut original = spawn_targets(&["archer", "knight", "mage"]);
+    let mut inserted = spawn_targets(&["archer", "cleric", "knight", "mage"]);
+
+    let original_seeds = seeds(&mut original);
+    let inserted_seeds = seeds(&mut inserted);
+
+    assert_eq!(original_seeds.len(), 3);
+    assert_eq!(inserted_seeds.len(), 4);
+
+    for seed in &original_seeds {
+        assert!(inserted_seeds.contains(seed));
+    }
+
+    // Reseeding the source rederives all targets from their keys
+    for app in [&mut original, &mut inserted] {
+        let source = app
+            .world_mut()
+            .query_filtered::<Entity, With<Source>>()
+            .iter(app.world())
+            .next()
+            .unwrap();
+
+        app.world_mut()
+            .entity_mut(source)
+            .insert(RngSeed::<WyRand>::from_seed([6; 8]));
+        app.world_mut().flush();
+    }
+
+    let reseeded = seeds(&mut original);
+
+    for (before, after) in original_seeds.iter().zip(&reseeded) {
+        assert_eq!(before.0, after.0);
+        assert_ne!(before.1, after.1);
+    }
+
+    for seed in &reseeded {
+        assert!(seeds(&mut inserted).contains(seed));
+    }
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pooled_targets_survive_disabling() {
+    use bevy_ecs::entity_disabling::Disabled;
+    use bevy_rand::{
+        commands::RngCommandsExt,
+        observers::{ReseedOnEnable, RngParent},
+    };
+
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component)]
+    struct Target;
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([2; 8]));
+
+    let world = app.world_mut();
+
+    let marked = world
+        .spawn((Target, ReseedOnEnable::<WyRand>::default()))
+        .id();
+    let unmarked = world.spawn(Target).id();
+    let pooled = world
+        .spawn((
+            RngSeed::<WyRand>::from_seed([3; 8]),
+            ReseedOnEnable::<WyRand>::default(),
+        ))
+        .id();
+    let source = world.spawn(Source).id();
+
+    world
+        .commands()
+        .link_entropy_sources::<Source, Target, WyRand>();
+    world.flush();
+
+    world
+        .entity_mut(source)
+        .insert(RngSeed::<WyRand>::from_seed([5; 8]));
+    world.flush();
+
+    let seed = |world: &World, entity| world.get::<RngSeed<WyRand>>(entity).unwrap().clone_seed();
+
+    let marked_seed = seed(world, marked);
+    let unmarked_seed = seed(world, unmarked);
+
+    // Disabling leaves seeds untouched
+    for entity in [marked, unmarked, pooled] {
+        world.entity_mut(entity).insert(Disabled);
+    }
+    world.flush();
+
+    assert_eq!(seed(world, marked), marked_seed);
+    assert_eq!(seed(world, unmarked), unmarked_seed);
+
+    // Reseeding the source cascades to disabled targets exactly once each
+    world
+        .entity_mut(source)
+        .insert(RngSeed::<WyRand>::from_seed([6; 8]));
+    world.flush();
+
+    let mut mirror = Entropy::<WyRand>::from_seed([6; 8]);
+    let mut cascaded: Vec<_> = (0..2).map(|_| mirror.fork_inner_seed()).collect();
+    let mut reseeded = vec![seed(world, marked), seed(world, unmarked)];
+
+    cascaded.sort_unstable();
+    reseeded.sort_unstable();
+
+    assert_eq!(reseeded, cascaded);
+    assert_eq!(world.get::<Entropy<WyRand>>(source), Some(&mirror));
+
+    let marked_seed = seed(world, marked);
+    let unmarked_seed = seed(world, unmarked);
+
+    for entity in [marked, unmarked, pooled] {
+        world.entity_mut(entity).remove::<Disabled>();
+    }
+    world.flush();
+
+    // Marked targets pull a new seed from their source, or the global source if unlinked
+    let mut global = Entropy::<WyRand>::from_seed([2; 8]);
+
+    assert_ne!(seed(world, marked), marked_seed);
+    assert_eq!(seed(world, marked), mirror.fork_inner_seed());
+    assert_eq!(seed(world, pooled), global.fork_inner_seed());
+    assert_eq!(seed(world, unmarked), unmarked_seed);
+
+    for target in [marked, unmarked] {
+        assert_eq!(
+            world
+                .get::<RngParent<WyRand>>(target)
+                .map(RngParent::entity),
+            Some(source)
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn observed_reseeds_fire_only_for_the_observed_target() {
+    use bevy_rand::{
+        commands::{Reseeded, RngCommandsExt},
+        observers::{LinkRngSourceToTarget, LinkedReseeded},
+        plugin::LinkedEntropySources,
+    };
+
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component, Clone, Copy)]
+    struct Target;
+
+    #[derive(Resource, Default)]
+    struct Observed {
+        reseeds: Vec<(Entity, [u8; 8])>,
+        cascades: usize,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<WyRand>::with_seed([2; 8]),
+        LinkedEntropySources::<Source, Target, WyRand>::default(),
+    ))
+    .init_resource::<Observed>();
+
+    let world = app.world_mut();
+
+    let targets: Vec<Entity> = world.spawn_batch(vec![Target; 5]).collect();
+    let source = world.spawn(Source).id();
+    let observed = targets[2];
+
+    let mut commands = world.commands();
+
+    commands.trigger(LinkRngSourceToTarget::<Source, Target, WyRand>::default());
+    commands.reseed_from_global::<WyRand>(source);
+    commands.rng_entity::<WyRand>(observed).observe_reseeds(
+        |trigger: Trigger<Reseeded<WyRand>>, mut observed: ResMut<Observed>| {
+            let seed = trigger.event().seed.clone_seed();
+
+            observed.reseeds.push((trigger.target(), seed));
+        },
+    );
+    commands
+        .rng_entity::<WyRand>(source)
+        .observe_linked_reseeds(
+            |_trigger: Trigger<LinkedReseeded<WyRand>>, mut observed: ResMut<Observed>| {
+                observed.cascades += 1;
+            },
+        );
+    world.flush();
+
+    // Linking happened before observing, so nothing has been observed yet
+    assert!(world.resource::<Observed>().reseeds.is_empty());
+
+    let mut commands = world.commands();
+
+    commands.reseed_from_parent::<WyRand>(targets[0]);
+    commands.reseed_from_parent::<WyRand>(observed);
+    commands.reseed_from_parent::<WyRand>(targets[4]);
+    world.flush();
+
+    let seed = |world: &World, entity| world.get::<RngSeed<WyRand>>(entity).unwrap().clone_seed();
+
+    assert_eq!(
+        world.resource::<Observed>().reseeds,
+        vec![(observed, seed(world, observed))]
+    );
+    assert_eq!(world.resource::<Observed>().cascades, 0);
+
+    // Reseeding the source reseeds every target once, completing a single cascade
+    world.commands().reseed_from_global::<WyRand>(source);
+    world.flush();
+
+    let observed_state = world.resource::<Observed>();
+
+    assert_eq!(observed_state.reseeds.len(), 2);
+    assert_eq!(observed_state.reseeds[1], (observed, seed(world, observed)));
+    assert_eq!(observed_state.cascades, 1);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseeding_linked_targets_forks_from_the_source_only() {
+    use bevy_rand::{
+        commands::RngCommandsExt,
+        observers::{RngLinkIndex, RngParent},
+    };
+
+    #[derive(Component, Default)]
+    struct Source;
+    #[derive(Component, Default)]
+    struct Target;
+
+    let mut app = DeterministicApp::new()
+        .with_rng::<WyRand>([2; 8])
+        .with_relations::<Source, Target, WyRand>();
+
+    let source = app.spawn_linked::<Source, Target, WyRand>(3);
+
+    let world = app.world_mut();
+    let unlinked = world.spawn(Target).id();
+
+    let global = world
+        .query_filtered::<&Entropy<WyRand>, With<Global>>()
+        .single(world)
+        .unwrap()
+        .clone();
+    let source_seed = world.get::<RngSeed<WyRand>>(source).unwrap().clone();
+    let mut source_rng = world.get::<Entropy<WyRand>>(source).unwrap().clone();
+
+    world
+        .commands()
+        .reseed_linked::<Source, Target, WyRand>(source);
+    world.flush();
+
+    // The targets are seeded from the source's current state, in link index order
+    let mut targets: Vec<_> = world
+        .query::<(&RngLinkIndex, &RngSeed<WyRand>)>()
+        .iter(world)
+        .map(|(index, seed)| (index.index(), seed.clone()))
+        .collect();
+
+    targets.sort_unstable_by_key(|(index, _)| *index);
+
+    let expected: Vec<_> = (0..3)
+        .map(|index| (index, source_rng.fork_seed()))
+        .collect();
+
+    assert_eq!(targets, expected);
+    assert_eq!(world.get::<Entropy<WyRand>>(source), Some(&source_rng));
+
+    // Neither the source's seed nor the global source are touched, and nothing new is linked
+    assert_eq!(world.get::<RngSeed<WyRand>>(source), Some(&source_seed));
+    assert_eq!(
+        world
+            .query_filtered::<&Entropy<WyRand>, With<Global>>()
+            .single(world)
+            .unwrap(),
+        &global
+    );
+    assert!(world.get::<RngParent<WyRand>>(unlinked).is_none());
+    assert!(world.get::<RngSeed<WyRand>>(unlinked).is_none());
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rewinding_repeats_the_sequence() {
+    use bevy_rand::{
+        commands::RngCommandsExt, observers::LinkRngSourceToTarget, plugin::LinkedEntropySources,
+    };
+
+    #[derive(Component, Clone, Copy)]
+    struct Target;
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<WyRand>::with_seed([2; 8]),
+        LinkedEntropySources::<Global, Target, WyRand>::default(),
+    ));
+
+    let world = app.world_mut();
+
+    world.spawn_batch(vec![Target; 3]);
+
+    let global = world
+        .query_filtered::<Entity, With<Global>>()
+        .iter(world)
+        .next()
+        .unwrap();
+
+    world.trigger(LinkRngSourceToTarget::<Global, Target, WyRand>::default());
+    world.commands().reseed_from_global::<WyRand>(global);
+    world.flush();
+
+    let mut q_targets = world.query_filtered::<&RngSeed<WyRand>, With<Target>>();
+    let seeds = |world: &mut World, q_targets: &mut QueryState<&RngSeed<WyRand>, With<Target>>| {
+        q_targets
+            .iter(world)
+            .map(RngSeed::clone_seed)
+            .collect::<Vec<_>>()
+    };
+
+    let linked = seeds(world, &mut q_targets);
+
+    let draw = |world: &mut World| {
+        let mut rng = world.get_mut::<Entropy<WyRand>>(global).unwrap();
+
+        [rng.next_u64(), rng.next_u64(), rng.next_u64()]
+    };
+
+    let rewind = |world: &mut World| {
+        world.commands().rng_entity::<WyRand>(global).rewind();
+        world.flush();
+    };
+
+    rewind(world);
+
+    let first = draw(world);
+
+    rewind(world);
+
+    // The sequence repeats exactly, without reseeding linked targets
+    assert_eq!(draw(world), first);
+    assert_eq!(seeds(world, &mut q_targets), linked);
+
+    world
+        .commands()
+        .rng_entity::<WyRand>(global)
+        .rewind_and_cascade();
+    world.flush();
+
+    // Cascading reseeds the targets just as the initial link did, from the same starting state
+    assert_eq!(seeds(world, &mut q_targets), linked);
+    assert_ne!(draw(world), first);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forked_children_are_seeded_in_order_and_linked() {
+    use bevy_rand::{commands::RngCommandsExt, observers::RngParent};
+
+    #[derive(Component)]
+    struct Projectile;
+
+    let mut world = World::new();
+
+    let weapon = world.spawn(RngSeed::<WyRand>::from_seed([3; 8])).id();
+
+    let mut commands = world.commands();
+    let mut weapon_commands = commands.rng_entity::<WyRand>(weapon);
+
+    let first = weapon_commands.fork_child(Projectile).id();
+    let second = weapon_commands.fork_child(Projectile).id();
+    let cross = weapon_commands
+        .fork_child_as::<ChaCha8Rng, _>(Projectile)
+        .id();
+
+    world.flush();
+
+    let mut expected = Entropy::<WyRand>::from_seed([3; 8]);
+
+    for child in [first, second] {
+        assert_eq!(
+            world.get::<RngSeed<WyRand>>(child),
+            Some(&expected.fork_seed())
+        );
+        assert_eq!(
+            world.get::<RngParent<WyRand>>(child).map(RngParent::entity),
+            Some(weapon)
+        );
+    }
+
+    assert_eq!(
+        world.get::<RngSeed<ChaCha8Rng>>(cross),
+        Some(&expected.fork_as_seed::<ChaCha8Rng>())
+    );
+    assert!(world.get::<RngParent<WyRand>>(cross).is_none());
+    assert!(world
+        .get::<bevy_rand::observers::RngChildren<WyRand>>(weapon)
+        .is_some());
+    assert_eq!(world.get::<Entropy<WyRand>>(weapon), Some(&expected));
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseeding_the_world_is_repeatable() {
+    use bevy_rand::{
+        commands::{ReseedWorldPolicy, RngCommandsExt},
+        observers::{LinkRngSourceToTarget, RngLinkKey},
+        plugin::LinkedEntropySources,
+    };
+
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component)]
+    struct Target;
+
+    fn seeds(world: &mut World) -> Vec<(Entity, RngSeed<WyRand>)> {
+        let mut seeds: Vec<_> = world
+            .query::<(Entity, &RngSeed<WyRand>)>()
+            .iter(world)
+            .map(|(entity, seed)| (entity, seed.clone()))
+            .collect();
+
+        seeds.sort_unstable_by_key(|(entity, _)| *entity);
+        seeds
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<WyRand>::with_seed([1; 8]),
+        LinkedEntropySources::<Source, Target, WyRand>::default(),
+    ));
+
+    let world = app.world_mut();
+
+    let source = world
+        .spawn((Source, RngSeed::<WyRand>::from_seed([2; 8])))
+        .id();
+    world.spawn_batch([Target, Target]);
+    world.spawn(RngSeed::<WyRand>::from_seed([3; 8]));
+    world.spawn((
+        RngSeed::<WyRand>::from_seed([4; 8]),
+        RngLinkKey::new("boss"),
+    ));
+
+    world.trigger(LinkRngSourceToTarget::<Source, Target, WyRand>::default());
+    world.commands().reseed_from_global::<WyRand>(source);
+    world.flush();
+
+    let initial = seeds(world);
+
+    for policy in [
+        ReseedWorldPolicy::SkipUnlinked,
+        ReseedWorldPolicy::DeriveByEntityKey,
+        ReseedWorldPolicy::ForkFromGlobal,
+    ] {
+        let mut runs = Vec::new();
+
+        for master in [[5; 8], [6; 8], [5; 8]] {
+            world.commands().reseed_world::<WyRand>(master, policy);
+            world.flush();
+
+            runs.push(seeds(world));
+        }
+
+        assert_eq!(runs[0], runs[2]);
+        assert_ne!(runs[0], runs[1]);
+
+        // The source and its targets are reseeded unless unlinked sources are skipped.
+        let changed = runs[0]
+            .iter()
+            .zip(&initial)
+            .filter(|(after, before)| after != before)
+            .count();
+
+        match policy {
+            ReseedWorldPolicy::SkipUnlinked => assert_eq!(changed, 1),
+            _ => assert_eq!(changed, initial.len()),
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn heterogeneous_chains_cascade_from_one_reseed() {
+    use bevy_rand::{observers::LinkRngSourceToTarget, plugin::LinkedEntropySources};
+
+    #[derive(Component)]
+    struct Leader;
+    #[derive(Component)]
+    struct Member;
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]),
+        LinkedEntropySources::<Global, Leader, WyRand>::from_source_rng::<ChaCha8Rng>(),
+        LinkedEntropySources::<Leader, Member, WyRand>::default(),
+    ));
+
+    let world = app.world_mut();
+
+    let global = world
+        .query_filtered::<Entity, With<Global>>()
+        .iter(world)
+        .next()
+        .unwrap();
+    let leader = world.spawn((Leader, RngSeed::<WyRand>::default())).id();
+    let members: Vec<_> = world.spawn_batch([Member, Member]).collect();
+
+    world.trigger(LinkRngSourceToTarget::<Global, Leader, WyRand>::default());
+    world.trigger(LinkRngSourceToTarget::<Leader, Member, WyRand>::default());
+    world.flush();
+
+    world
+        .entity_mut(global)
+        .insert(RngSeed::<ChaCha8Rng>::from_seed([2; 32]));
+    world.flush();
+
+    let mut global_rng = Entropy::<ChaCha8Rng>::from_seed([2; 32]);
+    let leader_seed = global_rng.fork_as_seed::<WyRand>();
+    let mut leader_rng = Entropy::<WyRand>::from_seed(leader_seed.clone_seed());
+
+    assert_eq!(world.get::<Entropy<ChaCha8Rng>>(global), Some(&global_rng));
+    assert_eq!(world.get::<RngSeed<WyRand>>(leader), Some(&leader_seed));
+
+    let mut member_seeds: Vec<_> = members
+        .iter()
+        .map(|&member| world.get::<RngSeed<WyRand>>(member).unwrap().clone())
+        .collect();
+    let mut expected: Vec<_> = (0..2).map(|_| leader_rng.fork_seed()).collect();
+
+    member_seeds.sort_unstable_by_key(RngSeed::as_u64);
+    expected.sort_unstable_by_key(RngSeed::as_u64);
+
+    assert_eq!(member_seeds, expected);
+    assert_eq!(world.get::<Entropy<WyRand>>(leader), Some(&leader_rng));
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn heterogeneous_links_reseed_disabled_targets() {
+    use bevy_ecs::entity_disabling::Disabled;
+    use bevy_rand::{observers::LinkRngSourceToTarget, plugin::LinkedEntropySources};
+
+    #[derive(Component)]
+    struct Leader;
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]),
+        LinkedEntropySources::<Global, Leader, WyRand>::from_source_rng::<ChaCha8Rng>(),
+    ));
+
+    let world = app.world_mut();
+
+    let global = world
+        .query_filtered::<Entity, With<Global>>()
+        .iter(world)
+        .next()
+        .unwrap();
+    let leaders: Vec<_> = world.spawn_batch([Leader, Leader]).collect();
+
+    world.trigger(LinkRngSourceToTarget::<Global, Leader, WyRand>::default());
+    world.flush();
+
+    world.entity_mut(leaders[1]).insert(Disabled);
+    world
+        .entity_mut(global)
+        .insert(RngSeed::<ChaCha8Rng>::from_seed([2; 32]));
+    world.flush();
+
+    // Both leaders are reseeded in link order, including the disabled one
+    let mut global_rng = Entropy::<ChaCha8Rng>::from_seed([2; 32]);
+    let expected: Vec<_> = (0..2)
+        .map(|_| global_rng.fork_as_seed::<WyRand>())
+        .collect();
+    let seeds: Vec<_> = leaders
+        .iter()
+        .map(|&leader| world.get::<RngSeed<WyRand>>(leader).unwrap().clone())
+        .collect();
+
+    assert_eq!(seeds, expected);
+    assert_eq!(world.get::<Entropy<ChaCha8Rng>>(global), Some(&global_rng));
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn removed_linked_observers_no_longer_propagate() {
+    use bevy_ecs::observer::Observer;
+    use bevy_rand::{
+        commands::RngCommandsExt,
+        observers::{has_rng_observers, remove_rng_observers, RngObservers},
+    };
+
+    #[derive(Component, Default)]
+    struct Source;
+    #[derive(Component, Default)]
+    struct Target;
+
+    let mut app = DeterministicApp::new()
+        .with_rng::<WyRand>([2; 8])
+        .with_relations::<Source, Target, WyRand>();
+
+    let world = app.world();
+
+    let registry = world.resource::<RngObservers>();
+    let linked = registry.observers::<Source, Target, WyRand>().to_vec();
+
+    assert_eq!(linked.len(), 2);
+    assert_eq!(registry.iter().count(), 7);
+    assert!(linked
+        .iter()
+        .all(|&observer| world.get::<Observer>(observer).is_some()));
+
+    let source = app.spawn_linked::<Source, Target, WyRand>(1);
+
+    let linked_seeds = app.seeds_of::<Target, WyRand>();
+
+    assert_eq!(linked_seeds.len(), 1);
+
+    let world = app.world_mut();
+
+    remove_rng_observers::<Source, Target, WyRand>(world);
+
+    assert!(!has_rng_observers::<Source, Target, WyRand>(world));
+    assert!(linked
+        .iter()
+        .all(|&observer| !world.entities().contains(observer)));
+
+    world.commands().reseed_from_global::<WyRand>(source);
+    world.flush();
+
+    // The source is still reseeded from the global source, but no longer propagates.
+    assert_eq!(app.seeds_of::<Target, WyRand>(), linked_seeds);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn link_indices_keep_reseeds_aligned_across_saves() {
+    use bevy_rand::{
+        commands::RngCommandsExt,
+        observers::{LinkRngSourceToTarget, RngLinkIndex},
+    };
+
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component)]
+    struct Target(&'static str);
+
+    /// Spawns the targets in the given order, then links them to a fresh source and reseeds it.
+    fn reseeded(targets: &[(&'static str, Option<RngLinkIndex>)]) -> Vec<(&'static str, u32, u64)> {
+        let mut app = DeterministicApp::new()
+            .with_rng::<WyRand>([2; 8])
+            .with_relations::<Source, Target, WyRand>();
+
+        let world = app.world_mut();
+
+        for (name, index) in targets {
+            let mut target = world.spawn(Target(name));
+
+            if let Some(index) = index {
+                target.insert(*index);
+            }
+        }
+
+        let source = world.spawn(Source).id();
+
+        world.trigger(LinkRngSourceToTarget::<Source, Target, WyRand>::default());
+        world.commands().reseed_from_global::<WyRand>(source);
+        world.flush();
+
+        world
+            .entity_mut(source)
+            .insert(RngSeed::<WyRand>::from_seed([7; 8]));
+        world.flush();
+
+        let mut seeds: Vec<_> = world
+            .query::<(&Target, &RngLinkIndex, &RngSeed<WyRand>)>()
+            .iter(world)
+            .map(|(target, index, seed)| (target.0, index.index(), seed.as_u64()))
+            .collect();
+
+        seeds.sort_unstable();
+        seeds
+    }
+
+    let original = reseeded(&[("archer", None), ("knight", None), ("mage", None)]);
+
+    // Indices are assigned densely in link order
+    assert_eq!(
+        original
+            .iter()
+            .map(|(_, index, _)| *index)
+            .collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+
+    // Loading the save spawns the targets as new entities in another order, with their indices
+    let loaded = reseeded(
+        &original
+            .iter()
+            .rev()
+            .map(|(name, index, _)| (*name, Some(RngLinkIndex::new(*index))))
+            .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(loaded, original);
+
+    // Without the indices, the seeds follow the new order instead
+    let unindexed = reseeded(&[("mage", None), ("knight", None), ("archer", None)]);
+
+    assert_ne!(unindexed, original);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn link_requests_derive_extra_entropy_without_disturbing_seeds() {
+    use bevy_rand::{
+        commands::RngCommandsExt,
+        observers::{LinkRngSourceToTarget, RngExtraEntropy, RngLinkRequest},
+    };
+
+    #[derive(Component)]
+    struct Source;
+    #[derive(Component)]
+    struct Target(&'static str);
+
+    /// Links the targets to a fresh source and reseeds it, returning each target's seed, first
+    /// draw and extra entropy.
+    fn cascade(requests: bool) -> Vec<(&'static str, u64, u64, Option<Vec<u8>>)> {
+        let mut app = DeterministicApp::new()
+            .with_rng::<WyRand>([2; 8])
+            .with_relations::<Source, Target, WyRand>();
+
+        let world = app.world_mut();
+
+        world.spawn(Target("plain"));
+
+        let mut chunk = world.spawn(Target("chunk"));
+
+        if requests {
+            chunk.insert(RngLinkRequest::extra_bytes(64).with_label("material"));
+        }
+
+        let source = world.spawn(Source).id();
+
+        world.trigger(LinkRngSourceToTarget::<Source, Target, WyRand>::default());
+        world.commands().reseed_from_global::<WyRand>(source);
+        world.flush();
+
+        world
+            .entity_mut(source)
+            .insert(RngSeed::<WyRand>::from_seed([7; 8]));
+        world.flush();
+
+        let mut targets: Vec<_> = world
+            .query::<(
+                &Target,
+                &RngSeed<WyRand>,
+                &Entropy<WyRand>,
+                Option<&RngExtraEntropy>,
+            )>()
+            .iter(world)
+            .map(|(target, seed, rng, extra)| {
+                (
+                    target.0,
+                    seed.as_u64(),
+                    rng.clone().next_u64(),
+                    extra.map(|extra| extra.bytes().to_vec()),
+                )
+            })
+            .collect();
+
+        targets.sort_unstable();
+        targets
+    }
+
+    let requested = cascade(true);
+    let plain = cascade(false);
+
+    // Seeds and sequences are the same with and without the request
+    assert_eq!(
+        requested
+            .iter()
+            .map(|(name, seed, draw, _)| (*name, *seed, *draw))
+            .collect::<Vec<_>>(),
+        plain
+            .iter()
+            .map(|(name, seed, draw, _)| (*name, *seed, *draw))
+            .collect::<Vec<_>>(),
+    );
+
+    let extra = requested[0].3.as_ref().unwrap();
+
+    assert_eq!(requested[0].0, "chunk");
+    assert_eq!(extra.len(), 64);
+    assert_eq!(extra[..8], [154, 176, 174, 167, 218, 150, 18, 138]);
+    assert_eq!(
+        requested
+            .iter()
+            .map(|(name, seed, ..)| (*name, *seed))
+            .collect::<Vec<_>>(),
+        [
+            ("chunk", 3410608042435092909),
+            ("plain", 15734538039161424579)
+        ]
+    );
+    assert!(requested[1].3.is_none());
+    assert!(plain.iter().all(|(.., extra)| extra.is_none()));
+
+    // The extra entropy is pinned by the seed
+    assert_eq!(requested, cascade(true));
+}
+
+#[cfg(feature = "experimental")]
+#[derive(Component)]
+struct Monster;
+
+#[cfg(feature = "experimental")]
+fn monster_seeds(app: &mut App) -> Vec<(Entity, RngSeed<WyRand>)> {
+    let world = app.world_mut();
+
+    let mut seeds: Vec<_> = world
+        .query_filtered::<(Entity, &RngSeed<WyRand>), With<Monster>>()
+        .iter(world)
+        .map(|(entity, seed)| (entity, seed.clone()))
+        .collect();
+
+    seeds.sort_by_key(|(entity, _)| *entity);
+
+    seeds
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn auto_rng_seeds_monsters_deterministically() {
+    use bevy_rand::plugin::AutoRngPlugin;
+
+    let run = || {
+        let mut app = App::new();
+
+        app.add_plugins((
+            EntropyPlugin::<WyRand>::with_seed([1; 8]),
+            AutoRngPlugin::<Monster, WyRand>::default(),
+        ));
+
+        let world = app.world_mut();
+
+        world.spawn(Monster);
+        world.spawn(Monster);
+        let seeded = world
+            .spawn((Monster, RngSeed::<WyRand>::from_seed([9; 8])))
+            .id();
+        app.update();
+
+        app.world_mut().commands().spawn(Monster);
+        app.update();
+
+        (app, seeded)
+    };
+
+    let (mut app, seeded) = run();
+    let seeds = monster_seeds(&mut app);
+
+    assert_eq!(seeds, monster_seeds(&mut run().0));
+    assert_eq!(seeds.len(), 4);
+
+    // Pre-seeded monsters are left alone, and the others all get distinct seeds
+    let (unseeded, preseeded): (Vec<_>, Vec<_>) =
+        seeds.iter().partition(|(entity, _)| *entity != seeded);
+
+    assert_eq!(preseeded, [&(seeded, RngSeed::<WyRand>::from_seed([9; 8]))]);
+    assert!(unseeded
+        .iter()
+        .enumerate()
+        .all(|(i, (_, seed))| unseeded[..i].iter().all(|(_, other)| other != seed)));
+
+    // Linked monsters are reseeded along with the global source
+    let world = app.world_mut();
+    let global = world
+        .query_filtered::<Entity, (With<Global>, With<RngSeed<WyRand>>)>()
+        .iter(world)
+        .next()
+        .unwrap();
+
+    world
+        .entity_mut(global)
+        .insert(RngSeed::<WyRand>::from_seed([2; 8]));
+    world.flush();
+
+    let reseeded = monster_seeds(&mut app);
+
+    for ((entity, before), (_, after)) in seeds.iter().zip(&reseeded) {
+        assert_eq!(*entity == seeded, before == after);
+    }
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn auto_rng_seeds_from_labelled_sources() {
+    use bevy_rand::{commands::RngCommandsExt, observers::RngParent, plugin::AutoRngPlugin};
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<WyRand>::with_seed([1; 8]),
+        AutoRngPlugin::<Monster, WyRand>::default().linked_to_label("spawner"),
+    ));
+
+    let world = app.world_mut();
+
+    let spawner = world.spawn(RngSeed::<WyRand>::from_seed([3; 8])).id();
+
+    world
+        .commands()
+        .rng_entity::<WyRand>(spawner)
+        .labelled("spawner");
+    world.flush();
+
+    let monster = world.spawn(Monster).id();
+    world.flush();
+
+    let mut expected = Entropy::<WyRand>::from_seed([3; 8]);
+
+    assert_eq!(
+        world.get::<RngSeed<WyRand>>(monster),
+        Some(&expected.fork_seed())
+    );
+    assert_eq!(
+        world
+            .get::<RngParent<WyRand>>(monster)
+            .map(RngParent::entity),
+        Some(spawner)
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseeding_from_chosen_global_sources() {
+    use bevy_rand::{commands::RngCommandsExt, observers::RngError};
+
+    #[derive(Resource, Default)]
+    struct Errors(Vec<(Entity, RngError)>);
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]))
+        .init_resource::<Errors>();
+
+    let world = app.world_mut();
+
+    world.add_observer(|trigger: Trigger<RngError>, mut errors: ResMut<Errors>| {
+        errors.0.push((trigger.target(), *trigger.event()));
+    });
+
+    let first = world
+        .query_filtered::<Entity, With<Global>>()
+        .single(world)
+        .unwrap();
+    let second = world
+        .spawn((Global, RngSeed::<WyRand>::from_seed([2; 8])))
+        .id();
+    let targets: Vec<_> = (0..4)
+        .map(|_| world.spawn(RngSeed::<WyRand>::from_seed([0; 8])).id())
+        .collect();
+
+    let mut expected_first = world.get::<Entropy<WyRand>>(first).unwrap().clone();
+    let mut expected_second = world.get::<Entropy<WyRand>>(second).unwrap().clone();
+
+    for (index, &target) in targets.iter().enumerate() {
+        let source = if index % 2 == 0 { first } else { second };
+
+        world
+            .commands()
+            .rng_entity::<WyRand>(target)
+            .reseed_from_global_entity(source);
+    }
+
+    world.flush();
+
+    let seed = |world: &World, entity| world.get::<RngSeed<WyRand>>(entity).unwrap().clone_seed();
+
+    assert_eq!(
+        seed(world, targets[0]),
+        expected_first.fork_seed().clone_seed()
+    );
+    assert_eq!(
+        seed(world, targets[1]),
+        expected_second.fork_seed().clone_seed()
+    );
+    assert_eq!(
+        seed(world, targets[2]),
+        expected_first.fork_seed().clone_seed()
+    );
+    assert_eq!(
+        seed(world, targets[3]),
+        expected_second.fork_seed().clone_seed()
+    );
+    assert_eq!(
+        seed(world, targets[0]),
+        [157, 69, 174, 11, 136, 136, 235, 100]
+    );
+    assert_eq!(seed(world, targets[1]), [170, 83, 49, 135, 100, 1, 124, 34]);
+
+    // With two globals, the default event can't tell which one to fork from
+    world.commands().reseed_from_global::<WyRand>(targets[0]);
+    world.flush();
+
+    assert_eq!(
+        seed(world, targets[0]),
+        [157, 69, 174, 11, 136, 136, 235, 100]
+    );
+
+    // Sources without an `Entropy<WyRand>` are reported rather than silently ignored
+    let missing = world.spawn_empty().id();
+
+    world
+        .commands()
+        .rng_entity::<WyRand>(targets[0])
+        .reseed_from_global_entity(missing);
+    world.flush();
+
+    assert_eq!(
+        world.resource::<Errors>().0,
+        vec![(targets[0], RngError::MissingSource(missing))]
+    );
+    assert_eq!(
+        seed(world, targets[0]),
+        [157, 69, 174, 11, 136, 136, 235, 100]
+    );
 }
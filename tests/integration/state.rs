@@ -0,0 +1,135 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_prng::{ChaCha8Rng, WyRand};
+use bevy_rand::{
+    global::Global,
+    plugin::EntropyPlugin,
+    seed::RngSeed,
+    state::{SeedPolicy, StateSeedPlugin},
+    traits::SeedSource,
+};
+use bevy_state::{app::StatesPlugin, prelude::*};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, States)]
+enum GameState {
+    #[default]
+    Menu,
+    NewRun,
+    Shop,
+}
+
+fn global_seed<R: bevy_prng::EntropySource>(app: &mut App) -> R::Seed
+where
+    R::Seed: Send + Sync + Clone,
+{
+    app.world_mut()
+        .query_filtered::<&RngSeed<R>, With<Global>>()
+        .iter(app.world())
+        .next()
+        .unwrap()
+        .clone_seed()
+}
+
+fn enter(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn state_seed_policies() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        StatesPlugin,
+        EntropyPlugin::<WyRand>::with_seed([2; 8]),
+        EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+        StateSeedPlugin::<GameState, WyRand>::new()
+            .on_enter(GameState::NewRun, SeedPolicy::DeriveFromGlobal("run"))
+            .on_enter(GameState::Menu, SeedPolicy::Fixed([5; 8])),
+        StateSeedPlugin::<GameState, ChaCha8Rng>::new()
+            .on_enter(GameState::Shop, SeedPolicy::FromOsRng),
+    ))
+    .init_state::<GameState>();
+
+    app.update();
+
+    // Entering the initial state applies its policy
+    assert_eq!(global_seed::<WyRand>(&mut app), [5; 8]);
+
+    enter(&mut app, GameState::NewRun);
+
+    let first_run = global_seed::<WyRand>(&mut app);
+
+    assert_eq!(u64::from_ne_bytes(first_run), 11508580396356171277);
+
+    enter(&mut app, GameState::Shop);
+
+    // Only the ChaCha8 source is configured for the shop
+    assert_eq!(global_seed::<WyRand>(&mut app), first_run);
+    assert_ne!(global_seed::<ChaCha8Rng>(&mut app), [2; 32]);
+
+    enter(&mut app, GameState::NewRun);
+
+    // Each new run derives a new seed from the previous one
+    let second_run = global_seed::<WyRand>(&mut app);
+
+    assert_ne!(second_run, first_run);
+
+    // The derivation is deterministic, so restarting from the same seed repeats the sequence
+    enter(&mut app, GameState::Menu);
+    enter(&mut app, GameState::NewRun);
+
+    assert_eq!(global_seed::<WyRand>(&mut app), first_run);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn state_reseed_cascades_to_linked_targets() {
+    use bevy_rand::{observers::LinkRngSourceToTarget, plugin::LinkedEntropySources};
+
+    #[derive(Component, Clone, Copy)]
+    struct Target;
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        StatesPlugin,
+        EntropyPlugin::<WyRand>::with_seed([2; 8]),
+        LinkedEntropySources::<Global, Target, WyRand>::default(),
+        StateSeedPlugin::<GameState, WyRand>::new()
+            .on_enter(GameState::NewRun, SeedPolicy::DeriveFromGlobal("run")),
+    ))
+    .init_state::<GameState>()
+    .add_systems(Startup, |mut commands: Commands| {
+        commands.spawn_batch(vec![(Target, RngSeed::<WyRand>::from_seed([0; 8])); 3]);
+        commands.trigger(LinkRngSourceToTarget::<Global, Target, WyRand>::default());
+    });
+
+    app.update();
+
+    let targets = |app: &mut App| -> Vec<[u8; 8]> {
+        app.world_mut()
+            .query_filtered::<&RngSeed<WyRand>, With<Target>>()
+            .iter(app.world())
+            .map(RngSeed::clone_seed)
+            .collect()
+    };
+
+    let before = targets(&mut app);
+
+    assert!(before.iter().all(|seed| seed == &[0; 8]));
+
+    enter(&mut app, GameState::NewRun);
+
+    let after = targets(&mut app);
+
+    assert_eq!(after.len(), 3);
+    assert!(after.iter().all(|seed| seed != &[0; 8]));
+}
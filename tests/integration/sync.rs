@@ -0,0 +1,117 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_prng::{ChaCha8Rng, WyRand};
+use bevy_rand::{
+    global::GlobalEntropy,
+    plugin::EntropyPlugin,
+    sync::{ApplyGlobalSeed, GlobalSeedChanged, SeedGeneration, SeedSyncPlugin},
+};
+use rand_core::RngCore;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+fn peer(seed: [u8; 32]) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((
+        EntropyPlugin::<ChaCha8Rng>::with_seed(seed),
+        SeedSyncPlugin::<ChaCha8Rng>::default(),
+    ));
+
+    app
+}
+
+fn drain_changes(app: &mut App) -> Vec<GlobalSeedChanged<ChaCha8Rng>> {
+    app.world_mut()
+        .resource_mut::<Events<GlobalSeedChanged<ChaCha8Rng>>>()
+        .drain()
+        .collect()
+}
+
+fn checksum(app: &mut App) -> u64 {
+    app.world_mut()
+        .run_system_cached(|mut rng: GlobalEntropy<ChaCha8Rng>| {
+            (0..16).fold(0u64, |sum, _| sum ^ rng.next_u64())
+        })
+        .unwrap()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn server_seed_is_applied_to_client() {
+    let mut server = peer([1; 32]);
+    let mut client = peer([2; 32]);
+
+    server.update();
+    client.update();
+
+    let changes = drain_changes(&mut server);
+
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].generation, 1);
+    assert_eq!(changes[0].seed_bytes, [1; 32]);
+
+    for change in changes {
+        #[cfg(feature = "serialize")]
+        let change: GlobalSeedChanged<ChaCha8Rng> =
+            ron::from_str(&ron::to_string(&change).unwrap()).unwrap();
+
+        client.world_mut().send_event(ApplyGlobalSeed::from(change));
+    }
+
+    client.update();
+
+    assert_eq!(
+        client
+            .world()
+            .resource::<SeedGeneration<ChaCha8Rng>>()
+            .get(),
+        1
+    );
+    assert_eq!(checksum(&mut server), checksum(&mut client));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn out_of_order_and_mismatched_seeds_are_ignored() {
+    let mut client = peer([2; 32]);
+
+    client.update();
+
+    let mut expected = peer([3; 32]);
+
+    expected.update();
+
+    client
+        .world_mut()
+        .send_event(ApplyGlobalSeed::<ChaCha8Rng>::new([3; 32], 5));
+    client.update();
+
+    // Applied seeds are re-emitted with their generation
+    let changes = drain_changes(&mut client);
+
+    assert_eq!(changes.last().map(|change| change.generation), Some(5));
+
+    // An older generation arriving late must not revert the seed
+    client
+        .world_mut()
+        .send_event(ApplyGlobalSeed::<ChaCha8Rng>::new([4; 32], 3));
+    client.update();
+
+    // A seed for a different algorithm must not be applied
+    let mut wrong = ApplyGlobalSeed::<ChaCha8Rng>::new([4; 32], 9);
+    wrong.rng = String::from(<WyRand as bevy_reflect::TypePath>::type_path());
+
+    client.world_mut().send_event(wrong);
+    client.update();
+
+    assert_eq!(
+        client
+            .world()
+            .resource::<SeedGeneration<ChaCha8Rng>>()
+            .get(),
+        5
+    );
+    assert_eq!(checksum(&mut client), checksum(&mut expected));
+}
@@ -0,0 +1,254 @@
+// Save files and scenes refer to types by their type paths, so renaming or moving any of these
+// types silently orphans existing saves. If one of these assertions fails, keep the old path
+// loading with `bevy_rand::legacy::register_legacy_rng_paths` before updating the assertion.
+use bevy_prng::{
+    ChaCha12Rng, ChaCha20Rng, ChaCha8Rng, Seed512, WyRand, Xoroshiro128Plus, Xoroshiro128PlusPlus,
+    Xoroshiro128StarStar, Xoroshiro64Star, Xoroshiro64StarStar, Xoshiro128Plus, Xoshiro128PlusPlus,
+    Xoshiro128StarStar, Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar, Xoshiro512Plus,
+    Xoshiro512PlusPlus, Xoshiro512StarStar,
+};
+use bevy_rand::{
+    derived::DerivedFromRng,
+    global::GlobalEntropyResource,
+    label::RngLabel,
+    prelude::Entropy,
+    seed::{ForkCounters, RngSeed, SeedDispenser},
+};
+use bevy_reflect::TypePath;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_type_paths_are_stable() {
+    assert_eq!(
+        Entropy::<WyRand>::type_path(),
+        "bevy_rand::component::Entropy<bevy_prng::WyRand>"
+    );
+    assert_eq!(
+        Entropy::<ChaCha8Rng>::type_path(),
+        "bevy_rand::component::Entropy<bevy_prng::ChaCha8Rng>"
+    );
+    assert_eq!(
+        RngSeed::<WyRand>::type_path(),
+        "bevy_rand::seed::RngSeed<bevy_prng::WyRand>"
+    );
+    assert_eq!(
+        GlobalEntropyResource::<WyRand>::type_path(),
+        "bevy_rand::global::GlobalEntropyResource<bevy_prng::WyRand>"
+    );
+    assert_eq!(
+        SeedDispenser::<WyRand>::type_path(),
+        "bevy_rand::seed::SeedDispenser<bevy_prng::WyRand>"
+    );
+    assert_eq!(
+        DerivedFromRng::<WyRand>::type_path(),
+        "bevy_rand::derived::DerivedFromRng<bevy_prng::WyRand>"
+    );
+    assert_eq!(ForkCounters::type_path(), "bevy_rand::seed::ForkCounters");
+    assert_eq!(RngLabel::type_path(), "bevy_rand::label::RngLabel");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prng_type_paths_are_stable() {
+    let paths = [
+        WyRand::type_path(),
+        ChaCha8Rng::type_path(),
+        ChaCha12Rng::type_path(),
+        ChaCha20Rng::type_path(),
+        Xoshiro512StarStar::type_path(),
+        Xoshiro512PlusPlus::type_path(),
+        Xoshiro512Plus::type_path(),
+        Xoshiro256StarStar::type_path(),
+        Xoshiro256PlusPlus::type_path(),
+        Xoshiro256Plus::type_path(),
+        Xoroshiro128StarStar::type_path(),
+        Xoroshiro128PlusPlus::type_path(),
+        Xoroshiro128Plus::type_path(),
+        Xoshiro128StarStar::type_path(),
+        Xoshiro128PlusPlus::type_path(),
+        Xoshiro128Plus::type_path(),
+        Xoroshiro64StarStar::type_path(),
+        Xoroshiro64Star::type_path(),
+        Seed512::type_path(),
+    ];
+
+    assert_eq!(
+        paths,
+        [
+            "bevy_prng::WyRand",
+            "bevy_prng::ChaCha8Rng",
+            "bevy_prng::ChaCha12Rng",
+            "bevy_prng::ChaCha20Rng",
+            "bevy_prng::Xoshiro512StarStar",
+            "bevy_prng::Xoshiro512PlusPlus",
+            "bevy_prng::Xoshiro512Plus",
+            "bevy_prng::Xoshiro256StarStar",
+            "bevy_prng::Xoshiro256PlusPlus",
+            "bevy_prng::Xoshiro256Plus",
+            "bevy_prng::Xoroshiro128StarStar",
+            "bevy_prng::Xoroshiro128PlusPlus",
+            "bevy_prng::Xoroshiro128Plus",
+            "bevy_prng::Xoshiro128StarStar",
+            "bevy_prng::Xoshiro128PlusPlus",
+            "bevy_prng::Xoshiro128Plus",
+            "bevy_prng::Xoroshiro64StarStar",
+            "bevy_prng::Xoroshiro64Star",
+            "bevy_prng::Seed512",
+        ]
+    );
+
+    #[cfg(feature = "rand_pcg")]
+    assert_eq!(
+        [
+            bevy_prng::Pcg32::type_path(),
+            bevy_prng::Pcg64::type_path(),
+            bevy_prng::Pcg64Mcg::type_path(),
+        ],
+        [
+            "bevy_prng::Pcg32",
+            "bevy_prng::Pcg64",
+            "bevy_prng::Pcg64Mcg"
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn feature_type_paths_are_stable() {
+    #[cfg(all(feature = "bevy_time", feature = "std"))]
+    {
+        use bevy_rand::chance::{ChancePerSecond, RandomTimer, TimerDistribution};
+
+        assert_eq!(
+            ChancePerSecond::type_path(),
+            "bevy_rand::chance::ChancePerSecond"
+        );
+        assert_eq!(
+            TimerDistribution::type_path(),
+            "bevy_rand::chance::TimerDistribution"
+        );
+        assert_eq!(RandomTimer::type_path(), "bevy_rand::chance::RandomTimer");
+    }
+
+    #[cfg(feature = "bevy_math")]
+    assert_eq!(
+        bevy_rand::jitter::FrameJitter::type_path(),
+        "bevy_rand::jitter::FrameJitter"
+    );
+
+    #[cfg(feature = "rand")]
+    {
+        assert_eq!(
+            bevy_rand::shuffle::ShuffleBag::<u32>::type_path(),
+            "bevy_rand::shuffle::ShuffleBag<u32>"
+        );
+        assert_eq!(
+            bevy_rand::weighted::WeightedIndexTable::<u32>::type_path(),
+            "bevy_rand::weighted::WeightedIndexTable<u32>"
+        );
+    }
+
+    #[cfg(feature = "rand_distr")]
+    {
+        use bevy_rand::distributions::{ExpParams, NormalParams, PoissonParams};
+
+        assert_eq!(
+            NormalParams::type_path(),
+            "bevy_rand::distributions::NormalParams"
+        );
+        assert_eq!(
+            PoissonParams::type_path(),
+            "bevy_rand::distributions::PoissonParams"
+        );
+        assert_eq!(
+            ExpParams::type_path(),
+            "bevy_rand::distributions::ExpParams"
+        );
+    }
+
+    #[cfg(feature = "experimental")]
+    {
+        use bevy_rand::observers::{RngExtraEntropy, RngLinkIndex, RngLinkRequest};
+
+        assert_eq!(
+            RngLinkIndex::type_path(),
+            "bevy_rand::observers::RngLinkIndex"
+        );
+        assert_eq!(
+            RngLinkRequest::type_path(),
+            "bevy_rand::observers::RngLinkRequest"
+        );
+        assert_eq!(
+            RngExtraEntropy::type_path(),
+            "bevy_rand::observers::RngExtraEntropy"
+        );
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_rng_paths_are_stable() {
+    use bevy_rand::legacy::LegacyEntropyComponent;
+
+    assert_eq!(
+        LegacyEntropyComponent::<WyRand>::type_path(),
+        "bevy_rand::component::EntropyComponent<bevy_prng::WyRand>"
+    );
+}
+
+#[cfg(all(feature = "bevy_scene", feature = "serialize"))]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scenes_with_legacy_rng_paths_load() {
+    use bevy_app::App;
+    use bevy_ecs::{entity::EntityHashMap, prelude::*};
+    use bevy_rand::{legacy::LegacyEntropyComponent, plugin::EntropyPlugin};
+    use bevy_scene::serde::SceneDeserializer;
+    use rand_core::SeedableRng;
+    use serde::de::DeserializeSeed;
+
+    // A scene saved before `EntropyComponent` was renamed to `Entropy`
+    const SCENE: &str = r#"(
+  resources: {},
+  entities: {
+    4294967301: (
+      components: {
+        "bevy_rand::component::EntropyComponent<bevy_prng::WyRand>": (((
+          state: 506381209866536711,
+        ))),
+      },
+    ),
+  },
+)"#;
+
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<WyRand>::with_seed([1; 8]));
+
+    let world = app.world_mut();
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let scene = SceneDeserializer {
+        type_registry: &registry.read(),
+    }
+    .deserialize(&mut ron::Deserializer::from_str(SCENE).unwrap())
+    .unwrap();
+
+    let mut entities = EntityHashMap::default();
+
+    scene.write_to_world(world, &mut entities).unwrap();
+    world.flush();
+
+    let entity = *entities.values().next().unwrap();
+
+    assert!(world
+        .get::<LegacyEntropyComponent<WyRand>>(entity)
+        .is_none());
+    assert_eq!(
+        world.get::<Entropy<WyRand>>(entity),
+        Some(&Entropy::from_seed([7; 8]))
+    );
+}